@@ -0,0 +1,218 @@
+//! KULTA reconcile loop benchmarks
+//!
+//! Run with: cargo bench --bench reconcile_benchmark
+//!
+//! Baselines for the pure (non-I/O) functions on the reconcile hot path.
+//! See BENCHMARKS.md for soft targets.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use k8s_openapi::api::core::v1::{
+    Container, ContainerPort, EnvVar, PodSpec, PodTemplateSpec, ResourceRequirements,
+};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use kube::api::ObjectMeta;
+use kulta::controller::rollout::{
+    build_replicaset, calculate_replica_split_with_surge, compute_desired_status,
+    compute_pod_template_hash, validate_rollout,
+};
+use kulta::crd::rollout::{
+    CanaryStep, CanaryStrategy, PauseDuration, Phase, Rollout, RolloutSpec, RolloutStatus,
+    RolloutStrategy,
+};
+use std::collections::BTreeMap;
+
+fn create_container(index: usize) -> Container {
+    let resources: BTreeMap<String, Quantity> = [
+        ("cpu".to_string(), Quantity("100m".to_string())),
+        ("memory".to_string(), Quantity("128Mi".to_string())),
+    ]
+    .into();
+
+    Container {
+        name: format!("container-{}", index),
+        image: Some(format!("registry.example.com/app-{}:v1.2.{}", index, index)),
+        ports: Some(vec![ContainerPort {
+            container_port: 8080 + index as i32,
+            name: Some(format!("http-{}", index)),
+            ..Default::default()
+        }]),
+        env: Some(
+            (0..5)
+                .map(|i| EnvVar {
+                    name: format!("ENV_{}", i),
+                    value: Some(format!("value-{}-{}", index, i)),
+                    ..Default::default()
+                })
+                .collect(),
+        ),
+        resources: Some(ResourceRequirements {
+            requests: Some(resources.clone()),
+            limits: Some(resources),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn create_pod_template(containers: usize) -> PodTemplateSpec {
+    PodTemplateSpec {
+        metadata: Some(ObjectMeta {
+            labels: Some([("app".to_string(), "bench-app".to_string())].into()),
+            annotations: Some([("team".to_string(), "platform".to_string())].into()),
+            ..Default::default()
+        }),
+        spec: Some(PodSpec {
+            containers: (0..containers).map(create_container).collect(),
+            ..Default::default()
+        }),
+    }
+}
+
+fn create_canary_rollout(step_count: usize) -> Rollout {
+    let steps = (0..step_count)
+        .map(|i| CanaryStep {
+            set_weight: Some(((i + 1) * 100 / step_count) as i32),
+            pause: Some(PauseDuration {
+                duration: Some("30s".to_string()),
+            }),
+            analysis: None,
+        })
+        .collect();
+
+    Rollout {
+        metadata: ObjectMeta {
+            name: Some("bench-app".to_string()),
+            namespace: Some("default".to_string()),
+            ..Default::default()
+        },
+        spec: RolloutSpec {
+            replicas: 10,
+            selector: LabelSelector {
+                match_labels: Some([("app".to_string(), "bench-app".to_string())].into()),
+                ..Default::default()
+            },
+            template: create_pod_template(1),
+            strategy: RolloutStrategy {
+                simple: None,
+                canary: Some(CanaryStrategy {
+                    canary_service: "bench-app-canary".to_string(),
+                    stable_service: "bench-app-stable".to_string(),
+                    mirror_service: None,
+                    steps,
+                    traffic_routing: None,
+                    analysis: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
+                }),
+                blue_green: None,
+            },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
+        },
+        status: None,
+    }
+}
+
+fn bench_build_replicaset(c: &mut Criterion) {
+    let rollout = create_canary_rollout(3);
+
+    c.bench_function("build_replicaset x10000", |b| {
+        b.iter(|| {
+            for _ in 0..10_000 {
+                let rs = build_replicaset(
+                    black_box(&rollout),
+                    black_box("canary"),
+                    black_box("canary"),
+                    black_box(3),
+                );
+                black_box(rs).ok();
+            }
+        })
+    });
+}
+
+fn bench_compute_pod_template_hash(c: &mut Criterion) {
+    let template = create_pod_template(5);
+
+    c.bench_function("compute_pod_template_hash (5 containers)", |b| {
+        b.iter(|| compute_pod_template_hash(black_box(&template)))
+    });
+}
+
+fn bench_calculate_replica_split(c: &mut Criterion) {
+    let weights = [0, 1, 10, 25, 33, 50, 67, 75, 99, 100];
+
+    c.bench_function("calculate_replica_split (10 weights)", |b| {
+        b.iter(|| {
+            for weight in weights {
+                black_box(calculate_replica_split_with_surge(
+                    black_box(10),
+                    black_box(weight),
+                    false,
+                ));
+            }
+        })
+    });
+}
+
+fn bench_compute_desired_status(c: &mut Criterion) {
+    let base = create_canary_rollout(10);
+    let rollouts: Vec<Rollout> = (0..10)
+        .map(|step| {
+            let mut rollout = base.clone();
+            rollout.status = Some(RolloutStatus {
+                current_step_index: Some(step),
+                current_weight: Some((step + 1) * 10),
+                phase: Some(Phase::Paused),
+                pause_start_time: Some("2024-01-01T00:00:00Z".to_string()),
+                ..Default::default()
+            });
+            rollout
+        })
+        .collect();
+
+    c.bench_function("compute_desired_status (10-step canary, each step)", |b| {
+        b.iter(|| {
+            for rollout in &rollouts {
+                black_box(compute_desired_status(black_box(rollout)));
+            }
+        })
+    });
+}
+
+fn bench_validate_rollout(c: &mut Criterion) {
+    let rollout = create_canary_rollout(20);
+
+    c.bench_function("validate_rollout (20 steps)", |b| {
+        b.iter(|| validate_rollout(black_box(&rollout)))
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_build_replicaset,
+    bench_compute_pod_template_hash,
+    bench_calculate_replica_split,
+    bench_compute_desired_status,
+    bench_validate_rollout
+);
+criterion_main!(benches);
@@ -0,0 +1,21 @@
+//! Fuzz target for parse_duration
+//!
+//! Run with: cargo +nightly fuzz run fuzz_parse_duration
+//!
+//! Verifies parse_duration never panics and only returns None or a
+//! Duration within the documented limits (1s..=168h).
+
+#![no_main]
+
+use kulta::controller::rollout::parse_duration;
+use libfuzzer_sys::fuzz_target;
+use std::time::Duration;
+
+const MAX_DURATION: Duration = Duration::from_secs(168 * 3600);
+
+fuzz_target!(|input: &str| {
+    if let Some(duration) = parse_duration(input) {
+        assert!(duration >= Duration::from_secs(1));
+        assert!(duration <= MAX_DURATION);
+    }
+});
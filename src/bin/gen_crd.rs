@@ -0,0 +1,36 @@
+//! Generate the Rollout CustomResourceDefinition from the Rust types
+//!
+//! Usage:
+//!   cargo run --bin gen-crd                                      # print YAML to stdout
+//!   cargo run --bin gen-crd -- --check config/crds/rollout.yaml  # fail if the file is stale
+//!
+//! `make gen-crd` regenerates config/crds/, `make check-crd` runs the check.
+
+use anyhow::Context;
+use kube::CustomResourceExt;
+use kulta::crd::rollout::Rollout;
+
+fn main() -> anyhow::Result<()> {
+    let crd = Rollout::crd();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.as_slice() {
+        [] => {
+            print!("{}", serde_yaml::to_string(&crd)?);
+            Ok(())
+        }
+        [flag, path] if flag == "--check" => {
+            // Compare parsed documents so formatting differences don't matter
+            let committed = std::fs::read_to_string(path)
+                .with_context(|| format!("failed to read {}", path))?;
+            let committed: serde_json::Value = serde_yaml::from_str(&committed)
+                .with_context(|| format!("failed to parse {}", path))?;
+
+            if serde_json::to_value(&crd)? != committed {
+                anyhow::bail!("{} is out of date, regenerate it with: make gen-crd", path);
+            }
+            Ok(())
+        }
+        _ => anyhow::bail!("usage: gen-crd [--check <path>]"),
+    }
+}
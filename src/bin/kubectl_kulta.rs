@@ -0,0 +1,281 @@
+//! kubectl plugin for inspecting KULTA rollouts
+//!
+//! Installed as `kubectl-kulta` on the PATH, kubectl discovers it as `kubectl kulta`:
+//!   kubectl kulta rollouts [-n <namespace>] [--all-namespaces]
+//!   kubectl kulta rollout describe <name> [-n <namespace>]
+//!
+//! Uses the current kubeconfig context (and its namespace unless -n is given).
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use clap::{Parser, Subcommand};
+use kube::api::{Api, ListParams};
+use kube::{Client, ResourceExt};
+use kulta::controller::strategies::select_strategy;
+use kulta::crd::rollout::Rollout;
+
+#[derive(Parser)]
+#[command(
+    name = "kubectl-kulta",
+    about = "Inspect KULTA progressive delivery rollouts"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List rollouts with their strategy, phase, step and weight
+    Rollouts {
+        /// Namespace to list (default: current kubeconfig namespace)
+        #[arg(short, long)]
+        namespace: Option<String>,
+
+        /// List rollouts in all namespaces
+        #[arg(short = 'A', long)]
+        all_namespaces: bool,
+    },
+    /// Operate on a single rollout
+    Rollout {
+        #[command(subcommand)]
+        command: RolloutCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum RolloutCommand {
+    /// Print a rollout's full status, including its decision history
+    Describe {
+        /// Rollout name
+        name: String,
+
+        /// Namespace of the rollout (default: current kubeconfig namespace)
+        #[arg(short, long)]
+        namespace: Option<String>,
+    },
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let client = Client::try_default()
+        .await
+        .context("failed to connect to the cluster (check your kubeconfig)")?;
+
+    match cli.command {
+        Command::Rollouts {
+            namespace,
+            all_namespaces,
+        } => list_rollouts(client, namespace, all_namespaces).await,
+        Command::Rollout {
+            command: RolloutCommand::Describe { name, namespace },
+        } => describe_rollout(client, &name, namespace).await,
+    }
+}
+
+async fn list_rollouts(
+    client: Client,
+    namespace: Option<String>,
+    all_namespaces: bool,
+) -> anyhow::Result<()> {
+    let api: Api<Rollout> = if all_namespaces {
+        Api::all(client)
+    } else {
+        let namespace = namespace.unwrap_or_else(|| client.default_namespace().to_string());
+        Api::namespaced(client, &namespace)
+    };
+
+    let rollouts = api
+        .list(&ListParams::default())
+        .await
+        .context("failed to list rollouts")?;
+
+    if rollouts.items.is_empty() {
+        println!("No rollouts found.");
+        return Ok(());
+    }
+
+    let mut headers = vec!["NAME", "STRATEGY", "PHASE", "STEP", "WEIGHT", "AGE"];
+    if all_namespaces {
+        headers.insert(0, "NAMESPACE");
+    }
+
+    let now = Utc::now();
+    let rows = rollouts
+        .items
+        .iter()
+        .map(|rollout| {
+            let mut row = vec![
+                rollout.name_any(),
+                select_strategy(rollout).name().to_string(),
+                phase(rollout),
+                step(rollout),
+                weight(rollout),
+                rollout
+                    .creation_timestamp()
+                    .map(|created| format_age(now, created.0))
+                    .unwrap_or_else(|| "-".to_string()),
+            ];
+            if all_namespaces {
+                row.insert(0, rollout.namespace().unwrap_or_default());
+            }
+            row
+        })
+        .collect::<Vec<_>>();
+
+    print!("{}", format_table(&headers, &rows));
+    Ok(())
+}
+
+async fn describe_rollout(
+    client: Client,
+    name: &str,
+    namespace: Option<String>,
+) -> anyhow::Result<()> {
+    let namespace = namespace.unwrap_or_else(|| client.default_namespace().to_string());
+    let api: Api<Rollout> = Api::namespaced(client, &namespace);
+
+    let rollout = api
+        .get_opt(name)
+        .await
+        .with_context(|| format!("failed to get rollout {}/{}", namespace, name))?
+        .with_context(|| format!("rollout {} not found in namespace {}", name, namespace))?;
+    let status = rollout.status.clone().unwrap_or_default();
+
+    let fields = [
+        ("Name", rollout.name_any()),
+        ("Namespace", namespace.clone()),
+        ("Strategy", select_strategy(&rollout).name().to_string()),
+        ("Replicas", rollout.spec.replicas.to_string()),
+        ("Phase", phase(&rollout)),
+        ("Step", step(&rollout)),
+        ("Weight", weight(&rollout)),
+        (
+            "Ready",
+            format!(
+                "{} (stable: {}, canary: {})",
+                status.ready_replicas, status.stable_replicas, status.canary_replicas
+            ),
+        ),
+        ("Message", or_dash(status.message.clone())),
+        ("Started", or_dash(status.started_at.clone())),
+        ("Step Started", or_dash(status.step_start_time.clone())),
+        ("Pause Started", or_dash(status.pause_start_time.clone())),
+        (
+            "Observed Generation",
+            or_dash(status.observed_generation.map(|g| g.to_string())),
+        ),
+    ];
+    for (label, value) in fields {
+        println!("{:<21}{}", format!("{}:", label), value);
+    }
+
+    println!();
+    println!("Decisions:");
+    if status.decisions.is_empty() {
+        println!("  <none>");
+        return Ok(());
+    }
+
+    let rows = status
+        .decisions
+        .iter()
+        .map(|decision| {
+            vec![
+                decision.timestamp.clone(),
+                format!("{:?}", decision.action),
+                format!("{:?}", decision.reason),
+                or_dash(decision.from_step.map(|s| s.to_string())),
+                or_dash(decision.to_step.map(|s| s.to_string())),
+                or_dash(decision.message.clone()),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let table = format_table(
+        &["TIMESTAMP", "ACTION", "REASON", "FROM", "TO", "MESSAGE"],
+        &rows,
+    );
+    for line in table.lines() {
+        println!("  {}", line);
+    }
+    Ok(())
+}
+
+fn phase(rollout: &Rollout) -> String {
+    rollout
+        .status
+        .as_ref()
+        .and_then(|status| status.phase.as_ref())
+        .map(|phase| format!("{:?}", phase))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Current step as "index/total" (canary only)
+fn step(rollout: &Rollout) -> String {
+    let steps = rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .map(|canary| canary.steps.len());
+    let index = rollout
+        .status
+        .as_ref()
+        .and_then(|status| status.current_step_index);
+
+    match (index, steps) {
+        (Some(index), Some(steps)) => format!("{}/{}", index, steps),
+        _ => "-".to_string(),
+    }
+}
+
+fn weight(rollout: &Rollout) -> String {
+    rollout
+        .status
+        .as_ref()
+        .and_then(|status| status.current_weight)
+        .map(|weight| format!("{}%", weight))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn or_dash(value: Option<String>) -> String {
+    value.unwrap_or_else(|| "-".to_string())
+}
+
+/// Age in kubectl's short format (e.g. "45s", "12m", "3h", "5d")
+fn format_age(now: DateTime<Utc>, created: DateTime<Utc>) -> String {
+    let secs = now.signed_duration_since(created).num_seconds().max(0);
+    match secs {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86400 => format!("{}h", s / 3600),
+        s => format!("{}d", s / 86400),
+    }
+}
+
+/// Left-aligned columns separated by three spaces (like kubectl get)
+fn format_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths = headers.iter().map(|h| h.len()).collect::<Vec<_>>();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: Vec<&str>| {
+        let line = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("   ");
+        format!("{}\n", line.trim_end())
+    };
+
+    let mut table = format_row(headers.to_vec());
+    for row in rows {
+        table.push_str(&format_row(row.iter().map(String::as_str).collect()));
+    }
+    table
+}
@@ -0,0 +1,47 @@
+//! Validate Rollout manifests offline, without a cluster
+//!
+//! Usage:
+//!   cargo run --bin validate -- rollout.yaml [more.yaml ...]
+//!   cat rollout.yaml | cargo run --bin validate -- -
+//!
+//! Runs the controller's own validation on every Rollout in each file and
+//! prints all errors. Exits non-zero if any file is invalid.
+
+use anyhow::Context;
+use kulta::controller::manifest::validate_manifest;
+use std::io::Read;
+
+fn main() -> anyhow::Result<()> {
+    let paths: Vec<String> = std::env::args().skip(1).collect();
+    if paths.is_empty() {
+        anyhow::bail!("usage: validate <manifest.yaml|-> [...]");
+    }
+
+    let mut failed = 0;
+    for path in &paths {
+        let yaml = if path == "-" {
+            let mut yaml = String::new();
+            std::io::stdin()
+                .read_to_string(&mut yaml)
+                .context("failed to read stdin")?;
+            yaml
+        } else {
+            std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path))?
+        };
+
+        match validate_manifest(&yaml) {
+            Ok(()) => println!("{}: ok", path),
+            Err(errors) => {
+                failed += 1;
+                for error in errors {
+                    eprintln!("{}: {}", path, error);
+                }
+            }
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("{} of {} manifests failed validation", failed, paths.len());
+    }
+    Ok(())
+}
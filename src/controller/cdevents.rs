@@ -9,6 +9,12 @@ use thiserror::Error;
 #[cfg(test)]
 use std::sync::{Arc, Mutex};
 
+/// Rollout annotation carrying the CD pipeline ID, echoed into customData
+pub const PIPELINE_ID_ANNOTATION: &str = "kulta.io/pipeline-id";
+
+/// Rollout annotation carrying the CD pipeline run ID, echoed into customData
+pub const RUN_ID_ANNOTATION: &str = "kulta.io/run-id";
+
 #[derive(Debug, Error)]
 pub enum CDEventsError {
     #[error("cdevents error: {0}")]
@@ -108,9 +114,11 @@ pub async fn emit_status_change_event(
 ) -> Result<(), CDEventsError> {
     use crate::crd::rollout::Phase;
 
-    // Detect transition: None → Progressing/Completed/Preview = service.deployed
+    // Detect transition: None/Initializing → Progressing/Completed/Preview = service.deployed
     // (Simple strategy goes directly to Completed, Canary goes to Progressing, Blue-green goes to Preview)
-    let is_initialization = old_status.is_none()
+    let is_initialization = old_status
+        .as_ref()
+        .is_none_or(|old| old.phase == Some(Phase::Initializing))
         && matches!(
             new_status.phase,
             Some(Phase::Progressing) | Some(Phase::Completed) | Some(Phase::Preview)
@@ -119,14 +127,22 @@ pub async fn emit_status_change_event(
     // Detect step progression: Progressing → Progressing (different step)
     let is_step_progression = match (old_status, &new_status.phase) {
         (Some(old), Some(Phase::Progressing)) => {
-            matches!(old.phase, Some(Phase::Progressing))
-                && old.current_step_index != new_status.current_step_index
+            matches!(
+                old.phase,
+                Some(Phase::Progressing)
+                    | Some(Phase::Scaling)
+                    | Some(Phase::WaitingForReadiness)
+                    | Some(Phase::WaitingForAnalysis)
+            ) && old.current_step_index != new_status.current_step_index
         }
         _ => false,
     };
 
-    // Detect rollback: Any → Failed
-    let is_rollback = matches!(new_status.phase, Some(Phase::Failed));
+    // Detect rollback: Any → Failed/RollingBack
+    let is_rollback = matches!(
+        new_status.phase,
+        Some(Phase::Failed) | Some(Phase::RollingBack)
+    );
 
     // Detect completion: Progressing → Completed
     let is_completion = matches!(new_status.phase, Some(Phase::Completed));
@@ -507,26 +523,47 @@ fn build_kulta_custom_data(
         .map(|c| c.steps.len())
         .unwrap_or(0);
 
-    json!({
-        "kulta": {
-            "version": "v1",
-            "rollout": {
-                "name": rollout.metadata.name.as_deref().unwrap_or("unknown"),
-                "namespace": rollout.metadata.namespace.as_deref().unwrap_or("default"),
-                "uid": rollout.metadata.uid.as_deref().unwrap_or(""),
-                "generation": rollout.metadata.generation.unwrap_or(0)
-            },
-            "strategy": strategy,
-            "step": {
-                "index": status.current_step_index.unwrap_or(0),
-                "total": total_steps,
-                "traffic_weight": status.current_weight.unwrap_or(0)
-            },
-            "decision": {
-                "reason": decision_reason
-            }
+    let mut kulta = json!({
+        "version": "v1",
+        "rollout": {
+            "name": rollout.metadata.name.as_deref().unwrap_or("unknown"),
+            "namespace": rollout.metadata.namespace.as_deref().unwrap_or("default"),
+            "uid": rollout.metadata.uid.as_deref().unwrap_or(""),
+            "generation": rollout.metadata.generation.unwrap_or(0)
+        },
+        "strategy": strategy,
+        "step": {
+            "index": status.current_step_index.unwrap_or(0),
+            "total": total_steps,
+            "traffic_weight": status.current_weight.unwrap_or(0)
+        },
+        "decision": {
+            "reason": decision_reason
         }
-    })
+    });
+
+    if let Some(pipeline) = build_pipeline_context(rollout) {
+        kulta["pipeline"] = pipeline;
+    }
+
+    json!({ "kulta": kulta })
+}
+
+/// Build the pipeline context from the pipeline/run ID annotations
+///
+/// Missing annotations are omitted; returns None when neither is set.
+fn build_pipeline_context(rollout: &Rollout) -> Option<serde_json::Value> {
+    let annotations = rollout.metadata.annotations.as_ref()?;
+
+    let mut pipeline = serde_json::Map::new();
+    if let Some(id) = annotations.get(PIPELINE_ID_ANNOTATION) {
+        pipeline.insert("id".to_string(), json!(id));
+    }
+    if let Some(run_id) = annotations.get(RUN_ID_ANNOTATION) {
+        pipeline.insert("run_id".to_string(), json!(run_id));
+    }
+
+    (!pipeline.is_empty()).then_some(serde_json::Value::Object(pipeline))
 }
 
 /// Extract image from rollout's pod template
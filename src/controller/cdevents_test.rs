@@ -4,6 +4,11 @@ use crate::crd::rollout::{
 };
 use kube::api::ObjectMeta;
 
+#[allow(clippy::duplicate_mod)] // Also included by rollout_test.rs
+#[path = "../../tests/common/builders.rs"]
+mod builders;
+use builders::RolloutBuilder;
+
 // TDD Cycle 1: RED - Test that service.deployed event is emitted when rollout initializes
 #[tokio::test]
 async fn test_emit_service_deployed_on_initialization() {
@@ -24,14 +29,38 @@ async fn test_emit_service_deployed_on_initialization() {
                 canary: Some(CanaryStrategy {
                     canary_service: "test-app-canary".to_string(),
                     stable_service: "test-app-stable".to_string(),
+                    mirror_service: None,
                     steps: vec![CanaryStep {
                         set_weight: Some(10),
                         pause: None,
+                        analysis: None,
                     }],
                     analysis: None,
                     traffic_routing: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None, // No status yet - this is a new rollout
     };
@@ -93,6 +122,38 @@ async fn test_emit_service_deployed_on_initialization() {
     );
 }
 
+#[tokio::test]
+async fn test_emit_service_deployed_after_initializing() {
+    // ARRANGE: First reconcile recorded Initializing before creating ReplicaSets
+    let rollout = RolloutBuilder::new()
+        .with_template(create_test_pod_template("nginx:1.0"))
+        .with_step(10, None)
+        .build();
+    let sink = CDEventsSink::new_mock();
+
+    let old_status = Some(RolloutStatus {
+        phase: Some(Phase::Initializing),
+        ..Default::default()
+    });
+    let new_status = RolloutStatus {
+        phase: Some(Phase::Progressing),
+        current_step_index: Some(0),
+        current_weight: Some(10),
+        ..Default::default()
+    };
+
+    // ACT
+    emit_status_change_event(&rollout, &old_status, &new_status, &sink)
+        .await
+        .unwrap();
+
+    // ASSERT: Initializing → Progressing is still the initial deployment
+    use cloudevents::AttributesReader;
+    let events = sink.get_emitted_events();
+    assert_eq!(events.len(), 1, "Expected exactly 1 event");
+    assert_eq!(events[0].ty(), "dev.cdevents.service.deployed.0.2.0");
+}
+
 // TDD Cycle 2: RED - Test that service.upgraded event is emitted when canary progresses
 #[tokio::test]
 async fn test_emit_service_upgraded_on_step_progression() {
@@ -113,20 +174,45 @@ async fn test_emit_service_upgraded_on_step_progression() {
                 canary: Some(CanaryStrategy {
                     canary_service: "test-app-canary".to_string(),
                     stable_service: "test-app-stable".to_string(),
+                    mirror_service: None,
                     steps: vec![
                         CanaryStep {
                             set_weight: Some(10),
                             pause: None,
+                            analysis: None,
                         },
                         CanaryStep {
                             set_weight: Some(50),
                             pause: None,
+                            analysis: None,
                         },
                     ],
                     analysis: None,
                     traffic_routing: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -221,14 +307,38 @@ async fn test_emit_service_rolledback_on_failure() {
                 canary: Some(CanaryStrategy {
                     canary_service: "test-app-canary".to_string(),
                     stable_service: "test-app-stable".to_string(),
+                    mirror_service: None,
                     steps: vec![CanaryStep {
                         set_weight: Some(50),
                         pause: None,
+                        analysis: None,
                     }],
                     analysis: None,
                     traffic_routing: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -323,20 +433,45 @@ async fn test_emit_service_published_on_completion() {
                 canary: Some(CanaryStrategy {
                     canary_service: "test-app-canary".to_string(),
                     stable_service: "test-app-stable".to_string(),
+                    mirror_service: None,
                     steps: vec![
                         CanaryStep {
                             set_weight: Some(50),
                             pause: None,
+                            analysis: None,
                         },
                         CanaryStep {
                             set_weight: Some(100),
                             pause: None,
+                            analysis: None,
                         },
                     ],
                     analysis: None,
                     traffic_routing: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -425,20 +560,45 @@ async fn test_cdevent_contains_kulta_custom_data() {
                 canary: Some(CanaryStrategy {
                     canary_service: "test-app-canary".to_string(),
                     stable_service: "test-app-stable".to_string(),
+                    mirror_service: None,
                     steps: vec![
                         CanaryStep {
                             set_weight: Some(10),
                             pause: None,
+                            analysis: None,
                         },
                         CanaryStep {
                             set_weight: Some(50),
                             pause: None,
+                            analysis: None,
                         },
                     ],
                     analysis: None,
                     traffic_routing: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -491,6 +651,86 @@ async fn test_cdevent_contains_kulta_custom_data() {
     assert!(kulta["rollout"]["name"].as_str().is_some());
 }
 
+/// Emit a step progression for `rollout` and return the event's customData.kulta
+async fn emitted_kulta_custom_data(rollout: &Rollout) -> serde_json::Value {
+    let sink = CDEventsSink::new_mock();
+
+    let old_status = Some(RolloutStatus {
+        phase: Some(Phase::Progressing),
+        current_step_index: Some(0),
+        current_weight: Some(10),
+        ..Default::default()
+    });
+    let new_status = RolloutStatus {
+        phase: Some(Phase::Progressing),
+        current_step_index: Some(1),
+        current_weight: Some(50),
+        ..Default::default()
+    };
+
+    emit_status_change_event(rollout, &old_status, &new_status, &sink)
+        .await
+        .unwrap();
+
+    let events = sink.get_emitted_events();
+    assert_eq!(events.len(), 1);
+    let json: serde_json::Value = match events[0].data().expect("Event should have data") {
+        cloudevents::Data::Json(v) => v.clone(),
+        _ => panic!("Expected JSON data"),
+    };
+    json["customData"]["kulta"].clone()
+}
+
+#[tokio::test]
+async fn test_cdevent_includes_pipeline_ids_from_annotations() {
+    let rollout = RolloutBuilder::new()
+        .with_template(create_test_pod_template("nginx:1.0"))
+        .with_step(10, None)
+        .with_step(50, None)
+        .with_annotation(PIPELINE_ID_ANNOTATION, "deploy-checkout")
+        .with_annotation(RUN_ID_ANNOTATION, "run-4821")
+        .build();
+
+    let kulta = emitted_kulta_custom_data(&rollout).await;
+
+    assert_eq!(kulta["pipeline"]["id"], "deploy-checkout");
+    assert_eq!(kulta["pipeline"]["run_id"], "run-4821");
+}
+
+#[tokio::test]
+async fn test_cdevent_omits_missing_pipeline_ids() {
+    let rollout = RolloutBuilder::new()
+        .with_template(create_test_pod_template("nginx:1.0"))
+        .with_step(10, None)
+        .with_step(50, None)
+        .with_annotation(RUN_ID_ANNOTATION, "run-4821")
+        .build();
+
+    let kulta = emitted_kulta_custom_data(&rollout).await;
+
+    assert_eq!(kulta["pipeline"]["run_id"], "run-4821");
+    assert!(
+        kulta["pipeline"].get("id").is_none(),
+        "pipeline id should be omitted without its annotation"
+    );
+}
+
+#[tokio::test]
+async fn test_cdevent_omits_pipeline_context_without_annotations() {
+    let rollout = RolloutBuilder::new()
+        .with_template(create_test_pod_template("nginx:1.0"))
+        .with_step(10, None)
+        .with_step(50, None)
+        .build();
+
+    let kulta = emitted_kulta_custom_data(&rollout).await;
+
+    assert!(
+        kulta.get("pipeline").is_none(),
+        "pipeline context should be omitted without annotations"
+    );
+}
+
 // TDD: Test that simple strategy emits both deployed and published events
 #[tokio::test]
 async fn test_simple_strategy_emits_deployed_and_published() {
@@ -513,6 +753,10 @@ async fn test_simple_strategy_emits_deployed_and_published() {
                 canary: None,
                 blue_green: None,
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -580,8 +824,13 @@ async fn test_blue_green_emits_deployed_on_preview() {
                     auto_promotion_seconds: Some(30),
                     traffic_routing: None,
                     analysis: None,
+                    max_duration_seconds: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -652,8 +901,13 @@ async fn test_blue_green_emits_published_on_promotion() {
                     auto_promotion_seconds: Some(30),
                     traffic_routing: None,
                     analysis: None,
+                    max_duration_seconds: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -0,0 +1,196 @@
+//! What a completed canary rollout does with its emptied ReplicaSet
+//!
+//! Once the stable ReplicaSet has adopted the new version, the canary
+//! ReplicaSet sits at 0 replicas. With `canary.onComplete: retain` (default)
+//! it is kept, ready for a fast rollback; with `onComplete: delete` reconcile
+//! deletes it to free quota. The next rollout recreates it as usual.
+
+use crate::crd::rollout::{OnComplete, Phase, Rollout};
+use async_trait::async_trait;
+use k8s_openapi::api::apps::v1::ReplicaSet;
+use kube::api::{Api, DeleteParams};
+use tracing::info;
+
+/// ReplicaSet deletion (mockable in tests)
+#[async_trait]
+pub trait ReplicaSetDeleter: Send + Sync {
+    /// Delete a ReplicaSet by name, Ok(false) if it doesn't exist
+    async fn delete_replicaset(&self, namespace: &str, name: &str) -> Result<bool, kube::Error>;
+}
+
+/// ReplicaSetDeleter backed by the Kubernetes API
+pub struct KubeReplicaSetDeleter {
+    client: kube::Client,
+}
+
+impl KubeReplicaSetDeleter {
+    pub fn new(client: kube::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl ReplicaSetDeleter for KubeReplicaSetDeleter {
+    async fn delete_replicaset(&self, namespace: &str, name: &str) -> Result<bool, kube::Error> {
+        let api: Api<ReplicaSet> = Api::namespaced(self.client.clone(), namespace);
+        match api.delete(name, &DeleteParams::background()).await {
+            Ok(_) => Ok(true),
+            Err(kube::Error::Api(err)) if err.code == 404 => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// The rollout's canary.onComplete (retain if unset or not a canary)
+pub fn on_complete(rollout: &Rollout) -> OnComplete {
+    rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .and_then(|canary| canary.on_complete)
+        .unwrap_or_default()
+}
+
+/// Whether the canary ReplicaSet should be deleted rather than kept at 0
+///
+/// Only once the rollout completed and the stable ReplicaSet adopted the new
+/// version (weight back to 0): until then the canary still serves.
+pub fn should_delete_completed_canary(rollout: &Rollout) -> bool {
+    on_complete(rollout) == OnComplete::Delete
+        && rollout.status.as_ref().is_some_and(|status| {
+            status.phase == Some(Phase::Completed) && status.current_weight == Some(0)
+        })
+}
+
+/// Delete the emptied canary ReplicaSet of a completed rollout
+///
+/// # Returns
+/// true if a ReplicaSet was deleted, false if it was already gone
+pub async fn delete_completed_canary(
+    deleter: &dyn ReplicaSetDeleter,
+    namespace: &str,
+    rs_name: &str,
+) -> Result<bool, kube::Error> {
+    let deleted = deleter.delete_replicaset(namespace, rs_name).await?;
+    if deleted {
+        info!(
+            replicaset = ?rs_name,
+            "Rollout completed with onComplete=delete, canary ReplicaSet deleted"
+        );
+    }
+    Ok(deleted)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Tests can use unwrap for brevity
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    /// Records deletions; `existing` names the ReplicaSets present
+    struct MockReplicaSetDeleter {
+        existing: Vec<String>,
+        deleted: Mutex<Vec<String>>,
+    }
+
+    impl MockReplicaSetDeleter {
+        fn new(existing: &[&str]) -> Self {
+            Self {
+                existing: existing.iter().map(|name| name.to_string()).collect(),
+                deleted: Mutex::new(vec![]),
+            }
+        }
+
+        fn deleted(&self) -> Vec<String> {
+            self.deleted.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl ReplicaSetDeleter for MockReplicaSetDeleter {
+        async fn delete_replicaset(
+            &self,
+            _namespace: &str,
+            name: &str,
+        ) -> Result<bool, kube::Error> {
+            self.deleted.lock().unwrap().push(name.to_string());
+            Ok(self.existing.iter().any(|existing| existing == name))
+        }
+    }
+
+    fn completed_rollout(on_complete: Option<&str>, weight: i32) -> Rollout {
+        serde_json::from_value(json!({
+            "apiVersion": "kulta.io/v1alpha1",
+            "kind": "Rollout",
+            "metadata": {"name": "my-app", "namespace": "default"},
+            "spec": {
+                "replicas": 3,
+                "selector": {},
+                "template": {},
+                "strategy": {
+                    "canary": {
+                        "canaryService": "my-app-canary",
+                        "stableService": "my-app-stable",
+                        "steps": [{"setWeight": 50}, {"setWeight": 100}],
+                        "onComplete": on_complete
+                    }
+                }
+            },
+            "status": {"phase": "Completed", "currentStepIndex": 1, "currentWeight": weight}
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_on_complete_delete_deletes_canary_replicaset() {
+        let rollout = completed_rollout(Some("delete"), 0);
+        let deleter = MockReplicaSetDeleter::new(&["my-app-canary"]);
+
+        assert!(should_delete_completed_canary(&rollout));
+        let deleted = delete_completed_canary(&deleter, "default", "my-app-canary")
+            .await
+            .unwrap();
+
+        assert!(deleted);
+        assert_eq!(deleter.deleted(), vec!["my-app-canary".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_on_complete_delete_already_gone_is_ok() {
+        let deleter = MockReplicaSetDeleter::new(&[]);
+
+        let deleted = delete_completed_canary(&deleter, "default", "my-app-canary")
+            .await
+            .unwrap();
+
+        assert!(!deleted);
+    }
+
+    #[test]
+    fn test_on_complete_retain_keeps_replicaset() {
+        assert!(!should_delete_completed_canary(&completed_rollout(
+            Some("retain"),
+            0
+        )));
+        // Retain is the default
+        assert!(!should_delete_completed_canary(&completed_rollout(None, 0)));
+    }
+
+    #[test]
+    fn test_on_complete_delete_waits_for_stable_adoption() {
+        // Completed at 100%: the canary ReplicaSet still serves until stable adopts
+        let rollout = completed_rollout(Some("delete"), 100);
+
+        assert!(!should_delete_completed_canary(&rollout));
+    }
+
+    #[test]
+    fn test_on_complete_delete_ignored_while_progressing() {
+        let mut rollout = completed_rollout(Some("delete"), 0);
+        rollout.status.as_mut().unwrap().phase = Some(Phase::Progressing);
+
+        assert!(!should_delete_completed_canary(&rollout));
+    }
+}
@@ -0,0 +1,154 @@
+//! Dry-run mode: preview what reconcile would do
+//!
+//! With `spec.dryRun: true`, reconcile computes the ReplicaSets, traffic
+//! weights and status it would apply and logs each one ("DRY RUN: would
+//! apply ..."), but never creates or patches a ReplicaSet, HTTPRoute or
+//! TrafficSplit, nor moves the rollout's progress. The plan is recorded in
+//! `status.dryRunPlan` (the only write, made when the plan changes), and
+//! re-evaluated every DRY_RUN_REQUEUE. Setting `dryRun: false` applies it.
+
+use crate::controller::completion::should_delete_completed_canary;
+use crate::controller::rollout::{
+    build_replicaset, build_replicaset_for_simple, build_replicasets_for_blue_green,
+    calculate_blue_green_weights, calculate_canary_replicas, calculate_traffic_weights,
+    initializing_status, replicaset_name_suffix, replicaset_template_hash, ReconcileError,
+};
+use crate::crd::rollout::{DryRunPlan, Rollout, RolloutStatus, TrafficRouting};
+use k8s_openapi::api::apps::v1::ReplicaSet;
+use kube::ResourceExt;
+use std::fmt::Debug;
+use std::time::Duration;
+
+/// Requeue interval while a rollout is in dry-run mode
+pub const DRY_RUN_REQUEUE: Duration = Duration::from_secs(60);
+
+/// Whether the rollout only previews its changes (spec.dryRun)
+pub fn is_dry_run(rollout: &Rollout) -> bool {
+    rollout.spec.dry_run.unwrap_or(false)
+}
+
+/// Plan the writes reconcile would make to reach the desired status
+///
+/// ReplicaSets are built exactly as reconcile builds them (so the plan shows
+/// the real names and pod-template-hashes), at the replica counts and traffic
+/// weights of the desired status.
+pub fn compute_dry_run_plan(
+    rollout: &Rollout,
+    desired: &RolloutStatus,
+) -> Result<DryRunPlan, ReconcileError> {
+    // The rollout as it would be once the desired status is applied
+    let mut target = rollout.clone();
+    target.status = Some(desired.clone());
+
+    let mut operations = Vec::new();
+    if let Some(canary) = &rollout.spec.strategy.canary {
+        let weight = desired.current_weight.unwrap_or(0);
+        let (stable_replicas, canary_replicas) = calculate_canary_replicas(rollout, weight);
+        for (rs_type, replicas) in [("stable", stable_replicas), ("canary", canary_replicas)] {
+            let suffix = replicaset_name_suffix(rollout, rs_type);
+            let rs = build_replicaset(rollout, rs_type, suffix, replicas)?;
+            if rs_type == "canary" && should_delete_completed_canary(&target) {
+                operations.push(format!("ReplicaSet {}: delete (onComplete)", rs.name_any()));
+            } else {
+                operations.push(replicaset_operation(&rs, replicas));
+            }
+        }
+
+        let (stable_weight, canary_weight) = calculate_traffic_weights(&target);
+        operations.extend(traffic_operations(
+            canary.traffic_routing.as_ref(),
+            ("stable", stable_weight),
+            ("canary", canary_weight),
+        ));
+    } else if let Some(blue_green) = &rollout.spec.strategy.blue_green {
+        let (active_rs, preview_rs) =
+            build_replicasets_for_blue_green(rollout, rollout.spec.replicas)?;
+        operations.push(replicaset_operation(&active_rs, rollout.spec.replicas));
+        operations.push(replicaset_operation(&preview_rs, rollout.spec.replicas));
+
+        let (active_weight, preview_weight) = calculate_blue_green_weights(&target);
+        operations.extend(traffic_operations(
+            blue_green.traffic_routing.as_ref(),
+            ("active", active_weight),
+            ("preview", preview_weight),
+        ));
+    } else {
+        let rs = build_replicaset_for_simple(rollout, rollout.spec.replicas)?;
+        operations.push(replicaset_operation(&rs, rollout.spec.replicas));
+    }
+
+    let current = rollout.status.clone().unwrap_or_default();
+    let progressed = current.phase != desired.phase
+        || current.current_step_index != desired.current_step_index
+        || current.current_weight != desired.current_weight;
+    if progressed {
+        operations.push(format!(
+            "Rollout {} status: phase {} -> {}, step {} -> {}, weight {} -> {}",
+            rollout.name_any(),
+            display(&current.phase),
+            display(&desired.phase),
+            display(&current.current_step_index),
+            display(&desired.current_step_index),
+            display(&current.current_weight),
+            display(&desired.current_weight),
+        ));
+    }
+
+    Ok(DryRunPlan {
+        phase: desired.phase.clone(),
+        current_step_index: desired.current_step_index,
+        current_weight: desired.current_weight,
+        operations,
+    })
+}
+
+/// The status a dry-run reconcile writes: the current one plus the plan
+///
+/// A rollout without a status gets the Initializing marker, so leaving dry
+/// run starts it from the first reconcile as usual.
+pub fn dry_run_status(rollout: &Rollout, plan: DryRunPlan) -> RolloutStatus {
+    let mut status = rollout
+        .status
+        .clone()
+        .unwrap_or_else(|| initializing_status(rollout));
+    status.dry_run_plan = Some(plan);
+    status
+}
+
+fn replicaset_operation(rs: &ReplicaSet, replicas: i32) -> String {
+    format!(
+        "ReplicaSet {}: {} replicas (pod-template-hash {})",
+        rs.name_any(),
+        replicas,
+        replicaset_template_hash(rs).unwrap_or("unknown")
+    )
+}
+
+fn traffic_operations(
+    routing: Option<&TrafficRouting>,
+    (first, first_weight): (&str, i32),
+    (second, second_weight): (&str, i32),
+) -> Vec<String> {
+    let weights = format!("{} {}%, {} {}%", first, first_weight, second, second_weight);
+    let Some(routing) = routing else {
+        return vec![];
+    };
+
+    let mut operations = Vec::new();
+    if let Some(gateway_api) = &routing.gateway_api {
+        operations.push(format!("HTTPRoute {}: {}", gateway_api.http_route, weights));
+    }
+    if let Some(linkerd) = &routing.linkerd {
+        operations.push(format!(
+            "TrafficSplit {}: {}",
+            linkerd.traffic_split_name, weights
+        ));
+    }
+    operations
+}
+
+fn display<T: Debug>(value: &Option<T>) -> String {
+    value
+        .as_ref()
+        .map_or_else(|| "none".to_string(), |value| format!("{:?}", value))
+}
@@ -0,0 +1,139 @@
+//! Per-rollout exponential backoff for failed reconciles
+//!
+//! A rollout that fails every reconcile (e.g. a missing Service or an
+//! unreachable HTTPRoute) would otherwise retry at a flat interval forever,
+//! spamming logs and the API server. The backoff counts consecutive failures
+//! per rollout uid and doubles the error requeue interval with each one, up
+//! to a cap. A successful reconcile resets the count.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Upper bound for a backed-off error requeue interval
+pub const MAX_ERROR_BACKOFF: Duration = Duration::from_secs(300);
+
+/// Consecutive reconcile failures keyed by rollout uid
+#[derive(Default)]
+pub struct ErrorBackoff {
+    failures: Mutex<HashMap<String, u32>>,
+}
+
+impl ErrorBackoff {
+    /// Create a backoff with no recorded failures
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, u32>> {
+        match self.failures.lock() {
+            Ok(guard) => guard,
+            // A poisoned lock only means another reconcile panicked mid-update;
+            // the map is still usable
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// Record a failed reconcile
+    ///
+    /// # Returns
+    /// The consecutive failure count, including this one
+    pub fn record_failure(&self, uid: &str) -> u32 {
+        let mut failures = self.lock();
+        let count = failures.entry(uid.to_string()).or_insert(0);
+        *count = count.saturating_add(1);
+        *count
+    }
+
+    /// Forget a rollout's failures after a successful reconcile
+    pub fn reset(&self, uid: &str) {
+        self.lock().remove(uid);
+    }
+
+    /// Consecutive failures recorded for a rollout
+    pub fn failures(&self, uid: &str) -> u32 {
+        self.lock().get(uid).copied().unwrap_or(0)
+    }
+}
+
+/// Requeue interval after `consecutive_failures` failed reconciles
+///
+/// The first failure uses `base`; each further one doubles it, capped at
+/// `max` (a base already above `max` is kept as-is).
+pub fn backoff_interval(base: Duration, consecutive_failures: u32, max: Duration) -> Duration {
+    if base >= max {
+        return base;
+    }
+
+    // 2^(failures-1), saturating to avoid overflow on long outages
+    let multiplier = 1u32
+        .checked_shl(consecutive_failures.saturating_sub(1))
+        .unwrap_or(u32::MAX);
+    base.saturating_mul(multiplier).min(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_interval_doubles_per_failure() {
+        let base = Duration::from_secs(10);
+        let delays: Vec<u64> = (1..=5)
+            .map(|failures| backoff_interval(base, failures, MAX_ERROR_BACKOFF).as_secs())
+            .collect();
+
+        assert_eq!(delays, vec![10, 20, 40, 80, 160]);
+    }
+
+    #[test]
+    fn test_backoff_interval_capped() {
+        let base = Duration::from_secs(10);
+
+        assert_eq!(
+            backoff_interval(base, 6, MAX_ERROR_BACKOFF),
+            MAX_ERROR_BACKOFF
+        );
+        assert_eq!(
+            backoff_interval(base, u32::MAX, MAX_ERROR_BACKOFF),
+            MAX_ERROR_BACKOFF
+        );
+    }
+
+    #[test]
+    fn test_backoff_interval_keeps_base_above_cap() {
+        let base = Duration::from_secs(600);
+
+        assert_eq!(backoff_interval(base, 3, MAX_ERROR_BACKOFF), base);
+    }
+
+    #[test]
+    fn test_record_failure_counts_per_rollout() {
+        let backoff = ErrorBackoff::new();
+
+        assert_eq!(backoff.record_failure("uid-a"), 1);
+        assert_eq!(backoff.record_failure("uid-a"), 2);
+        assert_eq!(backoff.record_failure("uid-b"), 1);
+        assert_eq!(backoff.failures("uid-a"), 2);
+    }
+
+    #[test]
+    fn test_reset_on_success_restarts_backoff() {
+        let backoff = ErrorBackoff::new();
+        let base = Duration::from_secs(10);
+        backoff.record_failure("uid-a");
+        backoff.record_failure("uid-a");
+        backoff.record_failure("uid-b");
+
+        backoff.reset("uid-a");
+        let failures = backoff.record_failure("uid-a");
+
+        assert_eq!(failures, 1);
+        assert_eq!(backoff_interval(base, failures, MAX_ERROR_BACKOFF), base);
+        assert_eq!(
+            backoff.failures("uid-b"),
+            1,
+            "Other rollouts keep their count"
+        );
+    }
+}
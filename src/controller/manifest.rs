@@ -0,0 +1,308 @@
+//! Offline validation of Rollout manifests
+//!
+//! Runs the same `validate_rollout` checks as the reconcile path, plus
+//! selector/template checks the API server would otherwise only surface once
+//! ReplicaSets are created. Used by the `validate` binary to gate manifests in
+//! CI without a cluster.
+
+use crate::controller::rollout::validate_rollout;
+use crate::crd::rollout::Rollout;
+use kube::ResourceExt;
+use serde::Deserialize;
+use thiserror::Error;
+
+/// A problem found while validating a manifest
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ValidationError {
+    /// A YAML document could not be parsed (documents are numbered from 1)
+    #[error("document {document}: {message}")]
+    Parse { document: usize, message: String },
+
+    /// A Rollout failed validation
+    #[error("rollout {rollout}: {message}")]
+    Invalid { rollout: String, message: String },
+
+    /// The manifest contains no Rollout documents
+    #[error("no Rollout found in manifest")]
+    NoRollout,
+}
+
+/// Validate every Rollout in a (possibly multi-document) YAML manifest
+///
+/// Documents of other kinds (Services, HTTPRoutes, ...) are skipped. All
+/// errors are collected rather than stopping at the first one.
+pub fn validate_manifest(yaml: &str) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+    let mut rollouts = 0;
+
+    for (index, document) in serde_yaml::Deserializer::from_str(yaml).enumerate() {
+        let parse_error = |e: serde_yaml::Error| ValidationError::Parse {
+            document: index + 1,
+            message: e.to_string(),
+        };
+
+        let value = match serde_yaml::Value::deserialize(document) {
+            Ok(value) => value,
+            Err(e) => {
+                errors.push(parse_error(e));
+                continue;
+            }
+        };
+        if value.get("kind").and_then(|k| k.as_str()) != Some("Rollout") {
+            continue;
+        }
+        rollouts += 1;
+
+        match serde_yaml::from_value::<Rollout>(value) {
+            Ok(rollout) => errors.extend(validate_rollout_manifest(&rollout)),
+            Err(e) => errors.push(parse_error(e)),
+        }
+    }
+
+    if rollouts == 0 && errors.is_empty() {
+        errors.push(ValidationError::NoRollout);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Run reconcile-path validation plus selector/template checks on one Rollout
+fn validate_rollout_manifest(rollout: &Rollout) -> Vec<ValidationError> {
+    let mut messages = Vec::new();
+
+    if let Err(message) = validate_rollout(rollout) {
+        messages.push(message);
+    }
+    messages.extend(selector_errors(rollout));
+    messages.extend(template_errors(rollout));
+
+    let name = rollout.name_any();
+    messages
+        .into_iter()
+        .map(|message| ValidationError::Invalid {
+            rollout: name.clone(),
+            message,
+        })
+        .collect()
+}
+
+/// spec.selector must be non-empty and match the pod template's labels
+fn selector_errors(rollout: &Rollout) -> Vec<String> {
+    let selector = &rollout.spec.selector;
+    let match_labels = selector.match_labels.clone().unwrap_or_default();
+    let has_expressions = selector
+        .match_expressions
+        .as_ref()
+        .is_some_and(|e| !e.is_empty());
+
+    if match_labels.is_empty() && !has_expressions {
+        return vec!["spec.selector must specify matchLabels or matchExpressions".to_string()];
+    }
+
+    let template_labels = rollout
+        .spec
+        .template
+        .metadata
+        .as_ref()
+        .and_then(|m| m.labels.clone())
+        .unwrap_or_default();
+
+    match_labels
+        .iter()
+        .filter(|(key, value)| template_labels.get(*key) != Some(*value))
+        .map(|(key, value)| {
+            format!(
+                "spec.selector.matchLabels {}={} does not match spec.template.metadata.labels",
+                key, value
+            )
+        })
+        .collect()
+}
+
+/// spec.template must define at least one container, each with an image
+fn template_errors(rollout: &Rollout) -> Vec<String> {
+    let Some(pod_spec) = rollout.spec.template.spec.as_ref() else {
+        return vec!["spec.template.spec is required".to_string()];
+    };
+
+    if pod_spec.containers.is_empty() {
+        return vec!["spec.template.spec.containers must not be empty".to_string()];
+    }
+
+    pod_spec
+        .containers
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| c.image.as_deref().is_none_or(str::is_empty))
+        .map(|(i, c)| {
+            format!(
+                "spec.template.spec.containers[{}] ({}) has no image",
+                i, c.name
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID: &str = r#"
+apiVersion: v1
+kind: Service
+metadata:
+  name: app-stable
+---
+apiVersion: kulta.io/v1alpha1
+kind: Rollout
+metadata:
+  name: app
+spec:
+  replicas: 3
+  selector:
+    matchLabels:
+      app: app
+  template:
+    metadata:
+      labels:
+        app: app
+    spec:
+      containers:
+      - name: app
+        image: nginx:1.25
+  strategy:
+    canary:
+      stableService: app-stable
+      canaryService: app-canary
+      steps:
+      - setWeight: 20
+        pause: {}
+      - setWeight: 100
+"#;
+
+    fn errors(yaml: &str) -> Vec<ValidationError> {
+        match validate_manifest(yaml) {
+            Ok(()) => panic!("expected validation errors"),
+            Err(errors) => errors,
+        }
+    }
+
+    fn invalid(message: &str) -> ValidationError {
+        ValidationError::Invalid {
+            rollout: "app".to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_valid_manifest_passes() {
+        assert_eq!(validate_manifest(VALID), Ok(()));
+    }
+
+    #[test]
+    fn test_reports_every_error() {
+        let yaml = r#"
+apiVersion: kulta.io/v1alpha1
+kind: Rollout
+metadata:
+  name: app
+spec:
+  replicas: -1
+  selector:
+    matchLabels:
+      app: app
+  template:
+    metadata:
+      labels:
+        app: other
+    spec:
+      containers:
+      - name: app
+  strategy:
+    simple: {}
+"#;
+
+        assert_eq!(
+            errors(yaml),
+            vec![
+                invalid("spec.replicas must be >= 0, got -1"),
+                invalid(
+                    "spec.selector.matchLabels app=app does not match spec.template.metadata.labels"
+                ),
+                invalid("spec.template.spec.containers[0] (app) has no image"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_selector_and_missing_containers() {
+        let yaml = r#"
+apiVersion: kulta.io/v1alpha1
+kind: Rollout
+metadata:
+  name: app
+spec:
+  replicas: 1
+  selector: {}
+  template:
+    spec:
+      containers: []
+  strategy:
+    simple: {}
+"#;
+
+        assert_eq!(
+            errors(yaml),
+            vec![
+                invalid("spec.selector must specify matchLabels or matchExpressions"),
+                invalid("spec.template.spec.containers must not be empty"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors_from_multiple_documents() {
+        let yaml = r#"
+kind: Rollout
+metadata:
+  name: broken
+spec:
+  replicas: "three"
+---
+kind: Rollout
+metadata:
+  name: app
+spec:
+  replicas: 1
+  selector: {}
+  template:
+    spec:
+      containers:
+      - name: app
+        image: nginx
+  strategy:
+    simple: {}
+"#;
+
+        let errors = errors(yaml);
+        assert_eq!(errors.len(), 2, "got {:?}", errors);
+        assert!(matches!(
+            errors[0],
+            ValidationError::Parse { document: 1, .. }
+        ));
+        assert_eq!(
+            errors[1],
+            invalid("spec.selector must specify matchLabels or matchExpressions")
+        );
+    }
+
+    #[test]
+    fn test_manifest_without_rollout() {
+        let yaml = "apiVersion: v1\nkind: Service\nmetadata:\n  name: app\n";
+        assert_eq!(errors(yaml), vec![ValidationError::NoRollout]);
+    }
+}
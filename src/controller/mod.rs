@@ -1,6 +1,17 @@
 pub mod cdevents;
+pub mod completion;
+pub mod dry_run;
+pub mod error_backoff;
+pub mod manifest;
+pub mod port_resolver;
 pub mod prometheus;
+pub mod quota;
+pub mod rate_limiter;
+pub mod reconcile_cache;
 pub mod rollout;
+pub mod route_check;
+pub mod services;
+pub mod status_batcher;
 pub mod strategies;
 
 pub use rollout::{reconcile, Context, ReconcileError};
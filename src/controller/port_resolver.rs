@@ -0,0 +1,244 @@
+//! Resolve named Service ports for Gateway API backendRefs
+//!
+//! Gateway API backendRefs need a numeric port, but many Services only expose
+//! a port by name (e.g. "http"). When `canary.portName` is set, the resolver
+//! looks up each Service and uses the number of its port with that name.
+
+use async_trait::async_trait;
+use k8s_openapi::api::core::v1::Service;
+use kube::api::Api;
+use std::collections::HashMap;
+use tracing::warn;
+
+/// Port used for backendRefs when no named port is configured or it can't be resolved
+pub const DEFAULT_BACKEND_PORT: i32 = 80;
+
+/// Source of Service objects (mockable in tests)
+#[async_trait]
+pub trait ServiceLookup: Send + Sync {
+    /// Get a Service by name, None if it doesn't exist
+    async fn get_service(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Option<Service>, kube::Error>;
+}
+
+/// ServiceLookup backed by the Kubernetes API
+pub struct KubeServiceLookup {
+    client: kube::Client,
+}
+
+impl KubeServiceLookup {
+    pub fn new(client: kube::Client) -> Self {
+        Self { client }
+    }
+
+    /// The underlying Kubernetes client
+    pub fn client(&self) -> &kube::Client {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl ServiceLookup for KubeServiceLookup {
+    async fn get_service(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Option<Service>, kube::Error> {
+        let api: Api<Service> = Api::namespaced(self.client.clone(), namespace);
+        api.get_opt(name).await
+    }
+}
+
+/// Find the number of a Service port by name
+pub fn find_named_port(service: &Service, port_name: &str) -> Option<i32> {
+    service
+        .spec
+        .as_ref()?
+        .ports
+        .as_ref()?
+        .iter()
+        .find(|port| port.name.as_deref() == Some(port_name))
+        .map(|port| port.port)
+}
+
+/// Per-reconcile port resolver
+///
+/// Caches resolved ports by (Service, port name), so each Service is looked
+/// up at most once per reconcile. Create a new resolver for every reconcile.
+pub struct PortResolver<'a> {
+    lookup: &'a dyn ServiceLookup,
+    namespace: String,
+    cache: HashMap<(String, String), i32>,
+}
+
+impl<'a> PortResolver<'a> {
+    pub fn new(lookup: &'a dyn ServiceLookup, namespace: &str) -> Self {
+        Self {
+            lookup,
+            namespace: namespace.to_string(),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Resolve the numeric port for a Service
+    ///
+    /// # Returns
+    /// The number of the Service's port named `port_name`, or
+    /// DEFAULT_BACKEND_PORT if no name is given or it can't be resolved (logged)
+    pub async fn resolve(&mut self, service_name: &str, port_name: Option<&str>) -> i32 {
+        let Some(port_name) = port_name else {
+            return DEFAULT_BACKEND_PORT;
+        };
+
+        let key = (service_name.to_string(), port_name.to_string());
+        if let Some(port) = self.cache.get(&key) {
+            return *port;
+        }
+
+        let port = match self.lookup.get_service(&self.namespace, service_name).await {
+            Ok(Some(service)) => find_named_port(&service, port_name).unwrap_or_else(|| {
+                warn!(
+                    service = service_name,
+                    port_name = port_name,
+                    default_port = DEFAULT_BACKEND_PORT,
+                    "Service has no port with this name, using default port"
+                );
+                DEFAULT_BACKEND_PORT
+            }),
+            Ok(None) => {
+                warn!(
+                    service = service_name,
+                    default_port = DEFAULT_BACKEND_PORT,
+                    "Service not found, using default port"
+                );
+                DEFAULT_BACKEND_PORT
+            }
+            Err(e) => {
+                warn!(
+                    error = ?e,
+                    service = service_name,
+                    default_port = DEFAULT_BACKEND_PORT,
+                    "Failed to get Service, using default port"
+                );
+                DEFAULT_BACKEND_PORT
+            }
+        };
+
+        self.cache.insert(key, port);
+        port
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{ServicePort, ServiceSpec};
+    use kube::api::ObjectMeta;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct MockServiceLookup {
+        services: HashMap<String, Service>,
+        calls: AtomicUsize,
+    }
+
+    impl MockServiceLookup {
+        fn new(services: Vec<Service>) -> Self {
+            Self {
+                services: services
+                    .into_iter()
+                    .filter_map(|svc| Some((svc.metadata.name.clone()?, svc)))
+                    .collect(),
+                calls: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ServiceLookup for MockServiceLookup {
+        async fn get_service(
+            &self,
+            _namespace: &str,
+            name: &str,
+        ) -> Result<Option<Service>, kube::Error> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(self.services.get(name).cloned())
+        }
+    }
+
+    fn service(name: &str, ports: &[(&str, i32)]) -> Service {
+        Service {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            spec: Some(ServiceSpec {
+                ports: Some(
+                    ports
+                        .iter()
+                        .map(|(port_name, port)| ServicePort {
+                            name: Some(port_name.to_string()),
+                            port: *port,
+                            ..Default::default()
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            }),
+            status: None,
+        }
+    }
+
+    #[test]
+    fn test_find_named_port() {
+        let svc = service("app-stable", &[("http", 8080), ("metrics", 9090)]);
+
+        assert_eq!(find_named_port(&svc, "http"), Some(8080));
+        assert_eq!(find_named_port(&svc, "metrics"), Some(9090));
+        assert_eq!(find_named_port(&svc, "grpc"), None);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_named_port_from_service() {
+        let lookup = MockServiceLookup::new(vec![
+            service("app-stable", &[("http", 8080)]),
+            service("app-canary", &[("metrics", 9090), ("http", 8081)]),
+        ]);
+        let mut resolver = PortResolver::new(&lookup, "default");
+
+        assert_eq!(resolver.resolve("app-stable", Some("http")).await, 8080);
+        assert_eq!(resolver.resolve("app-canary", Some("http")).await, 8081);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_without_port_name_skips_lookup() {
+        let lookup = MockServiceLookup::new(vec![]);
+        let mut resolver = PortResolver::new(&lookup, "default");
+
+        assert_eq!(resolver.resolve("app-stable", None).await, 80);
+        assert_eq!(lookup.calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_falls_back_to_default_port() {
+        let lookup = MockServiceLookup::new(vec![service("app-stable", &[("grpc", 9000)])]);
+        let mut resolver = PortResolver::new(&lookup, "default");
+
+        // Port name missing on the Service
+        assert_eq!(resolver.resolve("app-stable", Some("http")).await, 80);
+        // Service missing
+        assert_eq!(resolver.resolve("app-canary", Some("http")).await, 80);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_caches_lookup_per_service() {
+        let lookup = MockServiceLookup::new(vec![service("app-stable", &[("http", 8080)])]);
+        let mut resolver = PortResolver::new(&lookup, "default");
+
+        assert_eq!(resolver.resolve("app-stable", Some("http")).await, 8080);
+        assert_eq!(resolver.resolve("app-stable", Some("http")).await, 8080);
+        assert_eq!(lookup.calls.load(Ordering::SeqCst), 1);
+    }
+}
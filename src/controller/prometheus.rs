@@ -2,7 +2,10 @@
 //!
 //! This module handles querying Prometheus and evaluating metrics against thresholds.
 
+use crate::crd::rollout::{MetricComparison, MetricResult, MetricResultStatus};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -21,6 +24,65 @@ pub enum PrometheusError {
 
     #[error("Invalid metric value: {0}")]
     InvalidValue(String),
+
+    #[error("Invalid Prometheus client config: {0}")]
+    InvalidConfig(String),
+}
+
+/// How many metrics in an analysis must pass for the rollout to be healthy
+///
+/// Configured via `analysis.requirement`: "all" (default), "any", or a count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricRequirement {
+    /// Every metric must pass (AND)
+    All,
+    /// At least one metric must pass (OR)
+    Any,
+    /// At least N metrics must pass (quorum, e.g. 2 of 3)
+    AtLeast(usize),
+}
+
+impl MetricRequirement {
+    /// Parse `analysis.requirement` (None defaults to All)
+    ///
+    /// Accepts "all", "any", or a positive count (as an integer or numeric string).
+    pub fn from_config(requirement: Option<&IntOrString>) -> Result<Self, String> {
+        let count = match requirement {
+            None => return Ok(MetricRequirement::All),
+            Some(IntOrString::String(value)) => match value.trim() {
+                "all" => return Ok(MetricRequirement::All),
+                "any" => return Ok(MetricRequirement::Any),
+                other => other.parse::<i32>().map_err(|_| {
+                    format!(
+                        "requirement must be \"all\", \"any\", or a count, got \"{}\"",
+                        value
+                    )
+                })?,
+            },
+            Some(IntOrString::Int(count)) => *count,
+        };
+
+        if count < 1 {
+            return Err(format!("requirement count must be >= 1, got {}", count));
+        }
+        Ok(MetricRequirement::AtLeast(count as usize))
+    }
+
+    /// Check whether individual metric results satisfy this requirement
+    ///
+    /// An empty result list (no metrics configured) is always satisfied.
+    pub fn is_satisfied(&self, results: &[bool]) -> bool {
+        if results.is_empty() {
+            return true;
+        }
+
+        let passed = results.iter().filter(|healthy| **healthy).count();
+        match self {
+            MetricRequirement::All => passed == results.len(),
+            MetricRequirement::Any => passed >= 1,
+            MetricRequirement::AtLeast(count) => passed >= *count,
+        }
+    }
 }
 
 /// Build PromQL query for error rate metric
@@ -45,6 +107,198 @@ fn build_latency_p95_query(rollout_name: &str, revision: &str) -> String {
     )
 }
 
+/// Build PromQL query for latency p99 metric
+///
+/// Uses histogram_quantile to calculate 99th percentile
+fn build_latency_p99_query(rollout_name: &str, revision: &str) -> String {
+    format!(
+        r#"histogram_quantile(0.99, rate(http_request_duration_seconds_bucket{{rollout="{}",revision="{}"}}[2m]))"#,
+        rollout_name, revision
+    )
+}
+
+/// Build PromQL query for latency p50 (median) metric
+fn build_latency_p50_query(rollout_name: &str, revision: &str) -> String {
+    format!(
+        r#"histogram_quantile(0.5, rate(http_request_duration_seconds_bucket{{rollout="{}",revision="{}"}}[2m]))"#,
+        rollout_name, revision
+    )
+}
+
+/// Metric template for total requests per second
+pub const REQUEST_RATE_METRIC: &str = "request-rate";
+
+/// Build PromQL query for request rate (total RPS) metric
+fn build_request_rate_query(rollout_name: &str, revision: &str) -> String {
+    format!(
+        r#"sum(rate(http_requests_total{{rollout="{}",revision="{}"}}[2m]))"#,
+        rollout_name, revision
+    )
+}
+
+/// Metric template counting 5xx responses over a window
+pub const COUNT_METRIC: &str = "count";
+
+/// Window of the `count` template when the metric sets no interval
+pub const DEFAULT_COUNT_WINDOW: &str = "5m";
+
+/// Build PromQL query for the count metric
+///
+/// Counts 5xx responses over the window from the raw request counter, for
+/// services without error-rate or latency histograms.
+fn build_count_query(rollout_name: &str, revision: &str, window: &str) -> String {
+    format!(
+        r#"sum(increase(http_requests_total{{status=~"5..",rollout="{}",revision="{}"}}[{}]))"#,
+        rollout_name, revision, window
+    )
+}
+
+/// Build the PromQL query of a metric template
+///
+/// The window only applies to the `count` template (default DEFAULT_COUNT_WINDOW).
+///
+/// # Errors
+/// Returns `InvalidQuery` for an unknown template name
+pub fn build_metric_query(
+    metric_name: &str,
+    rollout_name: &str,
+    revision: &str,
+    window: Option<&str>,
+) -> Result<String, PrometheusError> {
+    Ok(match metric_name {
+        "error-rate" => build_error_rate_query(rollout_name, revision),
+        "latency-p50" => build_latency_p50_query(rollout_name, revision),
+        "latency-p95" => build_latency_p95_query(rollout_name, revision),
+        "latency-p99" => build_latency_p99_query(rollout_name, revision),
+        REQUEST_RATE_METRIC => build_request_rate_query(rollout_name, revision),
+        COUNT_METRIC => build_count_query(
+            rollout_name,
+            revision,
+            window.unwrap_or(DEFAULT_COUNT_WINDOW),
+        ),
+        _ => {
+            return Err(PrometheusError::InvalidQuery(format!(
+                "Unknown metric template: {}",
+                metric_name
+            )))
+        }
+    })
+}
+
+/// Labels set by the templates themselves, which a metric's labels may not override
+pub const RESERVED_METRIC_LABELS: [&str; 2] = ["rollout", "revision"];
+
+/// Add label matchers (`key="value"`) to every series selector of a query
+///
+/// Each `{...}` selector gets the labels appended in key order, e.g.
+/// `up{job="api"}` with `cluster: eu` becomes `up{job="api",cluster="eu"}`.
+/// Values are escaped as PromQL strings.
+pub fn add_label_matchers(query: &str, labels: &BTreeMap<String, String>) -> String {
+    if labels.is_empty() {
+        return query.to_string();
+    }
+
+    let matchers = labels
+        .iter()
+        .map(|(key, value)| {
+            format!(
+                r#"{}="{}""#,
+                key,
+                value.replace('\\', r"\\").replace('"', r#"\""#)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let mut result = String::with_capacity(query.len() + matchers.len());
+    let mut selector_start = None;
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in query.chars() {
+        if in_string {
+            // Braces inside quoted values (e.g. regex matchers) are not selectors
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == '{' {
+            selector_start = Some(result.len() + 1);
+        } else if c == '}' {
+            if let Some(start) = selector_start.take() {
+                if result.len() > start {
+                    result.push(',');
+                }
+                result.push_str(&matchers);
+            }
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Validate a metric's labels: Prometheus label names, none reserved
+pub fn validate_metric_labels(labels: &BTreeMap<String, String>) -> Result<(), String> {
+    for key in labels.keys() {
+        let mut chars = key.chars();
+        let valid = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid {
+            return Err(format!("invalid label name {:?}", key));
+        }
+        if RESERVED_METRIC_LABELS.contains(&key.as_str()) {
+            return Err(format!("label {:?} is set by the query template", key));
+        }
+    }
+    Ok(())
+}
+
+/// Default comparison of a metric template
+///
+/// Request rate is healthy above its threshold (traffic still flowing);
+/// every other template is healthy below it.
+pub fn default_comparison(metric_name: &str) -> MetricComparison {
+    if metric_name == REQUEST_RATE_METRIC {
+        MetricComparison::GreaterThan
+    } else {
+        MetricComparison::LessThan
+    }
+}
+
+/// Compare a metric value against its threshold using the template's default comparison
+fn is_metric_healthy(metric_name: &str, value: f64, threshold: f64) -> bool {
+    compare_metric(
+        metric_name,
+        value,
+        threshold,
+        default_comparison(metric_name),
+    )
+}
+
+/// Compare a metric value against its threshold
+///
+/// LessThan: healthy strictly below the threshold. Counts fail only once
+/// they exceed it, compared as whole numbers since `increase()` extrapolates
+/// fractional counts. GreaterThan: healthy strictly above the threshold.
+fn compare_metric(
+    metric_name: &str,
+    value: f64,
+    threshold: f64,
+    comparison: MetricComparison,
+) -> bool {
+    match comparison {
+        MetricComparison::LessThan if metric_name == COUNT_METRIC => value.round() <= threshold,
+        MetricComparison::LessThan => value < threshold,
+        MetricComparison::GreaterThan => value > threshold,
+    }
+}
+
 /// Prometheus instant query response format
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)] // Used in parse_prometheus_instant_query, will be used in production
@@ -104,20 +358,174 @@ fn parse_prometheus_instant_query(json_response: &str) -> Result<f64, Prometheus
     Ok(value)
 }
 
+/// Authentication attached to Prometheus queries
+#[derive(Clone, Default, PartialEq, Eq)]
+pub enum PrometheusAuth {
+    /// No Authorization header
+    #[default]
+    None,
+    /// `Authorization: Bearer <token>`
+    Bearer(String),
+    /// `Authorization: Basic <base64(user:password)>`
+    Basic {
+        username: String,
+        password: Option<String>,
+    },
+}
+
+// Manual Debug so credentials never end up in logs
+impl std::fmt::Debug for PrometheusAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Bearer(_) => write!(f, "Bearer(<redacted>)"),
+            Self::Basic { username, .. } => write!(f, "Basic({}:<redacted>)", username),
+        }
+    }
+}
+
+impl PrometheusAuth {
+    /// Resolve auth from the raw KULTA_PROMETHEUS_TOKEN/USER/PASSWORD values
+    ///
+    /// Empty values count as unset. A token and a user are mutually
+    /// exclusive, and a password requires a user.
+    pub fn resolve(
+        token: Option<String>,
+        username: Option<String>,
+        password: Option<String>,
+    ) -> Result<Self, String> {
+        let non_empty = |v: Option<String>| v.filter(|v| !v.is_empty());
+        match (non_empty(token), non_empty(username), non_empty(password)) {
+            (None, None, None) => Ok(Self::None),
+            (Some(token), None, None) => Ok(Self::Bearer(token)),
+            (None, Some(username), password) => Ok(Self::Basic { username, password }),
+            (Some(_), _, _) => Err(
+                "KULTA_PROMETHEUS_TOKEN cannot be combined with KULTA_PROMETHEUS_USER/PASSWORD"
+                    .to_string(),
+            ),
+            (None, None, Some(_)) => {
+                Err("KULTA_PROMETHEUS_PASSWORD requires KULTA_PROMETHEUS_USER".to_string())
+            }
+        }
+    }
+
+    /// Attach the Authorization header for this auth to a request
+    pub fn apply(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match self {
+            Self::None => request,
+            Self::Bearer(token) => request.bearer_auth(token),
+            Self::Basic { username, password } => request.basic_auth(username, password.as_ref()),
+        }
+    }
+}
+
+/// Connection settings for Prometheus: auth and TLS
+///
+/// Configured via environment variables:
+/// - `KULTA_PROMETHEUS_TOKEN` - bearer token
+/// - `KULTA_PROMETHEUS_USER` / `KULTA_PROMETHEUS_PASSWORD` - basic auth
+/// - `KULTA_PROMETHEUS_CA_CERT` - path to a PEM CA bundle for internal TLS
+/// - `KULTA_PROMETHEUS_INSECURE_SKIP_VERIFY` - accept invalid certificates
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrometheusConnectionConfig {
+    /// Authentication attached to every query
+    pub auth: PrometheusAuth,
+    /// Path to a PEM-encoded CA certificate trusted in addition to system roots
+    pub ca_cert_path: Option<String>,
+    /// Skip TLS certificate verification (testing only)
+    pub accept_invalid_certs: bool,
+}
+
+impl PrometheusConnectionConfig {
+    /// Read connection settings from environment variables
+    pub fn from_env() -> Result<Self, PrometheusError> {
+        let var = |name: &str| std::env::var(name).ok();
+        let auth = PrometheusAuth::resolve(
+            var("KULTA_PROMETHEUS_TOKEN"),
+            var("KULTA_PROMETHEUS_USER"),
+            var("KULTA_PROMETHEUS_PASSWORD"),
+        )
+        .map_err(PrometheusError::InvalidConfig)?;
+
+        Ok(Self {
+            auth,
+            ca_cert_path: var("KULTA_PROMETHEUS_CA_CERT").filter(|v| !v.is_empty()),
+            accept_invalid_certs: var("KULTA_PROMETHEUS_INSECURE_SKIP_VERIFY")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+        })
+    }
+
+    /// Build an HTTP client honoring the TLS settings
+    ///
+    /// # Errors
+    /// Returns `InvalidConfig` if the CA file cannot be read or parsed
+    pub fn build_http_client(&self) -> Result<reqwest::Client, PrometheusError> {
+        let mut builder = reqwest::Client::builder();
+
+        if let Some(path) = &self.ca_cert_path {
+            let pem = std::fs::read(path).map_err(|e| {
+                PrometheusError::InvalidConfig(format!("cannot read CA cert {}: {}", path, e))
+            })?;
+            let cert = reqwest::Certificate::from_pem(&pem).map_err(|e| {
+                PrometheusError::InvalidConfig(format!("invalid CA cert {}: {}", path, e))
+            })?;
+            builder = builder.add_root_certificate(cert);
+        }
+
+        if self.accept_invalid_certs {
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+
+        builder
+            .build()
+            .map_err(|e| PrometheusError::InvalidConfig(format!("cannot build HTTP client: {}", e)))
+    }
+}
+
 /// Prometheus client for executing queries
 #[derive(Clone)]
 pub struct PrometheusClient {
     #[cfg(not(test))]
     address: String,
+    #[cfg(not(test))]
+    http: reqwest::Client,
+    #[cfg(not(test))]
+    auth: PrometheusAuth,
     #[cfg(test)]
     mock_response: std::sync::Arc<std::sync::Mutex<Option<String>>>,
+    #[cfg(test)]
+    mock_revision_responses:
+        std::sync::Arc<std::sync::Mutex<std::collections::HashMap<String, String>>>,
+    #[cfg(test)]
+    mock_query_count: std::sync::Arc<std::sync::atomic::AtomicUsize>,
 }
 
 impl PrometheusClient {
     /// Create new Prometheus client
     #[cfg(not(test))]
     pub fn new(address: String) -> Self {
-        Self { address }
+        Self {
+            address,
+            http: reqwest::Client::new(),
+            auth: PrometheusAuth::None,
+        }
+    }
+
+    /// Create Prometheus client with auth and TLS settings
+    ///
+    /// # Errors
+    /// Returns `InvalidConfig` if the TLS settings cannot be applied
+    #[cfg(not(test))]
+    pub fn with_config(
+        address: String,
+        config: &PrometheusConnectionConfig,
+    ) -> Result<Self, PrometheusError> {
+        Ok(Self {
+            address,
+            http: config.build_http_client()?,
+            auth: config.auth.clone(),
+        })
     }
 
     /// Create mock client for testing
@@ -125,6 +533,8 @@ impl PrometheusClient {
     pub fn new_mock() -> Self {
         Self {
             mock_response: std::sync::Arc::new(std::sync::Mutex::new(None)),
+            mock_revision_responses: std::sync::Arc::default(),
+            mock_query_count: std::sync::Arc::default(),
         }
     }
 
@@ -136,17 +546,32 @@ impl PrometheusClient {
         }
     }
 
+    /// Set mock response for queries on one revision label (takes precedence)
+    #[cfg(test)]
+    pub fn set_mock_response_for_revision(&self, revision: &str, response: String) {
+        if let Ok(mut mocks) = self.mock_revision_responses.lock() {
+            mocks.insert(revision.to_string(), response);
+        }
+    }
+
+    /// Number of queries issued to the mock
+    #[cfg(test)]
+    pub fn mock_query_count(&self) -> usize {
+        self.mock_query_count
+            .load(std::sync::atomic::Ordering::SeqCst)
+    }
+
     /// Execute instant query against Prometheus
     ///
     /// Queries the /api/v1/query endpoint and returns the first metric value.
     #[cfg(not(test))]
     pub async fn query_instant(&self, query: &str) -> Result<f64, PrometheusError> {
         let url = format!("{}/api/v1/query", self.address);
-        let client = reqwest::Client::new();
+        let request = self.http.get(&url).query(&[("query", query)]);
 
-        let response = client
-            .get(&url)
-            .query(&[("query", query)])
+        let response = self
+            .auth
+            .apply(request)
             .send()
             .await
             .map_err(|e| PrometheusError::HttpError(format!("HTTP request failed: {}", e)))?;
@@ -161,7 +586,20 @@ impl PrometheusClient {
 
     /// Execute instant query (mock version for tests)
     #[cfg(test)]
-    pub async fn query_instant(&self, _query: &str) -> Result<f64, PrometheusError> {
+    pub async fn query_instant(&self, query: &str) -> Result<f64, PrometheusError> {
+        self.mock_query_count
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        let by_revision = self
+            .mock_revision_responses
+            .lock()
+            .map_err(|_| PrometheusError::HttpError("Lock poisoned".to_string()))?
+            .iter()
+            .find(|(revision, _)| query.contains(&format!(r#"revision="{}""#, revision)))
+            .map(|(_, response)| response.clone());
+        if let Some(response) = by_revision {
+            return parse_prometheus_instant_query(&response);
+        }
+
         let mock = self
             .mock_response
             .lock()
@@ -178,14 +616,16 @@ impl PrometheusClient {
     /// executes it, and compares the result to the threshold.
     ///
     /// # Arguments
-    /// * `metric_name` - Template name ("error-rate", "latency-p95", "latency-p99")
+    /// * `metric_name` - Template name ("error-rate", "latency-p50", "latency-p95",
+    ///   "latency-p99", "request-rate", "count")
     /// * `rollout_name` - Name of the rollout
     /// * `revision` - Revision label ("canary" or "stable")
-    /// * `threshold` - Threshold value (metric must be below this)
+    /// * `threshold` - Threshold value (metric must be below this; a count must
+    ///   not exceed it; a request rate must be above it)
     ///
     /// # Returns
-    /// * `Ok(true)` - Metric is healthy (below threshold)
-    /// * `Ok(false)` - Metric is unhealthy (above or equal to threshold)
+    /// * `Ok(true)` - Metric is healthy (on the healthy side of the threshold)
+    /// * `Ok(false)` - Metric is unhealthy
     /// * `Err(_)` - Query execution failed
     pub async fn evaluate_metric(
         &self,
@@ -194,23 +634,66 @@ impl PrometheusClient {
         revision: &str,
         threshold: f64,
     ) -> Result<bool, PrometheusError> {
-        // Build query from template
-        let query = match metric_name {
-            "error-rate" => build_error_rate_query(rollout_name, revision),
-            "latency-p95" => build_latency_p95_query(rollout_name, revision),
-            _ => {
-                return Err(PrometheusError::InvalidQuery(format!(
-                    "Unknown metric template: {}",
-                    metric_name
-                )))
-            }
-        };
+        self.evaluate_metric_over(metric_name, rollout_name, revision, threshold, None, None)
+            .await
+    }
 
-        // Execute query
-        let value = self.query_instant(&query).await?;
+    /// Evaluate a metric by name against threshold, over the given window
+    ///
+    /// The window (e.g. "5m") only applies to the `count` template and
+    /// defaults to DEFAULT_COUNT_WINDOW. The comparison defaults to the
+    /// template's (see `default_comparison`).
+    pub async fn evaluate_metric_over(
+        &self,
+        metric_name: &str,
+        rollout_name: &str,
+        revision: &str,
+        threshold: f64,
+        window: Option<&str>,
+        comparison: Option<MetricComparison>,
+    ) -> Result<bool, PrometheusError> {
+        let value = self
+            .query_metric(metric_name, rollout_name, revision, window)
+            .await?;
+
+        Ok(match comparison {
+            Some(comparison) => compare_metric(metric_name, value, threshold, comparison),
+            None => is_metric_healthy(metric_name, value, threshold),
+        })
+    }
 
-        // Compare to threshold (healthy if < threshold)
-        Ok(value < threshold)
+    /// Query the current value of a metric template
+    ///
+    /// The window only applies to the `count` template (see `evaluate_metric_over`).
+    pub async fn query_metric(
+        &self,
+        metric_name: &str,
+        rollout_name: &str,
+        revision: &str,
+        window: Option<&str>,
+    ) -> Result<f64, PrometheusError> {
+        self.query_metric_with_labels(
+            metric_name,
+            rollout_name,
+            revision,
+            window,
+            &BTreeMap::new(),
+        )
+        .await
+    }
+
+    /// Query a metric template with extra label matchers (a metric's `labels`)
+    pub async fn query_metric_with_labels(
+        &self,
+        metric_name: &str,
+        rollout_name: &str,
+        revision: &str,
+        window: Option<&str>,
+        labels: &BTreeMap<String, String>,
+    ) -> Result<f64, PrometheusError> {
+        let query = build_metric_query(metric_name, rollout_name, revision, window)?;
+        self.query_instant(&add_label_matchers(&query, labels))
+            .await
     }
 
     /// Evaluate all metrics from analysis config
@@ -232,30 +715,234 @@ impl PrometheusClient {
         metrics: &[crate::crd::rollout::MetricConfig],
         rollout_name: &str,
         revision: &str,
+    ) -> Result<bool, PrometheusError> {
+        self.evaluate_metrics_with_requirement(
+            metrics,
+            MetricRequirement::All,
+            rollout_name,
+            revision,
+        )
+        .await
+    }
+
+    /// Evaluate metrics and check how many passed against a requirement
+    ///
+    /// # Arguments
+    /// * `metrics` - List of metrics from Rollout's analysis config
+    /// * `requirement` - How many metrics must pass (all, any, or a count)
+    /// * `rollout_name` - Name of the rollout
+    /// * `revision` - Revision label ("canary" or "stable")
+    ///
+    /// # Returns
+    /// * `Ok(true)` - Enough metrics healthy to satisfy the requirement
+    /// * `Ok(false)` - Too few metrics healthy
+    /// * `Err(_)` - Query execution failed
+    pub async fn evaluate_metrics_with_requirement(
+        &self,
+        metrics: &[crate::crd::rollout::MetricConfig],
+        requirement: MetricRequirement,
+        rollout_name: &str,
+        revision: &str,
     ) -> Result<bool, PrometheusError> {
         // Empty metrics list = no constraints = healthy
         if metrics.is_empty() {
             return Ok(true);
         }
 
-        // Evaluate each metric
+        // Evaluate each metric (all results are needed for any/count semantics)
+        let mut results = Vec::with_capacity(metrics.len());
         for metric in metrics {
-            let is_healthy = self
-                .evaluate_metric(&metric.name, rollout_name, revision, metric.threshold)
+            let value = self
+                .query_metric_with_labels(
+                    &metric.name,
+                    rollout_name,
+                    revision,
+                    metric.interval.as_deref(),
+                    &metric.labels,
+                )
                 .await?;
+            let comparison = metric
+                .comparison
+                .unwrap_or_else(|| default_comparison(&metric.name));
+            results.push(compare_metric(
+                &metric.name,
+                value,
+                metric.threshold,
+                comparison,
+            ));
+        }
 
-            // If ANY metric is unhealthy, return false immediately
-            if !is_healthy {
-                return Ok(false);
-            }
+        Ok(requirement.is_satisfied(&results))
+    }
+
+    /// Evaluate each metric, recording its value and outcome
+    ///
+    /// Unlike `evaluate_metrics_with_requirement`, a failed query doesn't
+    /// stop the run: that metric is recorded as Skipped with the error.
+    pub async fn evaluate_metric_results(
+        &self,
+        metrics: &[crate::crd::rollout::MetricConfig],
+        rollout_name: &str,
+        revision: &str,
+    ) -> Vec<MetricResult> {
+        let mut results = Vec::with_capacity(metrics.len());
+        for metric in metrics {
+            let value = self
+                .query_metric_with_labels(
+                    &metric.name,
+                    rollout_name,
+                    revision,
+                    metric.interval.as_deref(),
+                    &metric.labels,
+                )
+                .await;
+            let comparison = metric
+                .comparison
+                .unwrap_or_else(|| default_comparison(&metric.name));
+            results.push(metric_result(
+                metric,
+                metric.threshold,
+                value.map(|value| {
+                    let passed = compare_metric(&metric.name, value, metric.threshold, comparison);
+                    (value, passed)
+                }),
+            ));
         }
+        results
+    }
 
-        // All metrics passed
-        Ok(true)
+    /// Evaluate each metric for both canary and stable (canary-vs-stable mode)
+    ///
+    /// A metric passes if the canary is no worse than stable within the
+    /// tolerance (see `canary_bound`); its result records the canary value
+    /// and the bound it was held to. Failed queries (either revision) are
+    /// recorded as Skipped.
+    pub async fn evaluate_canary_vs_stable(
+        &self,
+        metrics: &[crate::crd::rollout::MetricConfig],
+        rollout_name: &str,
+        tolerance: f64,
+    ) -> Vec<MetricResult> {
+        let mut results = Vec::with_capacity(metrics.len());
+        for metric in metrics {
+            let window = metric.interval.as_deref();
+            let comparison = metric
+                .comparison
+                .unwrap_or_else(|| default_comparison(&metric.name));
+
+            let stable = match self
+                .query_metric_with_labels(
+                    &metric.name,
+                    rollout_name,
+                    "stable",
+                    window,
+                    &metric.labels,
+                )
+                .await
+            {
+                Ok(stable) => stable,
+                Err(e) => {
+                    results.push(metric_result(metric, metric.threshold, Err(e)));
+                    continue;
+                }
+            };
+            let bound = canary_bound(stable, tolerance, comparison);
+            let canary = self
+                .query_metric_with_labels(
+                    &metric.name,
+                    rollout_name,
+                    "canary",
+                    window,
+                    &metric.labels,
+                )
+                .await;
+
+            results.push(metric_result(
+                metric,
+                bound,
+                canary.map(|canary| (canary, is_no_worse(canary, bound, comparison))),
+            ));
+        }
+        results
+    }
+}
+
+/// Worst canary value still acceptable against a stable value
+///
+/// LessThan metrics may be up to `tolerance` (a fraction) above stable,
+/// GreaterThan metrics up to `tolerance` below it.
+pub fn canary_bound(stable: f64, tolerance: f64, comparison: MetricComparison) -> f64 {
+    match comparison {
+        MetricComparison::LessThan => stable + stable.abs() * tolerance,
+        MetricComparison::GreaterThan => stable - stable.abs() * tolerance,
+    }
+}
+
+/// Whether a canary value is within its bound (reaching the bound passes)
+fn is_no_worse(canary: f64, bound: f64, comparison: MetricComparison) -> bool {
+    match comparison {
+        MetricComparison::LessThan => canary <= bound,
+        MetricComparison::GreaterThan => canary >= bound,
+    }
+}
+
+/// Result of one metric: its value and whether it passed, or the query error
+fn metric_result(
+    metric: &crate::crd::rollout::MetricConfig,
+    threshold: f64,
+    outcome: Result<(f64, bool), PrometheusError>,
+) -> MetricResult {
+    match outcome {
+        Ok((value, passed)) => MetricResult {
+            name: metric.name.clone(),
+            status: if passed {
+                MetricResultStatus::Passed
+            } else {
+                MetricResultStatus::Failed
+            },
+            value: Some(value),
+            threshold,
+            error: None,
+        },
+        Err(e) => MetricResult {
+            name: metric.name.clone(),
+            status: MetricResultStatus::Skipped,
+            value: None,
+            threshold,
+            error: Some(e.to_string()),
+        },
     }
 }
 
+/// Overall verdict of an analysis from its per-metric results
+///
+/// # Returns
+/// * `Ok(true)` - Enough metrics passed to satisfy the requirement
+/// * `Ok(false)` - Too few metrics passed
+/// * `Err(message)` - A metric could not be evaluated (the first one's error)
+pub fn analysis_verdict(
+    results: &[MetricResult],
+    requirement: MetricRequirement,
+) -> Result<bool, String> {
+    if let Some(skipped) = results
+        .iter()
+        .find(|result| result.status == MetricResultStatus::Skipped)
+    {
+        return Err(skipped
+            .error
+            .clone()
+            .unwrap_or_else(|| format!("metric {} not evaluated", skipped.name)));
+    }
+
+    let passed: Vec<bool> = results
+        .iter()
+        .map(|result| result.status == MetricResultStatus::Passed)
+        .collect();
+    Ok(requirement.is_satisfied(&passed))
+}
+
 #[cfg(test)]
+#[allow(clippy::unwrap_used)] // Tests can use unwrap for brevity
 mod tests {
     use super::*;
 
@@ -288,6 +975,455 @@ mod tests {
         assert!(query.contains(revision));
     }
 
+    #[test]
+    fn test_build_latency_p99_query() {
+        assert_eq!(
+            build_latency_p99_query("my-app", "canary"),
+            r#"histogram_quantile(0.99, rate(http_request_duration_seconds_bucket{rollout="my-app",revision="canary"}[2m]))"#
+        );
+    }
+
+    #[test]
+    fn test_build_latency_p50_query() {
+        assert_eq!(
+            build_latency_p50_query("my-app", "canary"),
+            r#"histogram_quantile(0.5, rate(http_request_duration_seconds_bucket{rollout="my-app",revision="canary"}[2m]))"#
+        );
+    }
+
+    #[test]
+    fn test_build_request_rate_query() {
+        assert_eq!(
+            build_request_rate_query("my-app", "canary"),
+            r#"sum(rate(http_requests_total{rollout="my-app",revision="canary"}[2m]))"#
+        );
+    }
+
+    fn labels(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_add_label_matchers_to_every_selector() {
+        let query = build_metric_query("error-rate", "my-app", "canary", None).unwrap();
+
+        assert_eq!(
+            add_label_matchers(&query, &labels(&[("service", "api"), ("cluster", "eu-1")])),
+            r#"sum(rate(http_requests_total{status=~"5..",rollout="my-app",revision="canary",cluster="eu-1",service="api"}[2m])) / sum(rate(http_requests_total{rollout="my-app",revision="canary",cluster="eu-1",service="api"}[2m])) * 100"#
+        );
+    }
+
+    #[test]
+    fn test_add_label_matchers_to_custom_query() {
+        let query = r#"sum(rate(grpc_errors_total{code=~"Unavailable|{x}"}[1m])) / sum(rate(grpc_requests_total{}[1m]))"#;
+
+        assert_eq!(
+            add_label_matchers(query, &labels(&[("version", "v2"), ("cluster", "eu-1")])),
+            r#"sum(rate(grpc_errors_total{code=~"Unavailable|{x}",cluster="eu-1",version="v2"}[1m])) / sum(rate(grpc_requests_total{cluster="eu-1",version="v2"}[1m]))"#
+        );
+    }
+
+    #[test]
+    fn test_add_label_matchers_escapes_values_and_skips_empty() {
+        let query = r#"up{job="api"}"#;
+
+        assert_eq!(add_label_matchers(query, &BTreeMap::new()), query);
+        assert_eq!(
+            add_label_matchers(query, &labels(&[("team", r#"a"b"#)])),
+            r#"up{job="api",team="a\"b"}"#
+        );
+    }
+
+    #[test]
+    fn test_validate_metric_labels() {
+        assert!(validate_metric_labels(&labels(&[("service", "api"), ("_zone", "a")])).is_ok());
+        assert!(validate_metric_labels(&labels(&[("9lives", "x")])).is_err());
+        assert!(validate_metric_labels(&labels(&[("app-name", "x")])).is_err());
+        assert!(validate_metric_labels(&labels(&[("revision", "stable")]))
+            .unwrap_err()
+            .contains("set by the query template"));
+    }
+
+    #[tokio::test]
+    async fn test_metric_labels_applied_when_evaluating() {
+        let client = PrometheusClient::new_mock();
+        // Only queries carrying the cluster matcher hit this response
+        client.set_mock_response_for_revision(
+            r#"canary",cluster="eu-1"#,
+            r#"{"status":"success","data":{"resultType":"vector","result":[{"metric":{},"value":[1234567890,"1.0"]}]}}"#
+                .to_string(),
+        );
+        let mut metric = metric("error-rate", 5.0);
+        metric.labels = labels(&[("cluster", "eu-1")]);
+
+        let results = client
+            .evaluate_metric_results(&[metric], "my-app", "canary")
+            .await;
+
+        assert_eq!(results[0].status, MetricResultStatus::Passed);
+        assert_eq!(results[0].value, Some(1.0));
+    }
+
+    #[test]
+    fn test_default_comparison_by_template() {
+        assert_eq!(
+            default_comparison(REQUEST_RATE_METRIC),
+            MetricComparison::GreaterThan
+        );
+        for template in [
+            "error-rate",
+            "latency-p50",
+            "latency-p95",
+            "latency-p99",
+            "count",
+        ] {
+            assert_eq!(default_comparison(template), MetricComparison::LessThan);
+        }
+    }
+
+    #[test]
+    fn test_compare_metric_both_directions() {
+        // LessThan: strictly below is healthy
+        assert!(compare_metric(
+            "latency-p99",
+            0.4,
+            0.5,
+            MetricComparison::LessThan
+        ));
+        assert!(!compare_metric(
+            "latency-p99",
+            0.5,
+            0.5,
+            MetricComparison::LessThan
+        ));
+
+        // GreaterThan: strictly above is healthy
+        assert!(compare_metric(
+            REQUEST_RATE_METRIC,
+            120.0,
+            100.0,
+            MetricComparison::GreaterThan
+        ));
+        assert!(!compare_metric(
+            REQUEST_RATE_METRIC,
+            100.0,
+            100.0,
+            MetricComparison::GreaterThan
+        ));
+        assert!(!compare_metric(
+            REQUEST_RATE_METRIC,
+            80.0,
+            100.0,
+            MetricComparison::GreaterThan
+        ));
+    }
+
+    /// Mock client whose every query returns `value`
+    fn mock_client_returning(value: &str) -> PrometheusClient {
+        let client = PrometheusClient::new_mock();
+        client.set_mock_response(format!(
+            r#"{{"status":"success","data":{{"resultType":"vector","result":[{{"metric":{{}},"value":[1234567890,"{}"]}}]}}}}"#,
+            value
+        ));
+        client
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_new_templates() {
+        let client = mock_client_returning("0.3");
+
+        for template in ["latency-p50", "latency-p99"] {
+            let healthy = client
+                .evaluate_metric(template, "my-app", "canary", 0.5)
+                .await;
+            let unhealthy = client
+                .evaluate_metric(template, "my-app", "canary", 0.2)
+                .await;
+            assert!(matches!(healthy, Ok(true)), "{}: {:?}", template, healthy);
+            assert!(
+                matches!(unhealthy, Ok(false)),
+                "{}: {:?}",
+                template,
+                unhealthy
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_request_rate_is_healthy_above_threshold() {
+        let client = mock_client_returning("150");
+
+        let above = client
+            .evaluate_metric(REQUEST_RATE_METRIC, "my-app", "canary", 100.0)
+            .await;
+        let below = client
+            .evaluate_metric(REQUEST_RATE_METRIC, "my-app", "canary", 200.0)
+            .await;
+
+        assert!(
+            matches!(above, Ok(true)),
+            "150 rps > 100 should pass, got {:?}",
+            above
+        );
+        assert!(
+            matches!(below, Ok(false)),
+            "150 rps < 200 should fail, got {:?}",
+            below
+        );
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_metric_explicit_comparison_overrides_default() {
+        let client = mock_client_returning("150");
+
+        // Request rate capped from above (e.g. protect a downstream dependency)
+        let capped = client
+            .evaluate_metric_over(
+                REQUEST_RATE_METRIC,
+                "my-app",
+                "canary",
+                100.0,
+                None,
+                Some(MetricComparison::LessThan),
+            )
+            .await;
+        // Error rate flipped to greater-than
+        let inverted = client
+            .evaluate_metric_over(
+                "error-rate",
+                "my-app",
+                "canary",
+                100.0,
+                None,
+                Some(MetricComparison::GreaterThan),
+            )
+            .await;
+
+        assert!(matches!(capped, Ok(false)), "got {:?}", capped);
+        assert!(matches!(inverted, Ok(true)), "got {:?}", inverted);
+    }
+
+    fn metric(name: &str, threshold: f64) -> crate::crd::rollout::MetricConfig {
+        crate::crd::rollout::MetricConfig {
+            name: name.to_string(),
+            threshold,
+            interval: None,
+            failure_threshold: None,
+            min_sample_size: None,
+            comparison: None,
+            labels: BTreeMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_metric_results_records_values() {
+        let client = mock_client_returning("0.3");
+        let metrics = vec![metric("latency-p99", 0.5), metric("latency-p50", 0.2)];
+
+        let results = client
+            .evaluate_metric_results(&metrics, "my-app", "canary")
+            .await;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].name, "latency-p99");
+        assert_eq!(results[0].status, MetricResultStatus::Passed);
+        assert_eq!(results[0].value, Some(0.3));
+        assert_eq!(results[0].threshold, 0.5);
+        assert_eq!(results[1].status, MetricResultStatus::Failed);
+        assert!(results.iter().all(|result| result.error.is_none()));
+
+        assert_eq!(
+            analysis_verdict(&results, MetricRequirement::All),
+            Ok(false)
+        );
+        assert_eq!(analysis_verdict(&results, MetricRequirement::Any), Ok(true));
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_metric_results_skips_failed_queries() {
+        // No mock response: every query fails
+        let client = PrometheusClient::new_mock();
+        let metrics = vec![metric("error-rate", 5.0), metric("unknown-template", 1.0)];
+
+        let results = client
+            .evaluate_metric_results(&metrics, "my-app", "canary")
+            .await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results
+            .iter()
+            .all(|result| result.status == MetricResultStatus::Skipped && result.value.is_none()));
+        let error = results[0].error.clone().unwrap_or_default();
+        assert!(error.contains("No mock response"), "{}", error);
+        let error = results[1].error.clone().unwrap_or_default();
+        assert!(error.contains("Unknown metric template"), "{}", error);
+
+        match analysis_verdict(&results, MetricRequirement::Any) {
+            Err(message) => assert!(message.contains("No mock response"), "{}", message),
+            other => panic!("expected an error verdict, got {:?}", other),
+        }
+    }
+
+    /// Mock client returning `canary` for canary queries and `stable` for stable ones
+    fn mock_client_by_revision(canary: &str, stable: &str) -> PrometheusClient {
+        let client = PrometheusClient::new_mock();
+        for (revision, value) in [("canary", canary), ("stable", stable)] {
+            client.set_mock_response_for_revision(
+                revision,
+                format!(
+                    r#"{{"status":"success","data":{{"resultType":"vector","result":[{{"metric":{{}},"value":[1234567890,"{}"]}}]}}}}"#,
+                    value
+                ),
+            );
+        }
+        client
+    }
+
+    #[test]
+    fn test_canary_bound_by_comparison() {
+        assert_eq!(canary_bound(2.0, 0.5, MetricComparison::LessThan), 3.0);
+        assert_eq!(
+            canary_bound(100.0, 0.1, MetricComparison::GreaterThan),
+            90.0
+        );
+        assert_eq!(canary_bound(2.0, 0.0, MetricComparison::LessThan), 2.0);
+    }
+
+    #[tokio::test]
+    async fn test_canary_vs_stable_within_tolerance_is_healthy() {
+        // Canary 10% above stable, 20% tolerated, on every metric
+        let client = mock_client_by_revision("1.1", "1.0");
+        let metrics = vec![metric("error-rate", 5.0), metric("latency-p99", 0.5)];
+
+        let results = client
+            .evaluate_canary_vs_stable(&metrics, "my-app", 0.2)
+            .await;
+
+        assert_eq!(results.len(), 2);
+        for result in &results {
+            assert_eq!(result.status, MetricResultStatus::Passed, "{:?}", result);
+            assert_eq!(result.value, Some(1.1));
+            assert_eq!(result.threshold, 1.2);
+        }
+        assert_eq!(analysis_verdict(&results, MetricRequirement::All), Ok(true));
+    }
+
+    #[tokio::test]
+    async fn test_canary_vs_stable_regressed_metric_is_unhealthy() {
+        // Canary 50% above stable: latency regressed, request rate fine
+        let client = mock_client_by_revision("1.5", "1.0");
+        let metrics = vec![metric("latency-p99", 0.5), metric(REQUEST_RATE_METRIC, 1.0)];
+
+        let results = client
+            .evaluate_canary_vs_stable(&metrics, "my-app", 0.2)
+            .await;
+
+        assert_eq!(results[0].status, MetricResultStatus::Failed);
+        assert_eq!(results[0].value, Some(1.5));
+        // Higher request rate is never worse
+        assert_eq!(results[1].status, MetricResultStatus::Passed);
+        assert_eq!(
+            analysis_verdict(&results, MetricRequirement::All),
+            Ok(false)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_canary_vs_stable_without_stable_data_is_skipped() {
+        let client = PrometheusClient::new_mock();
+        client.set_mock_response_for_revision(
+            "canary",
+            r#"{"status":"success","data":{"resultType":"vector","result":[{"metric":{},"value":[1234567890,"1.0"]}]}}"#.to_string(),
+        );
+
+        let results = client
+            .evaluate_canary_vs_stable(&[metric("error-rate", 5.0)], "my-app", 0.2)
+            .await;
+
+        assert_eq!(results[0].status, MetricResultStatus::Skipped);
+        assert!(results[0].error.is_some());
+    }
+
+    #[test]
+    fn test_analysis_verdict_without_metrics_is_healthy() {
+        assert_eq!(analysis_verdict(&[], MetricRequirement::All), Ok(true));
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_all_metrics_uses_metric_comparison() {
+        let client = mock_client_returning("150");
+        let metrics = vec![crate::crd::rollout::MetricConfig {
+            name: "latency-p95".to_string(),
+            threshold: 100.0,
+            interval: None,
+            failure_threshold: None,
+            min_sample_size: None,
+            comparison: Some(MetricComparison::GreaterThan),
+            labels: BTreeMap::new(),
+        }];
+
+        let result = client
+            .evaluate_all_metrics(&metrics, "my-app", "canary")
+            .await;
+
+        assert!(matches!(result, Ok(true)), "got {:?}", result);
+    }
+
+    #[test]
+    fn test_build_count_query() {
+        let query = build_count_query("my-app", "canary", "10m");
+
+        assert_eq!(
+            query,
+            r#"sum(increase(http_requests_total{status=~"5..",rollout="my-app",revision="canary"}[10m]))"#
+        );
+    }
+
+    #[test]
+    fn test_count_metric_fails_only_above_threshold() {
+        // Greater-than: a count equal to the threshold is still healthy
+        assert!(is_metric_healthy(COUNT_METRIC, 0.0, 10.0));
+        assert!(is_metric_healthy(COUNT_METRIC, 10.0, 10.0));
+        assert!(!is_metric_healthy(COUNT_METRIC, 11.0, 10.0));
+
+        // Extrapolated counts are compared as whole numbers
+        assert!(is_metric_healthy(COUNT_METRIC, 10.4, 10.0));
+        assert!(!is_metric_healthy(COUNT_METRIC, 10.6, 10.0));
+
+        // Other templates keep the strict less-than comparison
+        assert!(!is_metric_healthy("error-rate", 10.0, 10.0));
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_count_metric_uses_window_and_greater_than() {
+        let client = PrometheusClient::new_mock();
+        client.set_mock_response(
+            r#"{"status":"success","data":{"resultType":"vector","result":[{"metric":{},"value":[1234567890,"3"]}]}}"#
+                .to_string(),
+        );
+
+        let at_threshold = client
+            .evaluate_metric_over(COUNT_METRIC, "my-app", "canary", 3.0, Some("5m"), None)
+            .await;
+        let above_threshold = client
+            .evaluate_metric(COUNT_METRIC, "my-app", "canary", 2.0)
+            .await;
+
+        assert!(
+            matches!(at_threshold, Ok(true)),
+            "3 errors with threshold 3 should be healthy, got {:?}",
+            at_threshold
+        );
+        assert!(
+            matches!(above_threshold, Ok(false)),
+            "3 errors with threshold 2 should be unhealthy, got {:?}",
+            above_threshold
+        );
+    }
+
     // TDD Cycle 2 Part 2: RED - Test parsing Prometheus instant query response
     #[test]
     fn test_parse_prometheus_response_with_data() {
@@ -483,6 +1619,8 @@ mod tests {
                 interval: None,
                 failure_threshold: None,
                 min_sample_size: None,
+                comparison: None,
+                labels: BTreeMap::new(),
             },
             MetricConfig {
                 name: "latency-p95".to_string(),
@@ -490,6 +1628,8 @@ mod tests {
                 interval: None,
                 failure_threshold: None,
                 min_sample_size: None,
+                comparison: None,
+                labels: BTreeMap::new(),
             },
         ];
 
@@ -534,6 +1674,8 @@ mod tests {
             interval: None,
             failure_threshold: None,
             min_sample_size: None,
+            comparison: None,
+            labels: BTreeMap::new(),
         }];
 
         let rollout_name = "my-app";
@@ -655,4 +1797,283 @@ mod tests {
             "+Inf value should return InvalidValue error"
         );
     }
+
+    // Analysis requirement (all / any / count) tests
+
+    /// Metrics evaluated against a mocked value of 8.0: only thresholds above
+    /// 8.0 pass, so each threshold decides whether its metric is healthy
+    fn error_rate_metrics(thresholds: &[f64]) -> Vec<crate::crd::rollout::MetricConfig> {
+        thresholds
+            .iter()
+            .map(|threshold| crate::crd::rollout::MetricConfig {
+                name: "error-rate".to_string(),
+                threshold: *threshold,
+                interval: None,
+                failure_threshold: None,
+                min_sample_size: None,
+                comparison: None,
+                labels: BTreeMap::new(),
+            })
+            .collect()
+    }
+
+    fn mock_client_returning_8() -> PrometheusClient {
+        let client = PrometheusClient::new_mock();
+        client.set_mock_response(
+            r#"{"status":"success","data":{"resultType":"vector","result":[{"metric":{},"value":[1234567890,"8.0"]}]}}"#
+                .to_string(),
+        );
+        client
+    }
+
+    #[tokio::test]
+    async fn test_requirement_all_passes_when_every_metric_healthy() {
+        let client = mock_client_returning_8();
+        let metrics = error_rate_metrics(&[10.0, 20.0, 30.0]);
+
+        let result = client
+            .evaluate_metrics_with_requirement(&metrics, MetricRequirement::All, "my-app", "canary")
+            .await;
+
+        assert!(
+            matches!(result, Ok(true)),
+            "All metrics healthy should pass"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_requirement_all_fails_when_one_metric_unhealthy() {
+        let client = mock_client_returning_8();
+        let metrics = error_rate_metrics(&[10.0, 5.0, 30.0]);
+
+        let result = client
+            .evaluate_metrics_with_requirement(&metrics, MetricRequirement::All, "my-app", "canary")
+            .await;
+
+        assert!(
+            matches!(result, Ok(false)),
+            "One unhealthy metric should fail 'all'"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_requirement_any_passes_with_one_healthy_metric() {
+        let client = mock_client_returning_8();
+        let metrics = error_rate_metrics(&[5.0, 6.0, 10.0]);
+
+        let result = client
+            .evaluate_metrics_with_requirement(&metrics, MetricRequirement::Any, "my-app", "canary")
+            .await;
+
+        assert!(
+            matches!(result, Ok(true)),
+            "One healthy metric should pass 'any'"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_requirement_any_fails_with_no_healthy_metric() {
+        let client = mock_client_returning_8();
+        let metrics = error_rate_metrics(&[5.0, 6.0, 7.0]);
+
+        let result = client
+            .evaluate_metrics_with_requirement(&metrics, MetricRequirement::Any, "my-app", "canary")
+            .await;
+
+        assert!(
+            matches!(result, Ok(false)),
+            "No healthy metric should fail 'any'"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_requirement_quorum_two_of_three() {
+        let client = mock_client_returning_8();
+
+        // 2 of 3 healthy: passes
+        let metrics = error_rate_metrics(&[10.0, 5.0, 30.0]);
+        let result = client
+            .evaluate_metrics_with_requirement(
+                &metrics,
+                MetricRequirement::AtLeast(2),
+                "my-app",
+                "canary",
+            )
+            .await;
+        assert!(matches!(result, Ok(true)), "2 of 3 healthy should pass");
+
+        // 1 of 3 healthy: fails
+        let metrics = error_rate_metrics(&[10.0, 5.0, 6.0]);
+        let result = client
+            .evaluate_metrics_with_requirement(
+                &metrics,
+                MetricRequirement::AtLeast(2),
+                "my-app",
+                "canary",
+            )
+            .await;
+        assert!(matches!(result, Ok(false)), "1 of 3 healthy should fail");
+    }
+
+    #[test]
+    fn test_metric_requirement_from_config() {
+        assert_eq!(
+            MetricRequirement::from_config(None),
+            Ok(MetricRequirement::All)
+        );
+        assert_eq!(
+            MetricRequirement::from_config(Some(&IntOrString::String("all".to_string()))),
+            Ok(MetricRequirement::All)
+        );
+        assert_eq!(
+            MetricRequirement::from_config(Some(&IntOrString::String("any".to_string()))),
+            Ok(MetricRequirement::Any)
+        );
+        assert_eq!(
+            MetricRequirement::from_config(Some(&IntOrString::Int(2))),
+            Ok(MetricRequirement::AtLeast(2))
+        );
+        assert_eq!(
+            MetricRequirement::from_config(Some(&IntOrString::String("2".to_string()))),
+            Ok(MetricRequirement::AtLeast(2))
+        );
+    }
+
+    #[test]
+    fn test_metric_requirement_rejects_invalid_config() {
+        assert!(MetricRequirement::from_config(Some(&IntOrString::Int(0))).is_err());
+        assert!(
+            MetricRequirement::from_config(Some(&IntOrString::String("most".to_string()))).is_err()
+        );
+    }
+
+    #[test]
+    fn test_metric_requirement_empty_results_satisfied() {
+        assert!(MetricRequirement::All.is_satisfied(&[]));
+        assert!(MetricRequirement::Any.is_satisfied(&[]));
+        assert!(MetricRequirement::AtLeast(2).is_satisfied(&[]));
+    }
+
+    // Auth / TLS configuration
+
+    #[allow(clippy::unwrap_used)] // Test helper - panicking is acceptable
+    fn authorization_header(auth: &PrometheusAuth) -> Option<String> {
+        let request = auth
+            .apply(reqwest::Client::new().get("http://prometheus:9090/api/v1/query"))
+            .build()
+            .unwrap();
+        request
+            .headers()
+            .get(reqwest::header::AUTHORIZATION)
+            .map(|v| v.to_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn test_auth_none_sends_no_header() {
+        assert_eq!(authorization_header(&PrometheusAuth::None), None);
+    }
+
+    #[test]
+    fn test_auth_bearer_header() {
+        let auth = PrometheusAuth::Bearer("s3cret".to_string());
+        assert_eq!(
+            authorization_header(&auth),
+            Some("Bearer s3cret".to_string())
+        );
+    }
+
+    #[test]
+    fn test_auth_basic_header() {
+        let auth = PrometheusAuth::Basic {
+            username: "user".to_string(),
+            password: Some("pass".to_string()),
+        };
+        // base64("user:pass")
+        assert_eq!(
+            authorization_header(&auth),
+            Some("Basic dXNlcjpwYXNz".to_string())
+        );
+    }
+
+    #[test]
+    fn test_auth_basic_header_without_password() {
+        let auth = PrometheusAuth::Basic {
+            username: "user".to_string(),
+            password: None,
+        };
+        // base64("user:")
+        assert_eq!(
+            authorization_header(&auth),
+            Some("Basic dXNlcjo=".to_string())
+        );
+    }
+
+    #[test]
+    fn test_auth_resolve() {
+        let some = |v: &str| Some(v.to_string());
+
+        assert_eq!(
+            PrometheusAuth::resolve(None, None, None),
+            Ok(PrometheusAuth::None)
+        );
+        assert_eq!(
+            PrometheusAuth::resolve(some(""), some(""), None),
+            Ok(PrometheusAuth::None)
+        );
+        assert_eq!(
+            PrometheusAuth::resolve(some("t"), None, None),
+            Ok(PrometheusAuth::Bearer("t".to_string()))
+        );
+        assert_eq!(
+            PrometheusAuth::resolve(None, some("u"), some("p")),
+            Ok(PrometheusAuth::Basic {
+                username: "u".to_string(),
+                password: Some("p".to_string()),
+            })
+        );
+        assert!(PrometheusAuth::resolve(some("t"), some("u"), None).is_err());
+        assert!(PrometheusAuth::resolve(None, None, some("p")).is_err());
+    }
+
+    #[test]
+    fn test_auth_debug_redacts_credentials() {
+        let bearer = format!("{:?}", PrometheusAuth::Bearer("s3cret".to_string()));
+        let basic = format!(
+            "{:?}",
+            PrometheusAuth::Basic {
+                username: "user".to_string(),
+                password: Some("s3cret".to_string()),
+            }
+        );
+
+        assert!(!bearer.contains("s3cret"));
+        assert!(!basic.contains("s3cret"));
+        assert!(basic.contains("user"));
+    }
+
+    #[test]
+    fn test_build_http_client_default_and_insecure() {
+        assert!(PrometheusConnectionConfig::default()
+            .build_http_client()
+            .is_ok());
+
+        let insecure = PrometheusConnectionConfig {
+            accept_invalid_certs: true,
+            ..Default::default()
+        };
+        assert!(insecure.build_http_client().is_ok());
+    }
+
+    #[test]
+    fn test_build_http_client_missing_ca_file_fails() {
+        let config = PrometheusConnectionConfig {
+            ca_cert_path: Some("/nonexistent/kulta-ca.pem".to_string()),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            config.build_http_client(),
+            Err(PrometheusError::InvalidConfig(_))
+        ));
+    }
 }
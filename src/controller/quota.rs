@@ -0,0 +1,599 @@
+//! ResourceQuota awareness for canary scale-ups
+//!
+//! With `spec.quotaAware: true`, a step that scales the canary up is only
+//! taken if the extra pods fit every ResourceQuota in the namespace. Otherwise
+//! the ReplicaSet would be scaled anyway and its pods silently rejected by the
+//! quota admission plugin, leaving the canary under-provisioned.
+//!
+//! The estimate is conservative: the canary's new pods are counted in full,
+//! ignoring the stable pods that the same step scales down.
+
+use crate::controller::rollout::{
+    apply_canary_overrides, build_pod_template, calculate_canary_replicas, is_condition_true,
+    record_decision, set_condition,
+};
+use crate::crd::rollout::{DecisionAction, DecisionReason, Rollout, RolloutStatus};
+use async_trait::async_trait;
+use k8s_openapi::api::core::v1::{PodTemplateSpec, ResourceQuota, ResourceRequirements};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use kube::api::{Api, ListParams};
+use kube::ResourceExt;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Status condition type set while a step is held for quota
+pub const QUOTA_EXCEEDED_CONDITION: &str = "QuotaExceeded";
+
+/// Condition reason while a step is held for quota
+pub const INSUFFICIENT_QUOTA_REASON: &str = "InsufficientQuota";
+
+/// Condition reason once the held step fits again
+pub const QUOTA_AVAILABLE_REASON: &str = "QuotaAvailable";
+
+/// Requeue interval while a step is held for quota
+pub const QUOTA_RECHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// ResourceQuota source (mockable in tests)
+#[async_trait]
+pub trait QuotaLookup: Send + Sync {
+    /// List the ResourceQuotas in a namespace
+    async fn list_quotas(&self, namespace: &str) -> Result<Vec<ResourceQuota>, kube::Error>;
+}
+
+/// QuotaLookup backed by the Kubernetes API
+pub struct KubeQuotaLookup {
+    client: kube::Client,
+}
+
+impl KubeQuotaLookup {
+    pub fn new(client: kube::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl QuotaLookup for KubeQuotaLookup {
+    async fn list_quotas(&self, namespace: &str) -> Result<Vec<ResourceQuota>, kube::Error> {
+        let api: Api<ResourceQuota> = Api::namespaced(self.client.clone(), namespace);
+        Ok(api.list(&ListParams::default()).await?.items)
+    }
+}
+
+/// Whether the rollout holds steps that would exceed a ResourceQuota
+pub fn is_quota_aware(rollout: &Rollout) -> bool {
+    rollout.spec.quota_aware.unwrap_or(false)
+}
+
+/// CPU (millicores) and memory (bytes) of a pod, for requests and limits
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PodResources {
+    pub requests_cpu_millis: i64,
+    pub requests_memory_bytes: i64,
+    pub limits_cpu_millis: i64,
+    pub limits_memory_bytes: i64,
+}
+
+/// Parse a Kubernetes quantity ("500m", "2", "128Mi", "1G", "1e3") into base units
+///
+/// Returns None for malformed quantities.
+pub fn parse_quantity(quantity: &str) -> Option<f64> {
+    let quantity = quantity.trim();
+    const SUFFIXES: [(&str, f64); 14] = [
+        ("Ki", 1024.0),
+        ("Mi", 1024.0 * 1024.0),
+        ("Gi", 1024.0 * 1024.0 * 1024.0),
+        ("Ti", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Pi", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Ei", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("n", 1e-9),
+        ("u", 1e-6),
+        ("m", 1e-3),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+        ("P", 1e15),
+    ];
+
+    let (number, multiplier) = SUFFIXES
+        .iter()
+        .find_map(|(suffix, multiplier)| {
+            quantity
+                .strip_suffix(suffix)
+                .map(|number| (number, *multiplier))
+        })
+        .or_else(|| quantity.strip_suffix('E').map(|number| (number, 1e18)))
+        .unwrap_or((quantity, 1.0));
+
+    let value = number.parse::<f64>().ok()?;
+    (value.is_finite() && value >= 0.0).then_some(value * multiplier)
+}
+
+fn cpu_millis(quantity: &Quantity) -> i64 {
+    parse_quantity(&quantity.0)
+        .map(|cores| (cores * 1000.0).ceil() as i64)
+        .unwrap_or(0)
+}
+
+fn base_units(quantity: &Quantity) -> i64 {
+    parse_quantity(&quantity.0)
+        .map(|bytes| bytes.ceil() as i64)
+        .unwrap_or(0)
+}
+
+/// Resources of one container (a missing request defaults to the limit, as in Kubernetes)
+fn container_resources(resources: Option<&ResourceRequirements>) -> PodResources {
+    let empty = BTreeMap::new();
+    let requests = resources
+        .and_then(|r| r.requests.as_ref())
+        .unwrap_or(&empty);
+    let limits = resources.and_then(|r| r.limits.as_ref()).unwrap_or(&empty);
+
+    let limits_cpu_millis = limits.get("cpu").map(cpu_millis).unwrap_or(0);
+    let limits_memory_bytes = limits.get("memory").map(base_units).unwrap_or(0);
+    PodResources {
+        requests_cpu_millis: requests
+            .get("cpu")
+            .map(cpu_millis)
+            .unwrap_or(limits_cpu_millis),
+        requests_memory_bytes: requests
+            .get("memory")
+            .map(base_units)
+            .unwrap_or(limits_memory_bytes),
+        limits_cpu_millis,
+        limits_memory_bytes,
+    }
+}
+
+/// Effective resources of a pod built from the template
+///
+/// Like the scheduler: the sum over containers, or the largest init
+/// container if that is higher.
+pub fn pod_template_resources(template: &PodTemplateSpec) -> PodResources {
+    let Some(pod_spec) = template.spec.as_ref() else {
+        return PodResources::default();
+    };
+
+    let mut total = PodResources::default();
+    for container in &pod_spec.containers {
+        let resources = container_resources(container.resources.as_ref());
+        total.requests_cpu_millis += resources.requests_cpu_millis;
+        total.requests_memory_bytes += resources.requests_memory_bytes;
+        total.limits_cpu_millis += resources.limits_cpu_millis;
+        total.limits_memory_bytes += resources.limits_memory_bytes;
+    }
+
+    for container in pod_spec.init_containers.iter().flatten() {
+        let resources = container_resources(container.resources.as_ref());
+        total.requests_cpu_millis = total.requests_cpu_millis.max(resources.requests_cpu_millis);
+        total.requests_memory_bytes = total
+            .requests_memory_bytes
+            .max(resources.requests_memory_bytes);
+        total.limits_cpu_millis = total.limits_cpu_millis.max(resources.limits_cpu_millis);
+        total.limits_memory_bytes = total.limits_memory_bytes.max(resources.limits_memory_bytes);
+    }
+
+    total
+}
+
+/// Check that `additional_pods` more pods fit every ResourceQuota
+///
+/// Compares used + additional against hard for the quota keys a pod consumes:
+/// `pods`, `cpu`/`requests.cpu`, `memory`/`requests.memory`, `limits.cpu`
+/// and `limits.memory`. Other keys are ignored.
+///
+/// # Errors
+/// Describes the first quota key that would be exceeded
+pub fn check_quota_headroom(
+    quotas: &[ResourceQuota],
+    per_pod: &PodResources,
+    additional_pods: i32,
+) -> Result<(), String> {
+    if additional_pods <= 0 {
+        return Ok(());
+    }
+    let pods = i64::from(additional_pods);
+
+    for quota in quotas {
+        let status = quota.status.as_ref();
+        let Some(hard) = status
+            .and_then(|s| s.hard.as_ref())
+            .or_else(|| quota.spec.as_ref().and_then(|s| s.hard.as_ref()))
+        else {
+            continue;
+        };
+        let empty = BTreeMap::new();
+        let used = status.and_then(|s| s.used.as_ref()).unwrap_or(&empty);
+
+        for (key, hard_quantity) in hard {
+            let (parse, needed, unit): (fn(&Quantity) -> i64, i64, &str) = match key.as_str() {
+                "pods" => (base_units, pods, ""),
+                "cpu" | "requests.cpu" => (cpu_millis, pods * per_pod.requests_cpu_millis, "m"),
+                "memory" | "requests.memory" => {
+                    (base_units, pods * per_pod.requests_memory_bytes, "")
+                }
+                "limits.cpu" => (cpu_millis, pods * per_pod.limits_cpu_millis, "m"),
+                "limits.memory" => (base_units, pods * per_pod.limits_memory_bytes, ""),
+                _ => continue,
+            };
+            if needed == 0 {
+                continue;
+            }
+
+            let limit = parse(hard_quantity);
+            let in_use = used.get(key).map(parse).unwrap_or(0);
+            if in_use + needed > limit {
+                return Err(format!(
+                    "ResourceQuota {}: {} more canary pods need {}{} of {}, only {}{} available",
+                    quota.name_any(),
+                    additional_pods,
+                    needed,
+                    unit,
+                    key,
+                    (limit - in_use).max(0),
+                    unit
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Hold the step computed for this reconcile if its canary scale-up exceeds a quota
+///
+/// Compares the canary replicas at the desired weight with those at the
+/// current weight. If the extra pods (sized from the canary pod template)
+/// don't fit, see `apply_quota_check`. A failed quota lookup doesn't block
+/// the rollout.
+pub async fn hold_step_for_quota(
+    lookup: &dyn QuotaLookup,
+    rollout: &Rollout,
+    desired: RolloutStatus,
+) -> RolloutStatus {
+    let current_weight = rollout
+        .status
+        .as_ref()
+        .and_then(|status| status.current_weight)
+        .unwrap_or(0);
+    let (_, current_canary) = calculate_canary_replicas(rollout, current_weight);
+    let (_, desired_canary) =
+        calculate_canary_replicas(rollout, desired.current_weight.unwrap_or(0));
+    let additional_pods = desired_canary - current_canary;
+
+    let check = match rollout.namespace() {
+        Some(namespace) if additional_pods > 0 => match lookup.list_quotas(&namespace).await {
+            Ok(quotas) => {
+                let mut template = build_pod_template(rollout);
+                apply_canary_overrides(rollout, &mut template);
+                check_quota_headroom(&quotas, &pod_template_resources(&template), additional_pods)
+            }
+            Err(e) => {
+                warn!(error = ?e, rollout = ?rollout.name_any(), "Failed to list ResourceQuotas, not holding step");
+                Ok(())
+            }
+        },
+        _ => Ok(()),
+    };
+
+    apply_quota_check(rollout, desired, check)
+}
+
+/// Apply the result of a quota check to the desired status
+///
+/// - Exceeded: keep the current status (the step is held) with
+///   QuotaExceeded=True and the reason in the message; a Pause decision is
+///   recorded when the hold starts
+/// - Fits: the desired status, with QuotaExceeded set back to False if it
+///   was True
+pub fn apply_quota_check(
+    rollout: &Rollout,
+    mut desired: RolloutStatus,
+    check: Result<(), String>,
+) -> RolloutStatus {
+    let message = match check {
+        Ok(()) => {
+            if is_condition_true(&desired, QUOTA_EXCEEDED_CONDITION) {
+                info!(rollout = ?rollout.name_any(), "Quota headroom available, resuming");
+                set_condition(
+                    &mut desired,
+                    QUOTA_EXCEEDED_CONDITION,
+                    false,
+                    QUOTA_AVAILABLE_REASON,
+                    None,
+                );
+            }
+            return desired;
+        }
+        Err(message) => message,
+    };
+
+    // Nothing to hold before the first status
+    let Some(current) = rollout.status.as_ref() else {
+        return desired;
+    };
+
+    let mut held = current.clone();
+    held.conditions = desired.conditions;
+    held.observed_generation = desired.observed_generation;
+    let entering = !is_condition_true(&held, QUOTA_EXCEEDED_CONDITION);
+
+    held.message = Some(format!("Step held, insufficient quota: {}", message));
+    set_condition(
+        &mut held,
+        QUOTA_EXCEEDED_CONDITION,
+        true,
+        INSUFFICIENT_QUOTA_REASON,
+        Some(message),
+    );
+    if entering {
+        warn!(rollout = ?rollout.name_any(), message = ?held.message, "Holding step for quota");
+        record_decision(
+            &mut held,
+            DecisionAction::Pause,
+            DecisionReason::QuotaExceeded,
+            current.current_step_index,
+            desired.current_step_index,
+        );
+    }
+    held
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Tests can use unwrap for brevity
+mod tests {
+    use super::*;
+    use crate::crd::rollout::{CanaryStep, CanaryStrategy, Phase, RolloutSpec, RolloutStrategy};
+    use k8s_openapi::api::core::v1::{Container, PodSpec, ResourceQuotaStatus};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+    use kube::api::ObjectMeta;
+
+    struct MockQuotaLookup {
+        quotas: Vec<ResourceQuota>,
+    }
+
+    #[async_trait]
+    impl QuotaLookup for MockQuotaLookup {
+        async fn list_quotas(&self, _namespace: &str) -> Result<Vec<ResourceQuota>, kube::Error> {
+            Ok(self.quotas.clone())
+        }
+    }
+
+    fn quantities(pairs: &[(&str, &str)]) -> BTreeMap<String, Quantity> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), Quantity(value.to_string())))
+            .collect()
+    }
+
+    fn quota(hard: &[(&str, &str)], used: &[(&str, &str)]) -> ResourceQuota {
+        ResourceQuota {
+            metadata: ObjectMeta {
+                name: Some("compute".to_string()),
+                ..Default::default()
+            },
+            spec: None,
+            status: Some(ResourceQuotaStatus {
+                hard: Some(quantities(hard)),
+                used: Some(quantities(used)),
+            }),
+        }
+    }
+
+    fn template(requests: &[(&str, &str)], limits: &[(&str, &str)]) -> PodTemplateSpec {
+        PodTemplateSpec {
+            metadata: None,
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: "app".to_string(),
+                    resources: Some(ResourceRequirements {
+                        requests: Some(quantities(requests)),
+                        limits: Some(quantities(limits)),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn status_at(step: i32, weight: i32) -> RolloutStatus {
+        RolloutStatus {
+            phase: Some(Phase::Progressing),
+            current_step_index: Some(step),
+            current_weight: Some(weight),
+            ..Default::default()
+        }
+    }
+
+    /// 10 replicas of 250m CPU, steps 20% -> 50%, currently at step 0
+    fn quota_aware_rollout() -> Rollout {
+        Rollout {
+            metadata: ObjectMeta {
+                name: Some("my-app".to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            },
+            spec: RolloutSpec {
+                replicas: 10,
+                selector: LabelSelector::default(),
+                template: template(&[("cpu", "250m")], &[]),
+                strategy: RolloutStrategy {
+                    simple: None,
+                    canary: Some(CanaryStrategy {
+                        canary_service: "my-app-canary".to_string(),
+                        stable_service: "my-app-stable".to_string(),
+                        mirror_service: None,
+                        steps: vec![
+                            CanaryStep {
+                                set_weight: Some(20),
+                                pause: None,
+                                analysis: None,
+                            },
+                            CanaryStep {
+                                set_weight: Some(50),
+                                pause: None,
+                                analysis: None,
+                            },
+                        ],
+                        traffic_routing: None,
+                        analysis: None,
+                        max_duration_seconds: None,
+                        canary_overrides: None,
+                        canary_suffix: None,
+                        stable_suffix: None,
+                        analysis_only: None,
+                        port_name: None,
+                        pause_at_step: None,
+                        create_services: None,
+                        min_canary_replicas: None,
+                        min_stable_replicas: None,
+                        keep_stable_at_full_scale: None,
+                        extra_canaries: None,
+                        max_weight_step: None,
+                        min_initial_weight: None,
+                        on_complete: None,
+                        replica_surge: None,
+                        max_surge: None,
+                        sticky_cookie: None,
+                    }),
+                    blue_green: None,
+                },
+                manage_pod_template_hash: None,
+                quota_aware: Some(true),
+                dry_run: None,
+                paused: None,
+            },
+            status: Some(status_at(0, 20)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tight_cpu_quota_holds_step() {
+        // 20% -> 50% adds 3 canary pods (750m), only 200m left
+        let lookup = MockQuotaLookup {
+            quotas: vec![quota(&[("requests.cpu", "1")], &[("requests.cpu", "800m")])],
+        };
+        let rollout = quota_aware_rollout();
+
+        let status = hold_step_for_quota(&lookup, &rollout, status_at(1, 50)).await;
+
+        assert_eq!(status.current_step_index, Some(0));
+        assert_eq!(status.current_weight, Some(20));
+        assert!(is_condition_true(&status, QUOTA_EXCEEDED_CONDITION));
+        assert_eq!(
+            status.conditions[0].reason.as_deref(),
+            Some(INSUFFICIENT_QUOTA_REASON)
+        );
+        assert_eq!(status.decisions.len(), 1);
+        assert_eq!(status.decisions[0].action, DecisionAction::Pause);
+        assert_eq!(status.decisions[0].reason, DecisionReason::QuotaExceeded);
+        assert_eq!(status.decisions[0].to_step, Some(1));
+
+        // Still held on requeue: no duplicate decision
+        let mut rollout = rollout;
+        rollout.status = Some(status.clone());
+        let mut desired = status_at(1, 50);
+        desired.conditions = status.conditions.clone();
+        let status = hold_step_for_quota(&lookup, &rollout, desired).await;
+        assert_eq!(status.current_step_index, Some(0));
+        assert_eq!(status.decisions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_held_step_resumes_with_headroom() {
+        let mut rollout = quota_aware_rollout();
+        let mut held = status_at(0, 20);
+        set_condition(
+            &mut held,
+            QUOTA_EXCEEDED_CONDITION,
+            true,
+            INSUFFICIENT_QUOTA_REASON,
+            None,
+        );
+        rollout.status = Some(held.clone());
+        let lookup = MockQuotaLookup {
+            quotas: vec![quota(&[("requests.cpu", "2")], &[("requests.cpu", "800m")])],
+        };
+
+        let mut desired = status_at(1, 50);
+        desired.conditions = held.conditions;
+        let status = hold_step_for_quota(&lookup, &rollout, desired).await;
+
+        assert_eq!(status.current_step_index, Some(1));
+        assert_eq!(status.current_weight, Some(50));
+        assert!(!is_condition_true(&status, QUOTA_EXCEEDED_CONDITION));
+        assert_eq!(status.conditions[0].status, "False");
+        assert_eq!(
+            status.conditions[0].reason.as_deref(),
+            Some(QUOTA_AVAILABLE_REASON)
+        );
+    }
+
+    #[test]
+    fn test_parse_quantity() {
+        assert_eq!(parse_quantity("500m"), Some(0.5));
+        assert_eq!(parse_quantity("2"), Some(2.0));
+        assert_eq!(parse_quantity("128Mi"), Some(128.0 * 1024.0 * 1024.0));
+        assert_eq!(parse_quantity("1G"), Some(1e9));
+        assert_eq!(parse_quantity("1e3"), Some(1000.0));
+        assert_eq!(parse_quantity("lots"), None);
+        assert_eq!(parse_quantity("-1"), None);
+    }
+
+    #[test]
+    fn test_pod_template_resources_defaults_requests_to_limits() {
+        let resources = pod_template_resources(&template(&[], &[("cpu", "1"), ("memory", "1Gi")]));
+
+        assert_eq!(resources.requests_cpu_millis, 1000);
+        assert_eq!(resources.requests_memory_bytes, 1024 * 1024 * 1024);
+        assert_eq!(resources.limits_cpu_millis, 1000);
+    }
+
+    #[test]
+    fn test_tight_cpu_quota_rejects_scale_up() {
+        let per_pod = pod_template_resources(&template(&[("cpu", "250m")], &[]));
+        let quotas = [quota(&[("requests.cpu", "1")], &[("requests.cpu", "800m")])];
+
+        let result = check_quota_headroom(&quotas, &per_pod, 2);
+
+        assert_eq!(
+            result,
+            Err(
+                "ResourceQuota compute: 2 more canary pods need 500m of requests.cpu, only 200m available"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_quota_with_headroom_allows_scale_up() {
+        let per_pod = pod_template_resources(&template(&[("cpu", "250m")], &[]));
+        let quotas = [quota(&[("requests.cpu", "2")], &[("requests.cpu", "800m")])];
+
+        assert_eq!(check_quota_headroom(&quotas, &per_pod, 2), Ok(()));
+        // Scaling down never needs quota
+        assert_eq!(check_quota_headroom(&quotas, &per_pod, -1), Ok(()));
+    }
+
+    #[test]
+    fn test_pod_count_quota() {
+        let per_pod = PodResources::default();
+        let quotas = [quota(&[("pods", "10")], &[("pods", "9")])];
+
+        assert!(check_quota_headroom(&quotas, &per_pod, 1).is_ok());
+        assert!(check_quota_headroom(&quotas, &per_pod, 2)
+            .unwrap_err()
+            .contains("2 more canary pods need 2 of pods, only 1 available"));
+    }
+
+    #[test]
+    fn test_unrelated_quota_keys_are_ignored() {
+        let per_pod = pod_template_resources(&template(&[("cpu", "1")], &[]));
+        let quotas = [quota(&[("services", "1")], &[("services", "1")])];
+
+        assert_eq!(check_quota_headroom(&quotas, &per_pod, 5), Ok(()));
+    }
+}
@@ -0,0 +1,143 @@
+//! Per-rollout step progression rate limiting
+//!
+//! Bursts of watch events for the same rollout (e.g., after a network partition
+//! recovers) can trigger many reconciles in quick succession. Without a limit,
+//! each one could advance the canary by a step. The limiter allows at most one
+//! step advancement per rollout within the minimum step interval.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Default minimum interval between step advancements for a single rollout
+pub const DEFAULT_MIN_STEP_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Rate limiter keyed by (namespace, name)
+///
+/// Records the time of the last step advancement per rollout and rejects
+/// further advancements until the minimum interval has elapsed.
+pub struct StepRateLimiter {
+    min_interval: Duration,
+    last_advance: Mutex<HashMap<(String, String), Instant>>,
+}
+
+impl StepRateLimiter {
+    /// Create a rate limiter with the given minimum step interval
+    pub fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            last_advance: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Create a rate limiter from KULTA_MIN_STEP_INTERVAL_SECS (default: 5s)
+    ///
+    /// Invalid values fall back to the default with a warning.
+    pub fn from_env() -> Self {
+        let min_interval = match std::env::var("KULTA_MIN_STEP_INTERVAL_SECS") {
+            Ok(value) => parse_min_step_interval(&value).unwrap_or_else(|| {
+                warn!(
+                    value = %value,
+                    default_secs = DEFAULT_MIN_STEP_INTERVAL.as_secs(),
+                    "Invalid KULTA_MIN_STEP_INTERVAL_SECS, using default"
+                );
+                DEFAULT_MIN_STEP_INTERVAL
+            }),
+            Err(_) => DEFAULT_MIN_STEP_INTERVAL,
+        };
+        Self::new(min_interval)
+    }
+
+    /// Minimum interval between step advancements
+    pub fn min_interval(&self) -> Duration {
+        self.min_interval
+    }
+
+    /// Try to record a step advancement for a rollout
+    ///
+    /// # Returns
+    /// true if the advancement is allowed (and recorded), false if rate limited
+    pub fn try_acquire(&self, namespace: &str, name: &str) -> bool {
+        self.try_acquire_at(namespace, name, Instant::now())
+    }
+
+    /// Try to record a step advancement at a given instant (testable variant)
+    pub fn try_acquire_at(&self, namespace: &str, name: &str, now: Instant) -> bool {
+        let mut last_advance = match self.last_advance.lock() {
+            Ok(guard) => guard,
+            // A poisoned lock only means another reconcile panicked mid-update;
+            // the map is still usable
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let key = (namespace.to_string(), name.to_string());
+        if let Some(last) = last_advance.get(&key) {
+            if now.saturating_duration_since(*last) < self.min_interval {
+                return false;
+            }
+        }
+
+        last_advance.insert(key, now);
+        true
+    }
+}
+
+impl Default for StepRateLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_STEP_INTERVAL)
+    }
+}
+
+/// Parse KULTA_MIN_STEP_INTERVAL_SECS (whole seconds, 0 disables limiting)
+pub fn parse_min_step_interval(value: &str) -> Option<Duration> {
+    value.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_advancement_allowed() {
+        let limiter = StepRateLimiter::new(Duration::from_secs(5));
+        assert!(limiter.try_acquire_at("default", "app", Instant::now()));
+    }
+
+    #[test]
+    fn test_second_advancement_within_interval_blocked() {
+        let limiter = StepRateLimiter::new(Duration::from_secs(5));
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire_at("default", "app", now));
+        assert!(!limiter.try_acquire_at("default", "app", now + Duration::from_secs(4)));
+        assert!(limiter.try_acquire_at("default", "app", now + Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_rollouts_are_limited_independently() {
+        let limiter = StepRateLimiter::new(Duration::from_secs(5));
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire_at("default", "app-a", now));
+        assert!(limiter.try_acquire_at("default", "app-b", now));
+        assert!(limiter.try_acquire_at("other", "app-a", now));
+    }
+
+    #[test]
+    fn test_zero_interval_never_limits() {
+        let limiter = StepRateLimiter::new(Duration::ZERO);
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire_at("default", "app", now));
+        assert!(limiter.try_acquire_at("default", "app", now));
+    }
+
+    #[test]
+    fn test_parse_min_step_interval() {
+        assert_eq!(parse_min_step_interval("10"), Some(Duration::from_secs(10)));
+        assert_eq!(parse_min_step_interval(" 0 "), Some(Duration::ZERO));
+        assert_eq!(parse_min_step_interval("5s"), None);
+        assert_eq!(parse_min_step_interval("-1"), None);
+    }
+}
@@ -0,0 +1,200 @@
+//! Per-rollout cache of the last reconciled state
+//!
+//! Every watch event (including the ones caused by our own status patches)
+//! triggers a full reconcile, which reads and patches ReplicaSets even when
+//! nothing changed. The cache remembers the (generation, status) each rollout
+//! was last reconciled to, so a reconcile that would be a no-op can be
+//! short-circuited to a requeue.
+
+use crate::crd::rollout::RolloutStatus;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Default lifetime of a cache entry
+///
+/// Entries expire so ReplicaSet drift (e.g., a manually deleted ReplicaSet)
+/// is still corrected by a periodic full reconcile.
+pub const DEFAULT_RECONCILE_CACHE_TTL: Duration = Duration::from_secs(300);
+
+struct CacheEntry {
+    generation: Option<i64>,
+    status: RolloutStatus,
+    recorded_at: Instant,
+}
+
+/// Last reconciled (generation, status) keyed by rollout uid
+pub struct ReconcileCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl ReconcileCache {
+    /// Create a cache whose entries expire after `ttl`
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, CacheEntry>> {
+        match self.entries.lock() {
+            Ok(guard) => guard,
+            // A poisoned lock only means another reconcile panicked mid-update;
+            // the map is still usable
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// Record the state a rollout was reconciled to
+    pub fn record(&self, uid: &str, generation: Option<i64>, status: &RolloutStatus) {
+        self.record_at(uid, generation, status, Instant::now())
+    }
+
+    /// Record the state a rollout was reconciled to at a given instant (testable variant)
+    pub fn record_at(
+        &self,
+        uid: &str,
+        generation: Option<i64>,
+        status: &RolloutStatus,
+        now: Instant,
+    ) {
+        let mut entries = self.lock();
+
+        // Drop expired entries (including rollouts that were deleted)
+        let ttl = self.ttl;
+        entries.retain(|_, entry| now.saturating_duration_since(entry.recorded_at) < ttl);
+
+        entries.insert(
+            uid.to_string(),
+            CacheEntry {
+                generation,
+                status: status.clone(),
+                recorded_at: now,
+            },
+        );
+    }
+
+    /// Check if a rollout is still in the state it was last reconciled to
+    ///
+    /// A generation change invalidates the entry.
+    ///
+    /// # Returns
+    /// true if the cached entry is unexpired and matches both the generation
+    /// and the observed status
+    pub fn is_fresh(&self, uid: &str, generation: Option<i64>, status: &RolloutStatus) -> bool {
+        self.is_fresh_at(uid, generation, status, Instant::now())
+    }
+
+    /// Check if a rollout is unchanged at a given instant (testable variant)
+    pub fn is_fresh_at(
+        &self,
+        uid: &str,
+        generation: Option<i64>,
+        status: &RolloutStatus,
+        now: Instant,
+    ) -> bool {
+        let mut entries = self.lock();
+
+        let Some(entry) = entries.get(uid) else {
+            return false;
+        };
+
+        if entry.generation != generation
+            || now.saturating_duration_since(entry.recorded_at) >= self.ttl
+        {
+            entries.remove(uid);
+            return false;
+        }
+
+        entry.status == *status
+    }
+
+    /// Remove a rollout's entry, forcing the next reconcile to run in full
+    pub fn invalidate(&self, uid: &str) {
+        self.lock().remove(uid);
+    }
+}
+
+impl Default for ReconcileCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_RECONCILE_CACHE_TTL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crd::rollout::Phase;
+
+    fn status(step: i32, weight: i32) -> RolloutStatus {
+        RolloutStatus {
+            phase: Some(Phase::Paused),
+            current_step_index: Some(step),
+            current_weight: Some(weight),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_unknown_rollout_is_not_fresh() {
+        let cache = ReconcileCache::default();
+        assert!(!cache.is_fresh("uid-1", Some(1), &status(0, 10)));
+    }
+
+    #[test]
+    fn test_same_generation_and_status_is_fresh() {
+        let cache = ReconcileCache::default();
+        cache.record("uid-1", Some(1), &status(0, 10));
+
+        assert!(cache.is_fresh("uid-1", Some(1), &status(0, 10)));
+    }
+
+    #[test]
+    fn test_changed_status_is_not_fresh() {
+        let cache = ReconcileCache::default();
+        cache.record("uid-1", Some(1), &status(0, 10));
+
+        assert!(!cache.is_fresh("uid-1", Some(1), &status(1, 50)));
+    }
+
+    #[test]
+    fn test_generation_change_invalidates_entry() {
+        let cache = ReconcileCache::default();
+        cache.record("uid-1", Some(1), &status(0, 10));
+
+        assert!(!cache.is_fresh("uid-1", Some(2), &status(0, 10)));
+        // The entry is gone, even for the old generation
+        assert!(!cache.is_fresh("uid-1", Some(1), &status(0, 10)));
+    }
+
+    #[test]
+    fn test_entry_expires_after_ttl() {
+        let cache = ReconcileCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+        cache.record_at("uid-1", Some(1), &status(0, 10), now);
+
+        assert!(cache.is_fresh_at(
+            "uid-1",
+            Some(1),
+            &status(0, 10),
+            now + Duration::from_secs(59)
+        ));
+        assert!(!cache.is_fresh_at(
+            "uid-1",
+            Some(1),
+            &status(0, 10),
+            now + Duration::from_secs(60)
+        ));
+    }
+
+    #[test]
+    fn test_invalidate_removes_entry() {
+        let cache = ReconcileCache::default();
+        cache.record("uid-1", Some(1), &status(0, 10));
+        cache.invalidate("uid-1");
+
+        assert!(!cache.is_fresh("uid-1", Some(1), &status(0, 10)));
+    }
+}
@@ -1,21 +1,55 @@
 use crate::controller::cdevents::emit_status_change_event;
-use crate::controller::prometheus::PrometheusClient;
-use crate::crd::rollout::{Phase, Rollout, RolloutStatus};
-use crate::server::LeaderState;
+use crate::controller::dry_run::{
+    compute_dry_run_plan, dry_run_status, is_dry_run, DRY_RUN_REQUEUE,
+};
+use crate::controller::error_backoff::{backoff_interval, ErrorBackoff, MAX_ERROR_BACKOFF};
+use crate::controller::port_resolver::{KubeServiceLookup, DEFAULT_BACKEND_PORT};
+use crate::controller::prometheus::{
+    analysis_verdict, validate_metric_labels, MetricRequirement, PrometheusClient,
+    PrometheusConnectionConfig, COUNT_METRIC,
+};
+use crate::controller::quota::{
+    hold_step_for_quota, is_quota_aware, KubeQuotaLookup, QUOTA_EXCEEDED_CONDITION,
+    QUOTA_RECHECK_INTERVAL,
+};
+use crate::controller::rate_limiter::{parse_min_step_interval, StepRateLimiter};
+use crate::controller::reconcile_cache::ReconcileCache;
+use crate::controller::route_check::{
+    hold_step_for_route, route_verification, KubeRouteLookup, ROUTE_NOT_ACCEPTED_CONDITION,
+    ROUTE_RECHECK_INTERVAL,
+};
+use crate::controller::services::{creates_services, ensure_canary_services};
+use crate::controller::status_batcher::{parse_batch_interval, StatusBatcher, StatusUpdate};
+use crate::controller::strategies::{
+    enabled_strategies_from_env, httproute_api_resource, parse_enabled_strategies,
+    resolve_httproute_version, select_enabled_strategy, select_strategy, HttpRouteRuleOptions,
+    StrategyError,
+};
+use crate::crd::rollout::{
+    AnalysisConfig, AnalysisMode, AnalysisResult, BlueGreenStrategy, CanaryStep, CanaryStrategy,
+    Decision, DecisionAction, DecisionReason, FailurePolicy, GatewayApiVersion, HttpRouteMatch,
+    MetricResult, Phase, RevisionRef, Rollout, RolloutCondition, RolloutStatus, StatusTransition,
+    StickyCookie, TransitionTrigger,
+};
+use crate::server::{Heartbeat, LeaderState};
 use chrono::{DateTime, Utc};
 use k8s_openapi::api::apps::v1::{ReplicaSet, ReplicaSetSpec};
 use k8s_openapi::api::core::v1::PodTemplateSpec;
-use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, OwnerReference};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use kube::api::{Api, ObjectMeta, PostParams};
 use kube::runtime::controller::Action;
+use kube::runtime::reflector::ObjectRef;
 use kube::{Resource, ResourceExt};
 use serde::{Deserialize, Serialize};
 use serde_json;
 use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
 #[derive(Debug, Error)]
@@ -32,6 +66,9 @@ pub enum ReconcileError {
     #[error("ReplicaSet missing name in metadata")]
     ReplicaSetMissingName,
 
+    #[error("ReplicaSet {0} has a stale template but is controlled by another owner")]
+    ReplicaSetOwnedByOther(String),
+
     #[error("Failed to serialize PodTemplateSpec: {0}")]
     SerializationError(String),
 
@@ -43,6 +80,70 @@ pub enum ReconcileError {
 
     #[error("Strategy reconciliation failed: {0}")]
     StrategyError(#[from] crate::controller::strategies::StrategyError),
+
+    #[error("Reconciliation cancelled by controller shutdown")]
+    Cancelled,
+}
+
+/// Requeue interval for validation errors (the spec must be fixed by a user)
+const VALIDATION_ERROR_REQUEUE: Duration = Duration::from_secs(5);
+
+/// Requeue interval for Kubernetes API errors (usually transient: conflicts, timeouts)
+const KUBE_ERROR_REQUEUE: Duration = Duration::from_secs(10);
+
+/// Requeue interval for reconciles cancelled by shutdown (error_policy waits
+/// for the next change instead, see main.rs)
+const CANCELLED_ERROR_REQUEUE: Duration = Duration::from_secs(30);
+
+/// Requeue interval for all other errors
+const DEFAULT_ERROR_REQUEUE: Duration = Duration::from_secs(10);
+
+/// How long to wait before retrying after a reconcile error
+///
+/// - Validation errors: 5s; a user must fix the spec, and repeated failures
+///   are backed off by error_policy
+/// - Kubernetes API errors: 10s; usually transient (conflicts, timeouts)
+/// - Cancelled reconciles: 30s, though error_policy waits for the next change
+/// - Everything else uses the default
+pub fn calculate_error_requeue_interval(error: &ReconcileError) -> Duration {
+    match error {
+        ReconcileError::ValidationError(_)
+        | ReconcileError::StrategyError(StrategyError::StrategyDisabled(_)) => {
+            VALIDATION_ERROR_REQUEUE
+        }
+        ReconcileError::KubeError(_)
+        | ReconcileError::StrategyError(StrategyError::KubeError(_)) => KUBE_ERROR_REQUEUE,
+        ReconcileError::Cancelled => CANCELLED_ERROR_REQUEUE,
+        _ => DEFAULT_ERROR_REQUEUE,
+    }
+}
+
+impl ReconcileError {
+    /// Error category for metrics labels and backoff decisions
+    ///
+    /// # Returns
+    /// One of: "kube_api", "validation", "traffic", "replicaset", "metrics",
+    /// "serialization", "missing_field", "cancelled"
+    pub fn category(&self) -> &'static str {
+        match self {
+            ReconcileError::KubeError(_) => "kube_api",
+            ReconcileError::MissingNamespace
+            | ReconcileError::MissingName
+            | ReconcileError::ReplicaSetMissingName => "missing_field",
+            ReconcileError::SerializationError(_) => "serialization",
+            ReconcileError::ValidationError(_) => "validation",
+            ReconcileError::ReplicaSetOwnedByOther(_) => "replicaset",
+            ReconcileError::MetricsEvaluationFailed(_) => "metrics",
+            ReconcileError::Cancelled => "cancelled",
+            ReconcileError::StrategyError(strategy_error) => match strategy_error {
+                StrategyError::ReplicaSetReconciliationFailed(_) => "replicaset",
+                StrategyError::TrafficReconciliationFailed(_) => "traffic",
+                StrategyError::KubeError(_) => "kube_api",
+                StrategyError::MissingField(_) => "missing_field",
+                StrategyError::StrategyDisabled(_) => "validation",
+            },
+        }
+    }
 }
 
 pub struct Context {
@@ -55,10 +156,75 @@ pub struct Context {
     /// Optional controller metrics for Prometheus
     /// When Some, records reconciliation counts and durations
     pub metrics: Option<crate::server::SharedMetrics>,
+    /// Per-rollout limiter for step advancements (KULTA_MIN_STEP_INTERVAL_SECS)
+    pub step_rate_limiter: Arc<StepRateLimiter>,
+    /// Last reconciled (generation, status) per rollout, used to skip no-op reconciles
+    pub reconcile_cache: Arc<ReconcileCache>,
+    /// Hold step advancement until the canary ReplicaSet is ready (KULTA_WAIT_FOR_RS_READY)
+    /// None means not configured (disabled)
+    pub wait_for_rs_ready: Option<bool>,
+    /// Field manager for server-side apply of status (KULTA_FIELD_MANAGER)
+    pub field_manager: String,
+    /// Buffer for batched status updates (KULTA_STATUS_BATCH_INTERVAL_MS)
+    /// When None, reconcile patches status directly
+    pub status_batcher: Option<Arc<StatusBatcher>>,
+    /// Cancelled on controller shutdown; in-flight reconciles stop at their
+    /// next Kubernetes API call
+    pub cancellation: CancellationToken,
+    /// HTTPRoute API version discovered from the cluster (cached after first use)
+    pub httproute_version: Arc<tokio::sync::OnceCell<GatewayApiVersion>>,
+    /// Strategies this controller may run (KULTA_ENABLED_STRATEGIES)
+    pub enabled_strategies: Arc<HashSet<&'static str>>,
+    /// Beaten at the start of every reconcile, served by /livez
+    pub heartbeat: Heartbeat,
+    /// Requeue interval for Completed/Degraded rollouts (KULTA_TERMINAL_REQUEUE)
+    pub terminal_requeue: Duration,
+    /// Consecutive reconcile failures per rollout, backing off error requeues
+    pub error_backoff: Arc<ErrorBackoff>,
 }
 
 impl Context {
+    /// Create a Context from environment configuration, failing fast on invalid config
+    ///
+    /// Validates the environment before building the CDEvents sink and Prometheus client:
+    /// - KULTA_PROMETHEUS_ADDRESS, if set, must be a valid http(s) URL
+    /// - KULTA_CDEVENTS_ENABLED=true requires KULTA_CDEVENTS_SINK_URL
+    ///
+    /// When leader_state is Some, reconciliation is gated on leadership.
+    ///
+    /// # Errors
+    /// Returns error describing the first invalid setting found
+    pub fn try_new(
+        client: kube::Client,
+        leader_state: Option<LeaderState>,
+        metrics: Option<crate::server::SharedMetrics>,
+    ) -> anyhow::Result<Self> {
+        validate_env_config()?;
+
+        Ok(Context {
+            client,
+            cdevents_sink: Arc::new(cdevents_sink_from_env()),
+            prometheus_client: Arc::new(prometheus_client_from_env()),
+            leader_state,
+            metrics,
+            step_rate_limiter: Arc::new(StepRateLimiter::from_env()),
+            reconcile_cache: Arc::new(ReconcileCache::default()),
+            wait_for_rs_ready: wait_for_rs_ready_from_env(),
+            field_manager: field_manager_from_env(),
+            status_batcher: Some(Arc::new(StatusBatcher::from_env())),
+            cancellation: CancellationToken::new(),
+            httproute_version: Arc::new(tokio::sync::OnceCell::new()),
+            enabled_strategies: Arc::new(enabled_strategies_from_env()),
+            heartbeat: Heartbeat::default(),
+            terminal_requeue: terminal_requeue_from_env(),
+            error_backoff: Arc::new(ErrorBackoff::new()),
+        })
+    }
+
     /// Create a new Context without leader election (single instance mode)
+    ///
+    /// Skips environment validation - intended for tests and embedding.
+    /// Use `try_new` in production.
     pub fn new(
         client: kube::Client,
         cdevents_sink: crate::controller::cdevents::CDEventsSink,
@@ -71,6 +237,17 @@ impl Context {
             prometheus_client: Arc::new(prometheus_client),
             leader_state: None,
             metrics,
+            step_rate_limiter: Arc::new(StepRateLimiter::from_env()),
+            reconcile_cache: Arc::new(ReconcileCache::default()),
+            wait_for_rs_ready: wait_for_rs_ready_from_env(),
+            field_manager: field_manager_from_env(),
+            status_batcher: None,
+            cancellation: CancellationToken::new(),
+            httproute_version: Arc::new(tokio::sync::OnceCell::new()),
+            enabled_strategies: Arc::new(enabled_strategies_from_env()),
+            heartbeat: Heartbeat::default(),
+            terminal_requeue: terminal_requeue_from_env(),
+            error_backoff: Arc::new(ErrorBackoff::new()),
         }
     }
 
@@ -91,9 +268,37 @@ impl Context {
             prometheus_client: Arc::new(prometheus_client),
             leader_state: Some(leader_state),
             metrics,
+            step_rate_limiter: Arc::new(StepRateLimiter::from_env()),
+            reconcile_cache: Arc::new(ReconcileCache::default()),
+            wait_for_rs_ready: wait_for_rs_ready_from_env(),
+            field_manager: field_manager_from_env(),
+            status_batcher: None,
+            cancellation: CancellationToken::new(),
+            httproute_version: Arc::new(tokio::sync::OnceCell::new()),
+            enabled_strategies: Arc::new(enabled_strategies_from_env()),
+            heartbeat: Heartbeat::default(),
+            terminal_requeue: terminal_requeue_from_env(),
+            error_backoff: Arc::new(ErrorBackoff::new()),
         }
     }
 
+    /// Use the given token to cancel in-flight reconciles (e.g. the shutdown token)
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Use the given heartbeat for reconcile loop liveness (e.g. the /livez heartbeat)
+    pub fn with_heartbeat(mut self, heartbeat: Heartbeat) -> Self {
+        self.heartbeat = heartbeat;
+        self
+    }
+
+    /// Check if step advancement should wait for canary ReplicaSet readiness
+    pub fn waits_for_rs_ready(&self) -> bool {
+        self.wait_for_rs_ready.unwrap_or(false)
+    }
+
     /// Check if this instance should reconcile
     ///
     /// Returns true if:
@@ -123,6 +328,21 @@ impl Context {
             prometheus_client: Arc::new(PrometheusClient::new_mock()),
             leader_state: None,
             metrics: None,
+            step_rate_limiter: Arc::new(StepRateLimiter::default()),
+            reconcile_cache: Arc::new(ReconcileCache::default()),
+            wait_for_rs_ready: None,
+            field_manager: STATUS_FIELD_MANAGER.to_string(),
+            status_batcher: None,
+            cancellation: CancellationToken::new(),
+            httproute_version: Arc::new(tokio::sync::OnceCell::new()),
+            enabled_strategies: Arc::new(
+                crate::controller::strategies::STRATEGY_NAMES
+                    .into_iter()
+                    .collect(),
+            ),
+            heartbeat: Heartbeat::default(),
+            terminal_requeue: DEFAULT_TERMINAL_REQUEUE,
+            error_backoff: Arc::new(ErrorBackoff::new()),
         }
     }
 
@@ -133,17 +353,233 @@ impl Context {
     #[cfg(test)]
     #[allow(clippy::unwrap_used)] // Test helper - panicking is acceptable
     pub fn new_mock_with_leader(leader_state: LeaderState) -> Self {
-        let mock = Self::new_mock();
         Context {
-            client: mock.client,
-            cdevents_sink: mock.cdevents_sink,
-            prometheus_client: mock.prometheus_client,
             leader_state: Some(leader_state),
-            metrics: None,
+            ..Self::new_mock()
+        }
+    }
+}
+
+/// Validate controller environment configuration
+///
+/// # Errors
+/// - KULTA_PROMETHEUS_ADDRESS is set but not a valid http(s) URL
+/// - KULTA_PROMETHEUS_TOKEN is combined with basic auth, KULTA_PROMETHEUS_PASSWORD
+///   is set without KULTA_PROMETHEUS_USER, or KULTA_PROMETHEUS_CA_CERT is unreadable
+/// - KULTA_MIN_STEP_INTERVAL_SECS is set but not a whole number of seconds
+/// - KULTA_FIELD_MANAGER is set but empty or longer than 128 characters
+/// - KULTA_ANNOTATION_DOMAIN is set but empty or contains '/'
+/// - KULTA_CDEVENTS_ENABLED=true but KULTA_CDEVENTS_SINK_URL is unset or empty
+/// - KULTA_ENABLED_STRATEGIES names an unknown strategy
+/// - KULTA_TERMINAL_REQUEUE is set but not a duration between 1s and 24h
+pub fn validate_env_config() -> anyhow::Result<()> {
+    let prometheus_address = std::env::var("KULTA_PROMETHEUS_ADDRESS").unwrap_or_default();
+    if !prometheus_address.is_empty() {
+        let url = reqwest::Url::parse(&prometheus_address).map_err(|e| {
+            anyhow::anyhow!(
+                "KULTA_PROMETHEUS_ADDRESS is not a valid URL ({}): {}",
+                prometheus_address,
+                e
+            )
+        })?;
+        if url.scheme() != "http" && url.scheme() != "https" {
+            anyhow::bail!(
+                "KULTA_PROMETHEUS_ADDRESS must use http or https, got scheme '{}'",
+                url.scheme()
+            );
+        }
+    }
+
+    // Auth/TLS settings: conflicting credentials or an unreadable CA fail fast
+    PrometheusConnectionConfig::from_env()
+        .and_then(|config| config.build_http_client().map(|_| ()))
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    if let Ok(min_step_interval) = std::env::var("KULTA_MIN_STEP_INTERVAL_SECS") {
+        if parse_min_step_interval(&min_step_interval).is_none() {
+            anyhow::bail!(
+                "KULTA_MIN_STEP_INTERVAL_SECS must be a whole number of seconds, got '{}'",
+                min_step_interval
+            );
+        }
+    }
+
+    if let Ok(batch_interval) = std::env::var("KULTA_STATUS_BATCH_INTERVAL_MS") {
+        if parse_batch_interval(&batch_interval).is_none() {
+            anyhow::bail!(
+                "KULTA_STATUS_BATCH_INTERVAL_MS must be a positive number of milliseconds, got '{}'",
+                batch_interval
+            );
+        }
+    }
+
+    if let Ok(field_manager) = std::env::var("KULTA_FIELD_MANAGER") {
+        if field_manager.is_empty() || field_manager.len() > MAX_FIELD_MANAGER_LEN {
+            anyhow::bail!(
+                "KULTA_FIELD_MANAGER must be 1-{} characters, got '{}'",
+                MAX_FIELD_MANAGER_LEN,
+                field_manager
+            );
+        }
+    }
+
+    if let Ok(domain) = std::env::var("KULTA_ANNOTATION_DOMAIN") {
+        if domain.is_empty() || domain.contains('/') {
+            anyhow::bail!(
+                "KULTA_ANNOTATION_DOMAIN must be a non-empty domain without '/', got '{}'",
+                domain
+            );
+        }
+    }
+
+    let cdevents_enabled = std::env::var("KULTA_CDEVENTS_ENABLED")
+        .map(|v| v == "true")
+        .unwrap_or(false);
+    let cdevents_sink_url = std::env::var("KULTA_CDEVENTS_SINK_URL").unwrap_or_default();
+    if cdevents_enabled && cdevents_sink_url.is_empty() {
+        anyhow::bail!("KULTA_CDEVENTS_ENABLED=true requires KULTA_CDEVENTS_SINK_URL to be set");
+    }
+
+    if let Ok(enabled_strategies) = std::env::var("KULTA_ENABLED_STRATEGIES") {
+        parse_enabled_strategies(Some(&enabled_strategies))
+            .map_err(|e| anyhow::anyhow!("KULTA_ENABLED_STRATEGIES: {}", e))?;
+    }
+
+    if let Ok(terminal_requeue) = std::env::var("KULTA_TERMINAL_REQUEUE") {
+        if parse_terminal_requeue(&terminal_requeue).is_none() {
+            anyhow::bail!(
+                "KULTA_TERMINAL_REQUEUE must be seconds or a duration (30s, 10m, 1h) between 1s and 24h, got '{}'",
+                terminal_requeue
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Run an operation unless the reconcile is cancelled first
+///
+/// # Returns
+/// * `Ok(output)` - The operation's own result
+/// * `Err(ReconcileError::Cancelled)` - The token was cancelled; the operation
+///   is dropped where it stands
+pub async fn until_cancelled<F: std::future::Future>(
+    cancellation: &CancellationToken,
+    operation: F,
+) -> Result<F::Output, ReconcileError> {
+    tokio::select! {
+        biased;
+        _ = cancellation.cancelled() => Err(ReconcileError::Cancelled),
+        output = operation => Ok(output),
+    }
+}
+
+/// Read KULTA_WAIT_FOR_RS_READY ("true"/"1" enables, anything else disables)
+fn wait_for_rs_ready_from_env() -> Option<bool> {
+    std::env::var("KULTA_WAIT_FOR_RS_READY")
+        .ok()
+        .map(|v| v == "true" || v == "1")
+}
+
+/// Default requeue interval for Completed/Degraded rollouts
+///
+/// Terminal rollouts have nothing left to do until their spec changes, and a
+/// spec change is picked up right away through the watch.
+pub const DEFAULT_TERMINAL_REQUEUE: Duration = Duration::from_secs(600);
+
+/// Longest KULTA_TERMINAL_REQUEUE accepted
+pub const MAX_TERMINAL_REQUEUE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Parse KULTA_TERMINAL_REQUEUE: plain seconds ("600") or a duration ("10m", "1h")
+///
+/// # Returns
+/// The interval, or None if unparseable or outside 1s-24h
+pub fn parse_terminal_requeue(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let interval = match value.parse::<u64>() {
+        Ok(secs) => Duration::from_secs(secs),
+        Err(_) => parse_duration(value)?,
+    };
+
+    (Duration::from_secs(1)..=MAX_TERMINAL_REQUEUE)
+        .contains(&interval)
+        .then_some(interval)
+}
+
+/// Read KULTA_TERMINAL_REQUEUE (default: 10m)
+fn terminal_requeue_from_env() -> Duration {
+    std::env::var("KULTA_TERMINAL_REQUEUE")
+        .ok()
+        .and_then(|value| parse_terminal_requeue(&value))
+        .unwrap_or(DEFAULT_TERMINAL_REQUEUE)
+}
+
+/// Whether a status is terminal (Completed or Degraded): nothing to do until the spec changes
+pub fn is_terminal_status(status: &RolloutStatus) -> bool {
+    matches!(status.phase, Some(Phase::Completed) | Some(Phase::Degraded))
+}
+
+/// Read KULTA_FIELD_MANAGER (default: "kulta-controller")
+fn field_manager_from_env() -> String {
+    std::env::var("KULTA_FIELD_MANAGER")
+        .ok()
+        .filter(|v| !v.is_empty())
+        .unwrap_or_else(|| STATUS_FIELD_MANAGER.to_string())
+}
+
+/// Build the CDEvents sink from environment variables
+#[cfg(not(test))]
+fn cdevents_sink_from_env() -> crate::controller::cdevents::CDEventsSink {
+    let sink = crate::controller::cdevents::CDEventsSink::new();
+    info!(
+        enabled = std::env::var("KULTA_CDEVENTS_ENABLED").unwrap_or_else(|_| "false".to_string()),
+        "CDEvents sink configured"
+    );
+    sink
+}
+
+#[cfg(test)]
+fn cdevents_sink_from_env() -> crate::controller::cdevents::CDEventsSink {
+    crate::controller::cdevents::CDEventsSink::new_mock()
+}
+
+/// Build the Prometheus client from KULTA_PROMETHEUS_ADDRESS
+#[cfg(not(test))]
+fn prometheus_client_from_env() -> PrometheusClient {
+    let prometheus_address = std::env::var("KULTA_PROMETHEUS_ADDRESS").unwrap_or_default();
+    if prometheus_address.is_empty() {
+        info!("Prometheus address not configured - metrics analysis disabled");
+        PrometheusClient::new("http://localhost:9090".to_string()) // Dummy address, metrics will be skipped
+    } else {
+        let config = match PrometheusConnectionConfig::from_env() {
+            Ok(config) => config,
+            Err(e) => {
+                warn!(error = %e, "Invalid Prometheus connection config, using defaults");
+                PrometheusConnectionConfig::default()
+            }
+        };
+        info!(
+            address = %prometheus_address,
+            auth = ?config.auth,
+            custom_ca = config.ca_cert_path.is_some(),
+            insecure_skip_verify = config.accept_invalid_certs,
+            "Prometheus client configured"
+        );
+        match PrometheusClient::with_config(prometheus_address.clone(), &config) {
+            Ok(client) => client,
+            Err(e) => {
+                warn!(error = %e, "Failed to apply Prometheus TLS config, using defaults");
+                PrometheusClient::new(prometheus_address)
+            }
         }
     }
 }
 
+#[cfg(test)]
+fn prometheus_client_from_env() -> PrometheusClient {
+    PrometheusClient::new_mock()
+}
+
 /// Compute a stable 10-character hash for a PodTemplateSpec
 ///
 /// This mimics Kubernetes' pod-template-hash label behavior:
@@ -151,6 +587,10 @@ impl Context {
 /// - Hash the JSON bytes
 /// - Return 10-character hex string
 ///
+/// The whole template is hashed, init containers included. Struct fields
+/// serialize in declaration order and k8s-openapi maps are BTreeMaps, so the
+/// hash doesn't depend on the order a template was built or read in.
+///
 /// # Errors
 /// Returns SerializationError if PodTemplateSpec cannot be serialized to JSON
 pub fn compute_pod_template_hash(template: &PodTemplateSpec) -> Result<String, ReconcileError> {
@@ -171,26 +611,36 @@ pub fn compute_pod_template_hash(template: &PodTemplateSpec) -> Result<String, R
 ///
 /// Given total replicas and canary weight percentage, calculates:
 /// - canary_replicas = ceil(total * weight / 100)
-/// - stable_replicas = total - canary_replicas
+/// - stable_replicas = total - canary_replicas, or all of `total` with
+///   `surge` (the canary runs on top of stable) until the weight reaches 100
 ///
 /// # Arguments
 /// * `total_replicas` - Total number of replicas desired (from rollout.spec.replicas)
 /// * `canary_weight` - Percentage of traffic to canary (0-100)
+/// * `surge` - Keep stable at `total_replicas` and add the canary on top
 ///
 /// # Returns
 /// Tuple of (stable_replicas, canary_replicas)
 ///
 /// # Examples
 /// ```ignore
-/// let (stable, canary) = calculate_replica_split(3, 0);
+/// let (stable, canary) = calculate_replica_split_with_surge(3, 0, false);
 /// assert_eq!(stable, 3); // 0% weight → all stable
 /// assert_eq!(canary, 0);
 ///
-/// let (stable, canary) = calculate_replica_split(3, 50);
+/// let (stable, canary) = calculate_replica_split_with_surge(3, 50, false);
 /// assert_eq!(stable, 1); // 50% of 3 → 1 stable, 2 canary (ceil)
 /// assert_eq!(canary, 2);
+///
+/// let (stable, canary) = calculate_replica_split_with_surge(3, 50, true);
+/// assert_eq!(stable, 3); // surge → stable untouched, 2 extra canary pods
+/// assert_eq!(canary, 2);
 /// ```
-pub fn calculate_replica_split(total_replicas: i32, canary_weight: i32) -> (i32, i32) {
+pub fn calculate_replica_split_with_surge(
+    total_replicas: i32,
+    canary_weight: i32,
+    surge: bool,
+) -> (i32, i32) {
     // Calculate canary replicas (ceiling to ensure at least 1 if weight > 0)
     let canary_replicas = if canary_weight == 0 {
         0
@@ -200,18 +650,354 @@ pub fn calculate_replica_split(total_replicas: i32, canary_weight: i32) -> (i32,
         ((total_replicas as f64 * canary_weight as f64) / 100.0).ceil() as i32
     };
 
-    // Stable gets the remainder
-    let stable_replicas = total_replicas - canary_replicas;
+    // Stable gets the remainder, or stays whole while surging
+    let stable_replicas = if surge && canary_weight < 100 {
+        total_replicas
+    } else {
+        total_replicas - canary_replicas
+    };
 
     (stable_replicas, canary_replicas)
 }
 
+/// Apply minCanaryReplicas/minStableReplicas to a replica split
+///
+/// Floors only hold while the rollout is in progress (0 < weight < 100), so
+/// 0% and 100% still fully scale one side down. Each floor is capped at
+/// `total_replicas`. The other side gives up replicas to make room, but never
+/// below its own floor; if both floors together exceed the total, the
+/// rollout temporarily runs more than `total_replicas`.
+///
+/// # Returns
+/// Tuple of (stable_replicas, canary_replicas)
+pub fn apply_replica_floors(
+    total_replicas: i32,
+    canary_weight: i32,
+    (stable_replicas, canary_replicas): (i32, i32),
+    min_canary_replicas: Option<i32>,
+    min_stable_replicas: Option<i32>,
+) -> (i32, i32) {
+    if canary_weight <= 0 || canary_weight >= 100 {
+        return (stable_replicas, canary_replicas);
+    }
+
+    let total = total_replicas.max(0);
+    let min_canary = min_canary_replicas.unwrap_or(0).clamp(0, total);
+    let min_stable = min_stable_replicas.unwrap_or(0).clamp(0, total);
+
+    let canary = canary_replicas.max(min_canary);
+    let stable = (total - canary).max(min_stable);
+    let canary = (total - stable).max(min_canary);
+
+    (stable, canary)
+}
+
+/// Resolve an absolute or percentage replica count against a total
+///
+/// Matches Kubernetes Deployment semantics: a percentage of `total` rounds
+/// up for maxSurge (`round_up = true`) and down for maxUnavailable, so
+/// "25%" of 10 is 3 surge pods but 2 unavailable pods. Integers are used
+/// as-is.
+///
+/// # Errors
+/// Returns error for strings that are not a whole percentage ("25%") or for
+/// negative values.
+pub fn resolve_int_or_percent(
+    value: &IntOrString,
+    total: i32,
+    round_up: bool,
+) -> Result<i32, String> {
+    let resolved = match value {
+        IntOrString::Int(count) => *count,
+        IntOrString::String(raw) => {
+            let percent = raw
+                .strip_suffix('%')
+                .and_then(|number| number.parse::<i64>().ok())
+                .ok_or_else(|| format!("must be an integer or a percentage, got {:?}", raw))?;
+            let scaled = percent * i64::from(total.max(0));
+            let resolved = if round_up {
+                (scaled + 99).div_euclid(100)
+            } else {
+                scaled.div_euclid(100)
+            };
+            i32::try_from(resolved).map_err(|_| format!("{} is out of range", raw))?
+        }
+    };
+
+    if resolved < 0 {
+        return Err(format!("must be >= 0, got {}", resolved));
+    }
+    Ok(resolved)
+}
+
+/// Number of canary replicas run in analysis-only mode before promotion
+pub const ANALYSIS_ONLY_CANARY_REPLICAS: i32 = 1;
+
+/// Check if a canary Rollout runs in analysis-only mode (no traffic split)
+pub fn is_analysis_only(rollout: &Rollout) -> bool {
+    rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .and_then(|canary| canary.analysis_only)
+        .unwrap_or(false)
+}
+
+/// Calculate replica split for analysis-only canaries
+///
+/// Stable keeps full scale (it serves all live traffic) while the canary runs
+/// a fixed number of replicas. Only once the final step is reached (weight 100)
+/// does the canary take over the full replica count.
+///
+/// # Returns
+/// Tuple of (stable_replicas, canary_replicas)
+pub fn calculate_analysis_only_replica_split(
+    total_replicas: i32,
+    canary_weight: i32,
+) -> (i32, i32) {
+    if canary_weight >= 100 {
+        return (0, total_replicas);
+    }
+
+    (
+        total_replicas,
+        ANALYSIS_ONLY_CANARY_REPLICAS.min(total_replicas),
+    )
+}
+
+/// Check if a canary Rollout keeps stable at full scale (keepStableAtFullScale)
+pub fn keeps_stable_at_full_scale(rollout: &Rollout) -> bool {
+    rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .and_then(|canary| canary.keep_stable_at_full_scale)
+        .unwrap_or(false)
+}
+
+/// Calculate replica split when stable stays at full scale
+///
+/// The canary gets the same replica count as in the default split (plus
+/// minCanaryReplicas while in progress), but added on top of a full stable
+/// fleet. Stable is only scaled to zero once the canary reaches 100%.
+///
+/// # Returns
+/// Tuple of (stable_replicas, canary_replicas)
+pub fn calculate_full_stable_replica_split(
+    total_replicas: i32,
+    canary_weight: i32,
+    min_canary_replicas: Option<i32>,
+) -> (i32, i32) {
+    if canary_weight >= 100 {
+        return (0, total_replicas);
+    }
+
+    let (_, canary_replicas) =
+        calculate_replica_split_with_surge(total_replicas, canary_weight, true);
+    let canary_replicas = if canary_weight > 0 {
+        canary_replicas.max(
+            min_canary_replicas
+                .unwrap_or(0)
+                .clamp(0, total_replicas.max(0)),
+        )
+    } else {
+        canary_replicas
+    };
+
+    (total_replicas, canary_replicas)
+}
+
+/// Check if a canary Rollout surges canary pods above spec.replicas (replicaSurge)
+pub fn uses_replica_surge(rollout: &Rollout) -> bool {
+    rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .and_then(|canary| canary.replica_surge)
+        .unwrap_or(false)
+}
+
+/// Most canary pods a replicaSurge rollout runs above spec.replicas
+///
+/// maxSurge resolved against spec.replicas (percentages round up, as for a
+/// Deployment); all of spec.replicas when unset or invalid (rejected by
+/// validation).
+pub fn max_surge_replicas(rollout: &Rollout) -> i32 {
+    let total_replicas = rollout.spec.replicas;
+    rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .and_then(|canary| canary.max_surge.as_ref())
+        .and_then(|max_surge| resolve_int_or_percent(max_surge, total_replicas, true).ok())
+        .unwrap_or(total_replicas)
+}
+
+/// Calculate a canary Rollout's (stable, canary) replicas at a canary weight
+///
+/// Applies the configured mode: analysis-only, replicaSurge,
+/// keepStableAtFullScale, or the weighted split with
+/// minCanaryReplicas/minStableReplicas floors.
+pub fn calculate_canary_replicas(rollout: &Rollout, canary_weight: i32) -> (i32, i32) {
+    let total_replicas = rollout.spec.replicas;
+    let canary = rollout.spec.strategy.canary.as_ref();
+
+    if is_analysis_only(rollout) {
+        calculate_analysis_only_replica_split(total_replicas, canary_weight)
+    } else if uses_replica_surge(rollout) {
+        let (stable, canary) =
+            calculate_replica_split_with_surge(total_replicas, canary_weight, true);
+        if canary_weight >= 100 {
+            (stable, canary)
+        } else {
+            (stable, canary.min(max_surge_replicas(rollout)))
+        }
+    } else if keeps_stable_at_full_scale(rollout) {
+        calculate_full_stable_replica_split(
+            total_replicas,
+            canary_weight,
+            canary.and_then(|c| c.min_canary_replicas),
+        )
+    } else {
+        apply_replica_floors(
+            total_replicas,
+            canary_weight,
+            calculate_replica_split_with_surge(total_replicas, canary_weight, false),
+            canary.and_then(|c| c.min_canary_replicas),
+            canary.and_then(|c| c.min_stable_replicas),
+        )
+    }
+}
+
+/// Get the pod-template-hash a ReplicaSet was built from
+///
+/// Reads the `pod-template-hash` label, falling back to the
+/// `rollouts.kulta.io/pod-template-hash` annotation (managePodTemplateHash: false).
+pub fn replicaset_template_hash(rs: &ReplicaSet) -> Option<&str> {
+    rs.metadata
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.get("pod-template-hash"))
+        .or_else(|| {
+            rs.metadata
+                .annotations
+                .as_ref()
+                .and_then(|annotations| annotations.get(POD_TEMPLATE_HASH_ANNOTATION))
+        })
+        .map(String::as_str)
+}
+
+/// What to do with a ReplicaSet that already exists under the desired name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExistingReplicaSetAction {
+    /// Template matches (or only the canary may be replaced): scale in place
+    Scale,
+    /// Canary built from a stale template: scale it down and recreate it
+    Replace,
+    /// Stale template, but another controller owns it: leave it alone
+    OwnedByOther,
+}
+
+/// Decide how to reconcile an existing ReplicaSet against the desired one
+///
+/// A ReplicaSet's selector (which includes the pod-template-hash) is immutable,
+/// so a canary whose hash differs from the desired one (e.g. a pre-existing or
+/// orphaned ReplicaSet) must be replaced rather than scaled, otherwise its pods
+/// keep running the old template. Only canary ReplicaSets are replaced.
+/// ReplicaSets with a controller owner reference to anything other than a
+/// Rollout are never touched.
+pub fn classify_existing_replicaset(
+    existing: &ReplicaSet,
+    desired: &ReplicaSet,
+    rs_type: &str,
+) -> ExistingReplicaSetAction {
+    let stale = match (
+        replicaset_template_hash(existing),
+        replicaset_template_hash(desired),
+    ) {
+        (Some(existing_hash), Some(desired_hash)) => existing_hash != desired_hash,
+        // No hash on the existing ReplicaSet: not built by KULTA, can't be trusted
+        (None, Some(_)) => true,
+        _ => false,
+    };
+
+    if !stale {
+        return ExistingReplicaSetAction::Scale;
+    }
+
+    let owned_by_other = existing
+        .metadata
+        .owner_references
+        .as_ref()
+        .is_some_and(|owners| {
+            owners
+                .iter()
+                .any(|owner| owner.controller == Some(true) && owner.kind != "Rollout")
+        });
+
+    if owned_by_other {
+        ExistingReplicaSetAction::OwnedByOther
+    } else if rs_type == "canary" {
+        ExistingReplicaSetAction::Replace
+    } else {
+        ExistingReplicaSetAction::Scale
+    }
+}
+
+/// Label marking a canary ReplicaSet whose pod template was superseded
+pub const SUPERSEDED_LABEL: &str = "rollouts.kulta.io/superseded";
+
+/// Scale a stale canary ReplicaSet to 0, delete it and create the desired one
+async fn replace_stale_replicaset(
+    rs_api: &Api<ReplicaSet>,
+    existing: &ReplicaSet,
+    rs: &ReplicaSet,
+    rs_name: &str,
+    rs_type: &str,
+) -> Result<(), ReconcileError> {
+    use kube::api::{DeleteParams, Patch, PatchParams};
+
+    warn!(
+        replicaset = ?rs_name,
+        rs_type = rs_type,
+        stale_hash = ?replicaset_template_hash(existing),
+        desired_hash = ?replicaset_template_hash(rs),
+        "ReplicaSet has a stale pod template, replacing it"
+    );
+
+    // Label it superseded so anything left behind (e.g. a failed delete) can be GC'd
+    let scale_down = serde_json::json!({
+        "metadata": { "labels": { SUPERSEDED_LABEL: "true" } },
+        "spec": { "replicas": 0 }
+    });
+    rs_api
+        .patch(rs_name, &PatchParams::default(), &Patch::Merge(&scale_down))
+        .await?;
+    rs_api.delete(rs_name, &DeleteParams::background()).await?;
+
+    // A 409 here means the deletion is still in progress; the error requeues
+    // the reconcile, which creates the ReplicaSet once the name is free
+    rs_api.create(&PostParams::default(), rs).await?;
+
+    info!(
+        replicaset = ?rs_name,
+        rs_type = rs_type,
+        "Stale ReplicaSet replaced"
+    );
+    Ok(())
+}
+
 /// Ensure a ReplicaSet exists (create if missing)
 ///
 /// This function is idempotent - it will:
 /// - Return Ok if ReplicaSet already exists
 /// - Create ReplicaSet if it doesn't exist (404)
-/// - Return Err on other API errors
+/// - Replace a canary ReplicaSet built from a stale template
+/// - Return Err on other API errors, or if a stale ReplicaSet is owned by another controller
 pub async fn ensure_replicaset_exists(
     rs_api: &Api<ReplicaSet>,
     rs: &ReplicaSet,
@@ -226,6 +1012,42 @@ pub async fn ensure_replicaset_exists(
 
     match rs_api.get(rs_name).await {
         Ok(existing) => {
+            match classify_existing_replicaset(&existing, rs, rs_type) {
+                ExistingReplicaSetAction::Scale => {}
+                ExistingReplicaSetAction::Replace => {
+                    return replace_stale_replicaset(rs_api, &existing, rs, rs_name, rs_type).await;
+                }
+                ExistingReplicaSetAction::OwnedByOther => {
+                    warn!(
+                        replicaset = ?rs_name,
+                        rs_type = rs_type,
+                        "ReplicaSet has a stale pod template but is owned by another controller"
+                    );
+                    return Err(ReconcileError::ReplicaSetOwnedByOther(rs_name.clone()));
+                }
+            }
+
+            // Adopt ReplicaSets created before owner references were set,
+            // so their changes also trigger reconciles
+            if existing.metadata.owner_references.is_none() {
+                if let Some(owners) = rs.metadata.owner_references.as_ref() {
+                    use kube::api::{Patch, PatchParams};
+                    let owner_patch = serde_json::json!({
+                        "metadata": {
+                            "ownerReferences": owners
+                        }
+                    });
+                    rs_api
+                        .patch(
+                            rs_name,
+                            &PatchParams::default(),
+                            &Patch::Merge(&owner_patch),
+                        )
+                        .await?;
+                    info!(replicaset = ?rs_name, rs_type = rs_type, "Adopted ReplicaSet");
+                }
+            }
+
             // Check if replicas need scaling
             let current_replicas = existing.spec.as_ref().and_then(|s| s.replicas).unwrap_or(0);
 
@@ -302,16 +1124,48 @@ pub async fn ensure_replicaset_exists(
     Ok(())
 }
 
-/// Simple representation of HTTPBackendRef for testing
+/// Ensure the stable ReplicaSet runs the current pod template
 ///
-/// This is a simplified version of Gateway API HTTPBackendRef
-/// focused on what we need for weight-based traffic splitting
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct HTTPBackendRef {
-    /// Name of the Kubernetes Service
-    pub name: String,
+/// Used after a canary completes: a stable ReplicaSet built from an older
+/// template is replaced (scaled down, deleted, recreated) instead of only
+/// scaled, so stable adopts the version the canary promoted.
+pub async fn adopt_stable_replicaset(
+    rs_api: &Api<ReplicaSet>,
+    rs: &ReplicaSet,
+    replicas: i32,
+) -> Result<(), ReconcileError> {
+    let rs_name = rs
+        .metadata
+        .name
+        .as_ref()
+        .ok_or(ReconcileError::ReplicaSetMissingName)?;
 
-    /// Port number on the service
+    if let Some(existing) = rs_api.get_opt(rs_name).await? {
+        // Classify as "canary" so a stale stable ReplicaSet is replaced too
+        match classify_existing_replicaset(&existing, rs, "canary") {
+            ExistingReplicaSetAction::Replace => {
+                return replace_stale_replicaset(rs_api, &existing, rs, rs_name, "stable").await;
+            }
+            ExistingReplicaSetAction::OwnedByOther => {
+                return Err(ReconcileError::ReplicaSetOwnedByOther(rs_name.clone()));
+            }
+            ExistingReplicaSetAction::Scale => {}
+        }
+    }
+
+    ensure_replicaset_exists(rs_api, rs, "stable", replicas).await
+}
+
+/// Simple representation of HTTPBackendRef for testing
+///
+/// This is a simplified version of Gateway API HTTPBackendRef
+/// focused on what we need for weight-based traffic splitting
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HTTPBackendRef {
+    /// Name of the Kubernetes Service
+    pub name: String,
+
+    /// Port number on the service
     #[serde(skip_serializing_if = "Option::is_none")]
     pub port: Option<i32>,
 
@@ -365,33 +1219,25 @@ pub fn build_backend_refs_with_weights(rollout: &Rollout) -> Vec<HTTPBackendRef>
 /// Vec of HTTPRouteRulesBackendRefs with correct weights for current rollout step
 pub fn build_gateway_api_backend_refs(
     rollout: &Rollout,
+) -> Vec<gateway_api::apis::standard::httproutes::HTTPRouteRulesBackendRefs> {
+    build_gateway_api_backend_refs_with_ports(rollout, DEFAULT_BACKEND_PORT, DEFAULT_BACKEND_PORT)
+}
+
+/// Build Gateway API backend refs with resolved stable/canary Service ports
+///
+/// Same as `build_gateway_api_backend_refs`, with the canary strategy's
+/// backend ports resolved from `canary.portName` (see `PortResolver`).
+/// Blue-green backends always use the default port.
+pub fn build_gateway_api_backend_refs_with_ports(
+    rollout: &Rollout,
+    stable_port: i32,
+    canary_port: i32,
 ) -> Vec<gateway_api::apis::standard::httproutes::HTTPRouteRulesBackendRefs> {
     use gateway_api::apis::standard::httproutes::HTTPRouteRulesBackendRefs;
 
     // Check for blue-green strategy first
-    if let Some(blue_green) = &rollout.spec.strategy.blue_green {
-        let (active_weight, preview_weight) = calculate_blue_green_weights(rollout);
-
-        return vec![
-            HTTPRouteRulesBackendRefs {
-                name: blue_green.active_service.clone(),
-                port: Some(80),
-                weight: Some(active_weight),
-                kind: Some("Service".to_string()),
-                group: Some("".to_string()),
-                namespace: None,
-                filters: None,
-            },
-            HTTPRouteRulesBackendRefs {
-                name: blue_green.preview_service.clone(),
-                port: Some(80),
-                weight: Some(preview_weight),
-                kind: Some("Service".to_string()),
-                group: Some("".to_string()),
-                namespace: None,
-                filters: None,
-            },
-        ];
+    if rollout.spec.strategy.blue_green.is_some() {
+        return build_blue_green_backend_refs(rollout);
     }
 
     // Get canary strategy
@@ -400,13 +1246,28 @@ pub fn build_gateway_api_backend_refs(
         None => return vec![], // No canary or blue-green strategy
     };
 
-    // Calculate current weights
+    // Analysis-only canaries never split live traffic
+    if canary_strategy.analysis_only == Some(true) {
+        return vec![];
+    }
+
+    // Calculate current weights; active extra canary tracks take their share from stable
     let (stable_weight, canary_weight) = calculate_traffic_weights(rollout);
+    let extra_weights = calculate_extra_canary_weights(rollout);
+    let stable_weight =
+        (stable_weight - extra_weights.iter().map(|(_, weight)| weight).sum::<i32>()).max(0);
 
-    vec![
+    // The sticky cookie is only set by the canary backend
+    let canary_filters = canary_strategy
+        .sticky_cookie
+        .as_ref()
+        .and_then(build_sticky_cookie_filter)
+        .map(|filter| vec![filter]);
+
+    let mut backend_refs = vec![
         HTTPRouteRulesBackendRefs {
             name: canary_strategy.stable_service.clone(),
-            port: Some(80), // Default HTTP port
+            port: Some(stable_port),
             weight: Some(stable_weight),
             kind: Some("Service".to_string()),
             group: Some("".to_string()), // Core API group (empty string)
@@ -415,16 +1276,232 @@ pub fn build_gateway_api_backend_refs(
         },
         HTTPRouteRulesBackendRefs {
             name: canary_strategy.canary_service.clone(),
-            port: Some(80),
+            port: Some(canary_port),
             weight: Some(canary_weight),
             kind: Some("Service".to_string()),
             group: Some("".to_string()),
             namespace: None,
+            filters: canary_filters,
+        },
+    ];
+
+    // Extra canary tracks (extraCanaries) use the default port
+    backend_refs.extend(extra_weights.into_iter().map(|(service, weight)| {
+        HTTPRouteRulesBackendRefs {
+            name: service,
+            port: Some(DEFAULT_BACKEND_PORT),
+            weight: Some(weight),
+            kind: Some("Service".to_string()),
+            group: Some("".to_string()),
+            namespace: None,
+            filters: None,
+        }
+    }));
+
+    backend_refs
+}
+
+/// Calculate the weights of the extra canary tracks active at the current step
+///
+/// A track is active once `current_step_index >= stepIndex`. No track gets
+/// traffic before the first step, after completion (the canary takes 100%),
+/// or while rolling back.
+///
+/// # Returns
+/// (service, weight) for each active track, in spec order
+pub fn calculate_extra_canary_weights(rollout: &Rollout) -> Vec<(String, i32)> {
+    let Some(canary) = rollout.spec.strategy.canary.as_ref() else {
+        return vec![];
+    };
+    let Some(extra_canaries) = canary.extra_canaries.as_ref() else {
+        return vec![];
+    };
+
+    let status = rollout.status.as_ref();
+    if matches!(
+        status.and_then(|s| s.phase.as_ref()),
+        Some(Phase::RollingBack) | Some(Phase::Degraded)
+    ) {
+        return vec![];
+    }
+
+    let current_step_index = status.and_then(|s| s.current_step_index).unwrap_or(-1);
+    if current_step_index < 0 || current_step_index as usize >= canary.steps.len() {
+        return vec![];
+    }
+
+    extra_canaries
+        .iter()
+        .filter(|extra| current_step_index >= extra.step_index)
+        .map(|extra| (extra.service.clone(), extra.weight))
+        .collect()
+}
+
+/// Build Gateway API backend refs for a blue-green Rollout
+///
+/// Blue-green never splits traffic: active/preview weights are always
+/// (100, 0) before promotion and (0, 100) after (see calculate_blue_green_weights).
+///
+/// # Returns
+/// Active and preview backend refs, or empty if no blue-green strategy
+pub fn build_blue_green_backend_refs(
+    rollout: &Rollout,
+) -> Vec<gateway_api::apis::standard::httproutes::HTTPRouteRulesBackendRefs> {
+    use gateway_api::apis::standard::httproutes::HTTPRouteRulesBackendRefs;
+
+    let blue_green = match &rollout.spec.strategy.blue_green {
+        Some(strategy) => strategy,
+        None => return vec![],
+    };
+
+    let (active_weight, preview_weight) = calculate_blue_green_weights(rollout);
+
+    // Declarative promotion (activeService == previewService): one backend
+    if is_declarative_promotion(rollout) {
+        return vec![HTTPRouteRulesBackendRefs {
+            name: blue_green.preview_service.clone(),
+            port: Some(80),
+            weight: Some(100),
+            kind: Some("Service".to_string()),
+            group: Some("".to_string()),
+            namespace: None,
+            filters: None,
+        }];
+    }
+
+    vec![
+        HTTPRouteRulesBackendRefs {
+            name: blue_green.active_service.clone(),
+            port: Some(80),
+            weight: Some(active_weight),
+            kind: Some("Service".to_string()),
+            group: Some("".to_string()),
+            namespace: None,
+            filters: None,
+        },
+        HTTPRouteRulesBackendRefs {
+            name: blue_green.preview_service.clone(),
+            port: Some(80),
+            weight: Some(preview_weight),
+            kind: Some("Service".to_string()),
+            group: Some("".to_string()),
+            namespace: None,
             filters: None,
         },
     ]
 }
 
+/// Build the rule-level HTTPRoute settings for a canary Rollout
+///
+/// `mirror_port` is the mirror Service's resolved port (see `PortResolver`).
+/// Blue-green and Rollouts without these features get the defaults.
+pub fn build_httproute_rule_options(rollout: &Rollout, mirror_port: i32) -> HttpRouteRuleOptions {
+    let Some(canary_strategy) = rollout.spec.strategy.canary.as_ref() else {
+        return HttpRouteRuleOptions::default();
+    };
+
+    HttpRouteRuleOptions {
+        mirror_filter: canary_strategy
+            .mirror_service
+            .as_deref()
+            .and_then(|mirror_service| build_request_mirror_filter(mirror_service, mirror_port)),
+        sticky_cookie: canary_strategy.sticky_cookie.clone(),
+    }
+}
+
+/// Build a Gateway API RequestMirror filter targeting the given service
+///
+/// Mirrored requests are fire-and-forget copies: the mirror backend receives
+/// traffic without consuming any of the stable/canary weight. The filter is
+/// set on the rule rather than on each backendRef, so every request is
+/// mirrored exactly once.
+///
+/// # Returns
+/// The rule filter, or None if it could not be constructed (logged)
+pub fn build_request_mirror_filter(
+    mirror_service: &str,
+    port: i32,
+) -> Option<gateway_api::apis::standard::httproutes::HTTPRouteRulesFilters> {
+    let filter_json = serde_json::json!({
+        "type": "RequestMirror",
+        "requestMirror": {
+            "backendRef": {
+                "name": mirror_service,
+                "port": port
+            }
+        }
+    });
+
+    match serde_json::from_value(filter_json) {
+        Ok(filter) => Some(filter),
+        Err(e) => {
+            warn!(
+                error = ?e,
+                mirror_service = mirror_service,
+                "Failed to build RequestMirror filter - mirroring disabled"
+            );
+            None
+        }
+    }
+}
+
+/// Value of the sticky cookie's Set-Cookie header, e.g. "canary=canary; Path=/; Max-Age=3600"
+pub fn sticky_cookie_header(cookie: &StickyCookie) -> String {
+    let mut header = format!("{}=canary; Path=/", cookie.name);
+    if let Some(max_age) = cookie.max_age_seconds {
+        header.push_str(&format!("; Max-Age={}", max_age));
+    }
+    header
+}
+
+/// Regular expression matching a Cookie header that carries the sticky cookie
+///
+/// Cookie names may contain regex metacharacters (e.g. '.', '+'), so every
+/// ASCII punctuation character of the name is escaped.
+pub fn sticky_cookie_match_pattern(cookie: &StickyCookie) -> String {
+    let mut name = String::with_capacity(cookie.name.len());
+    for c in cookie.name.chars() {
+        if c.is_ascii_punctuation() {
+            name.push('\\');
+        }
+        name.push(c);
+    }
+    format!("(^|;\\s*){}=canary(;|$)", name)
+}
+
+/// Build a Gateway API ResponseHeaderModifier filter setting the sticky cookie
+///
+/// Attached to the canary backendRef only, so just responses served by the
+/// canary carry the cookie.
+///
+/// # Returns
+/// The backendRef filter, or None if it could not be constructed (logged)
+pub fn build_sticky_cookie_filter(
+    cookie: &StickyCookie,
+) -> Option<gateway_api::apis::standard::httproutes::HTTPRouteRulesBackendRefsFilters> {
+    let filter_json = serde_json::json!({
+        "type": "ResponseHeaderModifier",
+        "responseHeaderModifier": {
+            "add": [{
+                "name": "Set-Cookie",
+                "value": sticky_cookie_header(cookie)
+            }]
+        }
+    });
+
+    match serde_json::from_value(filter_json) {
+        Ok(filter) => Some(filter),
+        Err(e) => {
+            warn!(
+                error = ?e,
+                cookie = %cookie.name,
+                "Failed to build ResponseHeaderModifier filter - sticky cookie disabled"
+            );
+            None
+        }
+    }
+}
+
 /// Calculate traffic weights for blue-green strategy
 ///
 /// Returns (active_weight, preview_weight):
@@ -491,6 +1568,14 @@ pub fn calculate_traffic_weights(rollout: &Rollout) -> (i32, i32) {
         None => return (100, 0), // No canary strategy, 100% stable
     };
 
+    // Rolling back or rolled back: all traffic to stable
+    if matches!(
+        rollout.status.as_ref().and_then(|s| s.phase.as_ref()),
+        Some(Phase::RollingBack) | Some(Phase::Degraded)
+    ) {
+        return (100, 0);
+    }
+
     // Get current step index from status
     let current_step_index = match &rollout.status {
         Some(status) => status.current_step_index.unwrap_or(-1),
@@ -538,30 +1623,51 @@ pub fn calculate_traffic_weights(rollout: &Rollout) -> (i32, i32) {
 pub fn initialize_rollout_status(rollout: &Rollout) -> crate::crd::rollout::RolloutStatus {
     use crate::crd::rollout::RolloutStatus;
 
+    // Record rollout start time (used for maxDurationSeconds timeout)
+    let started_at = Some(Utc::now().to_rfc3339());
+
     // Check for simple strategy first
     if rollout.spec.strategy.simple.is_some() {
         // Simple strategy: no steps, just deploy and complete
-        return RolloutStatus {
+        let mut status = RolloutStatus {
             phase: Some(Phase::Completed),
             current_step_index: None,
             current_weight: None,
             message: Some("Simple rollout completed: all replicas updated".to_string()),
+            started_at,
             ..Default::default()
         };
+        record_decision(
+            &mut status,
+            DecisionAction::Complete,
+            DecisionReason::Initialization,
+            None,
+            None,
+        );
+        return status;
     }
 
     // Check for blue-green strategy
     if rollout.spec.strategy.blue_green.is_some() {
         // Blue-green strategy: preview RS ready, awaiting promotion
         // Set pause_start_time to track when preview started (for auto-promotion timer)
-        return RolloutStatus {
+        let mut status = RolloutStatus {
             phase: Some(Phase::Preview),
             current_step_index: None,
             current_weight: None,
             message: Some("Blue-green rollout: preview environment ready".to_string()),
             pause_start_time: Some(Utc::now().to_rfc3339()),
+            started_at,
             ..Default::default()
         };
+        record_decision(
+            &mut status,
+            DecisionAction::Initialize,
+            DecisionReason::Initialization,
+            None,
+            None,
+        );
+        return status;
     }
 
     // Get canary strategy
@@ -591,7 +1697,8 @@ pub fn initialize_rollout_status(rollout: &Rollout) -> crate::crd::rollout::Roll
         None
     };
 
-    RolloutStatus {
+    let mut status = RolloutStatus {
+        revision_history: stable_revision(rollout, Utc::now()).into_iter().collect(),
         current_step_index: Some(0),
         current_weight: Some(first_step_weight),
         phase: Some(Phase::Progressing),
@@ -599,9 +1706,171 @@ pub fn initialize_rollout_status(rollout: &Rollout) -> crate::crd::rollout::Roll
             "Starting canary rollout at step 0 ({}% traffic)",
             first_step_weight
         )),
+        pause_start_generation: pause_start_time.as_ref().and(rollout.metadata.generation),
         pause_start_time,
+        step_start_time: started_at.clone(),
+        started_at,
+        ..Default::default()
+    };
+    record_decision(
+        &mut status,
+        DecisionAction::Initialize,
+        DecisionReason::Initialization,
+        None,
+        Some(0),
+    );
+    status
+}
+
+/// Maximum number of entries kept in status.decisions
+pub const MAX_DECISIONS: usize = 100;
+
+/// Append a decision to the status' decision history
+///
+/// The decision is timestamped now (RFC3339) and carries the status message.
+/// Once the history exceeds MAX_DECISIONS the oldest entries are dropped.
+pub fn record_decision(
+    status: &mut RolloutStatus,
+    action: DecisionAction,
+    reason: DecisionReason,
+    from_step: Option<i32>,
+    to_step: Option<i32>,
+) {
+    status.decisions.push(Decision {
+        timestamp: Utc::now().to_rfc3339(),
+        action,
+        from_step,
+        to_step,
+        reason,
+        message: status.message.clone(),
+        metrics: None,
+    });
+
+    if status.decisions.len() > MAX_DECISIONS {
+        let excess = status.decisions.len() - MAX_DECISIONS;
+        status.decisions.drain(..excess);
+    }
+}
+
+/// Set a status condition, updating lastTransitionTime only when its status changes
+pub fn set_condition(
+    status: &mut RolloutStatus,
+    condition_type: &str,
+    value: bool,
+    reason: &str,
+    message: Option<String>,
+) {
+    let condition_status = if value { "True" } else { "False" };
+    let now = Utc::now().to_rfc3339();
+
+    match status
+        .conditions
+        .iter_mut()
+        .find(|condition| condition.type_ == condition_type)
+    {
+        Some(condition) => {
+            if condition.status != condition_status {
+                condition.status = condition_status.to_string();
+                condition.last_transition_time = Some(now);
+            }
+            condition.reason = Some(reason.to_string());
+            condition.message = message;
+        }
+        None => status.conditions.push(RolloutCondition {
+            type_: condition_type.to_string(),
+            status: condition_status.to_string(),
+            reason: Some(reason.to_string()),
+            message,
+            last_transition_time: Some(now),
+        }),
+    }
+}
+
+/// Check if a status condition is present with status "True"
+pub fn is_condition_true(status: &RolloutStatus, condition_type: &str) -> bool {
+    status
+        .conditions
+        .iter()
+        .any(|condition| condition.type_ == condition_type && condition.status == "True")
+}
+
+/// Status recorded on a Rollout's very first reconcile
+///
+/// Written before any ReplicaSet is created, so a rollout stuck creating its
+/// ReplicaSets (e.g. quota exceeded) shows Initializing rather than no status.
+/// The next reconcile initializes the strategy from here.
+pub fn initializing_status(rollout: &Rollout) -> RolloutStatus {
+    let mut status = RolloutStatus {
+        phase: Some(Phase::Initializing),
+        message: Some("Initializing rollout: creating ReplicaSets".to_string()),
         ..Default::default()
+    };
+    observe_generation(rollout, &mut status);
+    status
+}
+
+/// Check if the rollout is held at canary.pauseAtStep
+///
+/// # Returns
+/// true if the current step index equals pauseAtStep
+pub fn is_held_at_pause_step(rollout: &Rollout) -> bool {
+    let pause_at_step = rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .and_then(|canary| canary.pause_at_step);
+    let current_step_index = rollout.status.as_ref().and_then(|s| s.current_step_index);
+
+    pause_at_step.is_some() && pause_at_step == current_step_index
+}
+
+/// Status message while a rollout is held by spec.paused
+pub const SPEC_PAUSED_MESSAGE: &str = "Rollout paused by spec.paused field";
+
+/// Whether the rollout is held by spec.paused (canary only)
+pub fn is_spec_paused(rollout: &Rollout) -> bool {
+    rollout.spec.paused.unwrap_or(false) && rollout.spec.strategy.canary.is_some()
+}
+
+/// Whether a status was paused by spec.paused (rather than a manual pause)
+pub fn is_paused_by_spec(status: &RolloutStatus) -> bool {
+    status.phase == Some(Phase::Paused) && status.message.as_deref() == Some(SPEC_PAUSED_MESSAGE)
+}
+
+/// The status of a rollout held by spec.paused
+///
+/// The rollout stays at its current step and weight, in the Paused phase. A
+/// rollout that hasn't started yet is held before step 0 at weight 0, so its
+/// ReplicaSets are created without sending the canary any traffic.
+///
+/// None if spec.paused is not set, or the rollout is not in a phase a pause
+/// can hold (finished, rolling back, or manually paused).
+pub fn spec_paused_status(rollout: &Rollout, now: DateTime<Utc>) -> Option<RolloutStatus> {
+    if !is_spec_paused(rollout) {
+        return None;
+    }
+    let current = rollout.status.as_ref()?;
+    if is_paused_by_spec(current) {
+        return Some(current.clone());
     }
+
+    let mut paused = match current.phase {
+        Some(Phase::Initializing) => RolloutStatus {
+            current_step_index: None,
+            current_weight: Some(0),
+            ..current.clone()
+        },
+        Some(Phase::Progressing)
+        | Some(Phase::Scaling)
+        | Some(Phase::WaitingForReadiness)
+        | Some(Phase::WaitingForAnalysis) => current.clone(),
+        _ => return None,
+    };
+    paused.phase = Some(Phase::Paused);
+    paused.message = Some(SPEC_PAUSED_MESSAGE.to_string());
+    record_transition(Some(current), &mut paused, now);
+    Some(paused)
 }
 
 /// Check if rollout should progress to next step
@@ -609,6 +1878,7 @@ pub fn initialize_rollout_status(rollout: &Rollout) -> crate::crd::rollout::Roll
 /// Returns true if:
 /// - Current step has no pause defined
 /// - Phase is not "Paused"
+/// - Current step is not canary.pauseAtStep (unless promoted)
 ///
 /// # Arguments
 /// * `rollout` - The Rollout to check
@@ -645,6 +1915,11 @@ pub fn should_progress_to_next_step(rollout: &Rollout) -> bool {
         None => return false, // Invalid step index
     };
 
+    // Hold at pauseAtStep until promoted, whatever the step's own pause says
+    if is_held_at_pause_step(rollout) {
+        return has_promote_annotation(rollout);
+    }
+
     // Check if current step has pause
     if let Some(pause) = &current_step.pause {
         // Check for manual promotion annotation
@@ -695,11 +1970,197 @@ pub fn should_progress_to_next_step(rollout: &Rollout) -> bool {
 /// # Returns
 /// The desired RolloutStatus that should be written to K8s
 pub fn compute_desired_status(rollout: &Rollout) -> crate::crd::rollout::RolloutStatus {
-    // If no status, initialize
-    if rollout.status.is_none() {
+    compute_desired_status_at(rollout, Utc::now())
+}
+
+/// Compute the desired status for a Rollout at a given point in time
+///
+/// Same as `compute_desired_status`, with the current time injected so
+/// timeout behavior can be tested without sleeping. Phase and step changes
+/// are appended to status.transitionLog.
+pub fn compute_desired_status_at(
+    rollout: &Rollout,
+    now: DateTime<Utc>,
+) -> crate::crd::rollout::RolloutStatus {
+    let mut status = next_status_at(rollout, now);
+    record_transition(rollout.status.as_ref(), &mut status, now);
+    status
+}
+
+/// Maximum number of entries kept in status.transitionLog
+pub const MAX_TRANSITION_LOG: usize = 200;
+
+/// Append a transition to `next.transitionLog` if the phase or step changed
+///
+/// The log is carried over from `previous` (the status computations don't
+/// touch it) and capped at MAX_TRANSITION_LOG, dropping the oldest entries.
+pub fn record_transition(
+    previous: Option<&RolloutStatus>,
+    next: &mut RolloutStatus,
+    now: DateTime<Utc>,
+) {
+    next.transition_log = previous
+        .map(|status| status.transition_log.clone())
+        .unwrap_or_default();
+
+    let from_phase = previous.and_then(|status| status.phase.clone());
+    let from_step = previous.and_then(|status| status.current_step_index);
+    let Some(to_phase) = next.phase.clone() else {
+        return;
+    };
+    if from_phase.as_ref() == Some(&to_phase) && from_step == next.current_step_index {
+        return;
+    }
+
+    let trigger = transition_trigger(previous, next);
+    next.transition_log.push(StatusTransition {
+        timestamp: now.to_rfc3339(),
+        from_phase,
+        to_phase,
+        from_step,
+        to_step: next.current_step_index,
+        trigger,
+    });
+
+    let excess = next.transition_log.len().saturating_sub(MAX_TRANSITION_LOG);
+    next.transition_log.drain(..excess);
+}
+
+/// Classify a transition by the decision recorded with it
+///
+/// Transitions without a new decision (e.g. a readiness gate clearing) are
+/// AutoProgression.
+fn transition_trigger(previous: Option<&RolloutStatus>, next: &RolloutStatus) -> TransitionTrigger {
+    let Some(previous) = previous else {
+        return TransitionTrigger::Initialization;
+    };
+    let new_decision = next
+        .decisions
+        .last()
+        .filter(|decision| previous.decisions.last() != Some(*decision));
+
+    match new_decision.map(|decision| &decision.reason) {
+        Some(DecisionReason::Initialization) | Some(DecisionReason::RevisionSuperseded) => {
+            TransitionTrigger::Initialization
+        }
+        Some(DecisionReason::ManualPromotion) | Some(DecisionReason::SkipAnalysisAnnotation) => {
+            TransitionTrigger::ManualPromotion
+        }
+        Some(DecisionReason::PauseDurationExpired) => TransitionTrigger::PauseExpired,
+        Some(DecisionReason::AnalysisPassed)
+        | Some(DecisionReason::AnalysisFailed)
+        | Some(DecisionReason::AnalysisWarmup)
+        | Some(DecisionReason::MetricsUnavailable) => TransitionTrigger::AnalysisResult,
+        Some(DecisionReason::ManualRollback) => TransitionTrigger::Abort,
+        Some(DecisionReason::StepReady)
+        | Some(DecisionReason::Timeout)
+        | Some(DecisionReason::QuotaExceeded)
+        | Some(DecisionReason::RouteNotAccepted)
+        | None => TransitionTrigger::AutoProgression,
+    }
+}
+
+/// Compute the next status, without recording the transition
+fn next_status_at(rollout: &Rollout, now: DateTime<Utc>) -> RolloutStatus {
+    // If no status (or only the Initializing marker), initialize
+    let phase = rollout.status.as_ref().map(|s| s.phase.as_ref());
+    if matches!(phase, None | Some(Some(Phase::Initializing))) {
         return initialize_rollout_status(rollout);
     }
 
+    // Readiness gate cleared (reconcile only gets here once the canary RS is
+    // scaled and ready): resume progressing from the current step
+    if matches!(
+        phase,
+        Some(Some(Phase::WaitingForReadiness)) | Some(Some(Phase::Scaling))
+    ) {
+        let mut resumed = rollout.clone();
+        if let Some(status) = resumed.status.as_mut() {
+            status.phase = Some(Phase::Progressing);
+            status.message = None;
+        }
+        return next_status_at(&resumed, now);
+    }
+
+    // spec.paused cleared: start the held rollout, or resume it from its step
+    if let Some(status) = rollout
+        .status
+        .as_ref()
+        .filter(|s| is_paused_by_spec(s) && !is_spec_paused(rollout))
+    {
+        if status.current_step_index.is_none() {
+            return initialize_rollout_status(rollout);
+        }
+        let mut resumed = rollout.clone();
+        if let Some(status) = resumed.status.as_mut() {
+            status.phase = Some(Phase::Progressing);
+            status.message = None;
+        }
+        return next_status_at(&resumed, now);
+    }
+
+    // Rollback to a recorded revision (<domain>/rollback-to=<hash>)
+    if let Some(rollback_to_status) = compute_rollback_to_status(rollout, now) {
+        return rollback_to_status;
+    }
+
+    // Rollback state machine: RollingBack → Degraded once the canary is drained
+    if let Some(rollback_status) = compute_rollback_status(rollout) {
+        return rollback_status;
+    }
+
+    // Manual abort (<domain>/abort=true) rolls back an in-flight rollout
+    if has_abort_annotation(rollout)
+        && matches!(
+            rollout.status.as_ref().and_then(|s| s.phase.as_ref()),
+            Some(Phase::Progressing) | Some(Phase::Paused) | Some(Phase::WaitingForAnalysis)
+        )
+    {
+        return start_rollback(
+            rollout,
+            "aborted via annotation",
+            DecisionReason::ManualRollback,
+        );
+    }
+
+    // If rollout exceeded maxDurationSeconds, fail it
+    if let Some(timed_out_status) = check_rollout_timeout(rollout, now) {
+        return timed_out_status;
+    }
+
+    // Hold the step while its analysis warms up
+    if let Some(waiting_status) = compute_analysis_wait_status(rollout, now) {
+        return waiting_status;
+    }
+
+    // Analysis no longer blocking (warmup elapsed, or metrics reachable again):
+    // resume progressing from the current step
+    if matches!(phase, Some(Some(Phase::WaitingForAnalysis))) {
+        let mut resumed = rollout.clone();
+        if let Some(status) = resumed.status.as_mut() {
+            status.phase = Some(Phase::Progressing);
+            status.message = None;
+            // A skipped analysis did not pass; record_analysis_skipped audits it
+            if !has_skip_analysis_annotation(rollout) {
+                let step = status.current_step_index;
+                record_decision(
+                    status,
+                    DecisionAction::AnalysisComplete,
+                    DecisionReason::AnalysisPassed,
+                    step,
+                    step,
+                );
+            }
+        }
+        return next_status_at(&resumed, now);
+    }
+
+    // Completed on the canary ReplicaSet: hand the new version over to stable
+    // (reconcile only gets here once the adopted stable ReplicaSet is ready)
+    if is_stable_adoption_pending(rollout) {
+        return stable_adopted_status(rollout);
+    }
+
     // If should progress, advance to next step
     if should_progress_to_next_step(rollout) {
         return advance_to_next_step(rollout);
@@ -707,15 +2168,818 @@ pub fn compute_desired_status(rollout: &Rollout) -> crate::crd::rollout::Rollout
 
     // Otherwise, return current status (no change)
     // This should always exist since we checked is_none() above, but use unwrap_or_default for safety
-    rollout.status.as_ref().cloned().unwrap_or_default()
+    let mut status = rollout.status.as_ref().cloned().unwrap_or_default();
+    clear_stale_pause_timer(rollout, &mut status);
+    ensure_pause_timer(rollout, &mut status, now);
+    status
 }
 
-/// Advance rollout to next step
+/// Whether a canary status carries a pause timer no step is using
 ///
-/// Calculates new status with:
-/// - current_step_index incremented
-/// - current_weight from new step
-/// - phase = "Completed" if last step, else "Progressing"
+/// The timer belongs to a paused step. It is stale once the rollout stopped
+/// progressing (completed, aborted, failed) or sits on a step
+/// without a pause (e.g. after a spec edit). A stale timer would hold the
+/// promote annotation and skew the requeue interval. Blue-green keeps its
+/// Preview auto-promotion timer in the same field, so only canary is checked.
+pub fn has_stale_pause_timer(rollout: &Rollout, status: &RolloutStatus) -> bool {
+    let Some(canary) = rollout.spec.strategy.canary.as_ref() else {
+        return false;
+    };
+    if status.pause_start_time.is_none() {
+        return false;
+    }
+    if !matches!(
+        status.phase,
+        Some(Phase::Progressing) | Some(Phase::Paused) | Some(Phase::WaitingForAnalysis)
+    ) {
+        return true;
+    }
+
+    let step_has_pause = status
+        .current_step_index
+        .and_then(|index| canary.steps.get(usize::try_from(index).ok()?))
+        .is_some_and(|step| step.pause.is_some());
+    !step_has_pause
+}
+
+/// Clear a stale pause timer (see `has_stale_pause_timer`)
+pub fn clear_stale_pause_timer(rollout: &Rollout, status: &mut RolloutStatus) {
+    if has_stale_pause_timer(rollout, status) {
+        debug!(
+            rollout = ?rollout.name_any(),
+            pause_start_time = ?status.pause_start_time,
+            "Clearing stale pause start time"
+        );
+        status.pause_start_time = None;
+        status.pause_start_generation = None;
+    }
+}
+
+/// Keep the pause timer stable across spec edits
+///
+/// The timer is owned by the step: once started it is left untouched even if
+/// metadata.generation moves on (e.g. a user edits the spec mid-pause), so the
+/// wait is never re-extended. The only case handled here is a paused step with
+/// no timer at all (the pause was added by a spec edit, or the timer was lost),
+/// which starts the timer now at the current generation.
+pub fn ensure_pause_timer(
+    rollout: &Rollout,
+    status: &mut crate::crd::rollout::RolloutStatus,
+    now: DateTime<Utc>,
+) {
+    if status.pause_start_time.is_some() || status.phase != Some(Phase::Progressing) {
+        return;
+    }
+
+    let step_has_pause = rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .zip(status.current_step_index)
+        .and_then(|(canary, index)| canary.steps.get(usize::try_from(index).ok()?))
+        .map(|step| step.pause.is_some())
+        .unwrap_or(false);
+
+    if step_has_pause {
+        status.pause_start_time = Some(now.to_rfc3339());
+        status.pause_start_generation = rollout.metadata.generation;
+    }
+}
+
+/// Requeue interval while waiting for canary ReplicaSet readiness
+pub const READINESS_REQUEUE: Duration = Duration::from_secs(5);
+
+/// Get (ready, desired) replica counts for a ReplicaSet
+///
+/// Uses status.readyReplicas rather than spec.replicas, so an RS whose spec
+/// already matches (e.g., after external scaling) but whose pods are not yet
+/// ready is reported as such.
+pub fn replicaset_readiness(rs: &ReplicaSet) -> (i32, i32) {
+    let desired = rs.spec.as_ref().and_then(|s| s.replicas).unwrap_or(0);
+    let ready = rs
+        .status
+        .as_ref()
+        .and_then(|s| s.ready_replicas)
+        .unwrap_or(0);
+    (ready, desired)
+}
+
+/// Get (replicas, readyReplicas) observed in a ReplicaSet's status
+///
+/// A missing ReplicaSet (or one without status yet) counts as zero.
+fn observed_replica_counts(rs: Option<&ReplicaSet>) -> (i32, i32) {
+    let status = rs.and_then(|rs| rs.status.as_ref());
+    let replicas = status.map(|s| s.replicas).unwrap_or(0);
+    let ready = status.and_then(|s| s.ready_replicas).unwrap_or(0);
+    (replicas, ready)
+}
+
+/// Populate aggregate replica counts from the stable and canary ReplicaSets
+///
+/// Mirrors Deployment status so monitoring tools can read it:
+/// - replicas / readyReplicas: stable + canary
+/// - updatedReplicas: canary (the "new" ReplicaSet)
+/// - stableReplicas / canaryReplicas: individual counts
+pub fn apply_replicaset_counts(
+    status: &mut crate::crd::rollout::RolloutStatus,
+    stable_rs: Option<&ReplicaSet>,
+    canary_rs: Option<&ReplicaSet>,
+) {
+    let (stable_replicas, stable_ready) = observed_replica_counts(stable_rs);
+    let (canary_replicas, canary_ready) = observed_replica_counts(canary_rs);
+
+    status.replicas = stable_replicas + canary_replicas;
+    status.ready_replicas = stable_ready + canary_ready;
+    status.updated_replicas = canary_replicas;
+    status.stable_replicas = stable_replicas;
+    status.canary_replicas = canary_replicas;
+}
+
+/// Record the pod-template-hashes of the observed stable and canary ReplicaSets
+///
+/// A missing ReplicaSet keeps the previously recorded hash.
+pub fn apply_revision_hashes(
+    status: &mut RolloutStatus,
+    stable_rs: Option<&ReplicaSet>,
+    canary_rs: Option<&ReplicaSet>,
+) {
+    if let Some(hash) = stable_rs.and_then(replicaset_template_hash) {
+        status.stable_revision_hash = Some(hash.to_string());
+    }
+    if let Some(hash) = canary_rs.and_then(replicaset_template_hash) {
+        status.canary_revision_hash = Some(hash.to_string());
+    }
+}
+
+/// pod-template-hash the canary ReplicaSet should run for the current spec
+///
+/// Includes the restart trigger and canary overrides, like `build_replicaset`.
+pub fn desired_canary_revision_hash(rollout: &Rollout) -> Option<String> {
+    let mut template = build_pod_template(rollout);
+    apply_canary_overrides(rollout, &mut template);
+    compute_pod_template_hash(&template).ok()
+}
+
+/// Restart an in-flight canary whose pod template was superseded
+///
+/// When spec.template changes before the current canary finishes (rapid
+/// image updates), the newer revision must not inherit the older canary's
+/// step and traffic weight. Returns a status that restarts the rollout at
+/// step 0 for the new canaryRevisionHash; the stale canary ReplicaSet is
+/// scaled to 0 and replaced when the ReplicaSets are next applied.
+///
+/// None if the rollout is not in flight, has no recorded canary hash yet,
+/// or the template is unchanged.
+pub fn superseded_canary_status(rollout: &Rollout, now: DateTime<Utc>) -> Option<RolloutStatus> {
+    rollout.spec.strategy.canary.as_ref()?;
+    let status = rollout.status.as_ref()?;
+    if !matches!(
+        status.phase,
+        Some(Phase::Progressing)
+            | Some(Phase::Paused)
+            | Some(Phase::Scaling)
+            | Some(Phase::WaitingForReadiness)
+            | Some(Phase::WaitingForAnalysis)
+    ) {
+        return None;
+    }
+
+    let recorded_hash = status.canary_revision_hash.as_deref()?;
+    let desired_hash = desired_canary_revision_hash(rollout)?;
+    if recorded_hash == desired_hash {
+        return None;
+    }
+
+    Some(restart_at_first_step(
+        rollout,
+        status,
+        desired_hash.clone(),
+        format!(
+            "Revision {} superseded canary {}",
+            desired_hash, recorded_hash
+        ),
+        DecisionReason::RevisionSuperseded,
+        now,
+    ))
+}
+
+/// Start a new canary for a finished rollout whose pod template changed
+///
+/// A Completed (or Degraded) canary waits for the next spec change. Any change
+/// to the pod template (containers, initContainers, env, volumes, the restart
+/// trigger) gives a new canaryRevisionHash and starts a new rollout at step 0,
+/// just like an image update.
+///
+/// None if the rollout isn't finished, has no recorded canary hash yet, or the
+/// template is unchanged.
+pub fn new_revision_status(rollout: &Rollout, now: DateTime<Utc>) -> Option<RolloutStatus> {
+    rollout.spec.strategy.canary.as_ref()?;
+    let status = rollout.status.as_ref()?;
+    if !is_terminal_status(status) {
+        return None;
+    }
+
+    let recorded_hash = status.canary_revision_hash.as_deref()?;
+    let desired_hash = desired_canary_revision_hash(rollout)?;
+    if recorded_hash == desired_hash {
+        return None;
+    }
+
+    Some(restart_at_first_step(
+        rollout,
+        status,
+        desired_hash.clone(),
+        format!(
+            "Revision {} replaces canary {}",
+            desired_hash, recorded_hash
+        ),
+        DecisionReason::Initialization,
+        now,
+    ))
+}
+
+/// Restart the canary at step 0 for a new canaryRevisionHash
+///
+/// History (decisions, transitions, revisions, conditions) is kept; the step
+/// state and analysis failure count start over.
+fn restart_at_first_step(
+    rollout: &Rollout,
+    status: &RolloutStatus,
+    desired_hash: String,
+    message: String,
+    reason: DecisionReason,
+    now: DateTime<Utc>,
+) -> RolloutStatus {
+    let initial = initialize_rollout_status(rollout);
+    let mut restarted = RolloutStatus {
+        phase: initial.phase,
+        current_step_index: initial.current_step_index,
+        current_weight: initial.current_weight,
+        message: Some(format!(
+            "{}, restarting at step 0 ({}% traffic)",
+            message,
+            initial.current_weight.unwrap_or(0)
+        )),
+        pause_start_time: initial.pause_start_time,
+        pause_start_generation: initial.pause_start_generation,
+        step_start_time: initial.step_start_time,
+        started_at: initial.started_at,
+        canary_revision_hash: Some(desired_hash),
+        consecutive_analysis_failures: 0,
+        ..status.clone()
+    };
+    record_decision(
+        &mut restarted,
+        DecisionAction::Initialize,
+        reason,
+        status.current_step_index,
+        Some(0),
+    );
+    record_transition(Some(status), &mut restarted, now);
+    restarted
+}
+
+/// Compute the Scaling/WaitingForReadiness status for a progressing rollout
+///
+/// # Returns
+/// * `Some(RolloutStatus)` - Canary RS has not reached its desired replica
+///   count yet (Scaling), or its pods are not ready (WaitingForReadiness)
+/// * `None` - RS is ready, or the rollout is not progressing (nothing to hold)
+pub fn compute_readiness_status(
+    rollout: &Rollout,
+    canary_rs: &ReplicaSet,
+) -> Option<crate::crd::rollout::RolloutStatus> {
+    use crate::crd::rollout::RolloutStatus;
+
+    let status = rollout.status.as_ref()?;
+    if !matches!(
+        status.phase,
+        Some(Phase::Progressing) | Some(Phase::Scaling) | Some(Phase::WaitingForReadiness)
+    ) {
+        return None;
+    }
+
+    let (ready, desired) = replicaset_readiness(canary_rs);
+    if ready >= desired {
+        return None;
+    }
+
+    let (replicas, _) = observed_replica_counts(Some(canary_rs));
+    if replicas != desired {
+        return Some(RolloutStatus {
+            phase: Some(Phase::Scaling),
+            message: Some(format!(
+                "Scaling canary ReplicaSet ({}/{} replicas)",
+                replicas, desired
+            )),
+            ..status.clone()
+        });
+    }
+
+    Some(RolloutStatus {
+        phase: Some(Phase::WaitingForReadiness),
+        message: Some(format!(
+            "Waiting for canary ReplicaSet readiness ({}/{} ready)",
+            ready, desired
+        )),
+        ..status.clone()
+    })
+}
+
+/// Order in which a reconcile applies ReplicaSet scaling and traffic shifts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplyOrder {
+    /// Scale first, shift traffic once the canary can serve it
+    ReplicaSetsFirst,
+    /// Shift traffic away first, then scale the canary down
+    TrafficFirst,
+}
+
+/// Result of applying a reconcile's ReplicaSet and traffic changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrafficShift {
+    /// ReplicaSets and traffic routing are both at the current weight
+    Applied,
+    /// ReplicaSets scaled, traffic left as is until the canary is ready
+    HeldForReadiness,
+    /// ReplicaSets provisioned, traffic left as is while spec.paused is set
+    HeldForPause,
+}
+
+/// Canary replicas the current status weight calls for
+fn target_canary_replicas(rollout: &Rollout) -> i32 {
+    let weight = rollout
+        .status
+        .as_ref()
+        .and_then(|status| status.current_weight)
+        .unwrap_or(0);
+    calculate_canary_replicas(rollout, weight).1
+}
+
+/// Decide the apply order from the canary ReplicaSet observed before applying
+///
+/// Shrinking the canary (lower weight, rollback) moves traffic away first so
+/// requests never reach pods being removed. Anything else scales first.
+pub fn plan_apply_order(rollout: &Rollout, canary_rs: Option<&ReplicaSet>) -> ApplyOrder {
+    let current_replicas = canary_rs
+        .and_then(|rs| rs.spec.as_ref())
+        .and_then(|spec| spec.replicas)
+        .unwrap_or(0);
+
+    if rollout.spec.strategy.canary.is_some() && current_replicas > target_canary_replicas(rollout)
+    {
+        ApplyOrder::TrafficFirst
+    } else {
+        ApplyOrder::ReplicaSetsFirst
+    }
+}
+
+/// Check if traffic must wait for the canary ReplicaSet to become ready
+///
+/// Only canaries that route traffic are held, and only while the canary
+/// ReplicaSet (observed before scaling) has fewer ready replicas than the
+/// current weight needs.
+pub fn holds_traffic_for_readiness(rollout: &Rollout, canary_rs: Option<&ReplicaSet>) -> bool {
+    let routes_traffic = rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .is_some_and(|canary| canary.traffic_routing.is_some());
+    if !routes_traffic || is_analysis_only(rollout) {
+        return false;
+    }
+
+    let ready = canary_rs
+        .and_then(|rs| rs.status.as_ref())
+        .and_then(|status| status.ready_replicas)
+        .unwrap_or(0);
+    ready < target_canary_replicas(rollout)
+}
+
+/// Apply a reconcile's ReplicaSet and traffic changes in a safe order
+///
+/// See `plan_apply_order` and `holds_traffic_for_readiness`. Either step's
+/// error is returned before any status is computed, so a partially applied
+/// step (e.g. canary scaled but HTTPRoute patch failed) is never recorded as
+/// progressed; the next reconcile retries both.
+pub async fn apply_replicasets_and_traffic(
+    strategy: &dyn crate::controller::strategies::RolloutStrategy,
+    rollout: &Rollout,
+    ctx: &Context,
+    canary_rs: Option<&ReplicaSet>,
+) -> Result<TrafficShift, ReconcileError> {
+    // spec.paused: provision the ReplicaSets, never touch traffic
+    if is_spec_paused(rollout) {
+        strategy.reconcile_replicasets(rollout, ctx).await?;
+        return Ok(TrafficShift::HeldForPause);
+    }
+
+    match plan_apply_order(rollout, canary_rs) {
+        ApplyOrder::TrafficFirst => {
+            strategy.reconcile_traffic(rollout, ctx).await?;
+            strategy.reconcile_replicasets(rollout, ctx).await?;
+        }
+        ApplyOrder::ReplicaSetsFirst => {
+            strategy.reconcile_replicasets(rollout, ctx).await?;
+            if holds_traffic_for_readiness(rollout, canary_rs) {
+                return Ok(TrafficShift::HeldForReadiness);
+            }
+            strategy.reconcile_traffic(rollout, ctx).await?;
+        }
+    }
+
+    Ok(TrafficShift::Applied)
+}
+
+/// Get the warmup of an analysis config (analysis.warmupDuration)
+pub fn analysis_warmup(analysis: &AnalysisConfig) -> Option<Duration> {
+    analysis.warmup_duration.as_deref().and_then(parse_duration)
+}
+
+/// Check if metrics analysis is still warming up after the last weight change
+///
+/// The warmup counts from the current step's start (status.stepStartTime),
+/// falling back to the Rollout's creation time. If neither is known the
+/// warmup is treated as just started.
+pub fn is_analysis_warming_up(
+    rollout: &Rollout,
+    analysis: &AnalysisConfig,
+    now: DateTime<Utc>,
+) -> bool {
+    let Some(warmup) = analysis_warmup(analysis) else {
+        return false;
+    };
+
+    let step_start_time = rollout
+        .status
+        .as_ref()
+        .and_then(|s| s.step_start_time.as_ref())
+        .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+        .or_else(|| rollout.meta().creation_timestamp.as_ref().map(|t| t.0));
+
+    let Some(start_time) = step_start_time else {
+        warn!(
+            rollout = rollout.name_any(),
+            "Analysis warmup is configured but step_start_time is missing or invalid; treating warmup as just started"
+        );
+        return true;
+    };
+
+    let elapsed_secs = now.signed_duration_since(start_time).num_seconds();
+    let warmup_secs = warmup.as_secs() as i64;
+    if elapsed_secs < warmup_secs {
+        debug!(
+            rollout = rollout.name_any(),
+            warmup_remaining_secs = warmup_secs - elapsed_secs,
+            "Analysis warmup not elapsed"
+        );
+        return true;
+    }
+    false
+}
+
+/// Get the interval between metric queries of an analysis config (analysis.interval)
+pub fn analysis_interval(analysis: &AnalysisConfig) -> Option<Duration> {
+    analysis.interval.as_deref().and_then(parse_duration)
+}
+
+/// Check if the last passing analysis run can be reused instead of querying again
+///
+/// True while status.lastAnalysisTime is within analysis.interval of `now`,
+/// the recorded run passed, and it ran during the current step. Failing or
+/// unavailable runs are never reused, so failures are acted on promptly.
+pub fn has_fresh_passing_analysis(
+    rollout: &Rollout,
+    analysis: &AnalysisConfig,
+    now: DateTime<Utc>,
+) -> bool {
+    let Some(interval) = analysis_interval(analysis) else {
+        return false;
+    };
+    let Some(status) = rollout.status.as_ref() else {
+        return false;
+    };
+    if !status
+        .last_analysis_result
+        .as_ref()
+        .is_some_and(|result| result.healthy)
+    {
+        return false;
+    }
+
+    let parse = |ts: &String| {
+        DateTime::parse_from_rfc3339(ts)
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc))
+    };
+    let Some(last_run) = status.last_analysis_time.as_ref().and_then(parse) else {
+        return false;
+    };
+    if status
+        .step_start_time
+        .as_ref()
+        .and_then(parse)
+        .is_some_and(|step_start| last_run < step_start)
+    {
+        return false;
+    }
+
+    let elapsed_secs = now.signed_duration_since(last_run).num_seconds();
+    (0..interval.as_secs() as i64).contains(&elapsed_secs)
+}
+
+/// Compute the WaitingForAnalysis status while a step's analysis warms up
+///
+/// The step cannot advance until the warmup of its effective analysis
+/// (step-level, else strategy-level, see resolve_effective_analysis) has
+/// elapsed since the step started, so the analysis gets at least one
+/// evaluation on the step's traffic.
+///
+/// # Returns
+/// * `Some(RolloutStatus)` - Warmup still running: phase WaitingForAnalysis
+/// * `None` - No warmup, warmup elapsed, or not progressing
+pub fn compute_analysis_wait_status(
+    rollout: &Rollout,
+    now: DateTime<Utc>,
+) -> Option<RolloutStatus> {
+    // No analysis runs under skip-analysis, so there is nothing to warm up
+    if has_skip_analysis_annotation(rollout) {
+        return None;
+    }
+
+    let status = rollout.status.as_ref()?;
+    if !matches!(
+        status.phase,
+        Some(Phase::Progressing) | Some(Phase::WaitingForAnalysis)
+    ) {
+        return None;
+    }
+
+    let step_index = status.current_step_index?;
+    let analysis = resolve_effective_analysis(rollout)?;
+    let warmup = analysis_warmup(analysis)?;
+
+    let step_start = status
+        .step_start_time
+        .as_ref()
+        .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+        .map(|dt| dt.with_timezone(&Utc))?;
+
+    let elapsed_secs = now.signed_duration_since(step_start).num_seconds();
+    if elapsed_secs >= warmup.as_secs() as i64 {
+        return None;
+    }
+
+    let mut waiting = RolloutStatus {
+        phase: Some(Phase::WaitingForAnalysis),
+        message: Some(format!(
+            "Waiting for step {} analysis ({} warmup)",
+            step_index,
+            analysis.warmup_duration.as_deref().unwrap_or_default()
+        )),
+        ..status.clone()
+    };
+    if status.phase != Some(Phase::WaitingForAnalysis) {
+        record_decision(
+            &mut waiting,
+            DecisionAction::AnalysisStart,
+            DecisionReason::AnalysisWarmup,
+            Some(step_index),
+            Some(step_index),
+        );
+    }
+    Some(waiting)
+}
+
+/// Get the failurePolicy applied when metrics cannot be evaluated
+///
+/// Uses the effective analysis for the current step (default: Pause).
+pub fn metrics_failure_policy(rollout: &Rollout) -> FailurePolicy {
+    resolve_effective_analysis(rollout)
+        .and_then(|analysis| analysis.failure_policy.clone())
+        .unwrap_or_default()
+}
+
+/// Compute the WaitingForAnalysis status when metrics are unavailable
+///
+/// Used for failurePolicy: Pause - the step is held (not rolled back) and
+/// the error is surfaced in the status message until metrics recover.
+pub fn analysis_unavailable_status(rollout: &Rollout, error: &str) -> RolloutStatus {
+    let current = rollout.status.clone().unwrap_or_default();
+    let already_waiting = current.phase == Some(Phase::WaitingForAnalysis);
+    let step = current.current_step_index;
+
+    let mut status = RolloutStatus {
+        phase: Some(Phase::WaitingForAnalysis),
+        message: Some(format!(
+            "Analysis unavailable, holding step (failurePolicy: Pause): {}",
+            error
+        )),
+        ..current
+    };
+    if !already_waiting {
+        record_decision(
+            &mut status,
+            DecisionAction::Pause,
+            DecisionReason::MetricsUnavailable,
+            step,
+            step,
+        );
+    }
+    status
+}
+
+/// Start a rollback after metric analysis failed
+///
+/// Moves the rollout to RollingBack with canary weight 0, so the next
+/// ReplicaSet/traffic reconcile drains the canary to stable.
+///
+/// # Arguments
+/// * `rollout` - The Rollout being rolled back
+/// * `trigger` - What triggered the rollback (included in the status message)
+/// * `reason` - Reason recorded in the decision history (ManualRollback records an Abort)
+pub fn start_rollback(
+    rollout: &Rollout,
+    trigger: &str,
+    reason: DecisionReason,
+) -> crate::crd::rollout::RolloutStatus {
+    use crate::crd::rollout::RolloutStatus;
+
+    let mut status = RolloutStatus {
+        phase: Some(Phase::RollingBack),
+        current_weight: Some(0),
+        message: Some(format!("Rolling back: {}", trigger)),
+        pause_start_time: None,
+        pause_start_generation: None,
+        ..rollout.status.clone().unwrap_or_default()
+    };
+
+    let action = if reason == DecisionReason::ManualRollback {
+        DecisionAction::Abort
+    } else {
+        DecisionAction::Rollback
+    };
+    let step = status.current_step_index;
+    record_decision(&mut status, action, reason, step, None);
+    status
+}
+
+/// Compute the next status for a rollout that is rolling back (or rolled back)
+///
+/// - RollingBack with canary weight > 0: force weight to 0 (keep draining)
+/// - RollingBack with canary pods still running (status.canaryReplicas, as
+///   last observed): stay RollingBack until they terminate
+/// - RollingBack with canary weight 0 and no canary pods: drained → Degraded
+/// - Degraded: terminal, unchanged
+///
+/// # Returns
+/// * `Some(RolloutStatus)` - Rollout is in the rollback state machine
+/// * `None` - Rollout is not rolling back
+pub fn compute_rollback_status(rollout: &Rollout) -> Option<crate::crd::rollout::RolloutStatus> {
+    use crate::crd::rollout::RolloutStatus;
+
+    let status = rollout.status.as_ref()?;
+
+    match status.phase {
+        Some(Phase::RollingBack)
+            if status.current_weight.unwrap_or(0) > 0 || status.canary_replicas > 0 =>
+        {
+            Some(RolloutStatus {
+                current_weight: Some(0),
+                ..status.clone()
+            })
+        }
+        Some(Phase::RollingBack) => {
+            info!(rollout = ?rollout.name_any(), "Canary drained, rollback complete");
+            Some(RolloutStatus {
+                phase: Some(Phase::Degraded),
+                message: Some(
+                    "Rollback complete: canary drained, stable serving 100% of traffic".to_string(),
+                ),
+                ..status.clone()
+            })
+        }
+        Some(Phase::Degraded) => Some(status.clone()),
+        _ => None,
+    }
+}
+
+/// Get maxDurationSeconds from the rollout's canary or blue-green strategy
+pub fn get_max_duration_seconds(rollout: &Rollout) -> Option<u64> {
+    rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .and_then(|canary| canary.max_duration_seconds)
+        .or_else(|| {
+            rollout
+                .spec
+                .strategy
+                .blue_green
+                .as_ref()
+                .and_then(|blue_green| blue_green.max_duration_seconds)
+        })
+}
+
+/// Check whether the rollout has exceeded maxDurationSeconds
+///
+/// Prevents a rollout from blocking indefinitely (e.g., a misconfigured
+/// indefinite pause). Terminal phases (Completed, Failed, Degraded) never time out.
+///
+/// # Arguments
+/// * `rollout` - The Rollout to check
+/// * `now` - Current time
+///
+/// # Returns
+/// * `Some(RolloutStatus)` - Failed status if started_at + maxDurationSeconds is in the past
+/// * `None` - No timeout configured, not started, terminal, or still within limit
+pub fn check_rollout_timeout(
+    rollout: &Rollout,
+    now: DateTime<Utc>,
+) -> Option<crate::crd::rollout::RolloutStatus> {
+    use crate::crd::rollout::RolloutStatus;
+
+    let max_duration_seconds = get_max_duration_seconds(rollout)?;
+    let status = rollout.status.as_ref()?;
+
+    if matches!(
+        status.phase,
+        Some(Phase::Completed) | Some(Phase::Failed) | Some(Phase::Degraded)
+    ) {
+        return None;
+    }
+
+    let started_at = status
+        .started_at
+        .as_ref()
+        .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+        .map(|dt| dt.with_timezone(&Utc))?;
+
+    let elapsed_secs = now.signed_duration_since(started_at).num_seconds();
+    if elapsed_secs < 0 || (elapsed_secs as u64) < max_duration_seconds {
+        return None;
+    }
+
+    warn!(
+        rollout = ?rollout.name_any(),
+        max_duration_seconds = max_duration_seconds,
+        elapsed_secs = elapsed_secs,
+        "Rollout exceeded maxDurationSeconds"
+    );
+
+    let mut timed_out = RolloutStatus {
+        phase: Some(Phase::Failed),
+        message: Some(format!("Rollout timed out after {}s", max_duration_seconds)),
+        pause_start_time: None,
+        pause_start_generation: None,
+        ..status.clone()
+    };
+    let step = timed_out.current_step_index;
+    record_decision(
+        &mut timed_out,
+        DecisionAction::Abort,
+        DecisionReason::Timeout,
+        step,
+        None,
+    );
+    Some(timed_out)
+}
+
+/// Compute the Failed status for a rollout whose strategy is disabled
+///
+/// # Returns
+/// * `Some(status)` - Phase Failed, with a message naming the strategy
+/// * `None` - The rollout already records this failure
+pub fn strategy_disabled_status(rollout: &Rollout, strategy_name: &str) -> Option<RolloutStatus> {
+    let message = format!(
+        "Strategy {} is disabled on this controller (KULTA_ENABLED_STRATEGIES)",
+        strategy_name
+    );
+    let current = rollout.status.clone().unwrap_or_default();
+    if current.phase == Some(Phase::Failed) && current.message.as_deref() == Some(message.as_str())
+    {
+        return None;
+    }
+
+    Some(RolloutStatus {
+        phase: Some(Phase::Failed),
+        message: Some(message),
+        pause_start_time: None,
+        pause_start_generation: None,
+        ..current
+    })
+}
+
+/// Advance rollout to next step
+///
+/// Calculates new status with:
+/// - current_step_index incremented
+/// - current_weight from new step
+/// - phase = "Completed" if last step, else "Progressing"
 ///
 /// # Arguments
 /// * `rollout` - The Rollout to advance
@@ -734,75 +2998,495 @@ pub fn advance_to_next_step(rollout: &Rollout) -> crate::crd::rollout::RolloutSt
         }
     };
 
-    // Get current step index
-    let current_step_index = current_status.current_step_index.unwrap_or(-1);
-    let next_step_index = current_step_index + 1;
+    // Get current step index
+    let current_step_index = current_status.current_step_index.unwrap_or(-1);
+    let next_step_index = current_step_index + 1;
+
+    // Get canary strategy
+    let canary_strategy = match &rollout.spec.strategy.canary {
+        Some(strategy) => strategy,
+        None => {
+            // No canary strategy - return current status
+            return current_status.clone();
+        }
+    };
+
+    let reason = step_advance_reason(rollout, current_step_index);
+    let action = if reason == DecisionReason::ManualPromotion {
+        DecisionAction::Promotion
+    } else {
+        DecisionAction::StepAdvance
+    };
+    let from_step = Some(current_step_index).filter(|index| *index >= 0);
+
+    // Check if next step exists
+    if next_step_index as usize >= canary_strategy.steps.len() {
+        // Reached end of steps - mark as completed
+        let mut status = RolloutStatus {
+            current_step_index: Some(next_step_index),
+            current_weight: Some(100),
+            phase: Some(Phase::Completed),
+            message: Some("Rollout completed: 100% traffic to canary".to_string()),
+            // Promoted past the last (possibly indefinitely paused) step
+            pause_start_time: None,
+            pause_start_generation: None,
+            ..current_status.clone()
+        };
+        record_decision(
+            &mut status,
+            DecisionAction::Complete,
+            reason,
+            from_step,
+            Some(next_step_index),
+        );
+        return status;
+    }
+
+    // Get weight from next step
+    let next_step = &canary_strategy.steps[next_step_index as usize];
+    let next_weight = next_step.set_weight.unwrap_or(0);
+
+    // Check if this is the final step (100% canary)
+    let (phase, message) = if next_weight == 100 {
+        (
+            Phase::Completed,
+            "Rollout completed: 100% traffic to canary".to_string(),
+        )
+    } else {
+        (
+            Phase::Progressing,
+            format!(
+                "Advanced to step {} ({}% traffic)",
+                next_step_index, next_weight
+            ),
+        )
+    };
+
+    // Check if next step has pause - set pause start time
+    // (a completed rollout has nothing left to pause)
+    let pause_start_time = if next_step.pause.is_some() && phase == Phase::Progressing {
+        // Set pause start time to now (RFC3339)
+        Some(Utc::now().to_rfc3339())
+    } else {
+        // Clear pause start time if no pause
+        None
+    };
+
+    // A new step always starts a fresh pause timer (and records its generation)
+    let pause_start_generation = pause_start_time.as_ref().and(rollout.metadata.generation);
+
+    let completed = phase == Phase::Completed;
+    let mut status = RolloutStatus {
+        current_step_index: Some(next_step_index),
+        current_weight: Some(next_weight),
+        phase: Some(phase),
+        message: Some(message),
+        pause_start_time,
+        pause_start_generation,
+        step_start_time: Some(Utc::now().to_rfc3339()),
+        ..current_status.clone()
+    };
+    record_decision(
+        &mut status,
+        if completed {
+            DecisionAction::Complete
+        } else {
+            action
+        },
+        reason,
+        from_step,
+        Some(next_step_index),
+    );
+    status
+}
+
+/// Whether a completed canary rollout still serves from the canary ReplicaSet
+///
+/// On completion all traffic and replicas sit on the canary ReplicaSet. Until
+/// the stable ReplicaSet adopts the new template, the next rollout would start
+/// from a stable that runs the previous version.
+pub fn is_stable_adoption_pending(rollout: &Rollout) -> bool {
+    rollout.spec.strategy.canary.is_some()
+        && rollout.status.as_ref().is_some_and(|status| {
+            status.phase == Some(Phase::Completed) && status.current_weight == Some(100)
+        })
+}
+
+/// Clean completed baseline once stable has adopted the canary's template
+///
+/// No active step and weight 0: traffic and replicas go back to the stable
+/// ReplicaSet (now running the new version) and the canary scales to 0.
+pub fn stable_adopted_status(rollout: &Rollout) -> RolloutStatus {
+    let mut status = RolloutStatus {
+        phase: Some(Phase::Completed),
+        current_step_index: None,
+        current_weight: Some(0),
+        message: Some("Rollout completed: stable ReplicaSet promoted to new version".to_string()),
+        pause_start_time: None,
+        pause_start_generation: None,
+        step_start_time: None,
+        ..rollout.status.clone().unwrap_or_default()
+    };
+    if let Some(revision) = stable_revision(rollout, Utc::now()) {
+        record_revision(&mut status, revision);
+    }
+    status
+}
+
+/// Maximum number of revisions kept in status.revisionHistory
+pub const MAX_REVISION_HISTORY: usize = 10;
+
+/// Describe a pod template as a revision: its hash and container images
+pub fn revision_ref(
+    template: &PodTemplateSpec,
+    recorded_at: DateTime<Utc>,
+) -> Result<RevisionRef, ReconcileError> {
+    let images = template
+        .spec
+        .as_ref()
+        .map(|pod_spec| {
+            pod_spec
+                .containers
+                .iter()
+                .filter_map(|container| {
+                    container
+                        .image
+                        .clone()
+                        .map(|image| (container.name.clone(), image))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(RevisionRef {
+        hash: compute_pod_template_hash(template)?,
+        images,
+        recorded_at: Some(recorded_at.to_rfc3339()),
+    })
+}
+
+/// The revision the canary rollout's stable ReplicaSet runs once it adopts spec.template
+///
+/// None for non-canary strategies (rollback-to is canary only) or if the
+/// template cannot be hashed.
+pub fn stable_revision(rollout: &Rollout, now: DateTime<Utc>) -> Option<RevisionRef> {
+    rollout.spec.strategy.canary.as_ref()?;
+    revision_ref(&build_pod_template(rollout), now).ok()
+}
+
+/// Record a revision as the most recent one in status.revisionHistory
+///
+/// An existing entry with the same hash is moved to the end; the oldest
+/// entries are dropped beyond MAX_REVISION_HISTORY.
+pub fn record_revision(status: &mut RolloutStatus, revision: RevisionRef) {
+    status
+        .revision_history
+        .retain(|existing| existing.hash != revision.hash);
+    status.revision_history.push(revision);
+
+    let excess = status
+        .revision_history
+        .len()
+        .saturating_sub(MAX_REVISION_HISTORY);
+    status.revision_history.drain(..excess);
+}
+
+/// Annotation key requesting a rollback to a recorded revision: `<domain>/rollback-to`
+pub fn rollback_to_annotation_key() -> String {
+    format!("{}/rollback-to", annotation_domain())
+}
+
+/// Revision hash requested by the `<domain>/rollback-to` annotation, if set and non-empty
+pub fn get_rollback_to_annotation(rollout: &Rollout) -> Option<String> {
+    rollout
+        .metadata
+        .annotations
+        .as_ref()?
+        .get(&rollback_to_annotation_key())
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Select a revision from status.revisionHistory by hash
+pub fn find_revision<'a>(status: &'a RolloutStatus, hash: &str) -> Option<&'a RevisionRef> {
+    status
+        .revision_history
+        .iter()
+        .find(|revision| revision.hash == hash)
+}
+
+/// The revision the stable ReplicaSet was rolled back to, while it still runs it
+///
+/// Set once a rollback-to request is processed (status.rolledBackTo is the
+/// most recent revision); cleared by the next stable adoption, which records
+/// a newer revision.
+pub fn active_rollback_revision(rollout: &Rollout) -> Option<&RevisionRef> {
+    let status = rollout.status.as_ref()?;
+    let latest = status.revision_history.last()?;
+    (status.rolled_back_to.as_deref() == Some(latest.hash.as_str())).then_some(latest)
+}
+
+/// Set each container's image to the one recorded in a revision
+///
+/// Containers the revision does not know about keep their image.
+pub fn apply_revision_images(template: &mut PodTemplateSpec, revision: &RevisionRef) {
+    let Some(pod_spec) = template.spec.as_mut() else {
+        return;
+    };
+    for container in pod_spec.containers.iter_mut() {
+        if let Some(image) = revision.images.get(&container.name) {
+            container.image = Some(image.clone());
+        }
+    }
+}
+
+/// Start rolling the stable ReplicaSet back to the `<domain>/rollback-to` revision
+///
+/// Rolls back like an abort (canary drained to 0, then Degraded), records the
+/// target as the most recent revision and marks the annotation value as
+/// processed, so each requested hash is rolled back to once.
+///
+/// # Returns
+/// * `Some(status)` - A new rollback-to request for a recorded revision
+/// * `None` - No annotation, already processed, or unknown hash (rejected by validation)
+pub fn compute_rollback_to_status(rollout: &Rollout, now: DateTime<Utc>) -> Option<RolloutStatus> {
+    let hash = get_rollback_to_annotation(rollout)?;
+    let status = rollout.status.as_ref()?;
+    if status.rolled_back_to.as_deref() == Some(hash.as_str()) {
+        return None;
+    }
+    let target = find_revision(status, &hash)?;
+
+    info!(
+        rollout = ?rollout.name_any(),
+        revision = %target.hash,
+        "Rolling back to recorded revision"
+    );
+    let mut rolled_back = start_rollback(
+        rollout,
+        &format!("rollback to revision {} requested", target.hash),
+        DecisionReason::ManualRollback,
+    );
+    record_revision(
+        &mut rolled_back,
+        RevisionRef {
+            recorded_at: Some(now.to_rfc3339()),
+            ..target.clone()
+        },
+    );
+    rolled_back.rolled_back_to = Some(hash);
+    Some(rolled_back)
+}
+
+/// Why the rollout is leaving a step (for the decision history)
+///
+/// Promote annotation wins, then a timed pause, then step/rollout analysis
+/// (recorded as skipped, not passed, under the skip-analysis annotation).
+fn step_advance_reason(rollout: &Rollout, step_index: i32) -> DecisionReason {
+    if has_promote_annotation(rollout) {
+        return DecisionReason::ManualPromotion;
+    }
+
+    let step = usize::try_from(step_index).ok().and_then(|index| {
+        rollout
+            .spec
+            .strategy
+            .canary
+            .as_ref()
+            .and_then(|canary| canary.steps.get(index))
+    });
+    if step.and_then(|step| step.pause.as_ref()).is_some() {
+        DecisionReason::PauseDurationExpired
+    } else if resolve_effective_analysis(rollout).is_some() {
+        if has_skip_analysis_annotation(rollout) {
+            DecisionReason::SkipAnalysisAnnotation
+        } else {
+            DecisionReason::AnalysisPassed
+        }
+    } else {
+        DecisionReason::StepReady
+    }
+}
+
+/// Build the pod template for a Rollout's ReplicaSets
+///
+/// Clones the Rollout's template and, when the `kulta.io/restart` annotation is
+/// set, injects its value as the `kulta.io/restartedAt` pod annotation. Changing
+/// the restart value therefore changes the pod-template-hash and triggers a fresh
+/// rollout; an unchanged value produces the same hash (no-op).
+pub fn build_pod_template(rollout: &Rollout) -> PodTemplateSpec {
+    let mut template = rollout.spec.template.clone();
+
+    if let Some(restart_value) = get_restart_annotation(rollout) {
+        let mut metadata = template.metadata.take().unwrap_or_default();
+        metadata
+            .annotations
+            .get_or_insert_with(Default::default)
+            .insert(
+                RESTARTED_AT_ANNOTATION.to_string(),
+                restart_value.to_string(),
+            );
+        template.metadata = Some(metadata);
+    }
+
+    template
+}
+
+/// Apply canaryOverrides to a canary pod template
+///
+/// Overridden resources replace each container's requests/limits, so the
+/// canary pod-template-hash differs from stable's whenever overrides are set.
+pub fn apply_canary_overrides(rollout: &Rollout, template: &mut PodTemplateSpec) {
+    let Some(overrides) = rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .and_then(|c| c.canary_overrides.as_ref())
+    else {
+        return;
+    };
+
+    if let (Some(resources), Some(pod_spec)) = (&overrides.resources, template.spec.as_mut()) {
+        for container in pod_spec.containers.iter_mut() {
+            container.resources = Some(resources.clone());
+        }
+    }
+}
+
+/// Resolve the ReplicaSet name suffix for a canary rs_type ("stable" or "canary")
+///
+/// Uses `canarySuffix`/`stableSuffix` from the canary strategy when set,
+/// otherwise the rs_type itself (so names stay "{rollout}-canary"/"{rollout}-stable").
+pub fn replicaset_name_suffix<'a>(rollout: &'a Rollout, rs_type: &'a str) -> &'a str {
+    let canary = rollout.spec.strategy.canary.as_ref();
+    let configured = match rs_type {
+        "canary" => canary.and_then(|c| c.canary_suffix.as_deref()),
+        "stable" => canary.and_then(|c| c.stable_suffix.as_deref()),
+        _ => None,
+    };
+    configured.unwrap_or(rs_type)
+}
+
+/// Checks for Rollout fields that cannot change once ReplicaSets exist
+///
+/// `spec.selector` is immutable: ReplicaSet selectors cannot be updated, so
+/// a new selector would orphan every managed ReplicaSet. There is no admission
+/// webhook yet, so reconcile enforces this against the live stable ReplicaSet;
+/// `check` is the old/new form an admission handler would use.
+pub struct ImmutabilityChecker;
+
+impl ImmutabilityChecker {
+    /// Compare an updated Rollout with its previous version
+    ///
+    /// # Returns
+    /// Err if `old_rollout` is Some and its spec.selector differs
+    pub fn check(old_rollout: Option<&Rollout>, new_rollout: &Rollout) -> Result<(), String> {
+        match old_rollout {
+            Some(old) if old.spec.selector != new_rollout.spec.selector => Err(
+                "spec.selector is immutable; delete and recreate the Rollout to change it"
+                    .to_string(),
+            ),
+            _ => Ok(()),
+        }
+    }
 
-    // Get canary strategy
-    let canary_strategy = match &rollout.spec.strategy.canary {
-        Some(strategy) => strategy,
-        None => {
-            // No canary strategy - return current status
-            return current_status.clone();
+    /// Check a Rollout's selector against a ReplicaSet it already manages
+    ///
+    /// The ReplicaSet's selector carries KULTA's labels on top of the pod
+    /// labels, so it only has to contain every spec.selector matchLabel.
+    ///
+    /// # Returns
+    /// Err if spec.selector would no longer select the ReplicaSet's pods
+    pub fn check_replicaset(rollout: &Rollout, rs: &ReplicaSet) -> Result<(), String> {
+        let rs_labels = rs
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.selector.match_labels.as_ref());
+
+        let changed = rollout
+            .spec
+            .selector
+            .match_labels
+            .iter()
+            .flatten()
+            .any(|(key, value)| rs_labels.and_then(|labels| labels.get(key)) != Some(value));
+
+        if changed {
+            return Err(format!(
+                "spec.selector is immutable: it no longer matches ReplicaSet {}; \
+                 delete and recreate the Rollout to change it",
+                rs.metadata.name.as_deref().unwrap_or_default()
+            ));
         }
-    };
+        Ok(())
+    }
+}
 
-    // Check if next step exists
-    if next_step_index as usize >= canary_strategy.steps.len() {
-        // Reached end of steps - mark as completed
-        return RolloutStatus {
-            current_step_index: Some(next_step_index),
-            current_weight: Some(100),
-            phase: Some(Phase::Completed),
-            message: Some("Rollout completed: 100% traffic to canary".to_string()),
-            ..current_status.clone()
-        };
+/// Validate a canary/stable ReplicaSet name suffix
+///
+/// Suffixes must be non-empty and must not contain `/` or `.`.
+pub fn validate_replicaset_suffix(field: &str, suffix: &str) -> Result<(), String> {
+    if suffix.is_empty() {
+        return Err(format!("spec.strategy.canary.{} cannot be empty", field));
+    }
+    if suffix.contains('/') || suffix.contains('.') {
+        return Err(format!(
+            "spec.strategy.canary.{} must not contain '/' or '.', got {:?}",
+            field, suffix
+        ));
     }
+    Ok(())
+}
 
-    // Get weight from next step
-    let next_step = &canary_strategy.steps[next_step_index as usize];
-    let next_weight = next_step.set_weight.unwrap_or(0);
+/// Annotation holding the pod template hash when the label is not injected
+pub const POD_TEMPLATE_HASH_ANNOTATION: &str = "rollouts.kulta.io/pod-template-hash";
 
-    // Check if this is the final step (100% canary)
-    let (phase, message) = if next_weight == 100 {
-        (
-            Phase::Completed,
-            "Rollout completed: 100% traffic to canary".to_string(),
-        )
-    } else {
-        (
-            Phase::Progressing,
-            format!(
-                "Advanced to step {} ({}% traffic)",
-                next_step_index, next_weight
-            ),
-        )
-    };
+/// Whether KULTA injects the `pod-template-hash` label (spec.managePodTemplateHash)
+///
+/// Defaults to true. GitOps tools that manage the label themselves can turn
+/// it off; only the rollouts.kulta.io labels are injected then.
+pub fn manages_pod_template_hash(rollout: &Rollout) -> bool {
+    rollout.spec.manage_pod_template_hash.unwrap_or(true)
+}
 
-    // Check if next step has pause - set pause start time
-    let pause_start_time = if next_step.pause.is_some() {
-        // Set pause start time to now (RFC3339)
-        Some(Utc::now().to_rfc3339())
-    } else {
-        // Clear pause start time if no pause
-        None
-    };
+/// Add KULTA's labels to a ReplicaSet's pod labels
+///
+/// Without the `pod-template-hash` label, the hash is returned as the
+/// `rollouts.kulta.io/pod-template-hash` annotation so the revision stays
+/// visible on the ReplicaSet.
+///
+/// # Returns
+/// Annotations for the ReplicaSet metadata (None when the label is injected)
+fn insert_replicaset_labels(
+    rollout: &Rollout,
+    labels: &mut BTreeMap<String, String>,
+    rs_type: &str,
+    pod_template_hash: &str,
+) -> Option<BTreeMap<String, String>> {
+    labels.insert("rollouts.kulta.io/type".to_string(), rs_type.to_string());
+    labels.insert("rollouts.kulta.io/managed".to_string(), "true".to_string());
 
-    RolloutStatus {
-        current_step_index: Some(next_step_index),
-        current_weight: Some(next_weight),
-        phase: Some(phase),
-        message: Some(message),
-        pause_start_time,
-        ..current_status.clone()
+    if manages_pod_template_hash(rollout) {
+        labels.insert(
+            "pod-template-hash".to_string(),
+            pod_template_hash.to_string(),
+        );
+        None
+    } else {
+        Some(BTreeMap::from([(
+            POD_TEMPLATE_HASH_ANNOTATION.to_string(),
+            pod_template_hash.to_string(),
+        )]))
     }
 }
 
 /// Build a ReplicaSet for a Rollout
 ///
 /// Creates a ReplicaSet with:
-/// - Name: {rollout-name}-{type} (e.g., "my-app-stable", "my-app-canary")
-/// - Labels: pod-template-hash, rollouts.kulta.io/type, rollouts.kulta.io/managed
+/// - Name: {rollout-name}-{name_suffix} (e.g., "my-app-stable", "my-app-canary")
+/// - Labels: pod-template-hash (unless managePodTemplateHash is false),
+///   rollouts.kulta.io/type, rollouts.kulta.io/managed
 /// - Spec: from Rollout's template
 ///
 /// The `rollouts.kulta.io/managed=true` label prevents Kubernetes Deployment
@@ -813,6 +3497,7 @@ pub fn advance_to_next_step(rollout: &Rollout) -> crate::crd::rollout::RolloutSt
 pub fn build_replicaset(
     rollout: &Rollout,
     rs_type: &str,
+    name_suffix: &str,
     replicas: i32,
 ) -> Result<ReplicaSet, ReconcileError> {
     let rollout_name = rollout
@@ -822,20 +3507,23 @@ pub fn build_replicaset(
         .ok_or(ReconcileError::MissingName)?;
     let namespace = rollout.metadata.namespace.clone();
 
-    // Compute pod template hash
-    let pod_template_hash = compute_pod_template_hash(&rollout.spec.template)?;
+    // Compute pod template hash (includes restart trigger and canary overrides, if any)
+    let mut template = build_pod_template(rollout);
+    if rs_type == "canary" {
+        apply_canary_overrides(rollout, &mut template);
+    } else if let Some(revision) = active_rollback_revision(rollout) {
+        apply_revision_images(&mut template, revision);
+    }
+    let pod_template_hash = compute_pod_template_hash(&template)?;
 
-    // Clone the pod template and add labels
-    let mut template = rollout.spec.template.clone();
+    // Add labels to the pod template
     let mut labels = template
         .metadata
         .as_ref()
         .and_then(|m| m.labels.clone())
         .unwrap_or_default();
 
-    labels.insert("pod-template-hash".to_string(), pod_template_hash.clone());
-    labels.insert("rollouts.kulta.io/type".to_string(), rs_type.to_string());
-    labels.insert("rollouts.kulta.io/managed".to_string(), "true".to_string());
+    let annotations = insert_replicaset_labels(rollout, &mut labels, rs_type, &pod_template_hash);
 
     // Update template metadata
     let mut template_metadata = template.metadata.unwrap_or_default();
@@ -851,9 +3539,11 @@ pub fn build_replicaset(
     // Build ReplicaSet
     Ok(ReplicaSet {
         metadata: ObjectMeta {
-            name: Some(format!("{}-{}", rollout_name, rs_type)),
+            name: Some(format!("{}-{}", rollout_name, name_suffix)),
             namespace,
             labels: Some(labels),
+            annotations,
+            owner_references: rollout_owner_references(rollout),
             ..Default::default()
         },
         spec: Some(ReplicaSetSpec {
@@ -866,11 +3556,39 @@ pub fn build_replicaset(
     })
 }
 
+/// Controller owner reference pointing at the Rollout
+///
+/// Lets the controller watch owned ReplicaSets (`Controller::owns`) and lets
+/// Kubernetes garbage-collect them with the Rollout. None when the Rollout has
+/// no uid yet (e.g. built in tests).
+pub fn rollout_owner_references(rollout: &Rollout) -> Option<Vec<OwnerReference>> {
+    rollout.controller_owner_ref(&()).map(|owner| vec![owner])
+}
+
+/// Map a ReplicaSet to the Rollout that owns it
+///
+/// Mirrors the mapping `Controller::owns` applies to ReplicaSet events: the
+/// controller owner reference of kind Rollout in the kulta.io group, in the
+/// ReplicaSet's namespace.
+pub fn owning_rollout_ref(rs: &ReplicaSet) -> Option<ObjectRef<Rollout>> {
+    let namespace = rs.namespace()?;
+    rs.owner_references()
+        .iter()
+        .find(|owner| {
+            owner.controller == Some(true)
+                && owner.kind == Rollout::kind(&())
+                && owner.api_version == Rollout::api_version(&())
+        })
+        .map(|owner| ObjectRef::new(&owner.name).within(&namespace))
+}
+
 /// Build a ReplicaSet for a simple strategy Rollout
 ///
 /// Creates a single ReplicaSet (no stable/canary split) with:
-/// - Name: {rollout-name} (no suffix)
-/// - Labels: pod-template-hash, rollouts.kulta.io/type, rollouts.kulta.io/managed
+/// - Name: {rollout-name}-simple (see `adopt_legacy_simple_replicaset` for
+///   ReplicaSets created before the suffix)
+/// - Labels: pod-template-hash (unless managePodTemplateHash is false),
+///   rollouts.kulta.io/type, rollouts.kulta.io/managed
 /// - Spec: from Rollout's template
 ///
 /// The `rollouts.kulta.io/managed=true` label prevents Kubernetes Deployment
@@ -889,20 +3607,18 @@ pub fn build_replicaset_for_simple(
         .ok_or(ReconcileError::MissingName)?;
     let namespace = rollout.metadata.namespace.clone();
 
-    // Compute pod template hash
-    let pod_template_hash = compute_pod_template_hash(&rollout.spec.template)?;
+    // Compute pod template hash (includes restart trigger, if any)
+    let mut template = build_pod_template(rollout);
+    let pod_template_hash = compute_pod_template_hash(&template)?;
 
-    // Clone the pod template and add labels
-    let mut template = rollout.spec.template.clone();
+    // Add labels to the pod template
     let mut labels = template
         .metadata
         .as_ref()
         .and_then(|m| m.labels.clone())
         .unwrap_or_default();
 
-    labels.insert("pod-template-hash".to_string(), pod_template_hash.clone());
-    labels.insert("rollouts.kulta.io/type".to_string(), "simple".to_string());
-    labels.insert("rollouts.kulta.io/managed".to_string(), "true".to_string());
+    let annotations = insert_replicaset_labels(rollout, &mut labels, "simple", &pod_template_hash);
 
     // Update template metadata in place
     let mut template_metadata = template.metadata.take().unwrap_or_default();
@@ -915,12 +3631,18 @@ pub fn build_replicaset_for_simple(
         ..Default::default()
     };
 
-    // Build ReplicaSet - no suffix for simple strategy
+    // Build ReplicaSet - suffixed like the canary/blue-green ReplicaSets
     Ok(ReplicaSet {
         metadata: ObjectMeta {
-            name: Some(rollout_name.clone()),
+            name: Some(format!(
+                "{}-{}",
+                rollout_name,
+                replicaset_name_suffix(rollout, "simple")
+            )),
             namespace,
             labels: Some(labels),
+            annotations,
+            owner_references: rollout_owner_references(rollout),
             ..Default::default()
         },
         spec: Some(ReplicaSetSpec {
@@ -933,6 +3655,61 @@ pub fn build_replicaset_for_simple(
     })
 }
 
+/// Label set on simple ReplicaSets by releases that named them {rollout-name}
+pub const LEGACY_MANAGED_BY_LABEL: &str = "kulta.io/managed-by";
+
+/// Whether `existing` is a simple ReplicaSet created before the "-simple" suffix
+///
+/// Those were named after the rollout and labeled either
+/// `rollouts.kulta.io/type=simple` or `kulta.io/managed-by=kulta`. A
+/// ReplicaSet controlled by anything but this Rollout is never claimed.
+pub fn is_legacy_simple_replicaset(rollout: &Rollout, existing: &ReplicaSet) -> bool {
+    let rollout_name = rollout.name_any();
+    if existing.metadata.name.as_deref() != Some(rollout_name.as_str()) {
+        return false;
+    }
+
+    let labels = existing.labels();
+    let simple_labeled = labels.get("rollouts.kulta.io/type").map(String::as_str) == Some("simple")
+        || labels.get(LEGACY_MANAGED_BY_LABEL).map(String::as_str) == Some("kulta");
+
+    let controlled_by_other = existing.owner_references().iter().any(|owner| {
+        owner.controller == Some(true)
+            && (owner.kind != Rollout::kind(&()) || owner.name != rollout_name)
+    });
+
+    simple_labeled && !controlled_by_other
+}
+
+/// The simple ReplicaSet to reconcile: `desired`, or a legacy one to adopt
+///
+/// A legacy ReplicaSet (see `is_legacy_simple_replicaset`) keeps being
+/// managed under its old name. Creating `{rollout-name}-simple` next to it
+/// would start a second ReplicaSet selecting the same pods.
+pub fn adopt_legacy_simple_replicaset(
+    rollout: &Rollout,
+    desired: ReplicaSet,
+    legacy: Option<&ReplicaSet>,
+) -> ReplicaSet {
+    match legacy.filter(|existing| is_legacy_simple_replicaset(rollout, existing)) {
+        Some(existing) => {
+            info!(
+                rollout = ?rollout.name_any(),
+                replicaset = ?existing.metadata.name,
+                "Adopting simple ReplicaSet created without the -simple suffix"
+            );
+            ReplicaSet {
+                metadata: ObjectMeta {
+                    name: existing.metadata.name.clone(),
+                    ..desired.metadata
+                },
+                ..desired
+            }
+        }
+        None => desired,
+    }
+}
+
 /// Build ReplicaSets for a blue-green strategy Rollout
 ///
 /// Creates two full-size ReplicaSets:
@@ -968,20 +3745,18 @@ fn build_replicaset_for_blue_green_type(
         .ok_or(ReconcileError::MissingName)?;
     let namespace = rollout.metadata.namespace.clone();
 
-    // Compute pod template hash
-    let pod_template_hash = compute_pod_template_hash(&rollout.spec.template)?;
+    // Compute pod template hash (includes restart trigger, if any)
+    let mut template = build_pod_template(rollout);
+    let pod_template_hash = compute_pod_template_hash(&template)?;
 
-    // Clone the pod template and add labels
-    let mut template = rollout.spec.template.clone();
+    // Add labels to the pod template
     let mut labels = template
         .metadata
         .as_ref()
         .and_then(|m| m.labels.clone())
         .unwrap_or_default();
 
-    labels.insert("pod-template-hash".to_string(), pod_template_hash.clone());
-    labels.insert("rollouts.kulta.io/type".to_string(), rs_type.to_string());
-    labels.insert("rollouts.kulta.io/managed".to_string(), "true".to_string());
+    let annotations = insert_replicaset_labels(rollout, &mut labels, rs_type, &pod_template_hash);
 
     // Update template metadata in place
     let mut template_metadata = template.metadata.take().unwrap_or_default();
@@ -1000,6 +3775,8 @@ fn build_replicaset_for_blue_green_type(
             name: Some(format!("{}-{}", rollout_name, rs_type)),
             namespace,
             labels: Some(labels),
+            annotations,
+            owner_references: rollout_owner_references(rollout),
             ..Default::default()
         },
         spec: Some(ReplicaSetSpec {
@@ -1012,10 +3789,410 @@ fn build_replicaset_for_blue_green_type(
     })
 }
 
+/// Validate an analysis config's requirement against its metrics
+///
+/// # Arguments
+/// * `path` - Field path of the analysis config (for error messages)
+/// * `analysis` - The analysis config to validate
+pub fn validate_analysis_requirement(path: &str, analysis: &AnalysisConfig) -> Result<(), String> {
+    let requirement = MetricRequirement::from_config(analysis.requirement.as_ref())
+        .map_err(|e| format!("{}.{}", path, e))?;
+
+    if let MetricRequirement::AtLeast(count) = requirement {
+        if count > analysis.metrics.len() {
+            return Err(format!(
+                "{}.requirement {} exceeds the number of metrics ({})",
+                path,
+                count,
+                analysis.metrics.len()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate an analysis config's interval (a parse_duration value, e.g. "30s")
+pub fn validate_analysis_interval(path: &str, analysis: &AnalysisConfig) -> Result<(), String> {
+    match analysis.interval.as_deref() {
+        Some(interval) if parse_duration(interval).is_none() => {
+            Err(format!("{}.interval invalid: {}", path, interval))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Validate an analysis config's consecutiveFailureThreshold (>= 1 when set)
+pub fn validate_consecutive_failure_threshold(
+    path: &str,
+    analysis: &AnalysisConfig,
+) -> Result<(), String> {
+    match analysis.consecutive_failure_threshold {
+        Some(threshold) if threshold < 1 => Err(format!(
+            "{}.consecutiveFailureThreshold must be >= 1, got {}",
+            path, threshold
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Validate `count` metrics: whole-number thresholds and a valid window (interval)
+pub fn validate_count_metrics(path: &str, analysis: &AnalysisConfig) -> Result<(), String> {
+    for (i, metric) in analysis.metrics.iter().enumerate() {
+        if metric.name != COUNT_METRIC {
+            continue;
+        }
+        if metric.threshold < 0.0 || metric.threshold.fract() != 0.0 {
+            return Err(format!(
+                "{}.metrics[{}].threshold must be a whole number >= 0 for the count metric, got {}",
+                path, i, metric.threshold
+            ));
+        }
+        if let Some(window) = &metric.interval {
+            if parse_duration(window).is_none() {
+                return Err(format!(
+                    "{}.metrics[{}].interval invalid: {}",
+                    path, i, window
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Validate an analysis config's tolerance (finite, >= 0, canary-vs-stable only)
+pub fn validate_analysis_tolerance(path: &str, analysis: &AnalysisConfig) -> Result<(), String> {
+    let Some(tolerance) = analysis.tolerance else {
+        return Ok(());
+    };
+    if analysis.mode != Some(AnalysisMode::CanaryVsStable) {
+        return Err(format!(
+            "{}.tolerance only applies to mode canary-vs-stable",
+            path
+        ));
+    }
+    if !tolerance.is_finite() || tolerance < 0.0 {
+        return Err(format!(
+            "{}.tolerance must be a finite number >= 0, got {}",
+            path, tolerance
+        ));
+    }
+    Ok(())
+}
+
+/// Validate an analysis config (requirement, interval, failure threshold, count metrics,
+/// metric labels, tolerance)
+pub fn validate_analysis_config(path: &str, analysis: &AnalysisConfig) -> Result<(), String> {
+    validate_analysis_requirement(path, analysis)?;
+    validate_analysis_interval(path, analysis)?;
+    validate_consecutive_failure_threshold(path, analysis)?;
+    validate_count_metrics(path, analysis)?;
+    validate_analysis_metric_labels(path, analysis)?;
+    validate_analysis_tolerance(path, analysis)
+}
+
+/// Validate each metric's extra query labels (see `validate_metric_labels`)
+pub fn validate_analysis_metric_labels(
+    path: &str,
+    analysis: &AnalysisConfig,
+) -> Result<(), String> {
+    for (i, metric) in analysis.metrics.iter().enumerate() {
+        validate_metric_labels(&metric.labels)
+            .map_err(|e| format!("{}.metrics[{}].labels: {}", path, i, e))?;
+    }
+    Ok(())
+}
+
+/// Validate an analysis config's metric thresholds (finite and >= 0)
+pub fn validate_analysis_thresholds(path: &str, analysis: &AnalysisConfig) -> Result<(), String> {
+    for (i, metric) in analysis.metrics.iter().enumerate() {
+        if !metric.threshold.is_finite() || metric.threshold < 0.0 {
+            return Err(format!(
+                "{}.metrics[{}].threshold must be a finite number >= 0, got {}",
+                path, i, metric.threshold
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Longest blue-green autoPromotionSeconds accepted (one week)
+pub const MAX_AUTO_PROMOTION_SECONDS: i32 = 7 * 24 * 60 * 60;
+
+/// Validate a blue-green strategy
+///
+/// Equal activeService and previewService is a declarative promotion
+/// (see `is_declarative_promotion`), so it is only accepted once the rollout
+/// has something to promote (Preview) or has been promoted (Completed).
+pub fn validate_blue_green(
+    rollout: &Rollout,
+    blue_green: &BlueGreenStrategy,
+) -> Result<(), String> {
+    if blue_green.active_service.is_empty() {
+        return Err("spec.strategy.blueGreen.activeService cannot be empty".to_string());
+    }
+
+    if blue_green.preview_service.is_empty() {
+        return Err("spec.strategy.blueGreen.previewService cannot be empty".to_string());
+    }
+
+    let phase = rollout.status.as_ref().and_then(|s| s.phase.as_ref());
+    let promotable = matches!(phase, Some(Phase::Preview) | Some(Phase::Completed));
+    if blue_green.active_service == blue_green.preview_service && !promotable {
+        return Err(format!(
+            "spec.strategy.blueGreen.previewService must differ from activeService, got {} for both",
+            blue_green.active_service
+        ));
+    }
+
+    if let Some(seconds) = blue_green.auto_promotion_seconds {
+        if !(1..=MAX_AUTO_PROMOTION_SECONDS).contains(&seconds) {
+            return Err(format!(
+                "spec.strategy.blueGreen.autoPromotionSeconds must be 1-{}, got {}",
+                MAX_AUTO_PROMOTION_SECONDS, seconds
+            ));
+        }
+    }
+
+    if let Some(analysis) = &blue_green.analysis {
+        let path = "spec.strategy.blueGreen.analysis";
+        validate_analysis_config(path, analysis)?;
+        validate_analysis_thresholds(path, analysis)?;
+    }
+
+    let has_canary_match = blue_green
+        .traffic_routing
+        .as_ref()
+        .and_then(|routing| routing.gateway_api.as_ref())
+        .is_some_and(|gateway| gateway.canary_match.is_some());
+    if has_canary_match {
+        return Err(
+            "spec.strategy.blueGreen.trafficRouting.gatewayAPI.canaryMatch is only supported by the canary strategy"
+                .to_string(),
+        );
+    }
+
+    Ok(())
+}
+
+/// HTTP methods accepted in canaryMatch (the Gateway API HTTPMethod enum)
+const HTTP_ROUTE_METHODS: [&str; 9] = [
+    "GET", "HEAD", "POST", "PUT", "DELETE", "CONNECT", "OPTIONS", "TRACE", "PATCH",
+];
+
+/// Validate canaryMatch: each match needs a "/"-prefixed pathPrefix and/or known methods
+pub fn validate_canary_match(matches: &[HttpRouteMatch]) -> Result<(), String> {
+    let path = "spec.strategy.canary.trafficRouting.gatewayAPI.canaryMatch";
+    if matches.is_empty() {
+        return Err(format!("{} must not be empty", path));
+    }
+
+    for (i, route_match) in matches.iter().enumerate() {
+        let methods = route_match.methods.as_deref().unwrap_or_default();
+        if route_match.path_prefix.is_none() && methods.is_empty() {
+            return Err(format!(
+                "{}[{}] must set pathPrefix and/or methods",
+                path, i
+            ));
+        }
+        if let Some(prefix) = &route_match.path_prefix {
+            if !prefix.starts_with('/') {
+                return Err(format!(
+                    "{}[{}].pathPrefix must start with '/', got {:?}",
+                    path, i, prefix
+                ));
+            }
+        }
+        if let Some(method) = methods
+            .iter()
+            .find(|method| !HTTP_ROUTE_METHODS.contains(&method.as_str()))
+        {
+            return Err(format!(
+                "{}[{}].methods has unsupported method {:?} (expected one of {})",
+                path,
+                i,
+                method,
+                HTTP_ROUTE_METHODS.join(", ")
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate the backend weights assigned at a canary step
+///
+/// All weighted backends (stable + canary + any extras) must sum to exactly
+/// 100; without extras, that is the stable+canary pair. Any other sum would
+/// drop or misroute traffic.
+///
+/// # Arguments
+/// * `step_index` - Index of the step (for error messages)
+/// * `stable_weight` - Weight assigned to the stable backend
+/// * `canary_weight` - Weight assigned to the canary backend
+/// * `extra_weights` - Weights assigned to any additional backends
+pub fn validate_backend_weights(
+    step_index: usize,
+    stable_weight: i32,
+    canary_weight: i32,
+    extra_weights: &[i32],
+) -> Result<(), String> {
+    let total: i32 = stable_weight + canary_weight + extra_weights.iter().sum::<i32>();
+    if total > 100 {
+        return Err(format!(
+            "steps[{}]: backend weights sum to {}, must not exceed 100",
+            step_index, total
+        ));
+    }
+
+    if extra_weights.is_empty() && stable_weight + canary_weight != 100 {
+        return Err(format!(
+            "steps[{}]: stable ({}) + canary ({}) weights must sum to 100",
+            step_index, stable_weight, canary_weight
+        ));
+    }
+
+    if total != 100 {
+        return Err(format!(
+            "steps[{}]: backend weights sum to {}, must be 100",
+            step_index, total
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validate that no consecutive canary steps raise the weight by more than max_step
+///
+/// Steps without setWeight are skipped here (they are rejected separately).
+pub fn validate_max_weight_step(steps: &[CanaryStep], max_step: i32) -> Result<(), String> {
+    if !(1..=100).contains(&max_step) {
+        return Err(format!(
+            "spec.strategy.canary.maxWeightStep must be 1-100, got {}",
+            max_step
+        ));
+    }
+
+    for (i, pair) in steps.windows(2).enumerate() {
+        if let (Some(from), Some(to)) = (pair[0].set_weight, pair[1].set_weight) {
+            if to - from > max_step {
+                return Err(format!(
+                    "steps[{}] -> steps[{}]: weight jumps from {} to {} ({}), exceeds maxWeightStep {}",
+                    i,
+                    i + 1,
+                    from,
+                    to,
+                    to - from,
+                    max_step
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate that the first canary step sends at least min_weight to the canary
+pub fn validate_min_initial_weight(steps: &[CanaryStep], min_weight: i32) -> Result<(), String> {
+    if !(1..=100).contains(&min_weight) {
+        return Err(format!(
+            "spec.strategy.canary.minInitialWeight must be 1-100, got {}",
+            min_weight
+        ));
+    }
+
+    if let Some(first) = steps.first().and_then(|step| step.set_weight) {
+        if first < min_weight {
+            return Err(format!(
+                "steps[0]: initial weight {} is below minInitialWeight {}",
+                first, min_weight
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate stickyCookie: a valid cookie name, a Max-Age >= 0, and Gateway API routing
+pub fn validate_sticky_cookie(
+    cookie: &StickyCookie,
+    canary: &CanaryStrategy,
+) -> Result<(), String> {
+    // RFC 6265 cookie-name: a token (no separators, spaces or control characters)
+    let valid_name = !cookie.name.is_empty()
+        && cookie
+            .name
+            .chars()
+            .all(|c| c.is_ascii_graphic() && !"()<>@,;:\\\"/[]?={}".contains(c));
+    if !valid_name {
+        return Err(format!(
+            "spec.strategy.canary.stickyCookie.name is not a valid cookie name: {:?}",
+            cookie.name
+        ));
+    }
+    if let Some(max_age) = cookie.max_age_seconds {
+        if max_age < 0 {
+            return Err(format!(
+                "spec.strategy.canary.stickyCookie.maxAgeSeconds must be >= 0, got {}",
+                max_age
+            ));
+        }
+    }
+    let has_gateway_api = canary
+        .traffic_routing
+        .as_ref()
+        .is_some_and(|routing| routing.gateway_api.is_some());
+    if !has_gateway_api {
+        return Err(
+            "spec.strategy.canary.stickyCookie requires trafficRouting.gatewayAPI".to_string(),
+        );
+    }
+    Ok(())
+}
+
+/// Validate replicaSurge and its maxSurge bound
+///
+/// maxSurge only applies to replicaSurge, and must leave room for at least
+/// one canary pod: a surge that can't add pods would never scale the canary.
+/// replicaSurge replaces keepStableAtFullScale, so the two are exclusive.
+pub fn validate_replica_surge(canary: &CanaryStrategy, replicas: i32) -> Result<(), String> {
+    let surge = canary.replica_surge.unwrap_or(false);
+    if surge && canary.keep_stable_at_full_scale == Some(true) {
+        return Err(
+            "spec.strategy.canary: set either replicaSurge or keepStableAtFullScale, not both"
+                .to_string(),
+        );
+    }
+
+    let Some(max_surge) = &canary.max_surge else {
+        return Ok(());
+    };
+    if !surge {
+        return Err("spec.strategy.canary.maxSurge requires replicaSurge: true".to_string());
+    }
+    let resolved = resolve_int_or_percent(max_surge, replicas, true)
+        .map_err(|e| format!("spec.strategy.canary.maxSurge {}", e))?;
+    if resolved == 0 && replicas > 0 {
+        return Err(
+            "spec.strategy.canary.maxSurge resolves to 0: replicaSurge could never scale up the canary"
+                .to_string(),
+        );
+    }
+    Ok(())
+}
+
+/// Validation error for a spec.strategy with no strategy configured
+///
+/// An empty strategy is rejected rather than defaulted: select_strategy would
+/// fall back to canary, which then fails on its missing services and steps.
+pub const EMPTY_STRATEGY_ERROR: &str =
+    "spec.strategy: a strategy must be configured (simple, canary or blueGreen)";
+
 /// Validate Rollout specification
 ///
 /// Validates runtime constraints that cannot be enforced via CRD schema.
-/// This is necessary because our current CRD uses x-kubernetes-preserve-unknown-fields.
+/// The CRD schema (config/crds/) is explicit about field types, but cannot express
+/// cross-field rules or value ranges.
 ///
 /// # Arguments
 /// * `rollout` - The Rollout resource to validate
@@ -1023,7 +4200,10 @@ fn build_replicaset_for_blue_green_type(
 /// # Returns
 /// * `Ok(())` - Validation passed
 /// * `Err(String)` - Validation error message
-fn validate_rollout(rollout: &Rollout) -> Result<(), String> {
+pub fn validate_rollout(rollout: &Rollout) -> Result<(), String> {
+    validate_requeue_annotations(rollout)?;
+    validate_rollback_to_annotation(rollout)?;
+
     // Validate replicas >= 0
     if rollout.spec.replicas < 0 {
         return Err(format!(
@@ -1032,6 +4212,26 @@ fn validate_rollout(rollout: &Rollout) -> Result<(), String> {
         ));
     }
 
+    // Validate exactly one strategy is configured (otherwise one is silently ignored)
+    let strategy = &rollout.spec.strategy;
+    let configured_strategies = [
+        strategy.simple.is_some(),
+        strategy.canary.is_some(),
+        strategy.blue_green.is_some(),
+    ]
+    .iter()
+    .filter(|&&configured| configured)
+    .count();
+    if configured_strategies == 0 {
+        return Err(EMPTY_STRATEGY_ERROR.to_string());
+    }
+    if configured_strategies > 1 {
+        return Err(format!(
+            "spec.strategy: only one strategy may be configured (simple, canary, blueGreen), got {}",
+            configured_strategies
+        ));
+    }
+
     // Validate canary strategy if present
     if let Some(canary) = &rollout.spec.strategy.canary {
         // Validate canary service name is not empty
@@ -1044,11 +4244,85 @@ fn validate_rollout(rollout: &Rollout) -> Result<(), String> {
             return Err("spec.strategy.canary.stableService cannot be empty".to_string());
         }
 
+        // Validate mirror service name is not empty (if set)
+        if canary.mirror_service.as_deref() == Some("") {
+            return Err("spec.strategy.canary.mirrorService cannot be empty".to_string());
+        }
+
+        if let Some(cookie) = &canary.sticky_cookie {
+            validate_sticky_cookie(cookie, canary)?;
+        }
+
+        // Validate ReplicaSet name suffixes (they become part of the RS name)
+        for (field, suffix) in [
+            ("canarySuffix", &canary.canary_suffix),
+            ("stableSuffix", &canary.stable_suffix),
+        ] {
+            if let Some(suffix) = suffix {
+                validate_replicaset_suffix(field, suffix)?;
+            }
+        }
+
+        // Validate replica floors
+        for (field, floor) in [
+            ("minCanaryReplicas", canary.min_canary_replicas),
+            ("minStableReplicas", canary.min_stable_replicas),
+        ] {
+            if let Some(floor) = floor {
+                if floor < 0 {
+                    return Err(format!(
+                        "spec.strategy.canary.{} must be >= 0, got {}",
+                        field, floor
+                    ));
+                }
+            }
+        }
+
         // Validate at least one step exists
         if canary.steps.is_empty() {
             return Err("spec.strategy.canary.steps must have at least one step".to_string());
         }
 
+        // Validate pauseAtStep refers to an existing step
+        if let Some(pause_at_step) = canary.pause_at_step {
+            if pause_at_step < 0 || pause_at_step as usize >= canary.steps.len() {
+                return Err(format!(
+                    "spec.strategy.canary.pauseAtStep must be 0-{}, got {}",
+                    canary.steps.len() - 1,
+                    pause_at_step
+                ));
+            }
+        }
+
+        // Validate extra canary tracks
+        let extra_canaries = canary.extra_canaries.as_deref().unwrap_or_default();
+        for (i, extra) in extra_canaries.iter().enumerate() {
+            let field = format!("spec.strategy.canary.extraCanaries[{}]", i);
+            if extra.service.is_empty() {
+                return Err(format!("{}.service cannot be empty", field));
+            }
+            if extra.service == canary.stable_service || extra.service == canary.canary_service {
+                return Err(format!(
+                    "{}.service must differ from stableService and canaryService, got {}",
+                    field, extra.service
+                ));
+            }
+            if !(0..=100).contains(&extra.weight) {
+                return Err(format!(
+                    "{}.weight must be 0-100, got {}",
+                    field, extra.weight
+                ));
+            }
+            if extra.step_index < 0 || extra.step_index as usize >= canary.steps.len() {
+                return Err(format!(
+                    "{}.stepIndex must be 0-{}, got {}",
+                    field,
+                    canary.steps.len() - 1,
+                    extra.step_index
+                ));
+            }
+        }
+
         // Validate each step
         for (i, step) in canary.steps.iter().enumerate() {
             // Validate setWeight is required and in 0-100 range
@@ -1060,12 +4334,27 @@ fn validate_rollout(rollout: &Rollout) -> Result<(), String> {
                             i, weight
                         ));
                     }
+
+                    // Validate backend weights at this step don't drop traffic
+                    // (active extra tracks take their weight from stable)
+                    let extra_weights = extra_canaries
+                        .iter()
+                        .filter(|extra| i as i32 >= extra.step_index)
+                        .map(|extra| extra.weight)
+                        .collect::<Vec<_>>();
+                    let stable_weight = (100 - weight - extra_weights.iter().sum::<i32>()).max(0);
+                    validate_backend_weights(i, stable_weight, weight, &extra_weights)?;
                 }
                 None => {
                     return Err(format!("steps[{}].setWeight is required", i));
                 }
             }
 
+            // Validate step-level analysis if present
+            if let Some(analysis) = &step.analysis {
+                validate_analysis_config(&format!("steps[{}].analysis", i), analysis)?;
+            }
+
             // Validate pause duration if present
             if let Some(pause) = &step.pause {
                 if let Some(duration) = &pause.duration {
@@ -1076,6 +4365,41 @@ fn validate_rollout(rollout: &Rollout) -> Result<(), String> {
             }
         }
 
+        // Validate no consecutive steps jump by more than maxWeightStep
+        if let Some(max_step) = canary.max_weight_step {
+            validate_max_weight_step(&canary.steps, max_step)?;
+        }
+
+        // Validate the first step isn't a low-traffic start below minInitialWeight
+        if let Some(min_weight) = canary.min_initial_weight {
+            validate_min_initial_weight(&canary.steps, min_weight)?;
+        }
+
+        validate_replica_surge(canary, rollout.spec.replicas)?;
+
+        // Validate strategy-level analysis if present
+        if let Some(analysis) = &canary.analysis {
+            validate_analysis_config("spec.strategy.canary.analysis", analysis)?;
+        }
+
+        // Analysis-only mode relies on metrics alone, so analysis is mandatory
+        // and there is no traffic to route
+        if canary.analysis_only == Some(true) {
+            let has_step_analysis = canary.steps.iter().any(|step| step.analysis.is_some());
+            if canary.analysis.is_none() && !has_step_analysis {
+                return Err(
+                    "spec.strategy.canary.analysisOnly requires analysis to be configured"
+                        .to_string(),
+                );
+            }
+            if canary.traffic_routing.is_some() {
+                return Err(
+                    "spec.strategy.canary.analysisOnly cannot be combined with trafficRouting"
+                        .to_string(),
+                );
+            }
+        }
+
         // Validate traffic routing if present
         if let Some(traffic_routing) = &canary.traffic_routing {
             if let Some(gateway) = &traffic_routing.gateway_api {
@@ -1086,13 +4410,79 @@ fn validate_rollout(rollout: &Rollout) -> Result<(), String> {
                             .to_string(),
                     );
                 }
+
+                if let Some(matches) = &gateway.canary_match {
+                    validate_canary_match(matches)?;
+                }
+            }
+
+            if let Some(linkerd) = &traffic_routing.linkerd {
+                // Validate TrafficSplit name is not empty
+                if linkerd.traffic_split_name.is_empty() {
+                    return Err(
+                        "spec.strategy.canary.trafficRouting.linkerd.trafficSplitName cannot be empty"
+                            .to_string(),
+                    );
+                }
             }
         }
     }
 
+    // Validate blue-green strategy if present
+    if let Some(blue_green) = &rollout.spec.strategy.blue_green {
+        validate_blue_green(rollout, blue_green)?;
+    }
+
     Ok(())
 }
 
+/// Metric label for a status's phase ("None" before the first status write)
+fn phase_metric_label(status: Option<&RolloutStatus>) -> String {
+    status
+        .and_then(|s| s.phase.as_ref())
+        .map(|phase| format!("{:?}", phase))
+        .unwrap_or_else(|| "None".to_string())
+}
+
+/// Record a phase transition metric if the phase changed
+///
+/// Called after the new status has been persisted, so the counter only
+/// reflects transitions that actually landed on the API server.
+pub fn observe_phase_transition(
+    metrics: &crate::server::ControllerMetrics,
+    previous: Option<&RolloutStatus>,
+    desired: &RolloutStatus,
+) {
+    let from = phase_metric_label(previous);
+    let to = phase_metric_label(Some(desired));
+    if from != to {
+        metrics.record_phase_transition(&from, &to);
+    }
+}
+
+/// Record the canary weight applied to the routes and the current step
+///
+/// The weight comes from `calculate_traffic_weights`, i.e. what this
+/// reconcile wrote to the HTTPRoute. Only canary rollouts are recorded.
+pub fn observe_rollout_progress(metrics: &crate::server::ControllerMetrics, rollout: &Rollout) {
+    if rollout.spec.strategy.canary.is_none() {
+        return;
+    }
+
+    let (_, canary_weight) = calculate_traffic_weights(rollout);
+    let step = rollout
+        .status
+        .as_ref()
+        .and_then(|status| status.current_step_index)
+        .unwrap_or(-1);
+    metrics.set_rollout_progress(
+        &rollout.namespace().unwrap_or_default(),
+        &rollout.name_any(),
+        i64::from(canary_weight),
+        i64::from(step),
+    );
+}
+
 /// Reconcile a Rollout resource
 ///
 /// This function implements the main reconciliation logic:
@@ -1107,6 +4497,9 @@ fn validate_rollout(rollout: &Rollout) -> Result<(), String> {
 /// * `Ok(Action)` - Next reconciliation action (requeue after 5 minutes)
 /// * `Err(ReconcileError)` - Reconciliation error
 pub async fn reconcile(rollout: Arc<Rollout>, ctx: Arc<Context>) -> Result<Action, ReconcileError> {
+    // Prove the reconcile loop is alive (/livez), leader or not
+    ctx.heartbeat.beat();
+
     // Check if we should reconcile (leader election)
     if !ctx.should_reconcile() {
         // Not the leader - skip reconciliation, requeue later to check again
@@ -1145,38 +4538,480 @@ pub async fn reconcile(rollout: Arc<Rollout>, ctx: Arc<Context>) -> Result<Actio
         return Err(ReconcileError::ValidationError(validation_error));
     }
 
-    // Select strategy handler based on rollout spec
-    let strategy = crate::controller::strategies::select_strategy(&rollout);
+    // Dry run: log and record what this reconcile would apply, write nothing else
+    if is_dry_run(&rollout) {
+        let desired = select_strategy(&rollout).compute_next_status(&rollout);
+        let plan = compute_dry_run_plan(&rollout, &desired)?;
+        for operation in &plan.operations {
+            info!(rollout = ?name, "DRY RUN: would apply {}", operation);
+        }
+
+        let planned_status = dry_run_status(&rollout, plan);
+        if rollout.status.as_ref() != Some(&planned_status) {
+            let rollout_api: Api<Rollout> = Api::namespaced(ctx.client.clone(), &namespace);
+            match until_cancelled(
+                &ctx.cancellation,
+                patch_rollout_status(&rollout_api, &ctx.field_manager, &rollout, &planned_status),
+            )
+            .await?
+            {
+                Ok(_) => {}
+                Err(e) if is_conflict_error(&e) => {
+                    debug!(rollout = ?name, "Status conflict (stale resourceVersion), requeueing");
+                    return Ok(Action::requeue(Duration::ZERO));
+                }
+                Err(e) => return Err(ReconcileError::KubeError(e)),
+            }
+        }
+        return Ok(Action::requeue(DRY_RUN_REQUEUE));
+    }
+
+    // spec.selector can't change once the stable ReplicaSet exists
+    if rollout.spec.strategy.canary.is_some() && rollout.status.is_some() {
+        let rs_api: Api<ReplicaSet> = Api::namespaced(ctx.client.clone(), &namespace);
+        let stable_rs_name = format!("{}-{}", name, replicaset_name_suffix(&rollout, "stable"));
+        let stable_rs =
+            until_cancelled(&ctx.cancellation, rs_api.get_opt(&stable_rs_name)).await??;
+        if let Some(stable_rs) = stable_rs {
+            if let Err(immutability_error) =
+                ImmutabilityChecker::check_replicaset(&rollout, &stable_rs)
+            {
+                error!(
+                    rollout = ?name,
+                    error = ?immutability_error,
+                    "Rollout spec.selector changed"
+                );
+                return Err(ReconcileError::ValidationError(immutability_error));
+            }
+        }
+    }
+
+    // Select strategy handler based on rollout spec; disabled strategies fail the rollout
+    let strategy = match select_enabled_strategy(&rollout, &ctx.enabled_strategies) {
+        Ok(strategy) => strategy,
+        Err(StrategyError::StrategyDisabled(strategy_name)) => {
+            warn!(rollout = ?name, strategy = %strategy_name, "Rollout strategy is disabled");
+            if let Some(failed) = strategy_disabled_status(&rollout, &strategy_name) {
+                let rollout_api: Api<Rollout> = Api::namespaced(ctx.client.clone(), &namespace);
+                match until_cancelled(
+                    &ctx.cancellation,
+                    patch_rollout_status(&rollout_api, &ctx.field_manager, &rollout, &failed),
+                )
+                .await?
+                {
+                    Ok(_) => {}
+                    Err(e) if is_conflict_error(&e) => {
+                        debug!(rollout = ?name, "Status conflict (stale resourceVersion), requeueing");
+                        return Ok(Action::requeue(Duration::ZERO));
+                    }
+                    Err(e) => return Err(ReconcileError::KubeError(e)),
+                }
+            }
+            // Enabling the strategy requires a controller restart
+            return Ok(Action::await_change());
+        }
+        Err(e) => return Err(e.into()),
+    };
     info!(rollout = ?name, strategy = strategy.name(), "Selected deployment strategy");
 
-    // Reconcile ReplicaSets using strategy-specific logic
-    strategy.reconcile_replicasets(&rollout, &ctx).await?;
+    // First reconcile: record Initializing before creating any ReplicaSet
+    if rollout.status.is_none() {
+        let initializing = initializing_status(&rollout);
+        info!(rollout = ?name, "Initializing Rollout");
+
+        let rollout_api: Api<Rollout> = Api::namespaced(ctx.client.clone(), &namespace);
+        match until_cancelled(
+            &ctx.cancellation,
+            patch_rollout_status(&rollout_api, &ctx.field_manager, &rollout, &initializing),
+        )
+        .await?
+        {
+            Ok(_) => {}
+            Err(e) if is_conflict_error(&e) => {
+                debug!(rollout = ?name, "Status conflict (stale resourceVersion), requeueing");
+                return Ok(Action::requeue(Duration::ZERO));
+            }
+            Err(e) => return Err(ReconcileError::KubeError(e)),
+        }
 
-    // Reconcile traffic routing using strategy-specific logic
-    strategy.reconcile_traffic(&rollout, &ctx).await?;
+        if let Some(ref metrics) = ctx.metrics {
+            observe_phase_transition(metrics, None, &initializing);
+        }
 
-    // Evaluate metrics and trigger rollback if unhealthy (only for strategies that support it)
-    if strategy.supports_metrics_analysis() {
+        return Ok(Action::requeue(Duration::ZERO));
+    }
+
+    // spec.paused: provision the ReplicaSets at the held weight, take no step
+    if let Some(paused_status) = spec_paused_status(&rollout, Utc::now()) {
+        let mut held = (*rollout).clone();
+        held.status = Some(paused_status.clone());
+        until_cancelled(
+            &ctx.cancellation,
+            apply_replicasets_and_traffic(&*strategy, &held, &ctx, None),
+        )
+        .await??;
+
+        if rollout.status.as_ref() != Some(&paused_status) {
+            info!(rollout = ?name, "Rollout paused by spec.paused");
+            let rollout_api: Api<Rollout> = Api::namespaced(ctx.client.clone(), &namespace);
+            match until_cancelled(
+                &ctx.cancellation,
+                patch_rollout_status(&rollout_api, &ctx.field_manager, &rollout, &paused_status),
+            )
+            .await?
+            {
+                Ok(_) => {}
+                Err(e) if is_conflict_error(&e) => {
+                    debug!(rollout = ?name, "Status conflict (stale resourceVersion), requeueing");
+                    return Ok(Action::requeue(Duration::ZERO));
+                }
+                Err(e) => return Err(ReconcileError::KubeError(e)),
+            }
+        }
+
+        return Ok(Action::requeue(calculate_requeue_interval_from_rollout(
+            &rollout,
+            &paused_status,
+            ctx.terminal_requeue,
+        )));
+    }
+
+    // Skip no-op reconciles: unchanged since we last reconciled it and nothing due
+    if is_reconcile_cache_hit(
+        &ctx.reconcile_cache,
+        &rollout,
+        &strategy.compute_next_status(&rollout),
+    ) {
+        debug!(rollout = ?name, "Rollout unchanged since last reconcile, skipping");
         if let Some(current_status) = &rollout.status {
-            if current_status.phase == Some(Phase::Progressing) {
-                let is_healthy = evaluate_rollout_metrics(&rollout, &ctx).await?;
+            return Ok(Action::requeue(calculate_requeue_interval_from_rollout(
+                &rollout,
+                current_status,
+                ctx.terminal_requeue,
+            )));
+        }
+    }
 
-                if !is_healthy {
-                    warn!(rollout = ?name, "Metrics unhealthy, triggering rollback");
+    // Create the stable/canary Services if requested (canary.createServices)
+    if creates_services(&rollout) {
+        let store = KubeServiceLookup::new(ctx.client.clone());
+        until_cancelled(&ctx.cancellation, ensure_canary_services(&store, &rollout)).await??;
+    }
 
-                    let failed_status = RolloutStatus {
-                        phase: Some(Phase::Failed),
-                        message: Some(
-                            "Rollback triggered: metrics exceeded thresholds".to_string(),
-                        ),
-                        ..current_status.clone()
-                    };
+    // A newer pod template superseded the in-flight canary (or changed after the
+    // rollout finished): restart at step 0 before the new canary ReplicaSet can
+    // inherit the old traffic weight
+    let now = Utc::now();
+    if let Some(restarted) =
+        superseded_canary_status(&rollout, now).or_else(|| new_revision_status(&rollout, now))
+    {
+        warn!(
+            rollout = ?name,
+            canary_revision = ?restarted.canary_revision_hash,
+            "Pod template changed, restarting canary at step 0"
+        );
+
+        let rollout_api: Api<Rollout> = Api::namespaced(ctx.client.clone(), &namespace);
+        match until_cancelled(
+            &ctx.cancellation,
+            patch_rollout_status(&rollout_api, &ctx.field_manager, &rollout, &restarted),
+        )
+        .await?
+        {
+            Ok(_) => {}
+            Err(e) if is_conflict_error(&e) => {
+                debug!(rollout = ?name, "Status conflict (stale resourceVersion), requeueing");
+                return Ok(Action::requeue(Duration::ZERO));
+            }
+            Err(e) => return Err(ReconcileError::KubeError(e)),
+        }
+
+        if let Some(ref metrics) = ctx.metrics {
+            observe_phase_transition(metrics, rollout.status.as_ref(), &restarted);
+        }
+
+        return Ok(Action::requeue(Duration::ZERO));
+    }
+
+    // Observe the canary ReplicaSet before applying, to order the changes
+    let canary_rs_name = format!("{}-{}", name, replicaset_name_suffix(&rollout, "canary"));
+    let observed_canary_rs = if rollout.spec.strategy.canary.is_some() {
+        let rs_api: Api<ReplicaSet> = Api::namespaced(ctx.client.clone(), &namespace);
+        rs_api.get_opt(&canary_rs_name).await?
+    } else {
+        None
+    };
+
+    // Reconcile ReplicaSets and traffic routing (strategy-specific) in a safe order
+    let traffic_shift = match until_cancelled(
+        &ctx.cancellation,
+        apply_replicasets_and_traffic(&*strategy, &rollout, &ctx, observed_canary_rs.as_ref()),
+    )
+    .await?
+    {
+        Ok(shift) => shift,
+        Err(e) => {
+            warn!(error = ?e, rollout = ?name, "Failed to apply ReplicaSets/traffic, status not advanced");
+            return Err(e);
+        }
+    };
+
+    // Read back the canary strategy's ReplicaSets for readiness and replica counts
+    let (stable_rs, canary_rs) = if rollout.spec.strategy.canary.is_some() {
+        let rs_api: Api<ReplicaSet> = Api::namespaced(ctx.client.clone(), &namespace);
+        let stable_rs_name = format!("{}-{}", name, replicaset_name_suffix(&rollout, "stable"));
+        (
+            rs_api.get_opt(&stable_rs_name).await?,
+            rs_api.get_opt(&canary_rs_name).await?,
+        )
+    } else {
+        (None, None)
+    };
+
+    // Traffic stays at its previous weight until the canary can serve the new one
+    if traffic_shift == TrafficShift::HeldForReadiness {
+        info!(rollout = ?name, "Canary ReplicaSet not ready, holding traffic shift");
+
+        if let Some(mut waiting_status) = canary_rs
+            .as_ref()
+            .and_then(|rs| compute_readiness_status(&rollout, rs))
+        {
+            apply_replicaset_counts(&mut waiting_status, stable_rs.as_ref(), canary_rs.as_ref());
+
+            if rollout.status.as_ref() != Some(&waiting_status) {
+                let rollout_api: Api<Rollout> = Api::namespaced(ctx.client.clone(), &namespace);
+                match until_cancelled(
+                    &ctx.cancellation,
+                    patch_rollout_status(
+                        &rollout_api,
+                        &ctx.field_manager,
+                        &rollout,
+                        &waiting_status,
+                    ),
+                )
+                .await?
+                {
+                    Ok(_) => {}
+                    Err(e) if is_conflict_error(&e) => {
+                        debug!(rollout = ?name, "Status conflict (stale resourceVersion), requeueing");
+                        return Ok(Action::requeue(Duration::ZERO));
+                    }
+                    Err(e) => return Err(ReconcileError::KubeError(e)),
+                }
+            }
+        }
+
+        return Ok(Action::requeue(READINESS_REQUEUE));
+    }
+
+    // Keep serving from the canary until the adopted stable ReplicaSet is ready
+    if is_stable_adoption_pending(&rollout) {
+        let stable_ready = stable_rs.as_ref().is_some_and(|rs| {
+            let (ready, desired) = replicaset_readiness(rs);
+            ready >= desired
+        });
+        if !stable_ready {
+            info!(rollout = ?name, "Waiting for stable ReplicaSet to adopt the new version");
+            return Ok(Action::requeue(READINESS_REQUEUE));
+        }
+    }
+
+    // Hold step advancement until the canary ReplicaSet's pods are ready
+    if ctx.waits_for_rs_ready() && rollout.spec.strategy.canary.is_some() {
+        if let Some(mut waiting_status) = canary_rs
+            .as_ref()
+            .and_then(|rs| compute_readiness_status(&rollout, rs))
+        {
+            apply_replicaset_counts(&mut waiting_status, stable_rs.as_ref(), canary_rs.as_ref());
+
+            if rollout.status.as_ref() != Some(&waiting_status) {
+                info!(
+                    rollout = ?name,
+                    message = ?waiting_status.message,
+                    "Canary ReplicaSet not ready, holding step advancement"
+                );
+
+                let rollout_api: Api<Rollout> = Api::namespaced(ctx.client.clone(), &namespace);
+                match until_cancelled(
+                    &ctx.cancellation,
+                    patch_rollout_status(
+                        &rollout_api,
+                        &ctx.field_manager,
+                        &rollout,
+                        &waiting_status,
+                    ),
+                )
+                .await?
+                {
+                    Ok(_) => {}
+                    Err(e) if is_conflict_error(&e) => {
+                        debug!(rollout = ?name, "Status conflict (stale resourceVersion), requeueing");
+                        return Ok(Action::requeue(Duration::ZERO));
+                    }
+                    Err(e) => return Err(ReconcileError::KubeError(e)),
+                }
+            }
+
+            return Ok(Action::requeue(READINESS_REQUEUE));
+        }
+    }
+
+    // Evaluate metrics and trigger rollback if unhealthy (only for strategies that support it)
+    let mut analysis_skipped = false;
+    let mut analysis_passed = false;
+    let mut analysis_record: Option<(bool, Vec<MetricResult>)> = None;
+    let analysis_time = Utc::now();
+    if strategy.supports_metrics_analysis() && has_skip_analysis_annotation(&rollout) {
+        // Emergency promotion: no metrics are queried, the step advances on pause/timing alone
+        if rollout.status.as_ref().is_some_and(|status| {
+            matches!(
+                status.phase,
+                Some(Phase::Progressing) | Some(Phase::WaitingForAnalysis)
+            )
+        }) {
+            warn!(rollout = ?name, "Metrics analysis skipped by skip-analysis annotation");
+            analysis_skipped = true;
+        }
+    } else if strategy.supports_metrics_analysis() {
+        if let Some(current_status) = &rollout.status {
+            if matches!(
+                current_status.phase,
+                Some(Phase::Progressing) | Some(Phase::WaitingForAnalysis)
+            ) {
+                let analysis =
+                    until_cancelled(&ctx.cancellation, evaluate_rollout_metrics(&rollout, &ctx))
+                        .await?;
+                analysis_record = analysis
+                    .metric_results
+                    .map(|results| (matches!(analysis.verdict, Ok(true)), results));
+
+                let rollback_trigger = match analysis.verdict {
+                    Ok(true) => {
+                        analysis_passed = true;
+                        None
+                    }
+                    Ok(false) => {
+                        let failures = analysis_failures_after(&rollout, false);
+                        if let Some(mut held_status) =
+                            analysis_failure_hold_status(&rollout, failures)
+                        {
+                            if let Some((healthy, results)) = &analysis_record {
+                                record_analysis_run(
+                                    &rollout,
+                                    &mut held_status,
+                                    *healthy,
+                                    results,
+                                    analysis_time,
+                                );
+                            }
+                            // Below consecutiveFailureThreshold: hold the step, re-analyze later
+                            warn!(
+                                rollout = ?name,
+                                failures = failures,
+                                "Analysis failed, below consecutive failure threshold"
+                            );
+                            let rollout_api: Api<Rollout> =
+                                Api::namespaced(ctx.client.clone(), &namespace);
+                            match until_cancelled(
+                                &ctx.cancellation,
+                                patch_rollout_status(
+                                    &rollout_api,
+                                    &ctx.field_manager,
+                                    &rollout,
+                                    &held_status,
+                                ),
+                            )
+                            .await?
+                            {
+                                Ok(_) => {}
+                                Err(e) if is_conflict_error(&e) => {
+                                    debug!(rollout = ?name, "Status conflict (stale resourceVersion), requeueing");
+                                    return Ok(Action::requeue(Duration::ZERO));
+                                }
+                                Err(e) => return Err(ReconcileError::KubeError(e)),
+                            }
+                            return Ok(Action::requeue(calculate_requeue_interval_from_rollout(
+                                &rollout,
+                                &held_status,
+                                ctx.terminal_requeue,
+                            )));
+                        }
+                        Some((
+                            "metric analysis exceeded thresholds".to_string(),
+                            DecisionReason::AnalysisFailed,
+                        ))
+                    }
+                    Err(ReconcileError::MetricsEvaluationFailed(error)) => {
+                        match metrics_failure_policy(&rollout) {
+                            FailurePolicy::Continue => {
+                                warn!(rollout = ?name, error = %error, "Metrics unavailable, continuing (failurePolicy: Continue)");
+                                None
+                            }
+                            FailurePolicy::Rollback => Some((
+                                format!("metrics unavailable: {}", error),
+                                DecisionReason::MetricsUnavailable,
+                            )),
+                            FailurePolicy::Pause => {
+                                // Hold the step and surface the outage in status
+                                let mut waiting_status =
+                                    analysis_unavailable_status(&rollout, &error);
+                                if let Some((healthy, results)) = &analysis_record {
+                                    record_analysis_run(
+                                        &rollout,
+                                        &mut waiting_status,
+                                        *healthy,
+                                        results,
+                                        analysis_time,
+                                    );
+                                }
+                                if rollout.status.as_ref() != Some(&waiting_status) {
+                                    let rollout_api: Api<Rollout> =
+                                        Api::namespaced(ctx.client.clone(), &namespace);
+                                    match until_cancelled(
+                                        &ctx.cancellation,
+                                        patch_rollout_status(
+                                            &rollout_api,
+                                            &ctx.field_manager,
+                                            &rollout,
+                                            &waiting_status,
+                                        ),
+                                    )
+                                    .await?
+                                    {
+                                        Ok(_) => {}
+                                        Err(e) if is_conflict_error(&e) => {
+                                            debug!(rollout = ?name, "Status conflict (stale resourceVersion), requeueing");
+                                            return Ok(Action::requeue(Duration::ZERO));
+                                        }
+                                        Err(e) => return Err(ReconcileError::KubeError(e)),
+                                    }
+                                }
+                                return Err(ReconcileError::MetricsEvaluationFailed(error));
+                            }
+                        }
+                    }
+                    Err(e) => return Err(e),
+                };
+
+                if let Some((trigger, reason)) = rollback_trigger {
+                    warn!(rollout = ?name, trigger = %trigger, "Analysis failed, triggering rollback");
+
+                    let mut rolling_back_status = start_rollback(&rollout, &trigger, reason);
+                    if let Some((healthy, results)) = &analysis_record {
+                        record_analysis_run(
+                            &rollout,
+                            &mut rolling_back_status,
+                            *healthy,
+                            results,
+                            analysis_time,
+                        );
+                    }
 
                     // Emit rollback CDEvent (non-fatal)
                     if let Err(e) = emit_status_change_event(
                         &rollout,
                         &rollout.status,
-                        &failed_status,
+                        &rolling_back_status,
                         &ctx.cdevents_sink,
                     )
                     .await
@@ -1184,21 +5019,29 @@ pub async fn reconcile(rollout: Arc<Rollout>, ctx: Arc<Context>) -> Result<Actio
                         warn!(error = ?e, rollout = ?name, "Failed to emit rollback CDEvent (non-fatal)");
                     }
 
-                    // Patch status to Failed
-                    use kube::api::{Api, Patch, PatchParams};
+                    // Patch status to RollingBack (canary is drained on the next reconcile)
                     let rollout_api: Api<Rollout> = Api::namespaced(ctx.client.clone(), &namespace);
-                    rollout_api
-                        .patch_status(
-                            &name,
-                            &PatchParams::default(),
-                            &Patch::Merge(&serde_json::json!({
-                                "status": failed_status
-                            })),
-                        )
-                        .await?;
+                    match until_cancelled(
+                        &ctx.cancellation,
+                        patch_rollout_status(
+                            &rollout_api,
+                            &ctx.field_manager,
+                            &rollout,
+                            &rolling_back_status,
+                        ),
+                    )
+                    .await?
+                    {
+                        Ok(_) => {}
+                        Err(e) if is_conflict_error(&e) => {
+                            debug!(rollout = ?name, "Status conflict (stale resourceVersion), requeueing");
+                            return Ok(Action::requeue(Duration::ZERO));
+                        }
+                        Err(e) => return Err(ReconcileError::KubeError(e)),
+                    }
 
-                    info!(rollout = ?name, "Rollout marked as Failed due to unhealthy metrics");
-                    return Ok(Action::requeue(Duration::from_secs(30)));
+                    info!(rollout = ?name, "Rollout rolling back due to failed analysis");
+                    return Ok(Action::requeue(Duration::from_secs(5)));
                 }
             }
         }
@@ -1210,15 +5053,104 @@ pub async fn reconcile(rollout: Arc<Rollout>, ctx: Arc<Context>) -> Result<Actio
         .status
         .as_ref()
         .map(|s| s.phase == Some(Phase::Paused))
-        .unwrap_or(false);
+        .unwrap_or(false)
+        || is_held_at_pause_step(&rollout);
 
     // Compute desired status using strategy-specific logic
-    let desired_status = strategy.compute_next_status(&rollout);
+    let mut desired_status = strategy.compute_next_status(&rollout);
+
+    // Conditions are maintained here, not by the strategies: carry them forward
+    if let Some(status) = rollout.status.as_ref() {
+        desired_status.conditions = status.conditions.clone();
+    }
+
+    // Out of dry-run mode: the plan is being applied
+    desired_status.dry_run_plan = None;
+
+    // Hold the step if its canary scale-up would exceed a ResourceQuota (quotaAware)
+    if is_quota_aware(&rollout) && rollout.spec.strategy.canary.is_some() {
+        let lookup = KubeQuotaLookup::new(ctx.client.clone());
+        desired_status = hold_step_for_quota(&lookup, &rollout, desired_status).await;
+    }
+
+    // Hold the step until a Gateway has accepted the HTTPRoute (verifyAccepted)
+    if let Some(routing) = route_verification(&rollout) {
+        match resolve_httproute_version(&ctx, routing).await {
+            Ok(version) => {
+                let lookup =
+                    KubeRouteLookup::new(ctx.client.clone(), httproute_api_resource(version));
+                desired_status =
+                    hold_step_for_route(&lookup, &rollout, routing, desired_status).await;
+            }
+            Err(e) => {
+                warn!(error = ?e, rollout = ?name, "Cannot resolve HTTPRoute version, skipping route check");
+            }
+        }
+    }
+
+    // Record the processed restart trigger (pod template already carries it)
+    let restart_value = get_restart_annotation(&rollout).map(String::from);
+    let previous_restart = rollout.status.as_ref().and_then(|s| s.restarted_at.clone());
+    if restart_value.is_some() && restart_value != previous_restart {
+        info!(
+            rollout = ?name,
+            restart = ?restart_value,
+            "Restart requested, rolling pods with new template hash"
+        );
+    }
+    desired_status.restarted_at = restart_value;
+
+    // Record the spec generation this status was computed from
+    observe_generation(&rollout, &mut desired_status);
+
+    // Aggregate replica counts (canary strategy: stable + canary ReplicaSets)
+    if rollout.spec.strategy.canary.is_some() {
+        apply_replicaset_counts(&mut desired_status, stable_rs.as_ref(), canary_rs.as_ref());
+        apply_revision_hashes(&mut desired_status, stable_rs.as_ref(), canary_rs.as_ref());
+    }
+
+    // Rate limit step advancement (protects against bursts of watch events)
+    let current_step_index = rollout.status.as_ref().and_then(|s| s.current_step_index);
+    if current_step_index.is_some()
+        && desired_status.current_step_index > current_step_index
+        && !ctx.step_rate_limiter.try_acquire(&namespace, &name)
+    {
+        let min_step_interval = ctx.step_rate_limiter.min_interval();
+        debug!(
+            rollout = ?name,
+            current_step = ?current_step_index,
+            min_step_interval_secs = min_step_interval.as_secs(),
+            "Step advancement rate limited, requeueing"
+        );
+        return Ok(Action::requeue(min_step_interval));
+    }
+
+    // A passing (or bypassed) analysis resets the consecutive failure count
+    if analysis_passed || analysis_skipped {
+        desired_status.consecutive_analysis_failures = 0;
+    }
+    if let Some((healthy, results)) = &analysis_record {
+        record_analysis_run(
+            &rollout,
+            &mut desired_status,
+            *healthy,
+            results,
+            analysis_time,
+        );
+    }
 
-    // Determine if we progressed due to the annotation
+    // A bypassed analysis is recorded (and its annotation used up) once the step advances
+    let skip_analysis_used =
+        analysis_skipped && record_analysis_skipped(&rollout, &mut desired_status);
+
+    // Determine if we progressed due to the annotation (a step held for quota or route did not)
     let progressed_due_to_annotation = had_promote_annotation
         && was_paused_before
+        && !is_condition_true(&desired_status, QUOTA_EXCEEDED_CONDITION)
+        && !is_condition_true(&desired_status, ROUTE_NOT_ACCEPTED_CONDITION)
         && rollout.status.as_ref() != Some(&desired_status);
+    let remove_promote = progressed_due_to_annotation
+        || should_remove_promote_annotation(had_promote_annotation, &desired_status);
 
     // Update Rollout status if it changed
     if rollout.status.as_ref() != Some(&desired_status) {
@@ -1242,57 +5174,78 @@ pub async fn reconcile(rollout: Arc<Rollout>, ctx: Arc<Context>) -> Result<Actio
             warn!(error = ?e, rollout = ?name, "Failed to emit CDEvent (non-fatal)");
         }
 
-        // Patch status subresource
-        use kube::api::{Api, Patch, PatchParams};
-        let rollout_api: Api<Rollout> = Api::namespaced(ctx.client.clone(), &namespace);
+        // Batch the status write. Promotions and skipped analyses are patched
+        // directly: their annotation is only removed once the status has landed.
+        if let Some(batcher) = ctx
+            .status_batcher
+            .as_ref()
+            .filter(|_| !remove_promote && !skip_analysis_used)
+        {
+            batcher.push(StatusUpdate::new(&rollout, desired_status.clone()));
+            debug!(rollout = ?name, "Status update queued for batched patch");
+        } else {
+            // Patch status subresource
+            let rollout_api: Api<Rollout> = Api::namespaced(ctx.client.clone(), &namespace);
 
-        match rollout_api
-            .patch_status(
-                &name,
-                &PatchParams::default(),
-                &Patch::Merge(&serde_json::json!({
-                    "status": desired_status
-                })),
+            match until_cancelled(
+                &ctx.cancellation,
+                patch_rollout_status(&rollout_api, &ctx.field_manager, &rollout, &desired_status),
             )
-            .await
-        {
-            Ok(_) => {
-                info!(rollout = ?name, "Status updated successfully");
+            .await?
+            {
+                Ok(_) => {
+                    info!(rollout = ?name, "Status updated successfully");
 
-                // Remove promote annotation if it was used for progression
-                if progressed_due_to_annotation {
-                    info!(rollout = ?name, "Removing promote annotation after successful promotion");
+                    if let Some(ref metrics) = ctx.metrics {
+                        observe_phase_transition(metrics, rollout.status.as_ref(), &desired_status);
+                    }
 
-                    match rollout_api
-                        .patch(
-                            &name,
-                            &PatchParams::default(),
-                            &Patch::Merge(&serde_json::json!({
-                                "metadata": {
-                                    "annotations": {
-                                        "kulta.io/promote": serde_json::Value::Null
-                                    }
-                                }
-                            })),
-                        )
-                        .await
-                    {
-                        Ok(_) => info!(rollout = ?name, "Promote annotation removed successfully"),
-                        Err(e) => {
-                            warn!(error = ?e, rollout = ?name, "Failed to remove promote annotation (non-fatal)")
+                    // Remove promote annotation if it was used (or has nothing left to do)
+                    if remove_promote {
+                        remove_promote_annotation(&rollout_api, &name).await;
+                    }
+
+                    // The skip-analysis annotation covers a single step
+                    if skip_analysis_used {
+                        match remove_annotation(&rollout_api, &name, skip_analysis_annotation_key())
+                            .await
+                        {
+                            Ok(_) => {
+                                info!(rollout = ?name, "Skip-analysis annotation removed after use")
+                            }
+                            Err(e) => {
+                                warn!(error = ?e, rollout = ?name, "Failed to remove skip-analysis annotation (non-fatal)")
+                            }
                         }
                     }
                 }
-            }
-            Err(e) => {
-                error!(error = ?e, rollout = ?name, "Failed to update status");
-                return Err(ReconcileError::KubeError(e));
+                Err(e) if is_conflict_error(&e) => {
+                    // Another replica updated the Rollout since we read it; recompute
+                    // from the fresh object instead of overwriting its status
+                    debug!(rollout = ?name, "Status conflict (stale resourceVersion), requeueing");
+                    return Ok(Action::requeue(Duration::ZERO));
+                }
+                Err(e) => {
+                    error!(error = ?e, rollout = ?name, "Failed to update status");
+                    return Err(ReconcileError::KubeError(e));
+                }
             }
         }
+    } else if remove_promote {
+        // Nothing to patch, but the annotation lingers (e.g. on a Completed rollout)
+        let rollout_api: Api<Rollout> = Api::namespaced(ctx.client.clone(), &namespace);
+        remove_promote_annotation(&rollout_api, &name).await;
+    }
+
+    // Remember what we reconciled to, so the watch event for our own patch is a no-op
+    if let Some(uid) = rollout.metadata.uid.as_deref() {
+        ctx.reconcile_cache
+            .record(uid, rollout.metadata.generation, &desired_status);
     }
 
     // Calculate requeue interval and return
-    let requeue_interval = calculate_requeue_interval_from_rollout(&rollout, &desired_status);
+    let requeue_interval =
+        calculate_requeue_interval_from_rollout(&rollout, &desired_status, ctx.terminal_requeue);
 
     // Record success metrics
     if let Some(ref metrics) = ctx.metrics {
@@ -1303,93 +5256,263 @@ pub async fn reconcile(rollout: Arc<Rollout>, ctx: Arc<Context>) -> Result<Actio
         if let Some(weight) = desired_status.current_weight {
             metrics.set_traffic_weight(&namespace, &name, weight as i64);
         }
+        observe_rollout_progress(metrics, &rollout);
     }
 
     Ok(Action::requeue(requeue_interval))
 }
 
+/// Remove the promote annotation (non-fatal: a failure is retried next reconcile)
+async fn remove_promote_annotation(rollout_api: &Api<Rollout>, name: &str) {
+    info!(rollout = ?name, "Removing promote annotation");
+
+    match remove_annotation(rollout_api, name, promote_annotation_key()).await {
+        Ok(_) => info!(rollout = ?name, "Promote annotation removed successfully"),
+        Err(e) => {
+            warn!(error = ?e, rollout = ?name, "Failed to remove promote annotation (non-fatal)")
+        }
+    }
+}
+
+/// Remove a control annotation from a Rollout (merge patch setting it to null)
+async fn remove_annotation(
+    rollout_api: &Api<Rollout>,
+    name: &str,
+    key: String,
+) -> Result<Rollout, kube::Error> {
+    use kube::api::{Patch, PatchParams};
+
+    rollout_api
+        .patch(
+            name,
+            &PatchParams::default(),
+            &Patch::Merge(&serde_json::json!({
+                "metadata": {
+                    "annotations": {
+                        key: serde_json::Value::Null
+                    }
+                }
+            })),
+        )
+        .await
+}
+
+/// One analysis run: the verdict and what was evaluated
+///
+/// `metric_results` is None when nothing was evaluated (no analysis config,
+/// still warming up, or a passing run reused within analysis.interval).
+struct AnalysisRun {
+    verdict: Result<bool, ReconcileError>,
+    metric_results: Option<Vec<MetricResult>>,
+}
+
 /// Evaluate rollout metrics against Prometheus thresholds
 ///
 /// Checks if the canary revision is healthy based on the analysis config.
-/// Returns Ok(true) if healthy, Ok(false) if unhealthy.
 ///
 /// # Arguments
 /// * `rollout` - The Rollout to evaluate
 /// * `ctx` - Controller context with PrometheusClient
 ///
 /// # Returns
-/// * `Ok(true)` - All metrics healthy (or no analysis config)
-/// * `Ok(false)` - One or more metrics unhealthy
-/// * `Err(_)` - Query execution failed
-async fn evaluate_rollout_metrics(
+/// An `AnalysisRun` whose verdict is:
+/// * `Ok(true)` - Enough metrics healthy (or no analysis config)
+/// * `Ok(false)` - Too few metrics healthy
+/// * `Err(_)` - A metric query failed
+async fn evaluate_rollout_metrics(rollout: &Rollout, ctx: &Context) -> AnalysisRun {
+    evaluate_rollout_metrics_at(rollout, ctx, Utc::now()).await
+}
+
+/// Evaluate rollout metrics at a given time (testable variant)
+async fn evaluate_rollout_metrics_at(
     rollout: &Rollout,
     ctx: &Context,
-) -> Result<bool, ReconcileError> {
-    // Check if rollout has canary strategy with analysis config
-    let analysis_config = match &rollout.spec.strategy.canary {
-        Some(canary_strategy) => match &canary_strategy.analysis {
-            Some(analysis) => analysis,
-            None => {
-                // No analysis config - consider healthy (no constraints)
-                return Ok(true);
-            }
-        },
+    now: DateTime<Utc>,
+) -> AnalysisRun {
+    let nothing_evaluated = AnalysisRun {
+        verdict: Ok(true),
+        metric_results: None,
+    };
+
+    // Resolve analysis config for the current step (step-level overrides strategy-level)
+    let analysis_config = match resolve_effective_analysis(rollout) {
+        Some(analysis) => analysis,
         None => {
-            // No canary strategy - no metrics to check
-            return Ok(true);
+            // No canary strategy or no analysis config - consider healthy (no constraints)
+            return nothing_evaluated;
         }
     };
 
-    // Check if warmup period has elapsed
-    if let Some(warmup_str) = &analysis_config.warmup_duration {
-        if let Some(warmup_duration) = parse_duration(warmup_str) {
-            // Get step start time from status, or fall back to rollout creation time
-            let step_start_time = rollout
-                .status
-                .as_ref()
-                .and_then(|s| s.step_start_time.as_ref())
-                .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
-                .map(|dt| dt.with_timezone(&Utc))
-                .or_else(|| rollout.meta().creation_timestamp.as_ref().map(|t| t.0));
-
-            if let Some(start_time) = step_start_time {
-                let now = Utc::now();
-                let elapsed = now.signed_duration_since(start_time);
-                let warmup_duration_secs = warmup_duration.as_secs() as i64;
-
-                if elapsed.num_seconds() < warmup_duration_secs {
-                    // Still in warmup period - skip analysis, consider healthy
-                    let remaining = warmup_duration_secs - elapsed.num_seconds();
-                    debug!(
-                        rollout = rollout.name_any(),
-                        warmup_remaining_secs = remaining,
-                        "Skipping metrics analysis - warmup period not elapsed"
-                    );
-                    return Ok(true);
-                }
-            } else {
-                // Warmup is configured but step_start_time is missing or invalid.
-                // Treat this as if warmup just started: skip analysis for now.
-                warn!(
-                    rollout = rollout.name_any(),
-                    "Warmup duration is configured but step_start_time is missing or invalid; skipping metrics analysis and treating warmup as just started"
-                );
-                return Ok(true);
-            }
-        }
+    // Skip analysis (consider healthy) until the warmup after the weight change elapses
+    if is_analysis_warming_up(rollout, analysis_config, now) {
+        debug!(
+            rollout = rollout.name_any(),
+            "Skipping metrics analysis during warmup"
+        );
+        return nothing_evaluated;
+    }
+
+    // Reuse the last passing run until analysis.interval elapses
+    if has_fresh_passing_analysis(rollout, analysis_config, now) {
+        debug!(
+            rollout = rollout.name_any(),
+            "Reusing last passing analysis within interval"
+        );
+        return nothing_evaluated;
     }
 
     // Get rollout name for Prometheus labels
     let rollout_name = rollout.name_any();
 
-    // Evaluate all metrics
-    let is_healthy = ctx
-        .prometheus_client
-        .evaluate_all_metrics(&analysis_config.metrics, &rollout_name, "canary")
-        .await
-        .map_err(|e| ReconcileError::MetricsEvaluationFailed(e.to_string()))?;
+    // How many metrics must pass (validated in validate_rollout)
+    let requirement = match MetricRequirement::from_config(analysis_config.requirement.as_ref()) {
+        Ok(requirement) => requirement,
+        Err(e) => {
+            return AnalysisRun {
+                verdict: Err(ReconcileError::ValidationError(e)),
+                metric_results: None,
+            }
+        }
+    };
+
+    // Evaluate all metrics (canary-vs-stable: each against the stable revision)
+    let metric_results = match analysis_config.mode.unwrap_or_default() {
+        AnalysisMode::Threshold => {
+            ctx.prometheus_client
+                .evaluate_metric_results(&analysis_config.metrics, &rollout_name, "canary")
+                .await
+        }
+        AnalysisMode::CanaryVsStable => {
+            ctx.prometheus_client
+                .evaluate_canary_vs_stable(
+                    &analysis_config.metrics,
+                    &rollout_name,
+                    analysis_config.tolerance.unwrap_or(0.0),
+                )
+                .await
+        }
+    };
+    let verdict = analysis_verdict(&metric_results, requirement)
+        .map_err(ReconcileError::MetricsEvaluationFailed);
+
+    AnalysisRun {
+        verdict,
+        metric_results: Some(metric_results),
+    }
+}
+
+/// Record what an analysis run evaluated in status.lastAnalysisResult
+///
+/// Call once consecutiveAnalysisFailures is up to date for the run. If the
+/// outcome is unchanged from the recorded one, its timestamp is kept so a
+/// steady analysis doesn't rewrite the status on every reconcile.
+pub fn record_analysis_result(
+    status: &mut RolloutStatus,
+    healthy: bool,
+    metric_results: &[MetricResult],
+    now: DateTime<Utc>,
+) {
+    let unchanged = status.last_analysis_result.as_ref().is_some_and(|last| {
+        last.healthy == healthy
+            && last.metric_results == metric_results
+            && last.consecutive_failures == status.consecutive_analysis_failures
+    });
+    if unchanged {
+        return;
+    }
+
+    status.last_analysis_result = Some(AnalysisResult {
+        timestamp: now.to_rfc3339(),
+        healthy,
+        metric_results: metric_results.to_vec(),
+        consecutive_failures: status.consecutive_analysis_failures,
+    });
+}
+
+/// Record an analysis run that queried metrics at `now`
+///
+/// Records the result (see `record_analysis_result`) and, when the effective
+/// analysis sets an interval, status.lastAnalysisTime. Without an interval
+/// every reconcile queries, so the time is not kept (it would rewrite the
+/// status each time).
+pub fn record_analysis_run(
+    rollout: &Rollout,
+    status: &mut RolloutStatus,
+    healthy: bool,
+    metric_results: &[MetricResult],
+    now: DateTime<Utc>,
+) {
+    record_analysis_result(status, healthy, metric_results, now);
+    if resolve_effective_analysis(rollout)
+        .and_then(analysis_interval)
+        .is_some()
+    {
+        status.last_analysis_time = Some(now.to_rfc3339());
+    }
+}
+
+/// Consecutive failed analysis runs needed before rolling back (default: 1)
+pub fn consecutive_failure_threshold(rollout: &Rollout) -> i32 {
+    resolve_effective_analysis(rollout)
+        .and_then(|analysis| analysis.consecutive_failure_threshold)
+        .unwrap_or(1)
+}
+
+/// Consecutive analysis failures after this run: 0 if it passed, one more if it failed
+pub fn analysis_failures_after(rollout: &Rollout, healthy: bool) -> i32 {
+    if healthy {
+        return 0;
+    }
+    rollout
+        .status
+        .as_ref()
+        .map_or(0, |status| status.consecutive_analysis_failures)
+        .saturating_add(1)
+}
+
+/// Status holding the current step after a failed analysis below the threshold
+///
+/// # Returns
+/// * `Some(RolloutStatus)` - Tolerated: the current status with the failure counted
+/// * `None` - consecutiveFailureThreshold reached, the rollout should roll back
+pub fn analysis_failure_hold_status(rollout: &Rollout, failures: i32) -> Option<RolloutStatus> {
+    let threshold = consecutive_failure_threshold(rollout);
+    if failures >= threshold {
+        return None;
+    }
+
+    let mut status = rollout.status.clone().unwrap_or_default();
+    status.consecutive_analysis_failures = failures;
+    status.message = Some(format!(
+        "Analysis failed ({}/{} consecutive failures), holding step",
+        failures, threshold
+    ));
+    Some(status)
+}
+
+/// Resolve the effective analysis config for the current canary step
+///
+/// Step-level analysis takes precedence over strategy-level analysis, allowing
+/// stricter checks at higher-traffic steps.
+///
+/// # Arguments
+/// * `rollout` - The Rollout to resolve analysis for
+///
+/// # Returns
+/// * `Some(&AnalysisConfig)` - Current step's analysis, or strategy-level analysis as fallback
+/// * `None` - No canary strategy or no analysis configured
+pub fn resolve_effective_analysis(rollout: &Rollout) -> Option<&AnalysisConfig> {
+    let canary = rollout.spec.strategy.canary.as_ref()?;
+
+    let step_analysis = rollout
+        .status
+        .as_ref()
+        .and_then(|status| status.current_step_index)
+        .and_then(|step_index| usize::try_from(step_index).ok())
+        .and_then(|step_index| canary.steps.get(step_index))
+        .and_then(|step| step.analysis.as_ref());
 
-    Ok(is_healthy)
+    step_analysis.or(canary.analysis.as_ref())
 }
 
 /// Calculate optimal requeue interval based on rollout pause state
@@ -1449,7 +5572,39 @@ fn calculate_requeue_interval(
 }
 
 /// Helper to extract pause information from Rollout and RolloutStatus
-fn calculate_requeue_interval_from_rollout(rollout: &Rollout, status: &RolloutStatus) -> Duration {
+///
+/// Terminal rollouts (Completed/Degraded) requeue after `terminal_requeue`
+/// instead of polling; the per-rollout annotation still takes precedence.
+fn calculate_requeue_interval_from_rollout(
+    rollout: &Rollout,
+    status: &RolloutStatus,
+    terminal_requeue: Duration,
+) -> Duration {
+    // Per-rollout override (<domain>/requeue-success-seconds)
+    if let Some(interval) = requeue_override(rollout, &requeue_success_annotation_key()) {
+        return interval;
+    }
+
+    // Nothing left to do: the watch brings spec changes in promptly
+    if is_terminal_status(status) {
+        return terminal_requeue;
+    }
+
+    // Held by spec.paused: the watch brings the unpause in promptly
+    if is_paused_by_spec(status) {
+        return terminal_requeue;
+    }
+
+    // Step held for quota: re-check for headroom soon
+    if is_condition_true(status, QUOTA_EXCEEDED_CONDITION) {
+        return QUOTA_RECHECK_INTERVAL;
+    }
+
+    // Step held for the HTTPRoute: re-check for acceptance soon
+    if is_condition_true(status, ROUTE_NOT_ACCEPTED_CONDITION) {
+        return ROUTE_RECHECK_INTERVAL;
+    }
+
     let pause_start = status
         .pause_start_time
         .as_ref()
@@ -1485,6 +5640,9 @@ fn calculate_requeue_interval_from_rollout(rollout: &Rollout, status: &RolloutSt
 /// - Minutes limited to 24h (1440m) - use hours for longer durations
 /// - Hours limited to 1 week (168h) - prevents typos like "999999h"
 ///
+/// Never panics: arbitrary input (including non-ASCII and very long strings)
+/// returns None (see `fuzz/fuzz_targets/fuzz_parse_duration.rs`)
+///
 /// # Arguments
 /// * `duration_str` - Duration string to parse
 ///
@@ -1500,8 +5658,8 @@ pub fn parse_duration(duration_str: &str) -> Option<Duration> {
     // Get the last character (unit)
     let unit = duration_str.chars().last()?;
 
-    // Get the numeric part
-    let number_str = &duration_str[..duration_str.len() - 1];
+    // Get the numeric part (slice on a char boundary - unit may be multi-byte)
+    let number_str = &duration_str[..duration_str.len() - unit.len_utf8()];
     let number: u64 = number_str.parse().ok()?;
 
     // Reject zero duration
@@ -1541,6 +5699,215 @@ pub fn parse_duration(duration_str: &str) -> Option<Duration> {
     }
 }
 
+/// Check if a reconcile can be short-circuited to a requeue
+///
+/// True when the rollout's generation and status match what the cache
+/// recorded after the last full reconcile and no progression is pending.
+pub fn is_reconcile_cache_hit(
+    cache: &ReconcileCache,
+    rollout: &Rollout,
+    next_status: &RolloutStatus,
+) -> bool {
+    let (Some(uid), Some(current_status)) = (rollout.metadata.uid.as_deref(), &rollout.status)
+    else {
+        return false;
+    };
+
+    cache.is_fresh(uid, rollout.metadata.generation, current_status)
+        && !has_pending_progression(rollout, next_status)
+}
+
+/// Check if a reconcile would do more than requeue
+///
+/// Compares the current status with the strategy's next status (which
+/// accounts for elapsed pauses, timeouts and promote annotations), and
+/// treats a pending restart or in-flight metric analysis as work to do.
+///
+/// # Arguments
+/// * `rollout` - The Rollout with its current status
+/// * `next_status` - Status computed by the strategy's `compute_next_status`
+pub fn has_pending_progression(rollout: &Rollout, next_status: &RolloutStatus) -> bool {
+    let Some(current) = rollout.status.as_ref() else {
+        return true;
+    };
+
+    if current.phase != next_status.phase
+        || current.current_step_index != next_status.current_step_index
+        || current.current_weight != next_status.current_weight
+    {
+        return true;
+    }
+
+    if get_restart_annotation(rollout) != current.restarted_at.as_deref() {
+        return true;
+    }
+
+    // Metrics are evaluated on every reconcile while progressing
+    matches!(
+        current.phase,
+        Some(Phase::Progressing) | Some(Phase::WaitingForAnalysis)
+    ) && resolve_effective_analysis(rollout).is_some()
+}
+
+/// Record the Rollout's metadata.generation as status.observedGeneration
+///
+/// Called at the end of a successful reconcile so tools can compare
+/// observedGeneration to metadata.generation to know if the controller
+/// has processed the latest spec change.
+pub fn observe_generation(rollout: &Rollout, status: &mut RolloutStatus) {
+    status.observed_generation = rollout.metadata.generation;
+}
+
+/// Check if the controller has processed the Rollout's latest spec change
+///
+/// # Returns
+/// true if status.observedGeneration matches metadata.generation, false if
+/// the status is stale (or missing)
+pub fn is_generation_observed(rollout: &Rollout) -> bool {
+    let observed = rollout.status.as_ref().and_then(|s| s.observed_generation);
+    observed.is_some() && observed == rollout.metadata.generation
+}
+
+/// Default field manager used for server-side apply of Rollout status
+pub const STATUS_FIELD_MANAGER: &str = "kulta-controller";
+
+/// Maximum field manager length accepted by the API server
+pub const MAX_FIELD_MANAGER_LEN: usize = 128;
+
+/// Build the server-side apply parameters for a Rollout status update
+///
+/// Force is set so KULTA takes ownership of the status fields it applies when
+/// another manager previously set them. The apply payload only ever carries
+/// `status`, so fields owned by other tools elsewhere are left alone.
+pub fn build_status_patch_params(field_manager: &str) -> kube::api::PatchParams {
+    kube::api::PatchParams::apply(field_manager).force()
+}
+
+/// Build the server-side apply payload for a Rollout status update
+///
+/// Includes metadata.resourceVersion as a precondition: if another controller
+/// replica updated the Rollout after we read it, the API server rejects the
+/// patch with 409 Conflict instead of silently overwriting its status.
+pub fn build_status_patch(rollout: &Rollout, status: &RolloutStatus) -> serde_json::Value {
+    serde_json::json!({
+        "apiVersion": "kulta.io/v1alpha1",
+        "kind": "Rollout",
+        "metadata": {
+            "name": rollout.name_any(),
+            "resourceVersion": rollout.resource_version(),
+        },
+        "status": status
+    })
+}
+
+/// Check if a Kubernetes API error is a 409 Conflict
+pub fn is_conflict_error(error: &kube::Error) -> bool {
+    matches!(error, kube::Error::Api(e) if e.code == 409)
+}
+
+/// Patch the Rollout status subresource with optimistic concurrency
+///
+/// Uses server-side apply with the Rollout's resourceVersion as precondition.
+async fn patch_rollout_status(
+    rollout_api: &Api<Rollout>,
+    field_manager: &str,
+    rollout: &Rollout,
+    status: &RolloutStatus,
+) -> Result<Rollout, kube::Error> {
+    use kube::api::Patch;
+
+    rollout_api
+        .patch_status(
+            &rollout.name_any(),
+            &build_status_patch_params(field_manager),
+            &Patch::Apply(&build_status_patch(rollout, status)),
+        )
+        .await
+}
+
+/// Rollout annotation that triggers a rolling restart when its value changes
+pub const RESTART_ANNOTATION: &str = "kulta.io/restart";
+
+/// Pod template annotation carrying the restart trigger value
+pub const RESTARTED_AT_ANNOTATION: &str = "kulta.io/restartedAt";
+
+/// Check if Rollout has the restart annotation (kulta.io/restart=<timestamp>)
+///
+/// Used to force a rolling restart without changing the pod spec
+/// (e.g., to pick up a rotated secret).
+///
+/// # Arguments
+/// * `rollout` - The Rollout to check
+///
+/// # Returns
+/// true if annotation exists with a non-empty value, false otherwise
+pub fn has_restart_annotation(rollout: &Rollout) -> bool {
+    get_restart_annotation(rollout).is_some()
+}
+
+/// Get the restart annotation value (kulta.io/restart), if set and non-empty
+pub fn get_restart_annotation(rollout: &Rollout) -> Option<&str> {
+    rollout
+        .metadata
+        .annotations
+        .as_ref()
+        .and_then(|annotations| annotations.get(RESTART_ANNOTATION))
+        .map(|value| value.as_str())
+        .filter(|value| !value.is_empty())
+}
+
+/// Default domain for control annotations (`kulta.io/promote`, `kulta.io/abort`)
+pub const DEFAULT_ANNOTATION_DOMAIN: &str = "kulta.io";
+
+/// Domain for control annotations (KULTA_ANNOTATION_DOMAIN, default: "kulta.io")
+pub fn annotation_domain() -> String {
+    resolve_annotation_domain(std::env::var("KULTA_ANNOTATION_DOMAIN").ok().as_deref())
+}
+
+/// Resolve a configured annotation domain, falling back to the default when unset or empty
+pub fn resolve_annotation_domain(configured: Option<&str>) -> String {
+    configured
+        .filter(|domain| !domain.is_empty())
+        .unwrap_or(DEFAULT_ANNOTATION_DOMAIN)
+        .to_string()
+}
+
+/// Annotation key that promotes a paused rollout: `<domain>/promote`
+pub fn promote_annotation_key() -> String {
+    promote_annotation_key_for(&annotation_domain())
+}
+
+/// Promote annotation key for a given domain
+pub fn promote_annotation_key_for(domain: &str) -> String {
+    format!("{}/promote", domain)
+}
+
+/// Annotation key that aborts an in-flight rollout: `<domain>/abort`
+pub fn abort_annotation_key() -> String {
+    abort_annotation_key_for(&annotation_domain())
+}
+
+/// Abort annotation key for a given domain
+pub fn abort_annotation_key_for(domain: &str) -> String {
+    format!("{}/abort", domain)
+}
+
+/// Annotation key that bypasses a failed analysis for one step: `<domain>/skip-analysis`
+pub fn skip_analysis_annotation_key() -> String {
+    format!("{}/skip-analysis", annotation_domain())
+}
+
+/// Check if a Rollout annotation is set to "true"
+fn has_true_annotation(rollout: &Rollout, key: &str) -> bool {
+    rollout
+        .metadata
+        .annotations
+        .as_ref()
+        .and_then(|annotations| annotations.get(key))
+        .map(|value| value == "true")
+        .unwrap_or(false)
+}
+
 /// Check if Rollout has the promote annotation (kulta.io/promote=true)
 ///
 /// This annotation is used to manually promote a rollout that is paused.
@@ -1555,13 +5922,224 @@ pub fn parse_duration(duration_str: &str) -> Option<Duration> {
 /// # Returns
 /// true if annotation exists with value "true", false otherwise
 pub fn has_promote_annotation(rollout: &Rollout) -> bool {
+    has_true_annotation(rollout, &promote_annotation_key())
+}
+
+/// Whether a lingering promote annotation can be removed
+///
+/// Besides a promotion past a pause (handled by reconcile), the annotation has
+/// nothing left to do once the rollout completed, or when the step it sits on
+/// has no pause and advances on its own. A paused step (pause timer running,
+/// Paused phase) or a blue-green Preview keeps it: there it still promotes.
+///
+/// # Arguments
+/// * `had_annotation` - Whether the promote annotation was present
+/// * `status` - The status reconcile computed
+pub fn should_remove_promote_annotation(had_annotation: bool, status: &RolloutStatus) -> bool {
+    if !had_annotation {
+        return false;
+    }
+
+    match status.phase {
+        Some(Phase::Completed) => true,
+        Some(Phase::Progressing) => status.pause_start_time.is_none(),
+        _ => false,
+    }
+}
+
+/// Check if Rollout has the skip-analysis annotation (kulta.io/skip-analysis=true)
+///
+/// Emergency fast promotion: metrics analysis (and its warmup) is skipped
+/// entirely, so neither failing nor unavailable metrics hold or roll back
+/// the step. Unlike promote, pauses still have to expire.
+pub fn has_skip_analysis_annotation(rollout: &Rollout) -> bool {
+    has_true_annotation(rollout, &skip_analysis_annotation_key())
+}
+
+/// Record that the skip-analysis annotation carried a step forward unanalyzed
+///
+/// Only records once the step actually advanced (or the rollout completed);
+/// while a pause still holds the step nothing is recorded.
+///
+/// # Returns
+/// true if the step advanced, i.e. the annotation was used up
+pub fn record_analysis_skipped(rollout: &Rollout, status: &mut RolloutStatus) -> bool {
+    let previous = rollout.status.as_ref();
+    let from_step = previous.and_then(|s| s.current_step_index);
+    let completed_now = status.phase == Some(Phase::Completed)
+        && previous.and_then(|s| s.phase.as_ref()) != Some(&Phase::Completed);
+    if status.current_step_index <= from_step && !completed_now {
+        return false;
+    }
+
+    let to_step = status.current_step_index;
+    record_decision(
+        status,
+        DecisionAction::AnalysisSkipped,
+        DecisionReason::SkipAnalysisAnnotation,
+        from_step,
+        to_step,
+    );
+    if let Some(decision) = status.decisions.last_mut() {
+        decision.message = Some("skip-analysis annotation".to_string());
+    }
+    true
+}
+
+/// Check if a blue-green spec declares promotion (activeService == previewService)
+///
+/// GitOps alternative to the promote annotation: pointing
+/// `spec.strategy.blueGreen.activeService` at the preview Service promotes
+/// the preview ReplicaSet, exactly like `kulta.io/promote=true`.
+pub fn is_declarative_promotion(rollout: &Rollout) -> bool {
     rollout
-        .metadata
-        .annotations
+        .spec
+        .strategy
+        .blue_green
         .as_ref()
-        .and_then(|annotations| annotations.get("kulta.io/promote"))
-        .map(|value| value == "true")
-        .unwrap_or(false)
+        .is_some_and(|blue_green| blue_green.active_service == blue_green.preview_service)
+}
+
+/// Check if Rollout has the abort annotation (kulta.io/abort=true)
+///
+/// An aborted canary that is Progressing or Paused moves to RollingBack,
+/// the same path taken when metric analysis fails.
+pub fn has_abort_annotation(rollout: &Rollout) -> bool {
+    has_true_annotation(rollout, &abort_annotation_key())
+}
+
+/// Shortest requeue interval a Rollout annotation may set
+pub const MIN_REQUEUE_OVERRIDE: Duration = Duration::from_secs(1);
+
+/// Longest requeue interval a Rollout annotation may set
+pub const MAX_REQUEUE_OVERRIDE: Duration = Duration::from_secs(3600);
+
+/// Annotation key overriding the requeue interval after an error: `<domain>/requeue-error-seconds`
+pub fn requeue_error_annotation_key() -> String {
+    format!("{}/requeue-error-seconds", annotation_domain())
+}
+
+/// Annotation key overriding the requeue interval after a successful reconcile:
+/// `<domain>/requeue-success-seconds`
+pub fn requeue_success_annotation_key() -> String {
+    format!("{}/requeue-success-seconds", annotation_domain())
+}
+
+/// Parse a requeue override annotation value
+///
+/// Accepts plain seconds ("30") or a duration ("30s", "5m", "1h").
+///
+/// # Returns
+/// The interval, or None if unparseable or outside 1s-3600s
+pub fn parse_requeue_override(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let interval = match value.parse::<u64>() {
+        Ok(secs) => Duration::from_secs(secs),
+        Err(_) => parse_duration(value)?,
+    };
+
+    (MIN_REQUEUE_OVERRIDE..=MAX_REQUEUE_OVERRIDE)
+        .contains(&interval)
+        .then_some(interval)
+}
+
+/// Get a valid requeue override from a Rollout annotation
+fn requeue_override(rollout: &Rollout, key: &str) -> Option<Duration> {
+    let value = rollout.metadata.annotations.as_ref()?.get(key)?;
+    let interval = parse_requeue_override(value);
+    if interval.is_none() {
+        warn!(
+            rollout = ?rollout.name_any(),
+            annotation = key,
+            value = %value,
+            "Ignoring invalid requeue annotation (expected 1s-3600s)"
+        );
+    }
+    interval
+}
+
+/// Requeue interval after a failed reconcile
+///
+/// The `<domain>/requeue-error-seconds` annotation overrides the error's
+/// category-specific interval.
+pub fn error_requeue_interval(rollout: &Rollout, error: &ReconcileError) -> Duration {
+    requeue_override(rollout, &requeue_error_annotation_key())
+        .unwrap_or_else(|| calculate_error_requeue_interval(error))
+}
+
+/// Requeue interval after `consecutive_failures` failed reconciles in a row
+///
+/// The category-specific interval doubles with each consecutive failure (up
+/// to MAX_ERROR_BACKOFF); a `<domain>/requeue-error-seconds` override is
+/// used as-is.
+pub fn backed_off_error_requeue_interval(
+    rollout: &Rollout,
+    error: &ReconcileError,
+    consecutive_failures: u32,
+) -> Duration {
+    requeue_override(rollout, &requeue_error_annotation_key()).unwrap_or_else(|| {
+        backoff_interval(
+            calculate_error_requeue_interval(error),
+            consecutive_failures,
+            MAX_ERROR_BACKOFF,
+        )
+    })
+}
+
+/// Validate the `<domain>/rollback-to` annotation, if present
+///
+/// Only canary rollouts support it, and an unprocessed hash must be in
+/// status.revisionHistory.
+pub fn validate_rollback_to_annotation(rollout: &Rollout) -> Result<(), String> {
+    let Some(hash) = get_rollback_to_annotation(rollout) else {
+        return Ok(());
+    };
+    let key = rollback_to_annotation_key();
+
+    if rollout.spec.strategy.canary.is_none() {
+        return Err(format!("annotation {} requires a canary strategy", key));
+    }
+    let status = rollout.status.as_ref();
+    let processed = status.and_then(|s| s.rolled_back_to.as_deref()) == Some(hash.as_str());
+    if !processed && status.and_then(|s| find_revision(s, &hash)).is_none() {
+        let known = status
+            .map(|status| {
+                status
+                    .revision_history
+                    .iter()
+                    .map(|revision| revision.hash.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+        return Err(format!(
+            "annotation {}: unknown revision {:?} (recorded: [{}])",
+            key, hash, known
+        ));
+    }
+    Ok(())
+}
+
+/// Validate the requeue override annotations, if present
+pub fn validate_requeue_annotations(rollout: &Rollout) -> Result<(), String> {
+    let Some(annotations) = rollout.metadata.annotations.as_ref() else {
+        return Ok(());
+    };
+
+    for key in [
+        requeue_error_annotation_key(),
+        requeue_success_annotation_key(),
+    ] {
+        if let Some(value) = annotations.get(&key) {
+            if parse_requeue_override(value).is_none() {
+                return Err(format!(
+                    "annotation {} must be between 1s and 3600s, got {:?}",
+                    key, value
+                ));
+            }
+        }
+    }
+    Ok(())
 }
 
 #[cfg(test)]
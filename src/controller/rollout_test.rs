@@ -1,10 +1,15 @@
 use super::*;
 use crate::crd::rollout::{
-    CanaryStep, CanaryStrategy, GatewayAPIRouting, PauseDuration, Phase, Rollout, RolloutSpec,
-    RolloutStatus, RolloutStrategy, SimpleStrategy, TrafficRouting,
+    CanaryStep, CanaryStrategy, ExtraCanary, GatewayAPIRouting, HttpRouteMatch, PauseDuration,
+    Phase, RevisionRef, Rollout, RolloutSpec, RolloutStatus, RolloutStrategy, SimpleStrategy,
+    StatusTransition, StickyCookie, TrafficRouting, TransitionTrigger,
 };
 use kube::api::ObjectMeta;
 
+#[path = "../../tests/common/builders.rs"]
+mod builders;
+use builders::RolloutBuilder;
+
 // Helper function to create a test Rollout with simple strategy
 fn create_test_rollout_with_simple() -> Rollout {
     Rollout {
@@ -46,6 +51,10 @@ fn create_test_rollout_with_simple() -> Rollout {
                 canary: None,
                 blue_green: None,
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     }
@@ -99,8 +108,13 @@ fn create_test_rollout_with_blue_green() -> Rollout {
                     auto_promotion_seconds: None,
                     traffic_routing: None,
                     analysis: None,
+                    max_duration_seconds: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     }
@@ -169,7 +183,7 @@ fn test_simple_strategy_creates_single_replicaset() {
     // ASSERT: ReplicaSet has all replicas and correct naming
     assert_eq!(
         rs.metadata.name.as_deref(),
-        Some("simple-rollout") // No -stable/-canary suffix
+        Some("simple-rollout-simple") // Suffixed like the canary/blue-green ReplicaSets
     );
     assert_eq!(rs.spec.as_ref().unwrap().replicas, Some(3));
 
@@ -245,11 +259,34 @@ fn create_test_rollout_with_canary() -> Rollout {
                 canary: Some(CanaryStrategy {
                     canary_service: "test-app-canary".to_string(),
                     stable_service: "test-app-stable".to_string(),
+                    mirror_service: None,
                     steps: vec![], // Tests will set their own steps
                     analysis: None,
                     traffic_routing: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     }
@@ -298,27 +335,52 @@ async fn test_reconcile_creates_stable_replicaset() {
                 canary: Some(CanaryStrategy {
                     canary_service: "test-app-canary".to_string(),
                     stable_service: "test-app-stable".to_string(),
+                    mirror_service: None,
                     steps: vec![
                         CanaryStep {
                             set_weight: Some(20),
                             pause: None,
+                            analysis: None,
                         },
                         CanaryStep {
                             set_weight: Some(50),
                             pause: None,
+                            analysis: None,
                         },
                     ],
                     analysis: None,
                     traffic_routing: None, // No HTTPRoute for ReplicaSet unit tests
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
 
     // Test that build_replicaset creates a stable ReplicaSet with correct properties
     // (Full reconcile integration test requires real K8s cluster - see CI integration tests)
-    let stable_rs = build_replicaset(&rollout, "stable", rollout.spec.replicas).unwrap();
+    let stable_rs = build_replicaset(&rollout, "stable", "stable", rollout.spec.replicas).unwrap();
 
     // Verify stable ReplicaSet has correct properties
     assert_eq!(
@@ -418,17 +480,40 @@ async fn test_build_replicaset_spec() {
                 canary: Some(CanaryStrategy {
                     canary_service: "test-app-canary".to_string(),
                     stable_service: "test-app-stable".to_string(),
+                    mirror_service: None,
                     steps: vec![],
                     analysis: None,
                     traffic_routing: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
 
     // Build stable ReplicaSet
-    let rs = build_replicaset(&rollout, "stable", 3).unwrap();
+    let rs = build_replicaset(&rollout, "stable", "stable", 3).unwrap();
 
     assert_eq!(rs.metadata.name.as_deref(), Some("test-rollout-stable"));
     assert_eq!(rs.metadata.namespace.as_deref(), Some("default"));
@@ -498,20 +583,44 @@ async fn test_reconcile_creates_canary_replicaset() {
                 canary: Some(CanaryStrategy {
                     canary_service: "test-app-canary".to_string(),
                     stable_service: "test-app-stable".to_string(),
+                    mirror_service: None,
                     steps: vec![CanaryStep {
                         set_weight: Some(20),
                         pause: None,
+                        analysis: None,
                     }],
                     analysis: None,
                     traffic_routing: None, // No HTTPRoute for ReplicaSet unit tests
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
 
     // Build canary ReplicaSet (should have 0 replicas initially)
-    let canary_rs = build_replicaset(&rollout, "canary", 0).unwrap();
+    let canary_rs = build_replicaset(&rollout, "canary", "canary", 0).unwrap();
 
     // Verify canary ReplicaSet has correct properties
     assert_eq!(
@@ -590,16 +699,39 @@ async fn test_replicaset_has_kulta_managed_label() {
                 canary: Some(CanaryStrategy {
                     canary_service: "test-app-canary".to_string(),
                     stable_service: "test-app-stable".to_string(),
+                    mirror_service: None,
                     steps: vec![],
                     analysis: None,
                     traffic_routing: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
 
-    let stable_rs = build_replicaset(&rollout, "stable", 3).unwrap();
+    let stable_rs = build_replicaset(&rollout, "stable", "stable", 3).unwrap();
 
     // Verify ReplicaSet metadata has rollouts.kulta.io/managed label
     let rs_labels = stable_rs.metadata.labels.as_ref().unwrap();
@@ -645,7 +777,7 @@ async fn test_replicaset_has_kulta_managed_label() {
     );
 
     // Verify canary also has the label
-    let canary_rs = build_replicaset(&rollout, "canary", 0).unwrap();
+    let canary_rs = build_replicaset(&rollout, "canary", "canary", 0).unwrap();
     let canary_selector_labels = canary_rs
         .spec
         .as_ref()
@@ -705,18 +837,41 @@ async fn test_build_both_stable_and_canary_replicasets() {
                 canary: Some(CanaryStrategy {
                     canary_service: "test-app-canary".to_string(),
                     stable_service: "test-app-stable".to_string(),
+                    mirror_service: None,
                     steps: vec![],
                     analysis: None,
                     traffic_routing: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
 
     // Build both ReplicaSets
-    let stable_rs = build_replicaset(&rollout, "stable", rollout.spec.replicas).unwrap();
-    let canary_rs = build_replicaset(&rollout, "canary", 0).unwrap();
+    let stable_rs = build_replicaset(&rollout, "stable", "stable", rollout.spec.replicas).unwrap();
+    let canary_rs = build_replicaset(&rollout, "canary", "canary", 0).unwrap();
 
     // Verify stable ReplicaSet
     assert_eq!(
@@ -805,46 +960,12 @@ async fn test_build_both_stable_and_canary_replicasets() {
 #[tokio::test]
 async fn test_calculate_traffic_weights_step0() {
     // Test weight calculation for canary step 0 (20%)
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![
-                        CanaryStep {
-                            set_weight: Some(20),
-                            pause: None,
-                        },
-                        CanaryStep {
-                            set_weight: Some(50),
-                            pause: None,
-                        },
-                        CanaryStep {
-                            set_weight: Some(100),
-                            pause: None,
-                        },
-                    ],
-                    analysis: None,
-                    traffic_routing: None, // No HTTPRoute for ReplicaSet unit tests
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(0), // First step: 20% canary
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_step(50, None)
+        .with_step(100, None)
+        .with_step_index(0)
+        .build();
 
     // Calculate weights for step 0
     let (stable_weight, canary_weight) = calculate_traffic_weights(&rollout);
@@ -856,42 +977,11 @@ async fn test_calculate_traffic_weights_step0() {
 #[tokio::test]
 async fn test_calculate_traffic_weights_step1() {
     // Test weight calculation for canary step 1 (50%)
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![
-                        CanaryStep {
-                            set_weight: Some(20),
-                            pause: None,
-                        },
-                        CanaryStep {
-                            set_weight: Some(50),
-                            pause: None,
-                        },
-                    ],
-                    analysis: None,
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(1), // Second step: 50% canary
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_step(50, None)
+        .with_step_index(1)
+        .build();
 
     // Calculate weights for step 1
     let (stable_weight, canary_weight) = calculate_traffic_weights(&rollout);
@@ -903,33 +993,7 @@ async fn test_calculate_traffic_weights_step1() {
 #[tokio::test]
 async fn test_calculate_traffic_weights_no_step() {
     // Test weight calculation when no step is active (100% stable)
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![CanaryStep {
-                        set_weight: Some(20),
-                        pause: None,
-                    }],
-                    analysis: None,
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: None, // No status yet, default to 100% stable
-    };
+    let rollout = RolloutBuilder::new().with_step(20, None).build();
 
     // Calculate weights when no step is active
     let (stable_weight, canary_weight) = calculate_traffic_weights(&rollout);
@@ -941,42 +1005,11 @@ async fn test_calculate_traffic_weights_no_step() {
 #[tokio::test]
 async fn test_calculate_traffic_weights_complete() {
     // Test weight calculation when rollout is complete (100% canary)
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![
-                        CanaryStep {
-                            set_weight: Some(20),
-                            pause: None,
-                        },
-                        CanaryStep {
-                            set_weight: Some(100),
-                            pause: None,
-                        },
-                    ],
-                    analysis: None,
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(1), // Last step: 100% canary
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_step(100, None)
+        .with_step_index(1)
+        .build();
 
     // Calculate weights for final step
     let (stable_weight, canary_weight) = calculate_traffic_weights(&rollout);
@@ -988,36 +1021,10 @@ async fn test_calculate_traffic_weights_complete() {
 #[tokio::test]
 async fn test_calculate_traffic_weights_beyond_steps() {
     // Test weight calculation when step index is beyond available steps
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![CanaryStep {
-                        set_weight: Some(20),
-                        pause: None,
-                    }],
-                    analysis: None,
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(5), // Beyond available steps (only 1 step)
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_step_index(5)
+        .build();
 
     // When step index exceeds steps, rollout is complete (100% canary)
     let (stable_weight, canary_weight) = calculate_traffic_weights(&rollout);
@@ -1029,36 +1036,10 @@ async fn test_calculate_traffic_weights_beyond_steps() {
 #[tokio::test]
 async fn test_build_httproute_backend_weights() {
     // Test building HTTPRoute backendRefs with correct weights
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![CanaryStep {
-                        set_weight: Some(20),
-                        pause: None,
-                    }],
-                    analysis: None,
-                    traffic_routing: None, // No HTTPRoute for ReplicaSet unit tests
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(0), // 20% canary
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_step_index(0)
+        .build();
 
     // Build backendRefs with weights from rollout
     let backend_refs = build_backend_refs_with_weights(&rollout);
@@ -1084,40 +1065,11 @@ async fn test_build_httproute_backend_weights() {
 #[tokio::test]
 async fn test_convert_to_gateway_api_backend_refs() {
     // Test conversion from our HTTPBackendRef to gateway-api HTTPRouteRulesBackendRefs
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![CanaryStep {
-                        set_weight: Some(20),
-                        pause: None,
-                    }],
-                    analysis: None,
-                    traffic_routing: Some(TrafficRouting {
-                        gateway_api: Some(GatewayAPIRouting {
-                            http_route: "test-route".to_string(),
-                        }),
-                    }),
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(0), // 20% canary
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_traffic_routing("test-route")
+        .with_step_index(0)
+        .build();
 
     // Convert to gateway-api backend refs
     let gateway_backend_refs = build_gateway_api_backend_refs(&rollout);
@@ -1146,6 +1098,26 @@ async fn test_convert_to_gateway_api_backend_refs() {
     assert_eq!(canary.group.as_deref(), Some(""));
 }
 
+#[test]
+fn test_gateway_api_backend_refs_use_resolved_ports() {
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_traffic_routing("test-route")
+        .with_step_index(0)
+        .build();
+
+    let backend_refs = build_gateway_api_backend_refs_with_ports(&rollout, 8080, 8081);
+
+    let port_of = |name: &str| {
+        backend_refs
+            .iter()
+            .find(|b| b.name == name)
+            .and_then(|b| b.port)
+    };
+    assert_eq!(port_of("test-app-stable"), Some(8080));
+    assert_eq!(port_of("test-app-canary"), Some(8081));
+}
+
 #[tokio::test]
 async fn test_gateway_api_backend_refs_no_canary_strategy() {
     // Test that we return empty vec when no canary strategy exists
@@ -1164,6 +1136,10 @@ async fn test_gateway_api_backend_refs_no_canary_strategy() {
                 blue_green: None,
                 canary: None,
             }, // No canary strategy
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -1179,39 +1155,10 @@ async fn test_gateway_api_backend_refs_no_canary_strategy() {
 #[tokio::test]
 async fn test_initialize_rollout_status() {
     // Test that a new Rollout gets initialized with status.currentStepIndex = 0
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![
-                        CanaryStep {
-                            set_weight: Some(20),
-                            pause: None,
-                        },
-                        CanaryStep {
-                            set_weight: Some(50),
-                            pause: None,
-                        },
-                    ],
-                    analysis: None,
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: None, // No status yet - should be initialized
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_step(50, None)
+        .build();
 
     // Function to test: initialize_rollout_status
     // Should return a RolloutStatus with:
@@ -1233,43 +1180,12 @@ async fn test_initialize_rollout_status() {
 async fn test_should_progress_to_next_step() {
     // Test that we detect when it's time to progress to the next step
     // For now: progress immediately (no pause, no analysis)
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![
-                        CanaryStep {
-                            set_weight: Some(20),
-                            pause: None, // No pause - should progress immediately
-                        },
-                        CanaryStep {
-                            set_weight: Some(50),
-                            pause: None,
-                        },
-                    ],
-                    analysis: None,
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(0),
-            phase: Some(Phase::Progressing),
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_step(50, None)
+        .with_step_index(0)
+        .with_phase(Phase::Progressing)
+        .build();
 
     // Function to test: should_progress_to_next_step
     // Returns true if:
@@ -1283,45 +1199,12 @@ async fn test_should_progress_to_next_step() {
 #[tokio::test]
 async fn test_should_not_progress_when_paused() {
     // Test that we DON'T progress when current step has pause
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![
-                        CanaryStep {
-                            set_weight: Some(20),
-                            pause: Some(crate::crd::rollout::PauseDuration {
-                                duration: Some("5m".to_string()),
-                            }),
-                        },
-                        CanaryStep {
-                            set_weight: Some(50),
-                            pause: None,
-                        },
-                    ],
-                    analysis: None,
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(0),
-            phase: Some(Phase::Paused), // Currently paused
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, Some("5m"))
+        .with_step(50, None)
+        .with_step_index(0)
+        .with_phase(Phase::Paused)
+        .build();
 
     let should_progress = should_progress_to_next_step(&rollout);
 
@@ -1331,44 +1214,11 @@ async fn test_should_not_progress_when_paused() {
 #[tokio::test]
 async fn test_advance_to_next_step() {
     // Test advancing from step 0 to step 1
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![
-                        CanaryStep {
-                            set_weight: Some(20),
-                            pause: None,
-                        },
-                        CanaryStep {
-                            set_weight: Some(50),
-                            pause: None,
-                        },
-                    ],
-                    analysis: None,
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(0),
-            current_weight: Some(20),
-            phase: Some(Phase::Progressing),
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_step(50, None)
+        .with_status(0, 20, Phase::Progressing)
+        .build();
 
     // Function to test: advance_to_next_step
     // Returns new RolloutStatus with:
@@ -1389,44 +1239,11 @@ async fn test_advance_to_next_step() {
 #[tokio::test]
 async fn test_advance_to_final_step() {
     // Test advancing to the last step marks rollout as Complete
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![
-                        CanaryStep {
-                            set_weight: Some(20),
-                            pause: None,
-                        },
-                        CanaryStep {
-                            set_weight: Some(100), // Final step: 100% canary
-                            pause: None,
-                        },
-                    ],
-                    analysis: None,
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(0),
-            current_weight: Some(20),
-            phase: Some(Phase::Progressing),
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_step(100, None)
+        .with_status(0, 20, Phase::Progressing)
+        .build();
 
     // Advance from step 0 to step 1 (final step)
     let new_status = advance_to_next_step(&rollout);
@@ -1448,39 +1265,10 @@ async fn test_advance_to_final_step() {
 #[tokio::test]
 async fn test_compute_desired_status_for_new_rollout() {
     // Test that a new Rollout (no status) gets initialized
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![
-                        CanaryStep {
-                            set_weight: Some(20),
-                            pause: None,
-                        },
-                        CanaryStep {
-                            set_weight: Some(50),
-                            pause: None,
-                        },
-                    ],
-                    analysis: None,
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: None, // No status - should be initialized
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_step(50, None)
+        .build();
 
     // Function to test: compute_desired_status
     // Returns the status that should be written to K8s
@@ -1495,44 +1283,11 @@ async fn test_compute_desired_status_for_new_rollout() {
 #[tokio::test]
 async fn test_compute_desired_status_progresses_step() {
     // Test that a Rollout at step 0 progresses to step 1
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![
-                        CanaryStep {
-                            set_weight: Some(20),
-                            pause: None, // No pause - should progress
-                        },
-                        CanaryStep {
-                            set_weight: Some(50),
-                            pause: None,
-                        },
-                    ],
-                    analysis: None,
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(0),
-            current_weight: Some(20),
-            phase: Some(Phase::Progressing),
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_step(50, None)
+        .with_status(0, 20, Phase::Progressing)
+        .build();
 
     // Should progress to step 1
     let desired_status = compute_desired_status(&rollout);
@@ -1545,46 +1300,11 @@ async fn test_compute_desired_status_progresses_step() {
 #[tokio::test]
 async fn test_compute_desired_status_respects_pause() {
     // Test that a Rollout at a paused step doesn't progress
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![
-                        CanaryStep {
-                            set_weight: Some(20),
-                            pause: Some(crate::crd::rollout::PauseDuration {
-                                duration: Some("5m".to_string()),
-                            }),
-                        },
-                        CanaryStep {
-                            set_weight: Some(50),
-                            pause: None,
-                        },
-                    ],
-                    analysis: None,
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(0),
-            current_weight: Some(20),
-            phase: Some(Phase::Paused),
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(20, Some("5m"))
+        .with_step(50, None)
+        .with_status(0, 20, Phase::Paused)
+        .build();
 
     // Should NOT progress (paused)
     let desired_status = compute_desired_status(&rollout);
@@ -1640,19 +1360,68 @@ fn test_parse_duration_no_number() {
 }
 
 // ============================================================================
-// Duration Validation Tests
+// Duration Parsing Invariants (no panics on arbitrary input)
 // ============================================================================
 
 #[test]
-fn test_parse_duration_zero_rejected() {
-    // ARRANGE & ACT: Try to parse zero duration
-    let duration = parse_duration("0s");
+fn test_parse_duration_all_whitespace_returns_none() {
+    assert!(parse_duration("   ").is_none());
+    assert!(parse_duration("\t\n\r ").is_none());
+}
 
-    // ASSERT: Should reject zero duration
-    assert!(
-        duration.is_none(),
-        "Zero duration should be rejected (invalid pause)"
-    );
+#[test]
+fn test_parse_duration_embedded_nul_returns_none() {
+    assert!(parse_duration("\0").is_none());
+    assert!(parse_duration("5\0s").is_none());
+    assert!(parse_duration("5s\0").is_none());
+}
+
+#[test]
+fn test_parse_duration_very_long_digit_string_returns_none() {
+    // ARRANGE: 10,000 digits followed by 's' (overflows u64)
+    let input = format!("{}s", "9".repeat(10_000));
+
+    // ACT & ASSERT: Should return None, not panic
+    assert!(parse_duration(&input).is_none());
+}
+
+#[test]
+fn test_parse_duration_mixed_encoding_returns_none() {
+    // Multi-byte last character previously caused a slice on a non-char boundary
+    let inputs = [
+        "5é",
+        "5ß",
+        "30秒",
+        "1🕐",
+        "é",
+        "5\u{FEFF}s",
+        "５s",
+        "3\u{0301}m",
+    ];
+
+    for input in inputs {
+        assert!(
+            parse_duration(input).is_none(),
+            "Should return None for {:?}",
+            input
+        );
+    }
+}
+
+// ============================================================================
+// Duration Validation Tests
+// ============================================================================
+
+#[test]
+fn test_parse_duration_zero_rejected() {
+    // ARRANGE & ACT: Try to parse zero duration
+    let duration = parse_duration("0s");
+
+    // ASSERT: Should reject zero duration
+    assert!(
+        duration.is_none(),
+        "Zero duration should be rejected (invalid pause)"
+    );
 }
 
 #[test]
@@ -1757,10 +1526,12 @@ fn test_should_progress_when_pause_duration_elapsed() {
                 pause: Some(PauseDuration {
                     duration: Some("5m".to_string()),
                 }),
+                analysis: None,
             },
             CanaryStep {
                 set_weight: Some(100),
                 pause: None,
+                analysis: None,
             },
         ];
     }
@@ -1799,10 +1570,12 @@ fn test_should_not_progress_when_pause_duration_not_elapsed() {
                 pause: Some(PauseDuration {
                     duration: Some("5m".to_string()),
                 }),
+                analysis: None,
             },
             CanaryStep {
                 set_weight: Some(100),
                 pause: None,
+                analysis: None,
             },
         ];
     }
@@ -1840,10 +1613,12 @@ fn test_advance_sets_pause_start_time() {
                 pause: Some(PauseDuration {
                     duration: Some("5m".to_string()),
                 }),
+                analysis: None,
             },
             CanaryStep {
                 set_weight: Some(100),
                 pause: None,
+                analysis: None,
             },
         ];
     }
@@ -1891,10 +1666,12 @@ fn test_advance_clears_pause_start_time_when_no_pause() {
                 pause: Some(PauseDuration {
                     duration: Some("5m".to_string()),
                 }),
+                analysis: None,
             },
             CanaryStep {
                 set_weight: Some(100),
                 pause: None,
+                analysis: None,
             },
         ];
     }
@@ -1949,10 +1726,12 @@ fn test_has_promote_annotation() {
             CanaryStep {
                 set_weight: Some(20),
                 pause: Some(PauseDuration { duration: None }), // Indefinite pause
+                analysis: None,
             },
             CanaryStep {
                 set_weight: Some(100),
                 pause: None,
+                analysis: None,
             },
         ];
     }
@@ -1987,10 +1766,12 @@ fn test_should_progress_when_promoted() {
             CanaryStep {
                 set_weight: Some(20),
                 pause: Some(PauseDuration { duration: None }), // Indefinite pause
+                analysis: None,
             },
             CanaryStep {
                 set_weight: Some(100),
                 pause: None,
+                analysis: None,
             },
         ];
     }
@@ -2027,31 +1808,92 @@ fn test_should_progress_when_promoted() {
     );
 }
 
+fn create_rollout_paused_at_step(pause_at_step: i32, current_step: i32) -> Rollout {
+    let mut rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_step(50, None)
+        .with_step(100, None)
+        .with_status(current_step, 20, Phase::Progressing)
+        .build();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.pause_at_step = Some(pause_at_step);
+    }
+    rollout
+}
+
+#[test]
+fn test_pause_at_step_holds_at_configured_step() {
+    // Step 1 has no pause of its own, but pauseAtStep holds it
+    let rollout = create_rollout_paused_at_step(1, 1);
+
+    assert!(is_held_at_pause_step(&rollout));
+    assert!(
+        !should_progress_to_next_step(&rollout),
+        "Should hold at pauseAtStep without promotion"
+    );
+    assert_eq!(compute_desired_status(&rollout).current_step_index, Some(1));
+}
+
+#[test]
+fn test_pause_at_step_released_by_promote_annotation() {
+    let mut rollout = create_rollout_paused_at_step(1, 1);
+    rollout
+        .metadata
+        .annotations
+        .get_or_insert_with(BTreeMap::new)
+        .insert(promote_annotation_key(), "true".to_string());
+
+    assert!(should_progress_to_next_step(&rollout));
+    assert_eq!(compute_desired_status(&rollout).current_step_index, Some(2));
+}
+
+#[test]
+fn test_pause_at_step_progresses_normally_at_other_steps() {
+    let rollout = create_rollout_paused_at_step(1, 0);
+
+    assert!(!is_held_at_pause_step(&rollout));
+    assert!(should_progress_to_next_step(&rollout));
+    assert_eq!(compute_desired_status(&rollout).current_step_index, Some(1));
+}
+
+#[test]
+fn test_validate_rollout_pause_at_step_out_of_range() {
+    let rollout = create_rollout_paused_at_step(3, 0);
+
+    let error = validate_rollout(&rollout).unwrap_err();
+
+    assert!(
+        error.contains("spec.strategy.canary.pauseAtStep must be 0-2, got 3"),
+        "Expected pauseAtStep range error, got: {}",
+        error
+    );
+}
+
 // TDD Cycle 1: RED - Test replica calculation for canary scaling
 #[test]
 fn test_calculate_replica_split_0_percent() {
-    let (stable, canary) = calculate_replica_split(3, 0);
+    let (stable, canary) = calculate_replica_split_with_surge(3, 0, false);
     assert_eq!(stable, 3, "0% weight should give all replicas to stable");
     assert_eq!(canary, 0, "0% weight should give 0 canary replicas");
 }
 
 #[test]
 fn test_calculate_replica_split_10_percent() {
-    let (stable, canary) = calculate_replica_split(3, 10);
+    let (stable, canary) = calculate_replica_split_with_surge(3, 10, false);
     assert_eq!(stable, 2, "10% of 3 should give 2 stable replicas");
     assert_eq!(canary, 1, "10% of 3 should give 1 canary replica (ceil)");
 }
 
 #[test]
 fn test_calculate_replica_split_50_percent() {
-    let (stable, canary) = calculate_replica_split(3, 50);
+    let (stable, canary) = calculate_replica_split_with_surge(3, 50, false);
     assert_eq!(stable, 1, "50% of 3 should give 1 stable replica");
     assert_eq!(canary, 2, "50% of 3 should give 2 canary replicas (ceil)");
 }
 
 #[test]
 fn test_calculate_replica_split_100_percent() {
-    let (stable, canary) = calculate_replica_split(3, 100);
+    let (stable, canary) = calculate_replica_split_with_surge(3, 100, false);
     assert_eq!(stable, 0, "100% weight should give 0 stable replicas");
     assert_eq!(canary, 3, "100% weight should give all replicas to canary");
 }
@@ -2059,18 +1901,200 @@ fn test_calculate_replica_split_100_percent() {
 #[test]
 fn test_calculate_replica_split_with_rounding() {
     // 33% of 3 = 0.99, should ceil to 1
-    let (stable, canary) = calculate_replica_split(3, 33);
+    let (stable, canary) = calculate_replica_split_with_surge(3, 33, false);
     assert_eq!(canary, 1, "33% of 3 should ceil to 1 canary replica");
     assert_eq!(stable, 2, "Remaining should be 2 stable replicas");
 }
 
 #[test]
 fn test_calculate_replica_split_large_count() {
-    let (stable, canary) = calculate_replica_split(10, 25);
+    let (stable, canary) = calculate_replica_split_with_surge(10, 25, false);
     assert_eq!(canary, 3, "25% of 10 should ceil to 3 canary replicas");
     assert_eq!(stable, 7, "Remaining should be 7 stable replicas");
 }
 
+#[test]
+fn test_apply_replica_floors_keeps_min_stable_at_intermediate_weight() {
+    // 90% of 3 ceils to 3 canary replicas, leaving no stable pod
+    let split = calculate_replica_split_with_surge(3, 90, false);
+    assert_eq!(split, (0, 3));
+
+    let (stable, canary) = apply_replica_floors(3, 90, split, None, Some(1));
+    assert_eq!(stable, 1, "minStableReplicas should keep 1 stable replica");
+    assert_eq!(
+        canary, 2,
+        "Canary should give up a replica to the stable floor"
+    );
+}
+
+#[test]
+fn test_apply_replica_floors_keeps_min_canary_at_intermediate_weight() {
+    let split = calculate_replica_split_with_surge(10, 5, false);
+    assert_eq!(split, (9, 1));
+
+    let (stable, canary) = apply_replica_floors(10, 5, split, Some(2), None);
+    assert_eq!(canary, 2, "minCanaryReplicas should raise canary to 2");
+    assert_eq!(
+        stable, 8,
+        "Stable should give up a replica to the canary floor"
+    );
+}
+
+#[test]
+fn test_apply_replica_floors_both_floors_exceed_total() {
+    // Floors take precedence over spec.replicas: the rollout surges
+    let split = calculate_replica_split_with_surge(1, 50, false);
+    let (stable, canary) = apply_replica_floors(1, 50, split, Some(1), Some(1));
+    assert_eq!((stable, canary), (1, 1));
+}
+
+#[test]
+fn test_apply_replica_floors_released_at_0_and_100_percent() {
+    let at_zero = apply_replica_floors(
+        3,
+        0,
+        calculate_replica_split_with_surge(3, 0, false),
+        Some(1),
+        Some(1),
+    );
+    assert_eq!(at_zero, (3, 0), "0% should scale canary to 0 despite floor");
+
+    let at_full = apply_replica_floors(
+        3,
+        100,
+        calculate_replica_split_with_surge(3, 100, false),
+        Some(1),
+        Some(1),
+    );
+    assert_eq!(
+        at_full,
+        (0, 3),
+        "100% should scale stable to 0 despite floor"
+    );
+}
+
+#[test]
+fn test_apply_replica_floors_without_floors_is_unchanged() {
+    let split = calculate_replica_split_with_surge(10, 25, false);
+    assert_eq!(apply_replica_floors(10, 25, split, None, None), split);
+}
+
+#[test]
+fn test_full_stable_split_adds_canary_on_top_of_stable() {
+    // Default split at 20/50/80%: stable shrinks as canary grows
+    assert_eq!(calculate_replica_split_with_surge(10, 20, false), (8, 2));
+    assert_eq!(calculate_replica_split_with_surge(10, 50, false), (5, 5));
+    assert_eq!(calculate_replica_split_with_surge(10, 80, false), (2, 8));
+
+    // keepStableAtFullScale: stable stays at 10, canary is additive
+    assert_eq!(calculate_full_stable_replica_split(10, 20, None), (10, 2));
+    assert_eq!(calculate_full_stable_replica_split(10, 50, None), (10, 5));
+    assert_eq!(calculate_full_stable_replica_split(10, 80, None), (10, 8));
+}
+
+#[test]
+fn test_full_stable_split_zeroes_stable_only_at_completion() {
+    assert_eq!(calculate_full_stable_replica_split(10, 0, Some(2)), (10, 0));
+    assert_eq!(calculate_full_stable_replica_split(10, 100, None), (0, 10));
+}
+
+#[test]
+fn test_full_stable_split_applies_min_canary_replicas() {
+    assert_eq!(calculate_full_stable_replica_split(10, 5, Some(3)), (10, 3));
+}
+
+#[test]
+fn test_keeps_stable_at_full_scale_defaults_to_false() {
+    let mut rollout = create_test_rollout_with_canary();
+    assert!(!keeps_stable_at_full_scale(&rollout));
+
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.keep_stable_at_full_scale = Some(true);
+    }
+    assert!(keeps_stable_at_full_scale(&rollout));
+}
+
+#[test]
+fn test_replica_split_with_surge_keeps_stable_whole() {
+    assert_eq!(calculate_replica_split_with_surge(10, 0, true), (10, 0));
+    assert_eq!(calculate_replica_split_with_surge(10, 25, true), (10, 3));
+    assert_eq!(calculate_replica_split_with_surge(10, 80, true), (10, 8));
+    // Stable is only scaled down once the canary takes all traffic
+    assert_eq!(calculate_replica_split_with_surge(10, 100, true), (0, 10));
+}
+
+/// Canary rollout with 4 replicas surging up to `max_surge` extra canary pods
+fn surge_rollout(max_surge: Option<IntOrString>) -> Rollout {
+    let mut rollout = RolloutBuilder::new()
+        .with_replicas(4)
+        .with_step(50, None)
+        .with_step(100, None)
+        .build();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.replica_surge = Some(true);
+        canary.max_surge = max_surge;
+    }
+    rollout
+}
+
+#[test]
+fn test_replica_surge_adds_canary_on_top_of_spec_replicas() {
+    let rollout = surge_rollout(None);
+
+    assert!(uses_replica_surge(&rollout));
+    assert_eq!(calculate_canary_replicas(&rollout, 50), (4, 2));
+    assert_eq!(calculate_canary_replicas(&rollout, 75), (4, 3));
+    assert_eq!(calculate_canary_replicas(&rollout, 100), (0, 4));
+}
+
+#[test]
+fn test_replica_surge_bounded_by_max_surge() {
+    // 25% of 4 rounds up to 1 surge pod, like a Deployment's maxSurge
+    let rollout = surge_rollout(Some(IntOrString::String("25%".to_string())));
+
+    assert_eq!(max_surge_replicas(&rollout), 1);
+    assert_eq!(calculate_canary_replicas(&rollout, 50), (4, 1));
+    assert_eq!(calculate_canary_replicas(&rollout, 100), (0, 4));
+
+    let rollout = surge_rollout(Some(IntOrString::Int(2)));
+    assert_eq!(calculate_canary_replicas(&rollout, 75), (4, 2));
+}
+
+#[test]
+fn test_validate_replica_surge() {
+    assert!(validate_rollout(&surge_rollout(Some(IntOrString::String("50%".to_string())))).is_ok());
+
+    let zero = validate_rollout(&surge_rollout(Some(IntOrString::Int(0)))).unwrap_err();
+    assert!(zero.contains("maxSurge resolves to 0"), "got: {}", zero);
+
+    let invalid = validate_rollout(&surge_rollout(Some(IntOrString::String(
+        "half".to_string(),
+    ))))
+    .unwrap_err();
+    assert!(
+        invalid.contains("spec.strategy.canary.maxSurge"),
+        "got: {}",
+        invalid
+    );
+
+    let mut without_surge = surge_rollout(Some(IntOrString::Int(1)));
+    if let Some(canary) = without_surge.spec.strategy.canary.as_mut() {
+        canary.replica_surge = None;
+    }
+    let error = validate_rollout(&without_surge).unwrap_err();
+    assert!(
+        error.contains("maxSurge requires replicaSurge"),
+        "got: {}",
+        error
+    );
+
+    let mut both = surge_rollout(None);
+    if let Some(canary) = both.spec.strategy.canary.as_mut() {
+        canary.keep_stable_at_full_scale = Some(true);
+    }
+    assert!(validate_rollout(&both).is_err());
+}
+
 // TDD Cycle 2: RED - Test that reconcile scales ReplicaSets based on status
 #[tokio::test]
 async fn test_build_replicasets_with_canary_weight() {
@@ -2087,11 +2111,11 @@ async fn test_build_replicasets_with_canary_weight() {
     // ACT: Calculate what replica counts should be
     let current_weight = rollout.status.as_ref().unwrap().current_weight.unwrap_or(0);
     let (stable_replicas, canary_replicas) =
-        calculate_replica_split(rollout.spec.replicas, current_weight);
+        calculate_replica_split_with_surge(rollout.spec.replicas, current_weight, false);
 
     // Build ReplicaSets with calculated counts
-    let stable_rs = build_replicaset(&rollout, "stable", stable_replicas).unwrap();
-    let canary_rs = build_replicaset(&rollout, "canary", canary_replicas).unwrap();
+    let stable_rs = build_replicaset(&rollout, "stable", "stable", stable_replicas).unwrap();
+    let canary_rs = build_replicaset(&rollout, "canary", "canary", canary_replicas).unwrap();
 
     // ASSERT: Verify replica counts match the split
     assert_eq!(
@@ -2120,11 +2144,11 @@ async fn test_build_replicasets_at_initialization() {
         .and_then(|s| s.current_weight)
         .unwrap_or(0);
     let (stable_replicas, canary_replicas) =
-        calculate_replica_split(rollout.spec.replicas, current_weight);
+        calculate_replica_split_with_surge(rollout.spec.replicas, current_weight, false);
 
     // Build ReplicaSets
-    let stable_rs = build_replicaset(&rollout, "stable", stable_replicas).unwrap();
-    let canary_rs = build_replicaset(&rollout, "canary", canary_replicas).unwrap();
+    let stable_rs = build_replicaset(&rollout, "stable", "stable", stable_replicas).unwrap();
+    let canary_rs = build_replicaset(&rollout, "canary", "canary", canary_replicas).unwrap();
 
     // ASSERT: At initialization, all replicas should be stable
     assert_eq!(
@@ -2154,11 +2178,11 @@ async fn test_build_replicasets_at_completion() {
     // ACT: Calculate replica split
     let current_weight = rollout.status.as_ref().unwrap().current_weight.unwrap_or(0);
     let (stable_replicas, canary_replicas) =
-        calculate_replica_split(rollout.spec.replicas, current_weight);
+        calculate_replica_split_with_surge(rollout.spec.replicas, current_weight, false);
 
     // Build ReplicaSets
-    let stable_rs = build_replicaset(&rollout, "stable", stable_replicas).unwrap();
-    let canary_rs = build_replicaset(&rollout, "canary", canary_replicas).unwrap();
+    let stable_rs = build_replicaset(&rollout, "stable", "stable", stable_replicas).unwrap();
+    let canary_rs = build_replicaset(&rollout, "canary", "canary", canary_replicas).unwrap();
 
     // ASSERT: At completion, all replicas should be canary
     assert_eq!(
@@ -2196,10 +2220,12 @@ async fn test_replicaset_scaling_on_weight_change() {
         CanaryStep {
             set_weight: Some(20), // Step 0: 20% canary
             pause: None,
+            analysis: None,
         },
         CanaryStep {
             set_weight: Some(50), // Step 1: 50% canary
             pause: None,
+            analysis: None,
         },
     ];
 
@@ -2214,11 +2240,13 @@ async fn test_replicaset_scaling_on_weight_change() {
 
     // ACT: Calculate replica split for step 0 (20% weight)
     let (stable_replicas_step0, canary_replicas_step0) =
-        calculate_replica_split(rollout.spec.replicas, 20);
+        calculate_replica_split_with_surge(rollout.spec.replicas, 20, false);
 
     // Build ReplicaSets for step 0
-    let stable_rs_step0 = build_replicaset(&rollout, "stable", stable_replicas_step0).unwrap();
-    let canary_rs_step0 = build_replicaset(&rollout, "canary", canary_replicas_step0).unwrap();
+    let stable_rs_step0 =
+        build_replicaset(&rollout, "stable", "stable", stable_replicas_step0).unwrap();
+    let canary_rs_step0 =
+        build_replicaset(&rollout, "canary", "canary", canary_replicas_step0).unwrap();
 
     // ASSERT: Verify replica counts at step 0 (20% canary)
     // With 10 replicas total: canary=2 (20%), stable=8 (80%)
@@ -2244,11 +2272,13 @@ async fn test_replicaset_scaling_on_weight_change() {
 
     // Calculate replica split for step 1 (50% weight)
     let (stable_replicas_step1, canary_replicas_step1) =
-        calculate_replica_split(rollout.spec.replicas, 50);
+        calculate_replica_split_with_surge(rollout.spec.replicas, 50, false);
 
     // Build ReplicaSets for step 1
-    let stable_rs_step1 = build_replicaset(&rollout, "stable", stable_replicas_step1).unwrap();
-    let canary_rs_step1 = build_replicaset(&rollout, "canary", canary_replicas_step1).unwrap();
+    let stable_rs_step1 =
+        build_replicaset(&rollout, "stable", "stable", stable_replicas_step1).unwrap();
+    let canary_rs_step1 =
+        build_replicaset(&rollout, "canary", "canary", canary_replicas_step1).unwrap();
 
     // ASSERT: Verify replica counts changed at step 1 (50% weight)
     // With 10 replicas total: canary=5 (50%), stable=5 (50%)
@@ -2315,6 +2345,110 @@ async fn test_replicaset_scaling_on_weight_change() {
     );
 }
 
+fn stale_replicaset(rs_type: &str) -> ReplicaSet {
+    let mut rollout = create_test_rollout_with_canary();
+    let stale = build_replicaset(&rollout, rs_type, rs_type, 1).unwrap();
+
+    // Template changes after the ReplicaSet was created (new image)
+    if let Some(pod_spec) = rollout.spec.template.spec.as_mut() {
+        pod_spec.containers[0].image = Some("nginx:2.0".to_string());
+    }
+    let desired = build_replicaset(&rollout, rs_type, rs_type, 1).unwrap();
+    assert_ne!(
+        replicaset_template_hash(&stale),
+        replicaset_template_hash(&desired)
+    );
+    stale
+}
+
+fn desired_replicaset(rs_type: &str) -> ReplicaSet {
+    let mut rollout = create_test_rollout_with_canary();
+    if let Some(pod_spec) = rollout.spec.template.spec.as_mut() {
+        pod_spec.containers[0].image = Some("nginx:2.0".to_string());
+    }
+    build_replicaset(&rollout, rs_type, rs_type, 1).unwrap()
+}
+
+#[test]
+fn test_existing_replicaset_with_same_template_is_scaled() {
+    let rollout = create_test_rollout_with_canary();
+    let existing = build_replicaset(&rollout, "canary", "canary", 1).unwrap();
+    let desired = build_replicaset(&rollout, "canary", "canary", 3).unwrap();
+
+    assert_eq!(
+        classify_existing_replicaset(&existing, &desired, "canary"),
+        ExistingReplicaSetAction::Scale
+    );
+}
+
+#[test]
+fn test_stale_canary_replicaset_is_replaced() {
+    let existing = stale_replicaset("canary");
+    let desired = desired_replicaset("canary");
+
+    assert_eq!(
+        classify_existing_replicaset(&existing, &desired, "canary"),
+        ExistingReplicaSetAction::Replace
+    );
+}
+
+#[test]
+fn test_canary_replicaset_without_hash_is_replaced() {
+    let mut existing = stale_replicaset("canary");
+    existing.metadata.labels = Some(BTreeMap::from([(
+        "app".to_string(),
+        "test-app".to_string(),
+    )]));
+    let desired = desired_replicaset("canary");
+
+    assert_eq!(
+        classify_existing_replicaset(&existing, &desired, "canary"),
+        ExistingReplicaSetAction::Replace
+    );
+}
+
+#[test]
+fn test_stale_stable_replicaset_is_only_scaled() {
+    let existing = stale_replicaset("stable");
+    let desired = desired_replicaset("stable");
+
+    assert_eq!(
+        classify_existing_replicaset(&existing, &desired, "stable"),
+        ExistingReplicaSetAction::Scale
+    );
+}
+
+#[test]
+fn test_stale_replicaset_owned_by_other_controller_is_left_alone() {
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+
+    let mut existing = stale_replicaset("canary");
+    existing.metadata.owner_references = Some(vec![OwnerReference {
+        api_version: "apps/v1".to_string(),
+        kind: "Deployment".to_string(),
+        name: "test-app".to_string(),
+        uid: "deployment-uid".to_string(),
+        controller: Some(true),
+        block_owner_deletion: None,
+    }]);
+    let desired = desired_replicaset("canary");
+
+    assert_eq!(
+        classify_existing_replicaset(&existing, &desired, "canary"),
+        ExistingReplicaSetAction::OwnedByOther
+    );
+}
+
+#[test]
+fn test_replicaset_template_hash_reads_annotation_when_label_not_managed() {
+    let mut rollout = create_test_rollout_with_canary();
+    rollout.spec.manage_pod_template_hash = Some(false);
+    let rs = build_replicaset(&rollout, "canary", "canary", 1).unwrap();
+
+    let expected = compute_pod_template_hash(&build_pod_template(&rollout)).unwrap();
+    assert_eq!(replicaset_template_hash(&rs), Some(expected.as_str()));
+}
+
 #[tokio::test]
 async fn test_validate_rollout_negative_replicas() {
     // ARRANGE: Create rollout with negative replicas
@@ -2334,6 +2468,28 @@ async fn test_validate_rollout_negative_replicas() {
     );
 }
 
+#[tokio::test]
+async fn test_validate_rollout_negative_min_canary_replicas() {
+    let mut rollout = create_test_rollout_with_canary();
+    rollout
+        .spec
+        .strategy
+        .canary
+        .as_mut()
+        .unwrap()
+        .min_canary_replicas = Some(-1);
+
+    let result = validate_rollout(&rollout);
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert!(
+        error.contains("spec.strategy.canary.minCanaryReplicas must be >= 0"),
+        "Expected replica floor error, got: {}",
+        error
+    );
+}
+
 #[tokio::test]
 async fn test_validate_rollout_weight_out_of_range() {
     // ARRANGE: Create rollout with weight > 100
@@ -2341,6 +2497,7 @@ async fn test_validate_rollout_weight_out_of_range() {
     rollout.spec.strategy.canary.as_mut().unwrap().steps = vec![CanaryStep {
         set_weight: Some(150), // Invalid: > 100
         pause: None,
+        analysis: None,
     }];
 
     // ACT: Validate rollout
@@ -2363,6 +2520,7 @@ async fn test_validate_rollout_negative_weight() {
     rollout.spec.strategy.canary.as_mut().unwrap().steps = vec![CanaryStep {
         set_weight: Some(-10), // Invalid: < 0
         pause: None,
+        analysis: None,
     }];
 
     // ACT: Validate rollout
@@ -2387,6 +2545,7 @@ async fn test_validate_rollout_invalid_pause_duration() {
         pause: Some(PauseDuration {
             duration: Some("invalid".to_string()), // Invalid format
         }),
+        analysis: None,
     }];
 
     // ACT: Validate rollout
@@ -2460,6 +2619,7 @@ async fn test_validate_rollout_empty_httproute() {
     rollout.spec.strategy.canary.as_mut().unwrap().steps = vec![CanaryStep {
         set_weight: Some(50),
         pause: None,
+        analysis: None,
     }];
     rollout
         .spec
@@ -2470,7 +2630,11 @@ async fn test_validate_rollout_empty_httproute() {
         .traffic_routing = Some(TrafficRouting {
         gateway_api: Some(GatewayAPIRouting {
             http_route: String::new(), // Empty HTTPRoute name
+            gateway_api_version: None,
+            verify_accepted: None,
+            canary_match: None,
         }),
+        linkerd: None,
     });
 
     // ACT: Validate rollout
@@ -2497,10 +2661,12 @@ async fn test_validate_rollout_valid_rollout() {
             pause: Some(PauseDuration {
                 duration: Some("30s".to_string()),
             }),
+            analysis: None,
         },
         CanaryStep {
             set_weight: Some(100),
             pause: None,
+            analysis: None,
         },
     ];
     rollout
@@ -2512,7 +2678,11 @@ async fn test_validate_rollout_valid_rollout() {
         .traffic_routing = Some(TrafficRouting {
         gateway_api: Some(GatewayAPIRouting {
             http_route: "my-httproute".to_string(),
+            gateway_api_version: None,
+            verify_accepted: None,
+            canary_match: None,
         }),
+        linkerd: None,
     });
 
     // ACT: Validate rollout
@@ -2557,6 +2727,7 @@ async fn test_validate_rollout_requires_set_weight_on_steps() {
         pause: Some(PauseDuration {
             duration: Some("30s".to_string()),
         }),
+        analysis: None,
     }];
 
     // ACT: Validate rollout
@@ -2685,51 +2856,31 @@ async fn test_evaluate_rollout_metrics_healthy() {
     use crate::crd::rollout::{AnalysisConfig, MetricConfig, PrometheusConfig};
 
     // ARRANGE: Rollout with analysis config
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![CanaryStep {
-                        set_weight: Some(10),
-                        pause: None,
-                    }],
-                    analysis: Some(AnalysisConfig {
-                        prometheus: Some(PrometheusConfig {
-                            address: Some("http://prometheus:9090".to_string()),
-                        }),
-                        failure_policy: None,
-                        warmup_duration: None,
-                        metrics: vec![MetricConfig {
-                            name: "error-rate".to_string(),
-                            threshold: 5.0,
-                            interval: None,
-                            failure_threshold: None,
-                            min_sample_size: None,
-                        }],
-                    }),
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(0),
-            current_weight: Some(10),
-            phase: Some(Phase::Progressing),
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(10, None)
+        .with_analysis(AnalysisConfig {
+            prometheus: Some(PrometheusConfig {
+                address: Some("http://prometheus:9090".to_string()),
+            }),
+            failure_policy: None,
+            warmup_duration: None,
+            interval: None,
+            metrics: vec![MetricConfig {
+                name: "error-rate".to_string(),
+                threshold: 5.0,
+                interval: None,
+                failure_threshold: None,
+                min_sample_size: None,
+                comparison: None,
+                labels: BTreeMap::new(),
+            }],
+            requirement: None,
+            consecutive_failure_threshold: None,
+            mode: None,
+            tolerance: None,
+        })
+        .with_status(0, 10, Phase::Progressing)
+        .build();
 
     let ctx = Context::new_mock();
 
@@ -2750,7 +2901,7 @@ async fn test_evaluate_rollout_metrics_healthy() {
         .set_mock_response(mock_response.to_string());
 
     // ACT: Evaluate metrics
-    let result = evaluate_rollout_metrics(&rollout, &ctx).await;
+    let result = evaluate_rollout_metrics(&rollout, &ctx).await.verdict;
 
     // ASSERT: Should return Ok(true) - metrics are healthy
     match result {
@@ -2764,51 +2915,31 @@ async fn test_evaluate_rollout_metrics_unhealthy() {
     use crate::crd::rollout::{AnalysisConfig, MetricConfig, PrometheusConfig};
 
     // ARRANGE: Rollout with analysis config
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![CanaryStep {
-                        set_weight: Some(10),
-                        pause: None,
-                    }],
-                    analysis: Some(AnalysisConfig {
-                        prometheus: Some(PrometheusConfig {
-                            address: Some("http://prometheus:9090".to_string()),
-                        }),
-                        failure_policy: None,
-                        warmup_duration: None,
-                        metrics: vec![MetricConfig {
-                            name: "error-rate".to_string(),
-                            threshold: 5.0,
-                            interval: None,
-                            failure_threshold: None,
-                            min_sample_size: None,
-                        }],
-                    }),
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(0),
-            current_weight: Some(10),
-            phase: Some(Phase::Progressing),
-            ..Default::default()
-        }),
-    };
+    let rollout = RolloutBuilder::new()
+        .with_step(10, None)
+        .with_analysis(AnalysisConfig {
+            prometheus: Some(PrometheusConfig {
+                address: Some("http://prometheus:9090".to_string()),
+            }),
+            failure_policy: None,
+            warmup_duration: None,
+            interval: None,
+            metrics: vec![MetricConfig {
+                name: "error-rate".to_string(),
+                threshold: 5.0,
+                interval: None,
+                failure_threshold: None,
+                min_sample_size: None,
+                comparison: None,
+                labels: BTreeMap::new(),
+            }],
+            requirement: None,
+            consecutive_failure_threshold: None,
+            mode: None,
+            tolerance: None,
+        })
+        .with_status(0, 10, Phase::Progressing)
+        .build();
 
     let ctx = Context::new_mock();
 
@@ -2829,7 +2960,7 @@ async fn test_evaluate_rollout_metrics_unhealthy() {
         .set_mock_response(mock_response.to_string());
 
     // ACT: Evaluate metrics
-    let result = evaluate_rollout_metrics(&rollout, &ctx).await;
+    let result = evaluate_rollout_metrics(&rollout, &ctx).await.verdict;
 
     // ASSERT: Should return Ok(false) - metrics are unhealthy
     match result {
@@ -2839,369 +2970,692 @@ async fn test_evaluate_rollout_metrics_unhealthy() {
 }
 
 #[tokio::test]
-async fn test_evaluate_rollout_metrics_no_analysis_config() {
-    // ARRANGE: Rollout WITHOUT analysis config
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("test-rollout".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                blue_green: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-app-canary".to_string(),
-                    stable_service: "test-app-stable".to_string(),
-                    steps: vec![CanaryStep {
-                        set_weight: Some(10),
-                        pause: None,
-                    }],
-                    analysis: None, // No analysis config
-                    traffic_routing: None,
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            current_step_index: Some(0),
-            current_weight: Some(10),
-            phase: Some(Phase::Progressing),
-            ..Default::default()
-        }),
-    };
+async fn test_skip_analysis_annotation_overrides_failed_analysis() {
+    use crate::crd::rollout::{AnalysisConfig, MetricConfig, PrometheusConfig};
 
-    let ctx = Context::new_mock();
+    // ARRANGE: Step 0 analysis is failing, skip-analysis annotation set
+    let rollout = RolloutBuilder::new()
+        .with_step(10, None)
+        .with_step(50, None)
+        .with_analysis(AnalysisConfig {
+            prometheus: Some(PrometheusConfig {
+                address: Some("http://prometheus:9090".to_string()),
+            }),
+            failure_policy: None,
+            warmup_duration: None,
+            interval: None,
+            metrics: vec![MetricConfig {
+                name: "error-rate".to_string(),
+                threshold: 5.0,
+                interval: None,
+                failure_threshold: None,
+                min_sample_size: None,
+                comparison: None,
+                labels: BTreeMap::new(),
+            }],
+            requirement: None,
+            consecutive_failure_threshold: None,
+            mode: None,
+            tolerance: None,
+        })
+        .with_status(0, 10, Phase::Progressing)
+        .with_annotation(&skip_analysis_annotation_key(), "true")
+        .build();
 
-    // ACT: Evaluate metrics
-    let result = evaluate_rollout_metrics(&rollout, &ctx).await;
+    let ctx = Context::new_mock();
+    ctx.prometheus_client.set_mock_response(
+        r#"{"status":"success","data":{"resultType":"vector","result":[{"metric":{},"value":[1234567890,"8.0"]}]}}"#
+            .to_string(),
+    );
+    assert!(!evaluate_rollout_metrics(&rollout, &ctx)
+        .await
+        .verdict
+        .unwrap());
+    assert!(has_skip_analysis_annotation(&rollout));
+
+    // ACT: Progress as if analysis had passed, then record the bypass
+    let mut status = compute_desired_status(&rollout);
+    let used = record_analysis_skipped(&rollout, &mut status);
+
+    // ASSERT: Step advanced and the bypass is in the decision log
+    assert!(used, "Annotation should be used up once the step advances");
+    assert_eq!(status.current_step_index, Some(1));
+    assert_eq!(status.phase, Some(Phase::Progressing));
+    let decision = status.decisions.last().unwrap();
+    assert_eq!(decision.action, DecisionAction::AnalysisSkipped);
+    assert_eq!(decision.reason, DecisionReason::SkipAnalysisAnnotation);
+    assert_eq!(
+        decision.message.as_deref(),
+        Some("skip-analysis annotation")
+    );
+    assert_eq!((decision.from_step, decision.to_step), (Some(0), Some(1)));
+}
 
-    // ASSERT: Should return Ok(true) - no metrics to check = healthy
-    match result {
-        Ok(is_healthy) => assert!(
-            is_healthy,
-            "No analysis config should be considered healthy"
-        ),
-        Err(e) => panic!("Should succeed, got error: {:?}", e),
+/// Canary at step 0 (started at `step_start`) analyzing error-rate every `interval`
+fn rollout_with_analysis_interval(interval: Option<&str>, step_start: DateTime<Utc>) -> Rollout {
+    use crate::crd::rollout::{AnalysisConfig, MetricConfig};
+
+    let mut rollout = RolloutBuilder::new()
+        .with_step(10, None)
+        .with_step(50, None)
+        .with_analysis(AnalysisConfig {
+            prometheus: None,
+            failure_policy: None,
+            warmup_duration: None,
+            interval: interval.map(str::to_string),
+            metrics: vec![MetricConfig {
+                name: "error-rate".to_string(),
+                threshold: 5.0,
+                interval: None,
+                failure_threshold: None,
+                min_sample_size: None,
+                comparison: None,
+                labels: BTreeMap::new(),
+            }],
+            requirement: None,
+            consecutive_failure_threshold: None,
+            mode: None,
+            tolerance: None,
+        })
+        .with_status(0, 10, Phase::Progressing)
+        .build();
+    if let Some(status) = rollout.status.as_mut() {
+        status.step_start_time = Some(step_start.to_rfc3339());
     }
+    rollout
 }
 
-// =============================================================================
-// Warmup Duration Tests
-// =============================================================================
+/// Run analysis at `now` as reconcile does, recording any query in the status
+async fn analyze_at(rollout: &mut Rollout, ctx: &Context, now: DateTime<Utc>) {
+    let run = evaluate_rollout_metrics_at(rollout, ctx, now).await;
+    if let Some(results) = run.metric_results {
+        let healthy = matches!(run.verdict, Ok(true));
+        let mut status = rollout.status.clone().unwrap_or_default();
+        record_analysis_run(rollout, &mut status, healthy, &results, now);
+        rollout.status = Some(status);
+    }
+}
 
-/// Test that metrics analysis is skipped during warmup period
 #[tokio::test]
-async fn test_evaluate_rollout_metrics_skips_during_warmup() {
-    use crate::crd::rollout::{
-        AnalysisConfig, CanaryStrategy, GatewayAPIRouting, MetricConfig, TrafficRouting,
-    };
-    use chrono::Utc;
+async fn test_analysis_interval_limits_prometheus_queries() {
+    let start = Utc::now();
+    let mut rollout = rollout_with_analysis_interval(Some("30s"), start);
+    let ctx = Context::new_mock();
+    ctx.prometheus_client
+        .set_mock_response(prometheus_sample("2.5"));
 
-    // ARRANGE: Rollout with warmup duration, step just started (within warmup)
-    let now = Utc::now();
-    let step_start = now.to_rfc3339();
+    // Reconciles every 5s for a minute: one query per 30s interval
+    for secs in (0..60).step_by(5) {
+        analyze_at(&mut rollout, &ctx, start + chrono::Duration::seconds(secs)).await;
+    }
 
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("warmup-test".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-canary".to_string(),
-                    stable_service: "test-stable".to_string(),
-                    steps: vec![],
-                    traffic_routing: Some(TrafficRouting {
-                        gateway_api: Some(GatewayAPIRouting {
-                            http_route: "test-route".to_string(),
-                        }),
-                    }),
-                    analysis: Some(AnalysisConfig {
-                        prometheus: None,
-                        metrics: vec![MetricConfig {
-                            name: "error-rate".to_string(),
-                            threshold: 0.05,
-                            interval: None,
-                            failure_threshold: None,
-                            min_sample_size: None,
-                        }],
-                        failure_policy: None,
-                        warmup_duration: Some("60s".to_string()), // 60 second warmup
-                    }),
-                }),
-                blue_green: None,
-            },
-        },
-        status: Some(RolloutStatus {
-            replicas: 3,
-            ready_replicas: 3,
-            updated_replicas: 1,
-            current_step_index: Some(0),
-            current_weight: Some(10),
-            phase: Some(Phase::Progressing),
-            step_start_time: Some(step_start), // Just started
-            ..Default::default()
-        }),
-    };
+    assert_eq!(ctx.prometheus_client.mock_query_count(), 2);
+    assert_eq!(
+        rollout.status.as_ref().unwrap().last_analysis_time,
+        Some((start + chrono::Duration::seconds(30)).to_rfc3339())
+    );
+}
 
+#[tokio::test]
+async fn test_analysis_without_interval_queries_every_reconcile() {
+    let start = Utc::now();
+    let mut rollout = rollout_with_analysis_interval(None, start);
     let ctx = Context::new_mock();
+    ctx.prometheus_client
+        .set_mock_response(prometheus_sample("2.5"));
 
-    // ACT: Evaluate metrics (should skip due to warmup)
-    let result = evaluate_rollout_metrics(&rollout, &ctx).await;
-
-    // ASSERT: Should return Ok(true) - warmup not elapsed, skip analysis
-    match result {
-        Ok(is_healthy) => assert!(
-            is_healthy,
-            "Should skip analysis during warmup and return healthy"
-        ),
-        Err(e) => panic!("Should succeed during warmup, got error: {:?}", e),
+    for secs in [0, 5, 10] {
+        analyze_at(&mut rollout, &ctx, start + chrono::Duration::seconds(secs)).await;
     }
+
+    assert_eq!(ctx.prometheus_client.mock_query_count(), 3);
+    assert_eq!(rollout.status.as_ref().unwrap().last_analysis_time, None);
 }
 
-/// Test that metrics analysis runs after warmup period elapses
 #[tokio::test]
-async fn test_evaluate_rollout_metrics_runs_after_warmup() {
-    use crate::crd::rollout::{
-        AnalysisConfig, CanaryStrategy, GatewayAPIRouting, MetricConfig, TrafficRouting,
-    };
-    use chrono::{Duration as ChronoDuration, Utc};
+async fn test_analysis_interval_does_not_reuse_failing_run() {
+    let start = Utc::now();
+    let mut rollout = rollout_with_analysis_interval(Some("30s"), start);
+    let ctx = Context::new_mock();
+    ctx.prometheus_client
+        .set_mock_response(prometheus_sample("8.0"));
 
-    // ARRANGE: Rollout with warmup duration, step started long ago (warmup elapsed)
-    let step_start = (Utc::now() - ChronoDuration::seconds(120)).to_rfc3339(); // 2 min ago
+    analyze_at(&mut rollout, &ctx, start).await;
+    let run =
+        evaluate_rollout_metrics_at(&rollout, &ctx, start + chrono::Duration::seconds(5)).await;
 
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("warmup-elapsed-test".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-canary".to_string(),
-                    stable_service: "test-stable".to_string(),
-                    steps: vec![],
-                    traffic_routing: Some(TrafficRouting {
-                        gateway_api: Some(GatewayAPIRouting {
-                            http_route: "test-route".to_string(),
-                        }),
-                    }),
-                    analysis: Some(AnalysisConfig {
-                        prometheus: None,
-                        metrics: vec![MetricConfig {
-                            name: "error-rate".to_string(),
-                            threshold: 0.05,
-                            interval: None,
-                            failure_threshold: None,
-                            min_sample_size: None,
-                        }],
-                        failure_policy: None,
-                        warmup_duration: Some("60s".to_string()), // 60 second warmup
-                    }),
-                }),
-                blue_green: None,
-            },
-        },
-        status: Some(RolloutStatus {
-            replicas: 3,
-            ready_replicas: 3,
-            updated_replicas: 1,
-            current_step_index: Some(0),
-            current_weight: Some(10),
-            phase: Some(Phase::Progressing),
-            step_start_time: Some(step_start), // Started 2 min ago - warmup elapsed
-            ..Default::default()
-        }),
-    };
+    assert!(!run.verdict.unwrap(), "A failing run is re-evaluated");
+    assert_eq!(ctx.prometheus_client.mock_query_count(), 2);
+}
 
-    // Set mock Prometheus response (healthy metrics)
-    let ctx = Context::new_mock();
-    ctx.prometheus_client.set_mock_response(
-        r#"{"status":"success","data":{"resultType":"vector","result":[{"metric":{},"value":[1234567890,"0.01"]}]}}"#.to_string()
-    );
+#[test]
+fn test_analysis_interval_not_reused_across_steps() {
+    let start = Utc::now();
+    let mut rollout = rollout_with_analysis_interval(Some("30s"), start);
+    let analysis = rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .and_then(|canary| canary.analysis.clone())
+        .unwrap();
+    let status = rollout.status.as_mut().unwrap();
+    status.last_analysis_result = Some(AnalysisResult {
+        timestamp: start.to_rfc3339(),
+        healthy: true,
+        metric_results: vec![],
+        consecutive_failures: 0,
+    });
+    status.last_analysis_time = Some(start.to_rfc3339());
+    let now = start + chrono::Duration::seconds(10);
 
-    // ACT: Evaluate metrics (should run since warmup elapsed)
-    let result = evaluate_rollout_metrics(&rollout, &ctx).await;
+    assert!(has_fresh_passing_analysis(&rollout, &analysis, now));
 
-    // ASSERT: Should succeed (mock Prometheus returns healthy)
-    // The important thing is that it actually tried to evaluate, not skip
-    assert!(result.is_ok(), "Should evaluate metrics after warmup");
+    // The step advanced after the last query: analyze the new step afresh
+    let status = rollout.status.as_mut().unwrap();
+    status.step_start_time = Some((start + chrono::Duration::seconds(5)).to_rfc3339());
+    assert!(!has_fresh_passing_analysis(&rollout, &analysis, now));
 }
 
-/// Test that metrics analysis runs when no warmup duration configured
-#[tokio::test]
-async fn test_evaluate_rollout_metrics_no_warmup_configured() {
-    use crate::crd::rollout::{
-        AnalysisConfig, CanaryStrategy, GatewayAPIRouting, MetricConfig, TrafficRouting,
-    };
-    use chrono::Utc;
+#[test]
+fn test_validate_analysis_interval() {
+    let mut rollout = rollout_with_analysis_interval(Some("1m"), Utc::now());
+    assert!(validate_rollout(&rollout).is_ok());
 
-    // ARRANGE: Rollout without warmup duration
-    let step_start = Utc::now().to_rfc3339();
+    if let Some(analysis) = rollout
+        .spec
+        .strategy
+        .canary
+        .as_mut()
+        .and_then(|canary| canary.analysis.as_mut())
+    {
+        analysis.interval = Some("often".to_string());
+    }
+    let error = validate_rollout(&rollout).unwrap_err();
+    assert!(
+        error.contains("analysis.interval invalid: often"),
+        "Expected interval error, got: {}",
+        error
+    );
+}
 
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("no-warmup-test".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                canary: Some(CanaryStrategy {
-                    canary_service: "test-canary".to_string(),
-                    stable_service: "test-stable".to_string(),
-                    steps: vec![],
-                    traffic_routing: Some(TrafficRouting {
-                        gateway_api: Some(GatewayAPIRouting {
-                            http_route: "test-route".to_string(),
-                        }),
-                    }),
-                    analysis: Some(AnalysisConfig {
-                        prometheus: None,
-                        metrics: vec![MetricConfig {
-                            name: "error-rate".to_string(),
-                            threshold: 0.05,
-                            interval: None,
-                            failure_threshold: None,
-                            min_sample_size: None,
-                        }],
-                        failure_policy: None,
-                        warmup_duration: None, // No warmup
-                    }),
-                }),
-                blue_green: None,
-            },
-        },
-        status: Some(RolloutStatus {
-            replicas: 3,
-            ready_replicas: 3,
-            updated_replicas: 1,
-            current_step_index: Some(0),
-            current_weight: Some(10),
-            phase: Some(Phase::Progressing),
-            step_start_time: Some(step_start),
-            ..Default::default()
-        }),
-    };
+/// Canary at step 0 whose analysis tolerates `threshold` consecutive failed runs
+fn rollout_with_consecutive_failure_threshold(threshold: Option<i32>) -> Rollout {
+    use crate::crd::rollout::{AnalysisConfig, MetricConfig};
+
+    RolloutBuilder::new()
+        .with_step(10, None)
+        .with_step(50, None)
+        .with_analysis(AnalysisConfig {
+            prometheus: None,
+            failure_policy: None,
+            warmup_duration: None,
+            interval: None,
+            metrics: vec![MetricConfig {
+                name: "error-rate".to_string(),
+                threshold: 5.0,
+                interval: None,
+                failure_threshold: None,
+                min_sample_size: None,
+                comparison: None,
+                labels: BTreeMap::new(),
+            }],
+            requirement: None,
+            consecutive_failure_threshold: threshold,
+            mode: None,
+            tolerance: None,
+        })
+        .with_status(0, 10, Phase::Progressing)
+        .build()
+}
 
-    // Set mock Prometheus response (healthy metrics)
-    let ctx = Context::new_mock();
-    ctx.prometheus_client.set_mock_response(
-        r#"{"status":"success","data":{"resultType":"vector","result":[{"metric":{},"value":[1234567890,"0.01"]}]}}"#.to_string()
+#[test]
+fn test_consecutive_failure_threshold_rolls_back_on_third_failure() {
+    let mut rollout = rollout_with_consecutive_failure_threshold(Some(3));
+
+    // Failures 1 and 2: tolerated, the step is held while still Progressing
+    for expected in 1..=2 {
+        let failures = analysis_failures_after(&rollout, false);
+        assert_eq!(failures, expected);
+        let held = analysis_failure_hold_status(&rollout, failures)
+            .expect("Failure below threshold should be tolerated");
+        assert_eq!(held.phase, Some(Phase::Progressing));
+        assert_eq!(held.current_step_index, Some(0));
+        assert_eq!(held.consecutive_analysis_failures, expected);
+        rollout.status = Some(held);
+    }
+
+    // Failure 3: threshold reached, roll back
+    let failures = analysis_failures_after(&rollout, false);
+    assert_eq!(failures, 3);
+    assert!(analysis_failure_hold_status(&rollout, failures).is_none());
+    let rolling_back = start_rollback(
+        &rollout,
+        "metric analysis exceeded thresholds",
+        DecisionReason::AnalysisFailed,
     );
+    assert_eq!(rolling_back.phase, Some(Phase::RollingBack));
+}
 
-    // ACT: Evaluate metrics (should run immediately, no warmup)
-    let result = evaluate_rollout_metrics(&rollout, &ctx).await;
+#[test]
+fn test_consecutive_failure_counter_resets_on_passing_analysis() {
+    let mut rollout = rollout_with_consecutive_failure_threshold(Some(3));
+    for _ in 0..2 {
+        let failures = analysis_failures_after(&rollout, false);
+        rollout.status = analysis_failure_hold_status(&rollout, failures);
+    }
+    assert_eq!(
+        rollout
+            .status
+            .as_ref()
+            .unwrap()
+            .consecutive_analysis_failures,
+        2
+    );
 
-    // ASSERT: Should succeed (evaluates immediately)
+    assert_eq!(analysis_failures_after(&rollout, true), 0);
+
+    // After the reset, it takes three more failures to roll back
+    rollout
+        .status
+        .as_mut()
+        .unwrap()
+        .consecutive_analysis_failures = 0;
+    let failures = analysis_failures_after(&rollout, false);
+    assert_eq!(failures, 1);
+    assert!(analysis_failure_hold_status(&rollout, failures).is_some());
+}
+
+#[test]
+fn test_without_consecutive_failure_threshold_first_failure_rolls_back() {
+    let rollout = rollout_with_consecutive_failure_threshold(None);
+
+    assert_eq!(consecutive_failure_threshold(&rollout), 1);
     assert!(
-        result.is_ok(),
-        "Should evaluate metrics immediately without warmup"
+        analysis_failure_hold_status(&rollout, analysis_failures_after(&rollout, false)).is_none()
     );
 }
 
-// =============================================================================
-// HTTPRoute Traffic Splitting Tests
-// =============================================================================
+/// Mock Prometheus response returning a single sample
+fn prometheus_sample(value: &str) -> String {
+    format!(
+        r#"{{"status":"success","data":{{"resultType":"vector","result":[{{"metric":{{}},"value":[1234567890,"{}"]}}]}}}}"#,
+        value
+    )
+}
 
-// TDD RED: Test blue-green builds HTTPRoute backend refs for active/preview
 #[tokio::test]
-async fn test_blue_green_builds_httproute_backend_refs() {
-    use crate::crd::rollout::{BlueGreenStrategy, GatewayAPIRouting, TrafficRouting};
+async fn test_last_analysis_result_for_passing_analysis() {
+    use crate::crd::rollout::MetricResultStatus;
 
-    // ARRANGE: Blue-green rollout in Preview phase (100% to active)
-    let rollout = Rollout {
-        metadata: ObjectMeta {
-            name: Some("bg-app".to_string()),
-            namespace: Some("default".to_string()),
-            ..Default::default()
-        },
-        spec: RolloutSpec {
-            replicas: 3,
-            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
-            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
-            strategy: RolloutStrategy {
-                simple: None,
-                canary: None,
-                blue_green: Some(BlueGreenStrategy {
-                    active_service: "bg-app-active".to_string(),
-                    preview_service: "bg-app-preview".to_string(),
-                    auto_promotion_enabled: None,
-                    auto_promotion_seconds: None,
-                    traffic_routing: Some(TrafficRouting {
-                        gateway_api: Some(GatewayAPIRouting {
-                            http_route: "bg-app-route".to_string(),
-                        }),
-                    }),
-                    analysis: None,
-                }),
-            },
-        },
-        status: Some(RolloutStatus {
-            phase: Some(Phase::Preview),
-            ..Default::default()
-        }),
-    };
+    let mut rollout = rollout_with_consecutive_failure_threshold(Some(3));
+    rollout
+        .status
+        .as_mut()
+        .unwrap()
+        .consecutive_analysis_failures = 2;
+    let ctx = Context::new_mock();
+    ctx.prometheus_client
+        .set_mock_response(prometheus_sample("2.5"));
+
+    let analysis = evaluate_rollout_metrics(&rollout, &ctx).await;
+    assert!(matches!(analysis.verdict, Ok(true)));
+    let results = analysis.metric_results.unwrap();
+
+    // Passing resets the failure count before the result is recorded
+    let mut status = rollout.status.clone().unwrap();
+    status.consecutive_analysis_failures = analysis_failures_after(&rollout, true);
+    record_analysis_result(&mut status, true, &results, Utc::now());
+
+    let recorded = status.last_analysis_result.unwrap();
+    assert!(recorded.healthy);
+    assert_eq!(recorded.consecutive_failures, 0);
+    assert_eq!(recorded.metric_results.len(), 1);
+    assert_eq!(recorded.metric_results[0].name, "error-rate");
+    assert_eq!(
+        recorded.metric_results[0].status,
+        MetricResultStatus::Passed
+    );
+    assert_eq!(recorded.metric_results[0].value, Some(2.5));
+    assert_eq!(recorded.metric_results[0].threshold, 5.0);
+}
 
-    // ACT: Build gateway API backend refs
-    let backend_refs = build_gateway_api_backend_refs(&rollout);
+#[tokio::test]
+async fn test_last_analysis_result_for_failing_analysis() {
+    use crate::crd::rollout::MetricResultStatus;
 
-    // ASSERT: Should have 2 backends - active (100%) and preview (0%)
+    let rollout = rollout_with_consecutive_failure_threshold(Some(3));
+    let ctx = Context::new_mock();
+    ctx.prometheus_client
+        .set_mock_response(prometheus_sample("8.0"));
+
+    let analysis = evaluate_rollout_metrics(&rollout, &ctx).await;
+    assert!(matches!(analysis.verdict, Ok(false)));
+    let results = analysis.metric_results.unwrap();
+
+    let mut held = analysis_failure_hold_status(&rollout, analysis_failures_after(&rollout, false))
+        .expect("First failure is below the threshold");
+    record_analysis_result(&mut held, false, &results, Utc::now());
+
+    let recorded = held.last_analysis_result.unwrap();
+    assert!(!recorded.healthy);
+    assert_eq!(recorded.consecutive_failures, 1);
     assert_eq!(
-        backend_refs.len(),
-        2,
-        "Should have active and preview backends"
+        recorded.metric_results[0].status,
+        MetricResultStatus::Failed
+    );
+    assert_eq!(recorded.metric_results[0].value, Some(8.0));
+    assert_eq!(recorded.metric_results[0].error, None);
+}
+
+#[tokio::test]
+async fn test_last_analysis_result_for_erroring_analysis() {
+    use crate::crd::rollout::MetricResultStatus;
+
+    // No mock response: the Prometheus query fails
+    let rollout = rollout_with_consecutive_failure_threshold(Some(3));
+    let ctx = Context::new_mock();
+
+    let analysis = evaluate_rollout_metrics(&rollout, &ctx).await;
+    assert!(matches!(
+        analysis.verdict,
+        Err(ReconcileError::MetricsEvaluationFailed(_))
+    ));
+    let results = analysis.metric_results.unwrap();
+
+    let mut status = rollout.status.clone().unwrap();
+    record_analysis_result(&mut status, false, &results, Utc::now());
+
+    let recorded = status.last_analysis_result.unwrap();
+    assert!(!recorded.healthy);
+    assert_eq!(recorded.metric_results.len(), 1);
+    let metric = &recorded.metric_results[0];
+    assert_eq!(metric.name, "error-rate");
+    assert_eq!(metric.status, MetricResultStatus::Skipped);
+    assert_eq!(metric.value, None);
+    assert!(metric
+        .error
+        .as_deref()
+        .unwrap()
+        .contains("No mock response"));
+}
+
+#[tokio::test]
+async fn test_no_analysis_result_without_evaluation() {
+    // Warming up: nothing evaluated, nothing to record
+    let rollout = create_rollout_with_warmup("30s", 5);
+    let ctx = Context::new_mock();
+
+    let analysis = evaluate_rollout_metrics(&rollout, &ctx).await;
+
+    assert!(matches!(analysis.verdict, Ok(true)));
+    assert!(analysis.metric_results.is_none());
+}
+
+#[test]
+fn test_unchanged_analysis_result_keeps_timestamp() {
+    use crate::crd::rollout::{MetricResult, MetricResultStatus};
+
+    let results = vec![MetricResult {
+        name: "error-rate".to_string(),
+        status: MetricResultStatus::Passed,
+        value: Some(1.0),
+        threshold: 5.0,
+        error: None,
+    }];
+    let first = Utc::now();
+    let mut status = RolloutStatus::default();
+    record_analysis_result(&mut status, true, &results, first);
+    record_analysis_result(
+        &mut status,
+        true,
+        &results,
+        first + chrono::Duration::seconds(30),
     );
 
-    let active = backend_refs
-        .iter()
-        .find(|b| b.name == "bg-app-active")
-        .expect("Should have active backend");
     assert_eq!(
-        active.weight,
-        Some(100),
-        "Active should have 100% in Preview phase"
+        status.last_analysis_result.as_ref().unwrap().timestamp,
+        first.to_rfc3339()
     );
 
-    let preview = backend_refs
-        .iter()
-        .find(|b| b.name == "bg-app-preview")
-        .expect("Should have preview backend");
+    // A new value is a new result
+    let mut changed = results.clone();
+    changed[0].value = Some(2.0);
+    let later = first + chrono::Duration::seconds(60);
+    record_analysis_result(&mut status, true, &changed, later);
     assert_eq!(
-        preview.weight,
-        Some(0),
-        "Preview should have 0% in Preview phase"
+        status.last_analysis_result.unwrap().timestamp,
+        later.to_rfc3339()
     );
 }
 
-// TDD RED: Test blue-green after promotion (100% to preview, now active)
+/// Rollout whose analysis compares canary with stable (20% tolerance)
+fn rollout_with_canary_vs_stable_analysis() -> Rollout {
+    use crate::crd::rollout::{AnalysisMode, MetricConfig};
+
+    let mut rollout = rollout_with_consecutive_failure_threshold(None);
+    let analysis = rollout
+        .spec
+        .strategy
+        .canary
+        .as_mut()
+        .unwrap()
+        .analysis
+        .as_mut()
+        .unwrap();
+    analysis.mode = Some(AnalysisMode::CanaryVsStable);
+    analysis.tolerance = Some(0.2);
+    analysis.metrics.push(MetricConfig {
+        name: "latency-p99".to_string(),
+        threshold: 0.5,
+        interval: None,
+        failure_threshold: None,
+        min_sample_size: None,
+        comparison: None,
+        labels: BTreeMap::new(),
+    });
+    rollout
+}
+
 #[tokio::test]
-async fn test_blue_green_httproute_after_promotion() {
-    use crate::crd::rollout::{BlueGreenStrategy, GatewayAPIRouting, TrafficRouting};
+async fn test_canary_vs_stable_analysis_within_tolerance() {
+    let rollout = rollout_with_canary_vs_stable_analysis();
+    assert_eq!(validate_rollout(&rollout), Ok(()));
+    let ctx = Context::new_mock();
+    ctx.prometheus_client
+        .set_mock_response_for_revision("stable", prometheus_sample("1.0"));
+    ctx.prometheus_client
+        .set_mock_response_for_revision("canary", prometheus_sample("1.15"));
 
-    // ARRANGE: Blue-green rollout in Completed phase (promoted)
-    let rollout = Rollout {
+    let analysis = evaluate_rollout_metrics(&rollout, &ctx).await;
+
+    assert!(matches!(analysis.verdict, Ok(true)));
+    assert_eq!(analysis.metric_results.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_canary_vs_stable_analysis_regressed() {
+    use crate::crd::rollout::MetricResultStatus;
+
+    // Canary 50% worse than stable on every metric; the thresholds (5.0, 0.5) are not used
+    let rollout = rollout_with_canary_vs_stable_analysis();
+    let ctx = Context::new_mock();
+    ctx.prometheus_client
+        .set_mock_response_for_revision("stable", prometheus_sample("0.2"));
+    ctx.prometheus_client
+        .set_mock_response_for_revision("canary", prometheus_sample("0.3"));
+
+    let analysis = evaluate_rollout_metrics(&rollout, &ctx).await;
+
+    assert!(matches!(analysis.verdict, Ok(false)));
+    let results = analysis.metric_results.unwrap();
+    assert!(results
+        .iter()
+        .all(|result| result.status == MetricResultStatus::Failed));
+}
+
+#[test]
+fn test_validate_analysis_tolerance() {
+    let mut rollout = rollout_with_canary_vs_stable_analysis();
+    let analysis = rollout
+        .spec
+        .strategy
+        .canary
+        .as_mut()
+        .unwrap()
+        .analysis
+        .as_mut()
+        .unwrap();
+
+    analysis.tolerance = Some(-0.1);
+    let err = validate_analysis_tolerance("analysis", analysis).unwrap_err();
+    assert!(err.contains("finite number >= 0"), "{}", err);
+
+    // Tolerance without canary-vs-stable has nothing to apply to
+    analysis.tolerance = Some(0.1);
+    analysis.mode = None;
+    let err = validate_analysis_tolerance("analysis", analysis).unwrap_err();
+    assert!(err.contains("canary-vs-stable"), "{}", err);
+}
+
+#[test]
+fn test_validate_rollout_rejects_zero_consecutive_failure_threshold() {
+    let rollout = rollout_with_consecutive_failure_threshold(Some(0));
+
+    let error = validate_rollout(&rollout).unwrap_err();
+
+    assert!(
+        error.contains(
+            "spec.strategy.canary.analysis.consecutiveFailureThreshold must be >= 1, got 0"
+        ),
+        "Expected consecutiveFailureThreshold error, got: {}",
+        error
+    );
+}
+
+/// Canary whose analysis has a single `count` metric
+fn rollout_with_count_metric(threshold: f64, interval: Option<&str>) -> Rollout {
+    let mut rollout = rollout_with_consecutive_failure_threshold(None);
+    if let Some(analysis) = rollout
+        .spec
+        .strategy
+        .canary
+        .as_mut()
+        .and_then(|canary| canary.analysis.as_mut())
+    {
+        analysis.metrics[0].name = "count".to_string();
+        analysis.metrics[0].threshold = threshold;
+        analysis.metrics[0].interval = interval.map(String::from);
+    }
+    rollout
+}
+
+#[test]
+fn test_validate_rollout_count_metric() {
+    assert!(validate_rollout(&rollout_with_count_metric(10.0, Some("5m"))).is_ok());
+    assert!(validate_rollout(&rollout_with_count_metric(0.0, None)).is_ok());
+
+    let error = validate_rollout(&rollout_with_count_metric(2.5, None)).unwrap_err();
+    assert!(
+        error.contains("spec.strategy.canary.analysis.metrics[0].threshold must be a whole number >= 0 for the count metric, got 2.5"),
+        "Expected count threshold error, got: {}",
+        error
+    );
+
+    let error = validate_rollout(&rollout_with_count_metric(10.0, Some("5x"))).unwrap_err();
+    assert!(
+        error.contains("spec.strategy.canary.analysis.metrics[0].interval invalid: 5x"),
+        "Expected count window error, got: {}",
+        error
+    );
+}
+
+#[test]
+fn test_skip_analysis_annotation_does_not_bypass_pause_duration() {
+    let mut rollout = RolloutBuilder::new()
+        .with_step(10, Some("5m"))
+        .with_step(50, None)
+        .with_status(0, 10, Phase::Progressing)
+        .with_annotation(&skip_analysis_annotation_key(), "true")
+        .build();
+    if let Some(status) = rollout.status.as_mut() {
+        status.pause_start_time = Some(Utc::now().to_rfc3339());
+    }
+
+    let mut status = compute_desired_status(&rollout);
+    let used = record_analysis_skipped(&rollout, &mut status);
+
+    assert!(!should_progress_to_next_step(&rollout));
+    assert!(!used, "Annotation is kept until the pause expires");
+    assert_eq!(status.current_step_index, Some(0));
+    assert!(status
+        .decisions
+        .iter()
+        .all(|decision| decision.action != DecisionAction::AnalysisSkipped));
+}
+
+#[test]
+fn test_skip_analysis_annotation_key_default_domain() {
+    assert_eq!(skip_analysis_annotation_key(), "kulta.io/skip-analysis");
+    let rollout = create_test_rollout_with_canary();
+    assert!(!has_skip_analysis_annotation(&rollout));
+}
+
+#[tokio::test]
+async fn test_evaluate_rollout_metrics_no_analysis_config() {
+    // ARRANGE: Rollout WITHOUT analysis config
+    let rollout = RolloutBuilder::new()
+        .with_step(10, None)
+        .with_status(0, 10, Phase::Progressing)
+        .build();
+
+    let ctx = Context::new_mock();
+
+    // ACT: Evaluate metrics
+    let result = evaluate_rollout_metrics(&rollout, &ctx).await.verdict;
+
+    // ASSERT: Should return Ok(true) - no metrics to check = healthy
+    match result {
+        Ok(is_healthy) => assert!(
+            is_healthy,
+            "No analysis config should be considered healthy"
+        ),
+        Err(e) => panic!("Should succeed, got error: {:?}", e),
+    }
+}
+
+// =============================================================================
+// Per-Step Analysis Tests
+// =============================================================================
+
+// Helper to create a canary rollout with strategy-level analysis (threshold 5.0)
+// and a stricter step-level analysis (threshold 1.0) on the second step
+fn create_rollout_with_step_analysis(current_step: i32) -> Rollout {
+    use crate::crd::rollout::{AnalysisConfig, MetricConfig};
+
+    let analysis_with_threshold = |threshold: f64| AnalysisConfig {
+        prometheus: None,
+        failure_policy: None,
+        warmup_duration: None,
+        interval: None,
+        metrics: vec![MetricConfig {
+            name: "error-rate".to_string(),
+            threshold,
+            interval: None,
+            failure_threshold: None,
+            min_sample_size: None,
+            comparison: None,
+            labels: BTreeMap::new(),
+        }],
+        requirement: None,
+        consecutive_failure_threshold: None,
+        mode: None,
+        tolerance: None,
+    };
+
+    Rollout {
         metadata: ObjectMeta {
-            name: Some("bg-app".to_string()),
+            name: Some("step-analysis-rollout".to_string()),
             namespace: Some("default".to_string()),
             ..Default::default()
         },
@@ -3211,96 +3665,5327 @@ async fn test_blue_green_httproute_after_promotion() {
             template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
             strategy: RolloutStrategy {
                 simple: None,
-                canary: None,
-                blue_green: Some(BlueGreenStrategy {
-                    active_service: "bg-app-active".to_string(),
-                    preview_service: "bg-app-preview".to_string(),
-                    auto_promotion_enabled: None,
-                    auto_promotion_seconds: None,
-                    traffic_routing: Some(TrafficRouting {
-                        gateway_api: Some(GatewayAPIRouting {
-                            http_route: "bg-app-route".to_string(),
-                        }),
-                    }),
-                    analysis: None,
+                blue_green: None,
+                canary: Some(CanaryStrategy {
+                    canary_service: "test-app-canary".to_string(),
+                    stable_service: "test-app-stable".to_string(),
+                    mirror_service: None,
+                    steps: vec![
+                        CanaryStep {
+                            set_weight: Some(10),
+                            pause: None,
+                            analysis: None, // Falls back to strategy-level
+                        },
+                        CanaryStep {
+                            set_weight: Some(50),
+                            pause: None,
+                            analysis: Some(analysis_with_threshold(1.0)), // Stricter
+                        },
+                    ],
+                    analysis: Some(analysis_with_threshold(5.0)),
+                    traffic_routing: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: Some(RolloutStatus {
-            phase: Some(Phase::Completed),
+            current_step_index: Some(current_step),
+            phase: Some(Phase::Progressing),
             ..Default::default()
         }),
-    };
+    }
+}
 
-    // ACT: Build gateway API backend refs
-    let backend_refs = build_gateway_api_backend_refs(&rollout);
+#[test]
+fn test_resolve_effective_analysis_step_overrides_strategy() {
+    // ARRANGE: Rollout at step 1 (has step-level analysis)
+    let rollout = create_rollout_with_step_analysis(1);
 
-    // ASSERT: After promotion, traffic goes to preview (which becomes new active)
-    assert_eq!(
-        backend_refs.len(),
-        2,
-        "Should have active and preview backends"
-    );
+    // ACT
+    let analysis = resolve_effective_analysis(&rollout).expect("Should resolve analysis");
 
-    let active = backend_refs
-        .iter()
-        .find(|b| b.name == "bg-app-active")
-        .expect("Should have active backend");
-    assert_eq!(
-        active.weight,
-        Some(0),
-        "Old active should have 0% after promotion"
-    );
+    // ASSERT: Step-level threshold wins
+    assert_eq!(analysis.metrics.len(), 1);
+    assert_eq!(analysis.metrics[0].threshold, 1.0);
+}
 
-    let preview = backend_refs
-        .iter()
-        .find(|b| b.name == "bg-app-preview")
-        .expect("Should have preview backend");
-    assert_eq!(
-        preview.weight,
-        Some(100),
-        "Preview should have 100% after promotion"
-    );
+#[test]
+fn test_resolve_effective_analysis_falls_back_to_strategy() {
+    // ARRANGE: Rollout at step 0 (no step-level analysis)
+    let rollout = create_rollout_with_step_analysis(0);
+
+    // ACT
+    let analysis = resolve_effective_analysis(&rollout).expect("Should resolve analysis");
+
+    // ASSERT: Strategy-level threshold used
+    assert_eq!(analysis.metrics[0].threshold, 5.0);
 }
 
-/// Test Context.should_reconcile without leader state
-#[tokio::test]
-async fn test_context_should_reconcile_without_leader_state() {
-    // ARRANGE: Create context without leader election
-    let ctx = Context::new_mock();
+#[test]
+fn test_resolve_effective_analysis_without_status_uses_strategy() {
+    // ARRANGE: Rollout without status (not yet initialized)
+    let mut rollout = create_rollout_with_step_analysis(1);
+    rollout.status = None;
 
-    // ACT & ASSERT: Should always reconcile in single instance mode
-    assert!(
-        ctx.should_reconcile(),
-        "Without leader election, should always reconcile"
-    );
+    // ACT
+    let analysis = resolve_effective_analysis(&rollout).expect("Should resolve analysis");
+
+    // ASSERT: No current step → strategy-level analysis
+    assert_eq!(analysis.metrics[0].threshold, 5.0);
 }
 
-/// Test Context.should_reconcile with leader state - not leader
 #[tokio::test]
-async fn test_context_should_not_reconcile_when_not_leader() {
-    // ARRANGE: Create context with leader state (not leader by default)
-    let leader_state = crate::server::LeaderState::new();
-    let ctx = Context::new_mock_with_leader(leader_state);
+async fn test_evaluate_rollout_metrics_uses_step_level_analysis() {
+    // ARRANGE: Error rate 2.5% passes strategy threshold (5.0) but fails step threshold (1.0)
+    let mock_response = r#"{
+        "status": "success",
+        "data": {
+            "resultType": "vector",
+            "result": [
+                {
+                    "metric": {},
+                    "value": [1234567890, "2.5"]
+                }
+            ]
+        }
+    }"#;
 
-    // ACT & ASSERT: Should not reconcile when not leader
+    let ctx = Context::new_mock();
+    ctx.prometheus_client
+        .set_mock_response(mock_response.to_string());
+
+    // ACT: Evaluate at step 0 (strategy-level) and step 1 (step-level)
+    let step0_healthy = evaluate_rollout_metrics(&create_rollout_with_step_analysis(0), &ctx)
+        .await
+        .verdict
+        .expect("Should evaluate metrics");
+    let step1_healthy = evaluate_rollout_metrics(&create_rollout_with_step_analysis(1), &ctx)
+        .await
+        .verdict
+        .expect("Should evaluate metrics");
+
+    // ASSERT
+    assert!(step0_healthy, "Step 0 should use strategy-level threshold");
     assert!(
-        !ctx.should_reconcile(),
-        "When leader election enabled but not leader, should not reconcile"
+        !step1_healthy,
+        "Step 1 should use stricter step-level threshold"
     );
 }
 
-/// Test Context.should_reconcile with leader state - is leader
+// =============================================================================
+// Warmup Duration Tests
+// =============================================================================
+
+/// Test that metrics analysis is skipped during warmup period
 #[tokio::test]
-async fn test_context_should_reconcile_when_leader() {
-    // ARRANGE: Create context with leader state set to leader
-    let leader_state = crate::server::LeaderState::new();
-    leader_state.set_leader(true);
-    let ctx = Context::new_mock_with_leader(leader_state);
+async fn test_evaluate_rollout_metrics_skips_during_warmup() {
+    use crate::crd::rollout::{AnalysisConfig, MetricConfig};
+    use chrono::Utc;
 
-    // ACT & ASSERT: Should reconcile when leader
+    // ARRANGE: Rollout with warmup duration, step just started (within warmup)
+    let now = Utc::now();
+    let step_start = now.to_rfc3339();
+
+    let rollout = RolloutBuilder::new()
+        .with_name("warmup-test")
+        .with_canary_strategy("test-stable", "test-canary")
+        .with_traffic_routing("test-route")
+        .with_analysis(AnalysisConfig {
+            prometheus: None,
+            metrics: vec![MetricConfig {
+                name: "error-rate".to_string(),
+                threshold: 0.05,
+                interval: None,
+                failure_threshold: None,
+                min_sample_size: None,
+                comparison: None,
+                labels: BTreeMap::new(),
+            }],
+            failure_policy: None,
+            warmup_duration: Some("60s".to_string()),
+            interval: None,
+            requirement: None,
+            consecutive_failure_threshold: None,
+            mode: None,
+            tolerance: None,
+        })
+        .with_status(0, 10, Phase::Progressing)
+        .with_step_start_time(&step_start)
+        .build();
+
+    let ctx = Context::new_mock();
+
+    // ACT: Evaluate metrics (should skip due to warmup)
+    let result = evaluate_rollout_metrics(&rollout, &ctx).await.verdict;
+
+    // ASSERT: Should return Ok(true) - warmup not elapsed, skip analysis
+    match result {
+        Ok(is_healthy) => assert!(
+            is_healthy,
+            "Should skip analysis during warmup and return healthy"
+        ),
+        Err(e) => panic!("Should succeed during warmup, got error: {:?}", e),
+    }
+}
+
+/// Test that metrics analysis runs after warmup period elapses
+#[tokio::test]
+async fn test_evaluate_rollout_metrics_runs_after_warmup() {
+    use crate::crd::rollout::{AnalysisConfig, MetricConfig};
+    use chrono::{Duration as ChronoDuration, Utc};
+
+    // ARRANGE: Rollout with warmup duration, step started long ago (warmup elapsed)
+    let step_start = (Utc::now() - ChronoDuration::seconds(120)).to_rfc3339(); // 2 min ago
+
+    let rollout = RolloutBuilder::new()
+        .with_name("warmup-elapsed-test")
+        .with_canary_strategy("test-stable", "test-canary")
+        .with_traffic_routing("test-route")
+        .with_analysis(AnalysisConfig {
+            prometheus: None,
+            metrics: vec![MetricConfig {
+                name: "error-rate".to_string(),
+                threshold: 0.05,
+                interval: None,
+                failure_threshold: None,
+                min_sample_size: None,
+                comparison: None,
+                labels: BTreeMap::new(),
+            }],
+            failure_policy: None,
+            warmup_duration: Some("60s".to_string()),
+            interval: None,
+            requirement: None,
+            consecutive_failure_threshold: None,
+            mode: None,
+            tolerance: None,
+        })
+        .with_status(0, 10, Phase::Progressing)
+        .with_step_start_time(&step_start)
+        .build();
+
+    // Set mock Prometheus response (healthy metrics)
+    let ctx = Context::new_mock();
+    ctx.prometheus_client.set_mock_response(
+        r#"{"status":"success","data":{"resultType":"vector","result":[{"metric":{},"value":[1234567890,"0.01"]}]}}"#.to_string()
+    );
+
+    // ACT: Evaluate metrics (should run since warmup elapsed)
+    let result = evaluate_rollout_metrics(&rollout, &ctx).await.verdict;
+
+    // ASSERT: Should succeed (mock Prometheus returns healthy)
+    // The important thing is that it actually tried to evaluate, not skip
+    assert!(result.is_ok(), "Should evaluate metrics after warmup");
+}
+
+/// Rollout with strategy-level analysis.warmupDuration whose current step started `elapsed_secs` ago
+fn create_rollout_with_warmup(warmup: &str, elapsed_secs: i64) -> Rollout {
+    use crate::crd::rollout::{AnalysisConfig, MetricConfig};
+
+    let step_start = (Utc::now() - chrono::Duration::seconds(elapsed_secs)).to_rfc3339();
+    RolloutBuilder::new()
+        .with_name("warmup-test")
+        .with_canary_strategy("test-stable", "test-canary")
+        .with_step(20, None)
+        .with_step(50, None)
+        .with_traffic_routing("test-route")
+        .with_analysis(AnalysisConfig {
+            prometheus: None,
+            metrics: vec![MetricConfig {
+                name: "error-rate".to_string(),
+                threshold: 0.05,
+                interval: None,
+                failure_threshold: None,
+                min_sample_size: None,
+                comparison: None,
+                labels: BTreeMap::new(),
+            }],
+            failure_policy: None,
+            warmup_duration: Some(warmup.to_string()),
+            interval: None,
+            requirement: None,
+            consecutive_failure_threshold: None,
+            mode: None,
+            tolerance: None,
+        })
+        .with_status(1, 50, Phase::Progressing)
+        .with_step_start_time(&step_start)
+        .build()
+}
+
+#[test]
+fn test_is_analysis_warming_up_after_weight_change() {
+    let rollout = create_rollout_with_warmup("30s", 10);
+    let analysis = resolve_effective_analysis(&rollout).unwrap();
+
+    assert!(is_analysis_warming_up(&rollout, analysis, Utc::now()));
+    assert!(!is_analysis_warming_up(
+        &rollout,
+        analysis,
+        Utc::now() + chrono::Duration::seconds(25)
+    ));
+}
+
+/// Test that metrics analysis runs when no warmup duration configured
+#[tokio::test]
+async fn test_evaluate_rollout_metrics_no_warmup_configured() {
+    use crate::crd::rollout::{AnalysisConfig, MetricConfig};
+    use chrono::Utc;
+
+    // ARRANGE: Rollout without warmup duration
+    let step_start = Utc::now().to_rfc3339();
+
+    let rollout = RolloutBuilder::new()
+        .with_name("no-warmup-test")
+        .with_canary_strategy("test-stable", "test-canary")
+        .with_traffic_routing("test-route")
+        .with_analysis(AnalysisConfig {
+            prometheus: None,
+            metrics: vec![MetricConfig {
+                name: "error-rate".to_string(),
+                threshold: 0.05,
+                interval: None,
+                failure_threshold: None,
+                min_sample_size: None,
+                comparison: None,
+                labels: BTreeMap::new(),
+            }],
+            failure_policy: None,
+            warmup_duration: None,
+            interval: None,
+            requirement: None,
+            consecutive_failure_threshold: None,
+            mode: None,
+            tolerance: None,
+        })
+        .with_status(0, 10, Phase::Progressing)
+        .with_step_start_time(&step_start)
+        .build();
+
+    // Set mock Prometheus response (healthy metrics)
+    let ctx = Context::new_mock();
+    ctx.prometheus_client.set_mock_response(
+        r#"{"status":"success","data":{"resultType":"vector","result":[{"metric":{},"value":[1234567890,"0.01"]}]}}"#.to_string()
+    );
+
+    // ACT: Evaluate metrics (should run immediately, no warmup)
+    let result = evaluate_rollout_metrics(&rollout, &ctx).await.verdict;
+
+    // ASSERT: Should succeed (evaluates immediately)
     assert!(
-        ctx.should_reconcile(),
-        "When leader election enabled and is leader, should reconcile"
+        result.is_ok(),
+        "Should evaluate metrics immediately without warmup"
+    );
+}
+
+// =============================================================================
+// HTTPRoute Traffic Splitting Tests
+// =============================================================================
+
+// TDD RED: Test blue-green builds HTTPRoute backend refs for active/preview
+#[tokio::test]
+async fn test_blue_green_builds_httproute_backend_refs() {
+    use crate::crd::rollout::{BlueGreenStrategy, GatewayAPIRouting, TrafficRouting};
+
+    // ARRANGE: Blue-green rollout in Preview phase (100% to active)
+    let rollout = Rollout {
+        metadata: ObjectMeta {
+            name: Some("bg-app".to_string()),
+            namespace: Some("default".to_string()),
+            ..Default::default()
+        },
+        spec: RolloutSpec {
+            replicas: 3,
+            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
+            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
+            strategy: RolloutStrategy {
+                simple: None,
+                canary: None,
+                blue_green: Some(BlueGreenStrategy {
+                    active_service: "bg-app-active".to_string(),
+                    preview_service: "bg-app-preview".to_string(),
+                    auto_promotion_enabled: None,
+                    auto_promotion_seconds: None,
+                    traffic_routing: Some(TrafficRouting {
+                        gateway_api: Some(GatewayAPIRouting {
+                            http_route: "bg-app-route".to_string(),
+                            gateway_api_version: None,
+                            verify_accepted: None,
+                            canary_match: None,
+                        }),
+                        linkerd: None,
+                    }),
+                    analysis: None,
+                    max_duration_seconds: None,
+                }),
+            },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
+        },
+        status: Some(RolloutStatus {
+            phase: Some(Phase::Preview),
+            ..Default::default()
+        }),
+    };
+
+    // ACT: Build gateway API backend refs
+    let backend_refs = build_gateway_api_backend_refs(&rollout);
+
+    // ASSERT: Should have 2 backends - active (100%) and preview (0%)
+    assert_eq!(
+        backend_refs.len(),
+        2,
+        "Should have active and preview backends"
+    );
+
+    let active = backend_refs
+        .iter()
+        .find(|b| b.name == "bg-app-active")
+        .expect("Should have active backend");
+    assert_eq!(
+        active.weight,
+        Some(100),
+        "Active should have 100% in Preview phase"
+    );
+
+    let preview = backend_refs
+        .iter()
+        .find(|b| b.name == "bg-app-preview")
+        .expect("Should have preview backend");
+    assert_eq!(
+        preview.weight,
+        Some(0),
+        "Preview should have 0% in Preview phase"
+    );
+}
+
+// TDD RED: Test blue-green after promotion (100% to preview, now active)
+#[tokio::test]
+async fn test_blue_green_httproute_after_promotion() {
+    use crate::crd::rollout::{BlueGreenStrategy, GatewayAPIRouting, TrafficRouting};
+
+    // ARRANGE: Blue-green rollout in Completed phase (promoted)
+    let rollout = Rollout {
+        metadata: ObjectMeta {
+            name: Some("bg-app".to_string()),
+            namespace: Some("default".to_string()),
+            ..Default::default()
+        },
+        spec: RolloutSpec {
+            replicas: 3,
+            selector: k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector::default(),
+            template: k8s_openapi::api::core::v1::PodTemplateSpec::default(),
+            strategy: RolloutStrategy {
+                simple: None,
+                canary: None,
+                blue_green: Some(BlueGreenStrategy {
+                    active_service: "bg-app-active".to_string(),
+                    preview_service: "bg-app-preview".to_string(),
+                    auto_promotion_enabled: None,
+                    auto_promotion_seconds: None,
+                    traffic_routing: Some(TrafficRouting {
+                        gateway_api: Some(GatewayAPIRouting {
+                            http_route: "bg-app-route".to_string(),
+                            gateway_api_version: None,
+                            verify_accepted: None,
+                            canary_match: None,
+                        }),
+                        linkerd: None,
+                    }),
+                    analysis: None,
+                    max_duration_seconds: None,
+                }),
+            },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
+        },
+        status: Some(RolloutStatus {
+            phase: Some(Phase::Completed),
+            ..Default::default()
+        }),
+    };
+
+    // ACT: Build gateway API backend refs
+    let backend_refs = build_gateway_api_backend_refs(&rollout);
+
+    // ASSERT: After promotion, traffic goes to preview (which becomes new active)
+    assert_eq!(
+        backend_refs.len(),
+        2,
+        "Should have active and preview backends"
+    );
+
+    let active = backend_refs
+        .iter()
+        .find(|b| b.name == "bg-app-active")
+        .expect("Should have active backend");
+    assert_eq!(
+        active.weight,
+        Some(0),
+        "Old active should have 0% after promotion"
     );
+
+    let preview = backend_refs
+        .iter()
+        .find(|b| b.name == "bg-app-preview")
+        .expect("Should have preview backend");
+    assert_eq!(
+        preview.weight,
+        Some(100),
+        "Preview should have 100% after promotion"
+    );
+}
+
+/// Test Context.should_reconcile without leader state
+#[tokio::test]
+async fn test_context_should_reconcile_without_leader_state() {
+    // ARRANGE: Create context without leader election
+    let ctx = Context::new_mock();
+
+    // ACT & ASSERT: Should always reconcile in single instance mode
+    assert!(
+        ctx.should_reconcile(),
+        "Without leader election, should always reconcile"
+    );
+}
+
+/// Test Context.should_reconcile with leader state - not leader
+#[tokio::test]
+async fn test_context_should_not_reconcile_when_not_leader() {
+    // ARRANGE: Create context with leader state (not leader by default)
+    let leader_state = crate::server::LeaderState::new();
+    let ctx = Context::new_mock_with_leader(leader_state);
+
+    // ACT & ASSERT: Should not reconcile when not leader
+    assert!(
+        !ctx.should_reconcile(),
+        "When leader election enabled but not leader, should not reconcile"
+    );
+}
+
+/// Test Context.should_reconcile with leader state - is leader
+#[tokio::test]
+async fn test_context_should_reconcile_when_leader() {
+    // ARRANGE: Create context with leader state set to leader
+    let leader_state = crate::server::LeaderState::new();
+    leader_state.set_leader(true);
+    let ctx = Context::new_mock_with_leader(leader_state);
+
+    // ACT & ASSERT: Should reconcile when leader
+    assert!(
+        ctx.should_reconcile(),
+        "When leader election enabled and is leader, should reconcile"
+    );
+}
+
+// =============================================================================
+// Reconcile Error Category Tests
+// =============================================================================
+
+#[test]
+fn test_reconcile_error_category_mapping() {
+    use crate::controller::strategies::StrategyError;
+
+    let cases = vec![
+        (
+            ReconcileError::ValidationError("bad spec".to_string()),
+            "validation",
+        ),
+        (ReconcileError::MissingNamespace, "missing_field"),
+        (ReconcileError::MissingName, "missing_field"),
+        (ReconcileError::ReplicaSetMissingName, "missing_field"),
+        (
+            ReconcileError::SerializationError("bad json".to_string()),
+            "serialization",
+        ),
+        (
+            ReconcileError::MetricsEvaluationFailed("no data".to_string()),
+            "metrics",
+        ),
+        (
+            ReconcileError::StrategyError(StrategyError::TrafficReconciliationFailed(
+                "route".to_string(),
+            )),
+            "traffic",
+        ),
+        (
+            ReconcileError::StrategyError(StrategyError::ReplicaSetReconciliationFailed(
+                "rs".to_string(),
+            )),
+            "replicaset",
+        ),
+        (
+            ReconcileError::StrategyError(StrategyError::MissingField("name".to_string())),
+            "missing_field",
+        ),
+        (
+            ReconcileError::ReplicaSetOwnedByOther("my-app-canary".to_string()),
+            "replicaset",
+        ),
+        (
+            ReconcileError::StrategyError(StrategyError::StrategyDisabled("canary".to_string())),
+            "validation",
+        ),
+        (ReconcileError::Cancelled, "cancelled"),
+    ];
+
+    for (error, expected) in cases {
+        assert_eq!(error.category(), expected, "Wrong category for {:?}", error);
+    }
+}
+
+#[test]
+fn test_validation_error_requeues_sooner_than_kube_error() {
+    let validation = ReconcileError::ValidationError("bad spec".to_string());
+    let kube_error = ReconcileError::KubeError(kube::Error::LinesCodecMaxLineLengthExceeded);
+
+    assert_eq!(
+        calculate_error_requeue_interval(&validation),
+        Duration::from_secs(5)
+    );
+    assert_eq!(
+        calculate_error_requeue_interval(&kube_error),
+        Duration::from_secs(10)
+    );
+}
+
+#[test]
+fn test_calculate_error_requeue_interval_per_variant() {
+    let cases = vec![
+        (
+            ReconcileError::ValidationError("bad spec".to_string()),
+            Duration::from_secs(5),
+        ),
+        (
+            ReconcileError::StrategyError(StrategyError::StrategyDisabled("canary".to_string())),
+            Duration::from_secs(5),
+        ),
+        (
+            ReconcileError::KubeError(kube::Error::LinesCodecMaxLineLengthExceeded),
+            Duration::from_secs(10),
+        ),
+        (ReconcileError::Cancelled, Duration::from_secs(30)),
+        (ReconcileError::MissingName, Duration::from_secs(10)),
+        (
+            ReconcileError::MetricsEvaluationFailed("no data".to_string()),
+            Duration::from_secs(10),
+        ),
+    ];
+
+    for (error, expected) in cases {
+        assert_eq!(
+            calculate_error_requeue_interval(&error),
+            expected,
+            "Wrong requeue interval for {:?}",
+            error
+        );
+    }
+}
+
+// =============================================================================
+// Context::try_new Environment Validation Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_context_try_new_succeeds_with_empty_env() {
+    let client = Context::new_mock().client;
+
+    let result = temp_env::with_vars(
+        [
+            ("KULTA_PROMETHEUS_ADDRESS", None::<&str>),
+            ("KULTA_CDEVENTS_ENABLED", None),
+            ("KULTA_CDEVENTS_SINK_URL", None),
+        ],
+        || Context::try_new(client, None, None),
+    );
+
+    assert!(
+        result.is_ok(),
+        "Unset env should be valid: {:?}",
+        result.err()
+    );
+}
+
+#[tokio::test]
+async fn test_context_try_new_succeeds_with_valid_config() {
+    let client = Context::new_mock().client;
+
+    let result = temp_env::with_vars(
+        [
+            ("KULTA_PROMETHEUS_ADDRESS", Some("http://prometheus:9090")),
+            ("KULTA_CDEVENTS_ENABLED", Some("true")),
+            ("KULTA_CDEVENTS_SINK_URL", Some("http://sink:8080/events")),
+        ],
+        || Context::try_new(client, None, None),
+    );
+
+    assert!(
+        result.is_ok(),
+        "Valid env should succeed: {:?}",
+        result.err()
+    );
+}
+
+#[tokio::test]
+async fn test_context_try_new_rejects_invalid_prometheus_url() {
+    let client = Context::new_mock().client;
+
+    let result = temp_env::with_vars(
+        [
+            ("KULTA_PROMETHEUS_ADDRESS", Some("not a url")),
+            ("KULTA_CDEVENTS_ENABLED", None),
+            ("KULTA_CDEVENTS_SINK_URL", None),
+        ],
+        || Context::try_new(client, None, None),
+    );
+
+    let err = result.err().expect("Invalid Prometheus URL should fail");
+    assert!(err.to_string().contains("KULTA_PROMETHEUS_ADDRESS"));
+}
+
+#[tokio::test]
+async fn test_context_try_new_rejects_non_http_prometheus_scheme() {
+    let client = Context::new_mock().client;
+
+    let result = temp_env::with_vars(
+        [
+            ("KULTA_PROMETHEUS_ADDRESS", Some("ftp://prometheus:9090")),
+            ("KULTA_CDEVENTS_ENABLED", None),
+            ("KULTA_CDEVENTS_SINK_URL", None),
+        ],
+        || Context::try_new(client, None, None),
+    );
+
+    assert!(result.is_err(), "Non-http scheme should fail");
+}
+
+#[tokio::test]
+async fn test_context_try_new_rejects_cdevents_enabled_without_url() {
+    let client = Context::new_mock().client;
+
+    let result = temp_env::with_vars(
+        [
+            ("KULTA_PROMETHEUS_ADDRESS", None::<&str>),
+            ("KULTA_CDEVENTS_ENABLED", Some("true")),
+            ("KULTA_CDEVENTS_SINK_URL", None),
+        ],
+        || Context::try_new(client, None, None),
+    );
+
+    let err = result
+        .err()
+        .expect("CDEvents enabled without URL should fail");
+    assert!(err.to_string().contains("KULTA_CDEVENTS_SINK_URL"));
+}
+
+#[tokio::test]
+async fn test_context_try_new_with_leader_state() {
+    let client = Context::new_mock().client;
+    let leader_state = crate::server::LeaderState::new();
+
+    let ctx = temp_env::with_vars(
+        [
+            ("KULTA_PROMETHEUS_ADDRESS", None::<&str>),
+            ("KULTA_CDEVENTS_ENABLED", None),
+            ("KULTA_CDEVENTS_SINK_URL", None),
+        ],
+        || Context::try_new(client, Some(leader_state), None),
+    )
+    .expect("Should create context");
+
+    assert!(
+        !ctx.should_reconcile(),
+        "Leader-gated context should not reconcile until elected"
+    );
+}
+
+// =============================================================================
+// Restart Annotation Tests
+// =============================================================================
+
+fn with_annotation(mut rollout: Rollout, key: &str, value: &str) -> Rollout {
+    rollout
+        .metadata
+        .annotations
+        .get_or_insert_with(Default::default)
+        .insert(key.to_string(), value.to_string());
+    rollout
+}
+
+fn pod_template_hash_label(rs: &ReplicaSet) -> String {
+    rs.metadata
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.get("pod-template-hash"))
+        .cloned()
+        .expect("ReplicaSet should have pod-template-hash label")
+}
+
+#[test]
+fn test_has_restart_annotation() {
+    let rollout = create_test_rollout_with_canary();
+    assert!(!has_restart_annotation(&rollout));
+
+    let rollout = with_annotation(rollout, "kulta.io/restart", "2024-01-01T00:00:00Z");
+    assert!(has_restart_annotation(&rollout));
+
+    let empty = with_annotation(create_test_rollout_with_canary(), "kulta.io/restart", "");
+    assert!(
+        !has_restart_annotation(&empty),
+        "Empty value is not a trigger"
+    );
+}
+
+#[test]
+fn test_restart_annotation_injected_into_pod_template() {
+    let rollout = with_annotation(
+        create_test_rollout_with_canary(),
+        "kulta.io/restart",
+        "2024-01-01T00:00:00Z",
+    );
+
+    let rs = build_replicaset(&rollout, "canary", "canary", 1).expect("Should build ReplicaSet");
+    let pod_annotations = rs
+        .spec
+        .and_then(|spec| spec.template)
+        .and_then(|template| template.metadata)
+        .and_then(|metadata| metadata.annotations)
+        .expect("Pod template should have annotations");
+
+    assert_eq!(
+        pod_annotations.get("kulta.io/restartedAt"),
+        Some(&"2024-01-01T00:00:00Z".to_string())
+    );
+}
+
+#[test]
+fn test_restart_annotation_changes_pod_template_hash() {
+    let rollout = create_test_rollout_with_canary();
+    let original =
+        build_replicaset(&rollout, "stable", "stable", 1).expect("Should build ReplicaSet");
+
+    let restarted = with_annotation(rollout.clone(), "kulta.io/restart", "2024-01-01T00:00:00Z");
+    let first_restart =
+        build_replicaset(&restarted, "stable", "stable", 1).expect("Should build ReplicaSet");
+
+    let restarted_again = with_annotation(rollout, "kulta.io/restart", "2024-01-02T00:00:00Z");
+    let second_restart =
+        build_replicaset(&restarted_again, "stable", "stable", 1).expect("Should build ReplicaSet");
+
+    assert_ne!(
+        pod_template_hash_label(&original),
+        pod_template_hash_label(&first_restart),
+        "Restart annotation should change the hash"
+    );
+    assert_ne!(
+        pod_template_hash_label(&first_restart),
+        pod_template_hash_label(&second_restart),
+        "New restart value should change the hash again"
+    );
+}
+
+#[test]
+fn test_unchanged_restart_annotation_is_noop() {
+    let rollout = with_annotation(
+        create_test_rollout_with_canary(),
+        "kulta.io/restart",
+        "2024-01-01T00:00:00Z",
+    );
+
+    let first = build_replicaset(&rollout, "stable", "stable", 1).expect("Should build ReplicaSet");
+    let second =
+        build_replicaset(&rollout, "stable", "stable", 1).expect("Should build ReplicaSet");
+
+    assert_eq!(
+        pod_template_hash_label(&first),
+        pod_template_hash_label(&second),
+        "Same restart value should produce the same hash"
+    );
+}
+
+// =============================================================================
+// Rollout Timeout (maxDurationSeconds) Tests
+// =============================================================================
+
+// Helper to create a canary rollout paused indefinitely at step 0, started at `started_at`
+fn create_paused_rollout_with_timeout(
+    max_duration_seconds: Option<u64>,
+    started_at: chrono::DateTime<Utc>,
+) -> Rollout {
+    let mut rollout = create_test_rollout_with_canary();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.max_duration_seconds = max_duration_seconds;
+        canary.steps = vec![
+            CanaryStep {
+                set_weight: Some(20),
+                pause: Some(PauseDuration { duration: None }), // Indefinite pause
+                analysis: None,
+            },
+            CanaryStep {
+                set_weight: Some(100),
+                pause: None,
+                analysis: None,
+            },
+        ];
+    }
+    rollout.status = Some(RolloutStatus {
+        current_step_index: Some(0),
+        current_weight: Some(20),
+        phase: Some(Phase::Paused),
+        pause_start_time: Some(started_at.to_rfc3339()),
+        started_at: Some(started_at.to_rfc3339()),
+        ..Default::default()
+    });
+    rollout
+}
+
+#[test]
+fn test_initialize_rollout_status_sets_started_at() {
+    let mut rollout = create_test_rollout_with_canary();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.steps = vec![CanaryStep {
+            set_weight: Some(20),
+            pause: None,
+            analysis: None,
+        }];
+    }
+
+    let status = initialize_rollout_status(&rollout);
+
+    assert!(status.started_at.is_some(), "started_at should be set");
+}
+
+#[test]
+fn test_compute_desired_status_fails_after_max_duration() {
+    // ARRANGE: 1-second timeout, simulated time 2 seconds after start
+    let started_at = Utc::now();
+    let rollout = create_paused_rollout_with_timeout(Some(1), started_at);
+    let now = started_at + chrono::Duration::seconds(2);
+
+    // ACT
+    let status = compute_desired_status_at(&rollout, now);
+
+    // ASSERT
+    assert_eq!(status.phase, Some(Phase::Failed));
+    assert_eq!(
+        status.message,
+        Some("Rollout timed out after 1s".to_string())
+    );
+}
+
+#[test]
+fn test_compute_desired_status_within_max_duration_unchanged() {
+    // ARRANGE: 60-second timeout, simulated time 2 seconds after start
+    let started_at = Utc::now();
+    let rollout = create_paused_rollout_with_timeout(Some(60), started_at);
+    let now = started_at + chrono::Duration::seconds(2);
+
+    // ACT
+    let status = compute_desired_status_at(&rollout, now);
+
+    // ASSERT: Still paused at step 0
+    assert_eq!(status.phase, Some(Phase::Paused));
+    assert_eq!(status.current_step_index, Some(0));
+}
+
+#[test]
+fn test_compute_desired_status_without_max_duration_never_times_out() {
+    // ARRANGE: No timeout, simulated time one week after start
+    let started_at = Utc::now();
+    let rollout = create_paused_rollout_with_timeout(None, started_at);
+    let now = started_at + chrono::Duration::days(7);
+
+    // ACT
+    let status = compute_desired_status_at(&rollout, now);
+
+    // ASSERT
+    assert_eq!(status.phase, Some(Phase::Paused));
+}
+
+#[test]
+fn test_completed_rollout_does_not_time_out() {
+    // ARRANGE: Completed rollout past its timeout
+    let started_at = Utc::now();
+    let mut rollout = create_paused_rollout_with_timeout(Some(1), started_at);
+    if let Some(status) = rollout.status.as_mut() {
+        status.phase = Some(Phase::Completed);
+    }
+    let now = started_at + chrono::Duration::seconds(10);
+
+    // ACT & ASSERT
+    assert!(check_rollout_timeout(&rollout, now).is_none());
+}
+
+// =============================================================================
+// Traffic Mirroring Tests
+// =============================================================================
+
+fn create_rollout_with_mirror(mirror_service: Option<&str>) -> Rollout {
+    let mut rollout = create_test_rollout_with_canary();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.mirror_service = mirror_service.map(String::from);
+        canary.steps = vec![CanaryStep {
+            set_weight: Some(20),
+            pause: None,
+            analysis: None,
+        }];
+    }
+    rollout.status = Some(RolloutStatus {
+        current_step_index: Some(0),
+        ..Default::default()
+    });
+    rollout
+}
+
+#[test]
+fn test_gateway_api_backend_refs_with_mirror_service() {
+    // ARRANGE
+    let rollout = create_rollout_with_mirror(Some("test-app-shadow"));
+
+    // ACT
+    let backend_refs = build_gateway_api_backend_refs(&rollout);
+
+    // ASSERT: Mirror does not add a weighted backend or consume weight
+    assert_eq!(backend_refs.len(), 2);
+    let total_weight: i32 = backend_refs.iter().filter_map(|b| b.weight).sum();
+    assert_eq!(total_weight, 100);
+
+    // ASSERT: The mirror is a rule filter, not a per-backend one
+    assert!(backend_refs.iter().all(|b| b.filters.is_none()));
+}
+
+#[test]
+fn test_httproute_rule_options_mirror_uses_resolved_port() {
+    // ARRANGE
+    let rollout = create_rollout_with_mirror(Some("test-app-shadow"));
+
+    // ACT
+    let options = build_httproute_rule_options(&rollout, 8080);
+
+    // ASSERT: RequestMirror to the shadow service on its resolved port
+    let filter = serde_json::to_value(options.mirror_filter.expect("Should have mirror filter"))
+        .expect("Should serialize filter");
+    assert_eq!(filter["type"], "RequestMirror");
+    assert_eq!(
+        filter["requestMirror"]["backendRef"]["name"],
+        "test-app-shadow"
+    );
+    assert_eq!(filter["requestMirror"]["backendRef"]["port"], 8080);
+}
+
+#[test]
+fn test_httproute_rule_options_without_mirror_service() {
+    let rollout = create_rollout_with_mirror(None);
+
+    let options = build_httproute_rule_options(&rollout, 80);
+
+    assert!(options.mirror_filter.is_none());
+    assert!(options.sticky_cookie.is_none());
+}
+
+#[test]
+fn test_gateway_api_backend_refs_without_mirror_service() {
+    // ARRANGE
+    let rollout = create_rollout_with_mirror(None);
+
+    // ACT
+    let backend_refs = build_gateway_api_backend_refs(&rollout);
+
+    // ASSERT: No filters on any backend
+    assert_eq!(backend_refs.len(), 2);
+    assert!(backend_refs.iter().all(|b| b.filters.is_none()));
+}
+
+#[test]
+fn test_validate_rollout_rejects_empty_mirror_service() {
+    let rollout = create_rollout_with_mirror(Some(""));
+
+    let result = validate_rollout(&rollout);
+
+    assert_eq!(
+        result,
+        Err("spec.strategy.canary.mirrorService cannot be empty".to_string())
+    );
+}
+
+// =============================================================================
+// Sticky Cookie Tests
+// =============================================================================
+
+fn create_rollout_with_sticky_cookie(cookie: Option<StickyCookie>) -> Rollout {
+    let mut rollout = create_rollout_with_mirror(None);
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.sticky_cookie = cookie;
+        canary.traffic_routing = Some(TrafficRouting {
+            gateway_api: Some(GatewayAPIRouting {
+                http_route: "my-httproute".to_string(),
+                gateway_api_version: None,
+                verify_accepted: None,
+                canary_match: None,
+            }),
+            linkerd: None,
+        });
+    }
+    rollout
+}
+
+fn sticky_cookie(name: &str, max_age_seconds: Option<i64>) -> StickyCookie {
+    StickyCookie {
+        name: name.to_string(),
+        max_age_seconds,
+    }
+}
+
+#[test]
+fn test_gateway_api_backend_refs_sticky_cookie_only_on_canary() {
+    // ARRANGE
+    let rollout = create_rollout_with_sticky_cookie(Some(sticky_cookie("canary", None)));
+
+    // ACT
+    let backend_refs = build_gateway_api_backend_refs(&rollout);
+
+    // ASSERT: Stable carries no filter, canary sets the cookie on its responses
+    assert_eq!(backend_refs.len(), 2);
+    assert_eq!(backend_refs[0].name, "test-app-stable");
+    assert!(backend_refs[0].filters.is_none());
+
+    let json = serde_json::to_value(&backend_refs[1]).expect("Should serialize backend ref");
+    let filters = json["filters"]
+        .as_array()
+        .expect("Canary should have filters");
+    assert_eq!(filters.len(), 1);
+    assert_eq!(filters[0]["type"], "ResponseHeaderModifier");
+    assert_eq!(
+        filters[0]["responseHeaderModifier"]["add"][0]["name"],
+        "Set-Cookie"
+    );
+    assert_eq!(
+        filters[0]["responseHeaderModifier"]["add"][0]["value"],
+        "canary=canary; Path=/"
+    );
+}
+
+#[test]
+fn test_gateway_api_backend_refs_sticky_cookie_with_mirror() {
+    // ARRANGE
+    let mut rollout = create_rollout_with_sticky_cookie(Some(sticky_cookie("canary", None)));
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.mirror_service = Some("test-app-shadow".to_string());
+    }
+
+    // ACT
+    let backend_refs = build_gateway_api_backend_refs(&rollout);
+    let options = build_httproute_rule_options(&rollout, 80);
+
+    // ASSERT: Only the canary backend carries a filter (the cookie), the mirror is rule-level
+    assert!(backend_refs[0].filters.is_none());
+    let json = serde_json::to_value(&backend_refs[1]).expect("Should serialize backend ref");
+    let canary_filters = json["filters"].as_array().expect("Canary filters");
+    assert_eq!(canary_filters.len(), 1);
+    assert_eq!(canary_filters[0]["type"], "ResponseHeaderModifier");
+    assert!(options.mirror_filter.is_some());
+    assert_eq!(options.sticky_cookie, Some(sticky_cookie("canary", None)));
+}
+
+#[test]
+fn test_sticky_cookie_header_with_max_age() {
+    assert_eq!(
+        sticky_cookie_header(&sticky_cookie("kulta-canary", Some(3600))),
+        "kulta-canary=canary; Path=/; Max-Age=3600"
+    );
+}
+
+#[test]
+fn test_sticky_cookie_match_pattern_escapes_name() {
+    assert_eq!(
+        sticky_cookie_match_pattern(&sticky_cookie("kulta.canary", None)),
+        r"(^|;\s*)kulta\.canary=canary(;|$)"
+    );
+}
+
+#[test]
+fn test_validate_rollout_accepts_sticky_cookie() {
+    let rollout = create_rollout_with_sticky_cookie(Some(sticky_cookie("canary", Some(0))));
+
+    assert_eq!(validate_rollout(&rollout), Ok(()));
+}
+
+#[test]
+fn test_validate_rollout_rejects_invalid_sticky_cookie() {
+    for name in ["", "my cookie", "a;b", "a=b"] {
+        let rollout = create_rollout_with_sticky_cookie(Some(sticky_cookie(name, None)));
+        let result = validate_rollout(&rollout);
+        assert!(
+            result
+                .as_ref()
+                .is_err_and(|e| e.contains("stickyCookie.name is not a valid cookie name")),
+            "{:?} should be rejected, got {:?}",
+            name,
+            result
+        );
+    }
+
+    let rollout = create_rollout_with_sticky_cookie(Some(sticky_cookie("canary", Some(-1))));
+    assert_eq!(
+        validate_rollout(&rollout),
+        Err("spec.strategy.canary.stickyCookie.maxAgeSeconds must be >= 0, got -1".to_string())
+    );
+}
+
+#[test]
+fn test_validate_rollout_sticky_cookie_requires_gateway_api() {
+    let mut rollout = create_rollout_with_sticky_cookie(Some(sticky_cookie("canary", None)));
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.traffic_routing = None;
+    }
+
+    assert_eq!(
+        validate_rollout(&rollout),
+        Err("spec.strategy.canary.stickyCookie requires trafficRouting.gatewayAPI".to_string())
+    );
+}
+
+// =============================================================================
+// Canary Match Tests
+// =============================================================================
+
+fn create_rollout_with_canary_match(matches: Vec<HttpRouteMatch>) -> Rollout {
+    let mut rollout = create_rollout_with_sticky_cookie(None);
+    if let Some(gateway) = rollout
+        .spec
+        .strategy
+        .canary
+        .as_mut()
+        .and_then(|canary| canary.traffic_routing.as_mut())
+        .and_then(|routing| routing.gateway_api.as_mut())
+    {
+        gateway.canary_match = Some(matches);
+    }
+    rollout
+}
+
+fn route_match(path_prefix: Option<&str>, methods: &[&str]) -> HttpRouteMatch {
+    HttpRouteMatch {
+        path_prefix: path_prefix.map(String::from),
+        methods: (!methods.is_empty()).then(|| methods.iter().map(|m| m.to_string()).collect()),
+    }
+}
+
+#[test]
+fn test_validate_rollout_accepts_canary_match() {
+    let rollout = create_rollout_with_canary_match(vec![
+        route_match(Some("/api/v2"), &[]),
+        route_match(None, &["GET", "POST"]),
+    ]);
+
+    assert_eq!(validate_rollout(&rollout), Ok(()));
+}
+
+#[test]
+fn test_validate_rollout_rejects_invalid_canary_match() {
+    let path = "spec.strategy.canary.trafficRouting.gatewayAPI.canaryMatch";
+    let cases = [
+        (vec![], format!("{} must not be empty", path)),
+        (
+            vec![route_match(None, &[])],
+            format!("{}[0] must set pathPrefix and/or methods", path),
+        ),
+        (
+            vec![route_match(Some("api"), &[])],
+            format!("{}[0].pathPrefix must start with '/', got \"api\"", path),
+        ),
+        (
+            vec![route_match(Some("/api"), &[]), route_match(None, &["get"])],
+            format!(
+                "{}[1].methods has unsupported method \"get\" (expected one of GET, HEAD, POST, PUT, DELETE, CONNECT, OPTIONS, TRACE, PATCH)",
+                path
+            ),
+        ),
+    ];
+
+    for (matches, expected) in cases {
+        let rollout = create_rollout_with_canary_match(matches);
+        assert_eq!(validate_rollout(&rollout), Err(expected));
+    }
+}
+
+// =============================================================================
+// Step Rate Limiter Tests
+// =============================================================================
+
+#[test]
+fn test_step_rate_limiter_limits_tight_reconcile_loop() {
+    use crate::controller::rate_limiter::StepRateLimiter;
+    use std::time::Instant;
+
+    // ARRANGE: 5-step canary without pauses (every reconcile wants to advance)
+    let mut rollout = create_test_rollout_with_canary();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.steps = [10, 20, 30, 40, 50]
+            .iter()
+            .map(|weight| CanaryStep {
+                set_weight: Some(*weight),
+                pause: None,
+                analysis: None,
+            })
+            .collect();
+    }
+    rollout.status = Some(initialize_rollout_status(&rollout));
+
+    let limiter = StepRateLimiter::new(Duration::from_secs(5));
+    let start = Instant::now();
+
+    // Simulates reconcile's step rate limiting for a burst of events at one instant
+    let reconcile_burst = |rollout: &mut Rollout, now: Instant| {
+        for _ in 0..100 {
+            let desired = compute_desired_status(rollout);
+            let current = rollout.status.as_ref().and_then(|s| s.current_step_index);
+            if desired.current_step_index > current
+                && !limiter.try_acquire_at("default", "test-rollout", now)
+            {
+                continue; // Rate limited - reconcile would requeue
+            }
+            rollout.status = Some(desired);
+        }
+    };
+
+    // ACT & ASSERT: 100 reconciles at the same instant advance at most one step
+    reconcile_burst(&mut rollout, start);
+    assert_eq!(
+        rollout.status.as_ref().and_then(|s| s.current_step_index),
+        Some(1)
+    );
+
+    // ACT & ASSERT: Still within the interval - no further advancement
+    reconcile_burst(&mut rollout, start + Duration::from_secs(4));
+    assert_eq!(
+        rollout.status.as_ref().and_then(|s| s.current_step_index),
+        Some(1)
+    );
+
+    // ACT & ASSERT: Interval elapsed - exactly one more step
+    reconcile_burst(&mut rollout, start + Duration::from_secs(5));
+    assert_eq!(
+        rollout.status.as_ref().and_then(|s| s.current_step_index),
+        Some(2)
+    );
+}
+
+#[tokio::test]
+async fn test_context_try_new_rejects_invalid_min_step_interval() {
+    let client = Context::new_mock().client;
+
+    let result = temp_env::with_vars(
+        [
+            ("KULTA_PROMETHEUS_ADDRESS", None::<&str>),
+            ("KULTA_CDEVENTS_ENABLED", None),
+            ("KULTA_CDEVENTS_SINK_URL", None),
+            ("KULTA_MIN_STEP_INTERVAL_SECS", Some("fast")),
+        ],
+        || Context::try_new(client, None, None),
+    );
+
+    let err = result.err().expect("Invalid step interval should fail");
+    assert!(err.to_string().contains("KULTA_MIN_STEP_INTERVAL_SECS"));
+}
+
+#[tokio::test]
+async fn test_context_try_new_rejects_invalid_status_batch_interval() {
+    let client = Context::new_mock().client;
+
+    let result = temp_env::with_vars(
+        [
+            ("KULTA_PROMETHEUS_ADDRESS", None::<&str>),
+            ("KULTA_CDEVENTS_ENABLED", None),
+            ("KULTA_CDEVENTS_SINK_URL", None),
+            ("KULTA_STATUS_BATCH_INTERVAL_MS", Some("0")),
+        ],
+        || Context::try_new(client, None, None),
+    );
+
+    let err = result.err().expect("Zero batch interval should fail");
+    assert!(err.to_string().contains("KULTA_STATUS_BATCH_INTERVAL_MS"));
+}
+
+// =============================================================================
+// Observed Generation Tests
+// =============================================================================
+
+#[test]
+fn test_observe_generation_carries_incoming_generation() {
+    // ARRANGE: Rollout at generation 7
+    let mut rollout = create_test_rollout_with_canary();
+    rollout.metadata.generation = Some(7);
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.steps = vec![CanaryStep {
+            set_weight: Some(20),
+            pause: None,
+            analysis: None,
+        }];
+    }
+
+    // ACT
+    let mut desired_status = compute_desired_status(&rollout);
+    observe_generation(&rollout, &mut desired_status);
+
+    // ASSERT
+    assert_eq!(desired_status.observed_generation, Some(7));
+}
+
+#[test]
+fn test_generation_mismatch_is_detectable() {
+    // ARRANGE: Status observed generation 3, spec now at generation 4
+    let mut rollout = create_test_rollout_with_canary();
+    rollout.metadata.generation = Some(4);
+    rollout.status = Some(RolloutStatus {
+        observed_generation: Some(3),
+        ..Default::default()
+    });
+
+    // ACT & ASSERT: Stale
+    assert!(!is_generation_observed(&rollout));
+
+    // ACT & ASSERT: Caught up after observing
+    let mut status = rollout.status.clone().unwrap_or_default();
+    observe_generation(&rollout, &mut status);
+    rollout.status = Some(status);
+    assert!(is_generation_observed(&rollout));
+}
+
+#[test]
+fn test_generation_not_observed_without_status() {
+    let mut rollout = create_test_rollout_with_canary();
+    rollout.metadata.generation = Some(1);
+
+    assert!(!is_generation_observed(&rollout));
+}
+
+// ============================================================================
+// Status patch optimistic concurrency
+// ============================================================================
+
+#[test]
+fn test_status_patch_includes_resource_version_precondition() {
+    // ARRANGE
+    let mut rollout = create_test_rollout_with_canary();
+    rollout.metadata.resource_version = Some("42".to_string());
+    let status = RolloutStatus {
+        phase: Some(Phase::Progressing),
+        current_weight: Some(20),
+        ..Default::default()
+    };
+
+    // ACT
+    let patch = build_status_patch(&rollout, &status);
+
+    // ASSERT: Server-side apply payload with resourceVersion precondition
+    assert_eq!(patch["apiVersion"], "kulta.io/v1alpha1");
+    assert_eq!(patch["kind"], "Rollout");
+    assert_eq!(patch["metadata"]["name"], "test-rollout");
+    assert_eq!(patch["metadata"]["resourceVersion"], "42");
+    assert_eq!(patch["status"]["phase"], "Progressing");
+    assert_eq!(patch["status"]["currentWeight"], 20);
+}
+
+#[test]
+fn test_status_patch_uses_stale_resource_version_as_read() {
+    // ARRANGE: Rollout as read by this replica (another replica has since
+    // bumped the resourceVersion on the server)
+    let mut stale = create_test_rollout_with_canary();
+    stale.metadata.resource_version = Some("100".to_string());
+
+    let mut fresh = stale.clone();
+    fresh.metadata.resource_version = Some("101".to_string());
+
+    // ACT
+    let patch = build_status_patch(&stale, &RolloutStatus::default());
+
+    // ASSERT: The precondition carries the version we read, so the API
+    // server rejects it with 409 rather than overwriting the newer status
+    assert_eq!(patch["metadata"]["resourceVersion"], "100");
+    assert_ne!(
+        patch["metadata"]["resourceVersion"],
+        serde_json::json!(fresh.metadata.resource_version)
+    );
+}
+
+#[test]
+fn test_status_patch_params_use_default_field_manager() {
+    let params = build_status_patch_params(STATUS_FIELD_MANAGER);
+
+    assert_eq!(params.field_manager, Some("kulta-controller".to_string()));
+    assert!(
+        params.force,
+        "status apply should force ownership of KULTA fields"
+    );
+}
+
+#[test]
+fn test_status_patch_params_use_configured_field_manager() {
+    let field_manager = temp_env::with_var("KULTA_FIELD_MANAGER", Some("kulta-staging"), || {
+        field_manager_from_env()
+    });
+
+    let params = build_status_patch_params(&field_manager);
+
+    assert_eq!(params.field_manager, Some("kulta-staging".to_string()));
+    assert!(params.force);
+}
+
+#[test]
+fn test_field_manager_from_env_defaults() {
+    let unset = temp_env::with_var_unset("KULTA_FIELD_MANAGER", field_manager_from_env);
+    let empty = temp_env::with_var("KULTA_FIELD_MANAGER", Some(""), field_manager_from_env);
+
+    assert_eq!(unset, STATUS_FIELD_MANAGER);
+    assert_eq!(empty, STATUS_FIELD_MANAGER);
+}
+
+#[test]
+fn test_validate_env_config_rejects_invalid_field_manager() {
+    let too_long = "k".repeat(MAX_FIELD_MANAGER_LEN + 1);
+
+    for value in ["", too_long.as_str()] {
+        let result = temp_env::with_var("KULTA_FIELD_MANAGER", Some(value), validate_env_config);
+        assert!(
+            result.is_err(),
+            "field manager {:?} should be rejected",
+            value
+        );
+    }
+}
+
+#[test]
+fn test_validate_env_config_rejects_unknown_enabled_strategy() {
+    let result = temp_env::with_var(
+        "KULTA_ENABLED_STRATEGIES",
+        Some("canary,rolling"),
+        validate_env_config,
+    );
+    assert!(result.is_err(), "unknown strategy should be rejected");
+}
+
+// ============================================================================
+// Enabled strategies (KULTA_ENABLED_STRATEGIES)
+// ============================================================================
+
+#[test]
+fn test_canary_rollout_fails_when_only_simple_enabled() {
+    let rollout = create_test_rollout_with_canary();
+    let enabled = parse_enabled_strategies(Some("simple")).unwrap();
+
+    let result = select_enabled_strategy(&rollout, &enabled);
+
+    match result {
+        Err(StrategyError::StrategyDisabled(name)) => assert_eq!(name, "canary"),
+        Err(e) => panic!("expected StrategyDisabled, got {:?}", e),
+        Ok(strategy) => panic!("expected StrategyDisabled, got {}", strategy.name()),
+    }
+
+    let failed = strategy_disabled_status(&rollout, "canary").unwrap();
+    assert_eq!(failed.phase, Some(Phase::Failed));
+    assert_eq!(
+        failed.message.as_deref(),
+        Some("Strategy canary is disabled on this controller (KULTA_ENABLED_STRATEGIES)")
+    );
+}
+
+#[test]
+fn test_strategy_disabled_status_is_idempotent() {
+    let mut rollout = create_test_rollout_with_canary();
+    rollout.status = strategy_disabled_status(&rollout, "canary");
+
+    assert_eq!(strategy_disabled_status(&rollout, "canary"), None);
+}
+
+#[test]
+fn test_enabled_strategy_is_selected() {
+    let rollout = create_test_rollout_with_canary();
+    let enabled = parse_enabled_strategies(Some("canary, simple")).unwrap();
+
+    let strategy = select_enabled_strategy(&rollout, &enabled).unwrap();
+    assert_eq!(strategy.name(), "canary");
+}
+
+// ============================================================================
+// Multiple strategy validation
+// ============================================================================
+
+fn assert_rejects_multiple_strategies(rollout: &Rollout) {
+    let result = validate_rollout(rollout);
+    assert!(
+        result.is_err(),
+        "Expected multiple strategies to be rejected"
+    );
+    let error = result.unwrap_err();
+    assert!(
+        error.contains("only one strategy may be configured"),
+        "Expected only-one-strategy error, got: {}",
+        error
+    );
+}
+
+#[test]
+fn test_validate_rollout_rejects_canary_and_blue_green() {
+    let mut rollout = create_test_rollout_with_canary();
+    rollout.spec.strategy.blue_green = create_test_rollout_with_blue_green()
+        .spec
+        .strategy
+        .blue_green;
+
+    assert_rejects_multiple_strategies(&rollout);
+}
+
+#[test]
+fn test_validate_rollout_rejects_simple_and_canary() {
+    let mut rollout = create_test_rollout_with_simple();
+    rollout.spec.strategy.canary = create_test_rollout_with_canary().spec.strategy.canary;
+
+    assert_rejects_multiple_strategies(&rollout);
+}
+
+#[test]
+fn test_validate_rollout_rejects_simple_and_blue_green() {
+    let mut rollout = create_test_rollout_with_simple();
+    rollout.spec.strategy.blue_green = create_test_rollout_with_blue_green()
+        .spec
+        .strategy
+        .blue_green;
+
+    assert_rejects_multiple_strategies(&rollout);
+}
+
+#[test]
+fn test_validate_rollout_rejects_all_three_strategies() {
+    let mut rollout = create_test_rollout_with_simple();
+    rollout.spec.strategy.canary = create_test_rollout_with_canary().spec.strategy.canary;
+    rollout.spec.strategy.blue_green = create_test_rollout_with_blue_green()
+        .spec
+        .strategy
+        .blue_green;
+
+    assert_rejects_multiple_strategies(&rollout);
+}
+
+#[test]
+fn test_validate_rollout_rejects_empty_strategy() {
+    let mut rollout = create_test_rollout_with_canary();
+    rollout.spec.strategy = RolloutStrategy {
+        simple: None,
+        canary: None,
+        blue_green: None,
+    };
+
+    // The empty strategy would be handled as canary...
+    assert_eq!(select_strategy(&rollout).name(), "canary");
+    // ...but is rejected up front with an explicit error
+    assert_eq!(
+        validate_rollout(&rollout),
+        Err(EMPTY_STRATEGY_ERROR.to_string())
+    );
+}
+
+#[test]
+fn test_validate_rollout_accepts_single_strategy() {
+    assert!(validate_rollout(&create_test_rollout_with_simple()).is_ok());
+    assert!(validate_rollout(&create_test_rollout_with_blue_green()).is_ok());
+}
+
+// ============================================================================
+// Blue-green validation
+// ============================================================================
+
+/// Validate a blue-green rollout after `modify` and return the error
+fn blue_green_validation_error(modify: impl FnOnce(&mut Rollout)) -> String {
+    let mut rollout = create_test_rollout_with_blue_green();
+    modify(&mut rollout);
+    validate_rollout(&rollout).unwrap_err()
+}
+
+fn blue_green_mut(rollout: &mut Rollout) -> &mut crate::crd::rollout::BlueGreenStrategy {
+    rollout.spec.strategy.blue_green.as_mut().unwrap()
+}
+
+fn blue_green_analysis(threshold: f64) -> crate::crd::rollout::AnalysisConfig {
+    use crate::crd::rollout::{AnalysisConfig, MetricConfig};
+
+    AnalysisConfig {
+        prometheus: None,
+        failure_policy: None,
+        warmup_duration: None,
+        interval: None,
+        metrics: vec![MetricConfig {
+            name: "error-rate".to_string(),
+            threshold,
+            interval: None,
+            failure_threshold: None,
+            min_sample_size: None,
+            comparison: None,
+            labels: BTreeMap::new(),
+        }],
+        requirement: None,
+        consecutive_failure_threshold: None,
+        mode: None,
+        tolerance: None,
+    }
+}
+
+#[test]
+fn test_validate_rollout_empty_active_service() {
+    let error = blue_green_validation_error(|r| blue_green_mut(r).active_service = String::new());
+
+    assert!(
+        error.contains("spec.strategy.blueGreen.activeService cannot be empty"),
+        "Expected active service error, got: {}",
+        error
+    );
+}
+
+#[test]
+fn test_validate_rollout_empty_preview_service() {
+    let error = blue_green_validation_error(|r| blue_green_mut(r).preview_service = String::new());
+
+    assert!(
+        error.contains("spec.strategy.blueGreen.previewService cannot be empty"),
+        "Expected preview service error, got: {}",
+        error
+    );
+}
+
+#[test]
+fn test_validate_rollout_rejects_equal_blue_green_services() {
+    let error = blue_green_validation_error(|r| {
+        blue_green_mut(r).preview_service = "my-app-active".to_string();
+    });
+
+    assert!(
+        error.contains("previewService must differ from activeService"),
+        "Expected equal services error, got: {}",
+        error
+    );
+}
+
+#[test]
+fn test_validate_rollout_accepts_declarative_promotion_in_preview() {
+    let mut rollout = create_test_rollout_with_blue_green();
+    blue_green_mut(&mut rollout).active_service = "my-app-preview".to_string();
+
+    for phase in [Phase::Preview, Phase::Completed] {
+        rollout.status = Some(RolloutStatus {
+            phase: Some(phase.clone()),
+            ..Default::default()
+        });
+        assert!(
+            validate_rollout(&rollout).is_ok(),
+            "activeService == previewService promotes in {:?}",
+            phase
+        );
+    }
+}
+
+#[test]
+fn test_validate_rollout_auto_promotion_seconds_out_of_range() {
+    for seconds in [0, -5, MAX_AUTO_PROMOTION_SECONDS + 1] {
+        let error = blue_green_validation_error(|r| {
+            blue_green_mut(r).auto_promotion_seconds = Some(seconds);
+        });
+
+        assert!(
+            error.contains(&format!(
+                "spec.strategy.blueGreen.autoPromotionSeconds must be 1-{}, got {}",
+                MAX_AUTO_PROMOTION_SECONDS, seconds
+            )),
+            "Expected autoPromotionSeconds error, got: {}",
+            error
+        );
+    }
+}
+
+#[test]
+fn test_validate_rollout_accepts_auto_promotion_seconds() {
+    let mut rollout = create_test_rollout_with_blue_green();
+    blue_green_mut(&mut rollout).auto_promotion_seconds = Some(300);
+
+    assert!(validate_rollout(&rollout).is_ok());
+}
+
+#[test]
+fn test_validate_rollout_blue_green_invalid_threshold() {
+    for threshold in [-1.0, f64::NAN, f64::INFINITY] {
+        let error = blue_green_validation_error(|r| {
+            blue_green_mut(r).analysis = Some(blue_green_analysis(threshold));
+        });
+
+        assert!(
+            error.contains("spec.strategy.blueGreen.analysis.metrics[0].threshold must be a finite number >= 0"),
+            "Expected threshold error, got: {}",
+            error
+        );
+    }
+}
+
+#[test]
+fn test_validate_rollout_blue_green_analysis_requirement() {
+    use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+    let error = blue_green_validation_error(|r| {
+        let mut analysis = blue_green_analysis(5.0);
+        analysis.requirement = Some(IntOrString::Int(2));
+        blue_green_mut(r).analysis = Some(analysis);
+    });
+
+    assert!(
+        error.contains(
+            "spec.strategy.blueGreen.analysis.requirement 2 exceeds the number of metrics (1)"
+        ),
+        "Expected requirement error, got: {}",
+        error
+    );
+}
+
+#[test]
+fn test_validate_rollout_accepts_blue_green_analysis() {
+    let mut rollout = create_test_rollout_with_blue_green();
+    blue_green_mut(&mut rollout).analysis = Some(blue_green_analysis(5.0));
+
+    assert!(validate_rollout(&rollout).is_ok());
+}
+
+// ============================================================================
+// Canary overrides
+// ============================================================================
+
+fn create_rollout_with_canary_resource_overrides() -> Rollout {
+    use crate::crd::rollout::CanaryOverrides;
+    use k8s_openapi::api::core::v1::ResourceRequirements;
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+    let mut rollout = create_test_rollout_with_canary();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.canary_overrides = Some(CanaryOverrides {
+            resources: Some(ResourceRequirements {
+                limits: Some(
+                    [("memory".to_string(), Quantity("128Mi".to_string()))]
+                        .into_iter()
+                        .collect(),
+                ),
+                ..Default::default()
+            }),
+        });
+    }
+    rollout
+}
+
+fn first_container_resources(
+    rs: &ReplicaSet,
+) -> Option<k8s_openapi::api::core::v1::ResourceRequirements> {
+    rs.spec
+        .as_ref()
+        .and_then(|s| s.template.as_ref())
+        .and_then(|t| t.spec.as_ref())
+        .and_then(|s| s.containers.first())
+        .and_then(|c| c.resources.clone())
+}
+
+#[test]
+fn test_canary_overrides_apply_only_to_canary_replicaset() {
+    // ARRANGE
+    let rollout = create_rollout_with_canary_resource_overrides();
+
+    // ACT
+    let stable_rs = build_replicaset(&rollout, "stable", "stable", 2).unwrap();
+    let canary_rs = build_replicaset(&rollout, "canary", "canary", 1).unwrap();
+
+    // ASSERT: Canary gets the overridden limits, stable keeps the template's
+    let canary_resources = first_container_resources(&canary_rs).unwrap();
+    assert_eq!(
+        canary_resources
+            .limits
+            .as_ref()
+            .and_then(|l| l.get("memory"))
+            .map(|q| q.0.as_str()),
+        Some("128Mi")
+    );
+    assert_eq!(first_container_resources(&stable_rs), None);
+    assert_ne!(
+        first_container_resources(&stable_rs),
+        first_container_resources(&canary_rs)
+    );
+}
+
+#[test]
+fn test_canary_overrides_change_only_canary_hash() {
+    // ARRANGE
+    let plain = create_test_rollout_with_canary();
+    let overridden = create_rollout_with_canary_resource_overrides();
+
+    // ACT
+    let plain_stable = build_replicaset(&plain, "stable", "stable", 2).unwrap();
+    let plain_canary = build_replicaset(&plain, "canary", "canary", 1).unwrap();
+    let overridden_stable = build_replicaset(&overridden, "stable", "stable", 2).unwrap();
+    let overridden_canary = build_replicaset(&overridden, "canary", "canary", 1).unwrap();
+
+    // ASSERT: Stable hash unaffected, canary hash changes independently
+    assert_eq!(
+        pod_template_hash_label(&plain_stable),
+        pod_template_hash_label(&overridden_stable)
+    );
+    assert_ne!(
+        pod_template_hash_label(&plain_canary),
+        pod_template_hash_label(&overridden_canary)
+    );
+    assert_ne!(
+        pod_template_hash_label(&overridden_stable),
+        pod_template_hash_label(&overridden_canary)
+    );
+}
+
+// ============================================================================
+// Backend weight sum validation
+// ============================================================================
+
+#[test]
+fn test_validate_backend_weights_valid_sum() {
+    assert_eq!(validate_backend_weights(0, 80, 20, &[]), Ok(()));
+    assert_eq!(validate_backend_weights(1, 0, 100, &[0]), Ok(()));
+}
+
+#[test]
+fn test_validate_backend_weights_rejects_over_100() {
+    let result = validate_backend_weights(2, 70, 30, &[10]);
+
+    assert_eq!(
+        result,
+        Err("steps[2]: backend weights sum to 110, must not exceed 100".to_string())
+    );
+}
+
+#[test]
+fn test_validate_backend_weights_rejects_non_100_pair() {
+    let result = validate_backend_weights(0, 60, 30, &[]);
+
+    assert_eq!(
+        result,
+        Err("steps[0]: stable (60) + canary (30) weights must sum to 100".to_string())
+    );
+}
+
+#[test]
+fn test_validate_rollout_accepts_valid_step_weights() {
+    let mut rollout = create_test_rollout_with_canary();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.steps = [0, 25, 100]
+            .iter()
+            .map(|weight| CanaryStep {
+                set_weight: Some(*weight),
+                pause: None,
+                analysis: None,
+            })
+            .collect();
+    }
+
+    assert_eq!(validate_rollout(&rollout), Ok(()));
+}
+
+fn rollout_with_max_weight_step(weights: &[i32], max_weight_step: i32) -> Rollout {
+    let mut rollout = create_test_rollout_with_canary();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.steps = weights
+            .iter()
+            .map(|weight| CanaryStep {
+                set_weight: Some(*weight),
+                pause: None,
+                analysis: None,
+            })
+            .collect();
+        canary.max_weight_step = Some(max_weight_step);
+    }
+    rollout
+}
+
+#[test]
+fn test_validate_rollout_accepts_gradual_steps_within_max_weight_step() {
+    let rollout = rollout_with_max_weight_step(&[10, 25, 50, 75, 100], 25);
+
+    assert_eq!(validate_rollout(&rollout), Ok(()));
+}
+
+#[test]
+fn test_validate_rollout_rejects_jump_over_max_weight_step() {
+    let rollout = rollout_with_max_weight_step(&[5, 100], 25);
+
+    assert_eq!(
+        validate_rollout(&rollout),
+        Err(
+            "steps[0] -> steps[1]: weight jumps from 5 to 100 (95), exceeds maxWeightStep 25"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn test_validate_rollout_allows_weight_decrease_with_max_weight_step() {
+    let rollout = rollout_with_max_weight_step(&[50, 10, 30], 20);
+
+    assert_eq!(validate_rollout(&rollout), Ok(()));
+}
+
+#[test]
+fn test_validate_rollout_rejects_out_of_range_max_weight_step() {
+    let rollout = rollout_with_max_weight_step(&[50, 100], 0);
+
+    assert_eq!(
+        validate_rollout(&rollout),
+        Err("spec.strategy.canary.maxWeightStep must be 1-100, got 0".to_string())
+    );
+}
+
+fn rollout_with_min_initial_weight(weights: &[i32], min_initial_weight: i32) -> Rollout {
+    let mut rollout = rollout_with_max_weight_step(weights, 100);
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.max_weight_step = None;
+        canary.min_initial_weight = Some(min_initial_weight);
+    }
+    rollout
+}
+
+#[test]
+fn test_validate_rollout_accepts_initial_weight_at_minimum() {
+    let rollout = rollout_with_min_initial_weight(&[5, 50, 100], 5);
+
+    assert_eq!(validate_rollout(&rollout), Ok(()));
+}
+
+#[test]
+fn test_validate_rollout_rejects_initial_weight_below_minimum() {
+    let rollout = rollout_with_min_initial_weight(&[1, 50, 100], 5);
+
+    assert_eq!(
+        validate_rollout(&rollout),
+        Err("steps[0]: initial weight 1 is below minInitialWeight 5".to_string())
+    );
+}
+
+#[test]
+fn test_validate_rollout_rejects_out_of_range_min_initial_weight() {
+    let rollout = rollout_with_min_initial_weight(&[10, 100], 101);
+
+    assert_eq!(
+        validate_rollout(&rollout),
+        Err("spec.strategy.canary.minInitialWeight must be 1-100, got 101".to_string())
+    );
+}
+
+#[test]
+fn test_validate_rollout_combines_min_initial_weight_and_max_weight_step() {
+    let mut rollout = rollout_with_min_initial_weight(&[10, 100], 5);
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.max_weight_step = Some(25);
+    }
+
+    // The first step clears the minimum, the jump after it doesn't
+    assert_eq!(
+        validate_rollout(&rollout),
+        Err(
+            "steps[0] -> steps[1]: weight jumps from 10 to 100 (90), exceeds maxWeightStep 25"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn test_validate_backend_weights_with_extras_must_sum_to_100() {
+    assert_eq!(validate_backend_weights(1, 60, 20, &[20]), Ok(()));
+
+    assert_eq!(
+        validate_backend_weights(1, 50, 20, &[20]),
+        Err("steps[1]: backend weights sum to 90, must be 100".to_string())
+    );
+}
+
+/// Canary with steps 20/40/60 and an extra "app-canary-b" track (10%) from step 1
+fn create_multi_track_rollout(step_index: i32) -> Rollout {
+    let mut rollout = create_test_rollout_with_canary();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.steps = [20, 40, 60]
+            .iter()
+            .map(|weight| CanaryStep {
+                set_weight: Some(*weight),
+                pause: None,
+                analysis: None,
+            })
+            .collect();
+        canary.extra_canaries = Some(vec![ExtraCanary {
+            service: "app-canary-b".to_string(),
+            weight: 10,
+            step_index: 1,
+        }]);
+    }
+    rollout.status = Some(RolloutStatus {
+        phase: Some(Phase::Progressing),
+        current_step_index: Some(step_index),
+        ..Default::default()
+    });
+    rollout
+}
+
+fn backend_weights(rollout: &Rollout) -> Vec<(String, i32)> {
+    build_gateway_api_backend_refs(rollout)
+        .into_iter()
+        .map(|backend| (backend.name, backend.weight.unwrap_or(-1)))
+        .collect()
+}
+
+#[test]
+fn test_multi_track_backend_refs_split_three_ways() {
+    let rollout = create_multi_track_rollout(1);
+
+    assert_eq!(
+        backend_weights(&rollout),
+        vec![
+            ("test-app-stable".to_string(), 50),
+            ("test-app-canary".to_string(), 40),
+            ("app-canary-b".to_string(), 10),
+        ]
+    );
+    assert_eq!(validate_rollout(&rollout), Ok(()));
+}
+
+#[test]
+fn test_multi_track_extra_canary_inactive_before_step_index() {
+    let rollout = create_multi_track_rollout(0);
+
+    assert_eq!(
+        backend_weights(&rollout),
+        vec![
+            ("test-app-stable".to_string(), 80),
+            ("test-app-canary".to_string(), 20),
+        ]
+    );
+}
+
+#[test]
+fn test_multi_track_extra_canary_dropped_on_rollback_and_completion() {
+    let mut rollout = create_multi_track_rollout(1);
+    rollout.status.as_mut().unwrap().phase = Some(Phase::RollingBack);
+    assert_eq!(calculate_extra_canary_weights(&rollout), vec![]);
+
+    let completed = create_multi_track_rollout(3);
+    assert_eq!(calculate_extra_canary_weights(&completed), vec![]);
+}
+
+#[test]
+fn test_validate_rollout_rejects_extra_canaries_exceeding_100() {
+    let mut rollout = create_multi_track_rollout(0);
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.steps[2].set_weight = Some(95);
+    }
+
+    assert_eq!(
+        validate_rollout(&rollout),
+        Err("steps[2]: backend weights sum to 105, must not exceed 100".to_string())
+    );
+}
+
+#[test]
+fn test_validate_rollout_rejects_invalid_extra_canary() {
+    let mut rollout = create_multi_track_rollout(0);
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.extra_canaries.as_mut().unwrap()[0].step_index = 3;
+    }
+    assert_eq!(
+        validate_rollout(&rollout),
+        Err("spec.strategy.canary.extraCanaries[0].stepIndex must be 0-2, got 3".to_string())
+    );
+
+    let mut rollout = create_multi_track_rollout(0);
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.extra_canaries.as_mut().unwrap()[0].service = "test-app-stable".to_string();
+    }
+    assert!(validate_rollout(&rollout)
+        .unwrap_err()
+        .contains("must differ from stableService and canaryService"));
+}
+
+// ============================================================================
+// Rollback state machine (Progressing → RollingBack → Degraded)
+// ============================================================================
+
+fn create_progressing_canary_rollout() -> Rollout {
+    let mut rollout = create_test_rollout_with_canary();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.steps = [20, 50, 80, 100]
+            .iter()
+            .map(|weight| CanaryStep {
+                set_weight: Some(*weight),
+                pause: None,
+                analysis: None,
+            })
+            .collect();
+    }
+    rollout.status = Some(RolloutStatus {
+        phase: Some(Phase::Progressing),
+        current_step_index: Some(1),
+        current_weight: Some(50),
+        ..Default::default()
+    });
+    rollout
+}
+
+#[test]
+fn test_rollback_full_transition_sequence() {
+    // ARRANGE: Canary at 50%
+    let mut rollout = create_progressing_canary_rollout();
+    assert_eq!(calculate_traffic_weights(&rollout), (50, 50));
+
+    // ACT 1: Metric analysis fails
+    let rolling_back = start_rollback(
+        &rollout,
+        "metric analysis exceeded thresholds",
+        DecisionReason::AnalysisFailed,
+    );
+
+    // ASSERT 1: RollingBack, canary drained from traffic and replicas
+    assert_eq!(rolling_back.phase, Some(Phase::RollingBack));
+    assert_eq!(
+        rolling_back.message.as_deref(),
+        Some("Rolling back: metric analysis exceeded thresholds")
+    );
+    assert_eq!(rolling_back.current_weight, Some(0));
+    rollout.status = Some(rolling_back);
+    assert_eq!(calculate_traffic_weights(&rollout), (100, 0));
+    assert_eq!(
+        calculate_replica_split_with_surge(rollout.spec.replicas, 0, false).1,
+        0
+    );
+
+    // ACT 2: Next reconcile, canary drained
+    let degraded = compute_desired_status(&rollout);
+
+    // ASSERT 2: Degraded
+    assert_eq!(degraded.phase, Some(Phase::Degraded));
+    assert_eq!(
+        degraded.message.as_deref(),
+        Some("Rollback complete: canary drained, stable serving 100% of traffic")
+    );
+    assert_eq!(degraded.current_weight, Some(0));
+    rollout.status = Some(degraded.clone());
+    assert_eq!(calculate_traffic_weights(&rollout), (100, 0));
+
+    // ACT 3 & ASSERT 3: Degraded is terminal (does not advance steps)
+    assert_eq!(compute_desired_status(&rollout), degraded);
+}
+
+#[test]
+fn test_rolling_back_with_canary_weight_keeps_draining() {
+    // ARRANGE: RollingBack but canary weight still set (e.g., edited status)
+    let mut rollout = create_progressing_canary_rollout();
+    if let Some(status) = rollout.status.as_mut() {
+        status.phase = Some(Phase::RollingBack);
+        status.current_weight = Some(50);
+    }
+
+    // ACT
+    let status = compute_desired_status(&rollout);
+
+    // ASSERT: Still RollingBack, weight forced to 0
+    assert_eq!(status.phase, Some(Phase::RollingBack));
+    assert_eq!(status.current_weight, Some(0));
+}
+
+#[test]
+fn test_rolling_back_waits_for_canary_pods_to_terminate() {
+    // ARRANGE: Traffic already drained, but the canary ReplicaSet still has pods
+    let mut rollout = create_progressing_canary_rollout();
+    let rolling_back = start_rollback(
+        &rollout,
+        "metric analysis exceeded thresholds",
+        DecisionReason::AnalysisFailed,
+    );
+    rollout.status = Some(RolloutStatus {
+        canary_replicas: 2,
+        ..rolling_back
+    });
+
+    // ACT
+    let status = compute_desired_status(&rollout);
+
+    // ASSERT: Still RollingBack until the canary ReplicaSet is empty
+    assert_eq!(status.phase, Some(Phase::RollingBack));
+    assert_eq!(status.current_weight, Some(0));
+    assert_eq!(
+        status.message.as_deref(),
+        Some("Rolling back: metric analysis exceeded thresholds")
+    );
+
+    // ACT 2: Canary pods gone
+    rollout.status = Some(RolloutStatus {
+        canary_replicas: 0,
+        ..status
+    });
+
+    // ASSERT 2: Degraded
+    assert_eq!(
+        compute_desired_status(&rollout).phase,
+        Some(Phase::Degraded)
+    );
+}
+
+#[test]
+fn test_degraded_rollout_does_not_time_out() {
+    let mut rollout = create_progressing_canary_rollout();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.max_duration_seconds = Some(1);
+    }
+    if let Some(status) = rollout.status.as_mut() {
+        status.phase = Some(Phase::Degraded);
+        status.started_at = Some("2020-01-01T00:00:00Z".to_string());
+    }
+
+    assert_eq!(check_rollout_timeout(&rollout, Utc::now()), None);
+}
+
+// ============================================================================
+// Canary ReplicaSet readiness gate
+// ============================================================================
+
+fn create_replicaset_with_readiness(replicas: i32, ready_replicas: i32) -> ReplicaSet {
+    use k8s_openapi::api::apps::v1::ReplicaSetStatus;
+
+    ReplicaSet {
+        metadata: ObjectMeta {
+            name: Some("test-rollout-canary".to_string()),
+            ..Default::default()
+        },
+        spec: Some(ReplicaSetSpec {
+            replicas: Some(replicas),
+            ..Default::default()
+        }),
+        status: Some(ReplicaSetStatus {
+            replicas,
+            ready_replicas: Some(ready_replicas),
+            ..Default::default()
+        }),
+    }
+}
+
+#[test]
+fn test_replicaset_readiness_uses_ready_replicas() {
+    let rs = create_replicaset_with_readiness(3, 1);
+
+    assert_eq!(replicaset_readiness(&rs), (1, 3));
+}
+
+#[test]
+fn test_unready_canary_replicaset_holds_rollout_in_waiting_for_readiness() {
+    // ARRANGE: Progressing rollout, canary RS spec.replicas=3 but only 1 ready
+    let mut rollout = create_progressing_canary_rollout();
+    let canary_rs = create_replicaset_with_readiness(3, 1);
+
+    // ACT: First reconcile
+    let waiting = compute_readiness_status(&rollout, &canary_rs)
+        .expect("Unready canary RS should hold the rollout");
+
+    // ASSERT
+    assert_eq!(waiting.phase, Some(Phase::WaitingForReadiness));
+    assert_eq!(
+        waiting.message.as_deref(),
+        Some("Waiting for canary ReplicaSet readiness (1/3 ready)")
+    );
+    assert_eq!(waiting.current_step_index, Some(1), "Step must not advance");
+
+    // ACT & ASSERT: Still unready on the next reconcile - stays waiting
+    rollout.status = Some(waiting.clone());
+    assert_eq!(
+        compute_readiness_status(&rollout, &canary_rs),
+        Some(waiting)
+    );
+}
+
+#[test]
+fn test_ready_canary_replicaset_clears_waiting_for_readiness() {
+    // ARRANGE: Rollout waiting for readiness at step 1, RS now fully ready
+    let mut rollout = create_progressing_canary_rollout();
+    if let Some(status) = rollout.status.as_mut() {
+        status.phase = Some(Phase::WaitingForReadiness);
+        status.message = Some("Waiting for canary ReplicaSet readiness (1/3 ready)".to_string());
+    }
+    let canary_rs = create_replicaset_with_readiness(3, 3);
+
+    // ACT
+    let gate = compute_readiness_status(&rollout, &canary_rs);
+    let next = compute_desired_status(&rollout);
+
+    // ASSERT: Gate released, rollout resumes and advances to the next step
+    assert_eq!(gate, None);
+    assert_eq!(next.phase, Some(Phase::Progressing));
+    assert_eq!(next.current_step_index, Some(2));
+}
+
+#[test]
+fn test_readiness_gate_ignores_non_progressing_rollouts() {
+    let mut rollout = create_progressing_canary_rollout();
+    if let Some(status) = rollout.status.as_mut() {
+        status.phase = Some(Phase::Paused);
+    }
+
+    let gate = compute_readiness_status(&rollout, &create_replicaset_with_readiness(3, 0));
+
+    assert_eq!(gate, None);
+}
+
+// ============================================================================
+// Activity phases: Initializing, Scaling, WaitingForAnalysis, Degraded
+// ============================================================================
+
+#[test]
+fn test_initializing_status_before_replicasets_exist() {
+    let mut rollout = create_progressing_canary_rollout();
+    rollout.status = None;
+    rollout.metadata.generation = Some(1);
+
+    let status = initializing_status(&rollout);
+
+    assert_eq!(status.phase, Some(Phase::Initializing));
+    assert_eq!(status.current_step_index, None);
+    assert_eq!(status.observed_generation, Some(1));
+}
+
+#[test]
+fn test_initializing_transitions_to_progressing_at_step_0() {
+    let mut rollout = create_progressing_canary_rollout();
+    rollout.status = Some(initializing_status(&rollout));
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.phase, Some(Phase::Progressing));
+    assert_eq!(status.current_step_index, Some(0));
+    assert_eq!(status.current_weight, Some(20));
+    assert!(status.started_at.is_some());
+    assert!(status.step_start_time.is_some());
+}
+
+fn create_replicaset_scaling(desired: i32, created: i32) -> ReplicaSet {
+    let mut rs = create_replicaset_with_readiness(desired, 0);
+    if let Some(status) = rs.status.as_mut() {
+        status.replicas = created;
+    }
+    rs
+}
+
+#[test]
+fn test_canary_replicaset_below_desired_count_is_scaling() {
+    let rollout = create_progressing_canary_rollout();
+
+    let scaling = compute_readiness_status(&rollout, &create_replicaset_scaling(3, 1))
+        .expect("Scaling canary RS should hold the rollout");
+
+    assert_eq!(scaling.phase, Some(Phase::Scaling));
+    assert_eq!(
+        scaling.message.as_deref(),
+        Some("Scaling canary ReplicaSet (1/3 replicas)")
+    );
+    assert_eq!(scaling.current_step_index, Some(1), "Step must not advance");
+}
+
+#[test]
+fn test_scaling_transitions_to_waiting_for_readiness_then_progressing() {
+    let mut rollout = create_progressing_canary_rollout();
+    if let Some(status) = rollout.status.as_mut() {
+        status.phase = Some(Phase::Scaling);
+    }
+
+    // All replicas created, none ready yet
+    let waiting = compute_readiness_status(&rollout, &create_replicaset_with_readiness(3, 0))
+        .expect("Unready canary RS should hold the rollout");
+    assert_eq!(waiting.phase, Some(Phase::WaitingForReadiness));
+
+    // Scaled and ready: gate released, rollout resumes
+    assert_eq!(
+        compute_readiness_status(&rollout, &create_replicaset_with_readiness(3, 3)),
+        None
+    );
+    let next = compute_desired_status(&rollout);
+    assert_eq!(next.phase, Some(Phase::Progressing));
+    assert_eq!(next.current_step_index, Some(2));
+}
+
+fn create_rollout_with_step_warmup(warmup: &str, step_start: DateTime<Utc>) -> Rollout {
+    let mut rollout = create_progressing_canary_rollout();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.steps[1].analysis = Some(AnalysisConfig {
+            prometheus: None,
+            failure_policy: None,
+            warmup_duration: Some(warmup.to_string()),
+            interval: None,
+            metrics: vec![],
+            requirement: None,
+            consecutive_failure_threshold: None,
+            mode: None,
+            tolerance: None,
+        });
+    }
+    if let Some(status) = rollout.status.as_mut() {
+        status.step_start_time = Some(step_start.to_rfc3339());
+    }
+    rollout
+}
+
+#[test]
+fn test_step_analysis_warmup_holds_rollout_in_waiting_for_analysis() {
+    let now = Utc::now();
+    let rollout = create_rollout_with_step_warmup("5m", now - chrono::Duration::minutes(1));
+
+    let status = compute_desired_status_at(&rollout, now);
+
+    assert_eq!(status.phase, Some(Phase::WaitingForAnalysis));
+    assert_eq!(status.current_step_index, Some(1), "Step must not advance");
+    assert_eq!(
+        status.message.as_deref(),
+        Some("Waiting for step 1 analysis (5m warmup)")
+    );
+}
+
+#[test]
+fn test_waiting_for_analysis_resumes_after_warmup() {
+    let now = Utc::now();
+    let mut rollout = create_rollout_with_step_warmup("5m", now - chrono::Duration::minutes(6));
+    if let Some(status) = rollout.status.as_mut() {
+        status.phase = Some(Phase::WaitingForAnalysis);
+    }
+
+    let status = compute_desired_status_at(&rollout, now);
+
+    assert_eq!(status.phase, Some(Phase::Progressing));
+    assert_eq!(status.current_step_index, Some(2));
+}
+
+#[test]
+fn test_skip_analysis_annotation_bypasses_analysis_warmup() {
+    let now = Utc::now();
+    let mut rollout = create_rollout_with_step_warmup("5m", now - chrono::Duration::minutes(1));
+    rollout.metadata.annotations = Some(
+        [(skip_analysis_annotation_key(), "true".to_string())]
+            .into_iter()
+            .collect(),
+    );
+
+    let mut status = compute_desired_status_at(&rollout, now);
+    let used = record_analysis_skipped(&rollout, &mut status);
+
+    assert_eq!(compute_analysis_wait_status(&rollout, now), None);
+    assert_eq!(status.phase, Some(Phase::Progressing));
+    assert_eq!(
+        status.current_step_index,
+        Some(2),
+        "Step advances unanalyzed"
+    );
+    assert!(used);
+    assert_eq!(
+        status.decisions.last().map(|decision| &decision.reason),
+        Some(&DecisionReason::SkipAnalysisAnnotation)
+    );
+}
+
+#[test]
+fn test_analysis_warmup_enforced_without_skip_annotation() {
+    let now = Utc::now();
+    let rollout = create_rollout_with_step_warmup("5m", now - chrono::Duration::minutes(1));
+
+    assert!(!has_skip_analysis_annotation(&rollout));
+    let waiting = compute_analysis_wait_status(&rollout, now);
+
+    assert_eq!(
+        waiting.and_then(|status| status.phase),
+        Some(Phase::WaitingForAnalysis)
+    );
+}
+
+#[test]
+fn test_skip_analysis_resume_does_not_record_analysis_passed() {
+    let now = Utc::now();
+    let mut rollout = create_rollout_with_step_warmup("5m", now - chrono::Duration::minutes(1));
+    rollout.metadata.annotations = Some(
+        [(skip_analysis_annotation_key(), "true".to_string())]
+            .into_iter()
+            .collect(),
+    );
+    if let Some(status) = rollout.status.as_mut() {
+        status.phase = Some(Phase::WaitingForAnalysis);
+    }
+
+    let status = compute_desired_status_at(&rollout, now);
+
+    assert_eq!(status.phase, Some(Phase::Progressing));
+    assert!(status
+        .decisions
+        .iter()
+        .all(|decision| decision.reason != DecisionReason::AnalysisPassed));
+}
+
+#[test]
+fn test_strategy_level_analysis_warmup_holds_step() {
+    let now = Utc::now();
+    let rollout = create_rollout_with_warmup("5m", 60);
+
+    let status = compute_desired_status_at(&rollout, now);
+
+    assert_eq!(status.phase, Some(Phase::WaitingForAnalysis));
+    assert_eq!(status.current_step_index, Some(1), "Step must not advance");
+    assert_eq!(
+        status.message.as_deref(),
+        Some("Waiting for step 1 analysis (5m warmup)")
+    );
+}
+
+#[test]
+fn test_strategy_level_analysis_warmup_elapsed_does_not_hold_step() {
+    let rollout = create_rollout_with_warmup("5m", 360);
+
+    assert_eq!(compute_analysis_wait_status(&rollout, Utc::now()), None);
+}
+
+#[test]
+fn test_metrics_failure_policy_defaults_to_pause() {
+    let rollout = create_rollout_with_step_warmup("1m", Utc::now());
+
+    assert_eq!(metrics_failure_policy(&rollout), FailurePolicy::Pause);
+}
+
+#[test]
+fn test_analysis_unavailable_holds_step_in_waiting_for_analysis() {
+    let rollout = create_progressing_canary_rollout();
+
+    let status = analysis_unavailable_status(&rollout, "connection refused");
+
+    assert_eq!(status.phase, Some(Phase::WaitingForAnalysis));
+    assert_eq!(status.current_step_index, Some(1));
+    assert_eq!(status.current_weight, Some(50));
+    assert_eq!(
+        status.message.as_deref(),
+        Some("Analysis unavailable, holding step (failurePolicy: Pause): connection refused")
+    );
+}
+
+#[test]
+fn test_metrics_unavailable_with_rollback_policy_ends_degraded() {
+    let mut rollout = create_progressing_canary_rollout();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.analysis = Some(AnalysisConfig {
+            prometheus: None,
+            failure_policy: Some(FailurePolicy::Rollback),
+            warmup_duration: None,
+            interval: None,
+            metrics: vec![],
+            requirement: None,
+            consecutive_failure_threshold: None,
+            mode: None,
+            tolerance: None,
+        });
+    }
+    assert_eq!(metrics_failure_policy(&rollout), FailurePolicy::Rollback);
+
+    // Reconcile starts the rollback with the outage as trigger
+    rollout.status = Some(start_rollback(
+        &rollout,
+        "metrics unavailable: connection refused",
+        DecisionReason::MetricsUnavailable,
+    ));
+    let drained = compute_desired_status(&rollout);
+
+    assert_eq!(drained.phase, Some(Phase::Degraded));
+}
+
+// ============================================================================
+// Analysis requirement validation
+// ============================================================================
+
+#[test]
+fn test_validate_analysis_requirement_count_exceeds_metrics() {
+    use crate::crd::rollout::{AnalysisConfig, MetricConfig};
+    use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+    let analysis = AnalysisConfig {
+        prometheus: None,
+        failure_policy: None,
+        warmup_duration: None,
+        interval: None,
+        metrics: vec![MetricConfig {
+            name: "error-rate".to_string(),
+            threshold: 5.0,
+            interval: None,
+            failure_threshold: None,
+            min_sample_size: None,
+            comparison: None,
+            labels: BTreeMap::new(),
+        }],
+        requirement: Some(IntOrString::Int(2)),
+        consecutive_failure_threshold: None,
+        mode: None,
+        tolerance: None,
+    };
+
+    let result = validate_analysis_requirement("spec.strategy.canary.analysis", &analysis);
+
+    assert_eq!(
+        result,
+        Err(
+            "spec.strategy.canary.analysis.requirement 2 exceeds the number of metrics (1)"
+                .to_string()
+        )
+    );
+}
+
+// ============================================================================
+// Configurable canary/stable ReplicaSet name suffixes
+// ============================================================================
+
+fn create_rollout_with_suffixes(canary_suffix: &str, stable_suffix: &str) -> Rollout {
+    let mut rollout = create_test_rollout_with_canary();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.steps = vec![CanaryStep {
+            set_weight: Some(100),
+            pause: None,
+            analysis: None,
+        }];
+        canary.canary_suffix = Some(canary_suffix.to_string());
+        canary.stable_suffix = Some(stable_suffix.to_string());
+    }
+    rollout
+}
+
+#[test]
+fn test_replicaset_name_suffix_defaults_to_rs_type() {
+    let rollout = create_test_rollout_with_canary();
+
+    assert_eq!(replicaset_name_suffix(&rollout, "canary"), "canary");
+    assert_eq!(replicaset_name_suffix(&rollout, "stable"), "stable");
+}
+
+#[test]
+fn test_build_replicaset_uses_custom_suffixes() {
+    let rollout = create_rollout_with_suffixes("next", "current");
+    let name = rollout.metadata.name.clone().unwrap();
+
+    let canary_suffix = replicaset_name_suffix(&rollout, "canary");
+    let stable_suffix = replicaset_name_suffix(&rollout, "stable");
+    let canary_rs = build_replicaset(&rollout, "canary", canary_suffix, 1).unwrap();
+    let stable_rs = build_replicaset(&rollout, "stable", stable_suffix, 2).unwrap();
+
+    assert_eq!(canary_rs.metadata.name, Some(format!("{}-next", name)));
+    assert_eq!(stable_rs.metadata.name, Some(format!("{}-current", name)));
+
+    // The type label still identifies the role, independent of the name
+    let canary_type = canary_rs
+        .metadata
+        .labels
+        .as_ref()
+        .and_then(|l| l.get("rollouts.kulta.io/type"))
+        .cloned();
+    assert_eq!(canary_type, Some("canary".to_string()));
+}
+
+#[test]
+fn test_validate_rollout_accepts_custom_suffixes() {
+    let rollout = create_rollout_with_suffixes("next", "current");
+
+    assert_eq!(validate_rollout(&rollout), Ok(()));
+}
+
+#[test]
+fn test_validate_rollout_rejects_invalid_suffixes() {
+    let empty = create_rollout_with_suffixes("", "stable");
+    assert_eq!(
+        validate_rollout(&empty),
+        Err("spec.strategy.canary.canarySuffix cannot be empty".to_string())
+    );
+
+    let slash = create_rollout_with_suffixes("canary", "a/b");
+    assert_eq!(
+        validate_rollout(&slash),
+        Err(
+            "spec.strategy.canary.stableSuffix must not contain '/' or '.', got \"a/b\""
+                .to_string()
+        )
+    );
+
+    let dot = create_rollout_with_suffixes("v1.2", "stable");
+    assert!(validate_rollout(&dot).is_err());
+}
+
+// ============================================================================
+// Analysis-only canary (no traffic split)
+// ============================================================================
+
+fn create_analysis_only_rollout() -> Rollout {
+    use crate::crd::rollout::{AnalysisConfig, MetricConfig};
+
+    let mut rollout = create_progressing_canary_rollout();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.analysis_only = Some(true);
+        canary.analysis = Some(AnalysisConfig {
+            prometheus: None,
+            failure_policy: None,
+            warmup_duration: None,
+            interval: None,
+            metrics: vec![MetricConfig {
+                name: "error-rate".to_string(),
+                threshold: 5.0,
+                interval: None,
+                failure_threshold: None,
+                min_sample_size: None,
+                comparison: None,
+                labels: BTreeMap::new(),
+            }],
+            requirement: None,
+            consecutive_failure_threshold: None,
+            mode: None,
+            tolerance: None,
+        });
+    }
+    rollout
+}
+
+#[test]
+fn test_analysis_only_builds_no_backend_refs() {
+    let rollout = create_analysis_only_rollout();
+
+    assert!(is_analysis_only(&rollout));
+    assert!(
+        build_gateway_api_backend_refs(&rollout).is_empty(),
+        "analysis-only canary must not split live traffic"
+    );
+}
+
+#[test]
+fn test_analysis_only_replica_split_keeps_stable_at_full_scale() {
+    assert_eq!(calculate_analysis_only_replica_split(5, 0), (5, 1));
+    assert_eq!(calculate_analysis_only_replica_split(5, 50), (5, 1));
+    assert_eq!(calculate_analysis_only_replica_split(5, 100), (0, 5));
+}
+
+#[tokio::test]
+async fn test_analysis_only_progression_is_metric_gated() {
+    let rollout = create_analysis_only_rollout();
+    let ctx = Context::new_mock();
+
+    // Error rate 12.5% exceeds the 5% threshold
+    ctx.prometheus_client.set_mock_response(
+        r#"{"status":"success","data":{"resultType":"vector","result":[{"metric":{},"value":[1234567890,"12.5"]}]}}"#
+            .to_string(),
+    );
+
+    let healthy = evaluate_rollout_metrics(&rollout, &ctx)
+        .await
+        .verdict
+        .unwrap();
+
+    assert!(
+        !healthy,
+        "unhealthy metrics should block analysis-only promotion"
+    );
+}
+
+#[test]
+fn test_validate_analysis_only_requires_analysis() {
+    let mut rollout = create_analysis_only_rollout();
+    assert_eq!(validate_rollout(&rollout), Ok(()));
+
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.analysis = None;
+    }
+
+    assert_eq!(
+        validate_rollout(&rollout),
+        Err("spec.strategy.canary.analysisOnly requires analysis to be configured".to_string())
+    );
+}
+
+#[test]
+fn test_validate_analysis_only_rejects_traffic_routing() {
+    let mut rollout = create_analysis_only_rollout();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.traffic_routing = Some(TrafficRouting {
+            gateway_api: Some(GatewayAPIRouting {
+                http_route: "app-route".to_string(),
+                gateway_api_version: None,
+                verify_accepted: None,
+                canary_match: None,
+            }),
+            linkerd: None,
+        });
+    }
+
+    assert_eq!(
+        validate_rollout(&rollout),
+        Err("spec.strategy.canary.analysisOnly cannot be combined with trafficRouting".to_string())
+    );
+}
+
+// ============================================================================
+// Aggregate replica counts (status.replicas / readyReplicas / updatedReplicas)
+// ============================================================================
+
+#[test]
+fn test_apply_replicaset_counts_aggregates_stable_and_canary() {
+    // ARRANGE: stable 4 replicas (4 ready), canary 2 replicas (1 ready)
+    let stable_rs = create_replicaset_with_readiness(4, 4);
+    let canary_rs = create_replicaset_with_readiness(2, 1);
+    let mut status = RolloutStatus::default();
+
+    // ACT
+    apply_replicaset_counts(&mut status, Some(&stable_rs), Some(&canary_rs));
+
+    // ASSERT: Deployment-style aggregates plus individual counts
+    assert_eq!(status.replicas, 6);
+    assert_eq!(status.ready_replicas, 5);
+    assert_eq!(status.updated_replicas, 2);
+    assert_eq!(status.stable_replicas, 4);
+    assert_eq!(status.canary_replicas, 2);
+}
+
+#[test]
+fn test_apply_replicaset_counts_missing_canary_counts_as_zero() {
+    let stable_rs = create_replicaset_with_readiness(3, 2);
+    let mut status = RolloutStatus::default();
+
+    apply_replicaset_counts(&mut status, Some(&stable_rs), None);
+
+    assert_eq!(status.replicas, 3);
+    assert_eq!(status.ready_replicas, 2);
+    assert_eq!(status.updated_replicas, 0);
+    assert_eq!(status.canary_replicas, 0);
+}
+
+#[test]
+fn test_apply_replicaset_counts_ignores_spec_before_status_is_reported() {
+    // A freshly created RS has spec.replicas but no status yet
+    let mut canary_rs = create_replicaset_with_readiness(2, 0);
+    canary_rs.status = None;
+    let mut status = RolloutStatus::default();
+
+    apply_replicaset_counts(&mut status, None, Some(&canary_rs));
+
+    assert_eq!(status.replicas, 0);
+    assert_eq!(status.updated_replicas, 0);
+}
+
+// ============================================================================
+// Configurable annotation domain (promote / abort)
+// ============================================================================
+
+#[test]
+fn test_resolve_annotation_domain_defaults_to_kulta_io() {
+    assert_eq!(resolve_annotation_domain(None), "kulta.io");
+    assert_eq!(resolve_annotation_domain(Some("")), "kulta.io");
+    assert_eq!(
+        resolve_annotation_domain(Some("rollouts.example.com")),
+        "rollouts.example.com"
+    );
+}
+
+#[test]
+fn test_default_domain_promote_and_abort_recognized() {
+    let promote_key = promote_annotation_key_for(DEFAULT_ANNOTATION_DOMAIN);
+    let abort_key = abort_annotation_key_for(DEFAULT_ANNOTATION_DOMAIN);
+    assert_eq!(promote_key, "kulta.io/promote");
+    assert_eq!(abort_key, "kulta.io/abort");
+
+    let promoted = with_annotation(create_test_rollout_with_canary(), &promote_key, "true");
+    let aborted = with_annotation(create_test_rollout_with_canary(), &abort_key, "true");
+
+    assert!(has_true_annotation(&promoted, &promote_key));
+    assert!(has_true_annotation(&aborted, &abort_key));
+    assert!(!has_true_annotation(&promoted, &abort_key));
+}
+
+#[test]
+fn test_overridden_domain_promote_and_abort_recognized() {
+    let promote_key = promote_annotation_key_for("rollouts.kulta.io");
+    let abort_key = abort_annotation_key_for("rollouts.kulta.io");
+    assert_eq!(promote_key, "rollouts.kulta.io/promote");
+    assert_eq!(abort_key, "rollouts.kulta.io/abort");
+
+    let promoted = with_annotation(create_test_rollout_with_canary(), &promote_key, "true");
+    let aborted = with_annotation(create_test_rollout_with_canary(), &abort_key, "true");
+
+    assert!(has_true_annotation(&promoted, &promote_key));
+    assert!(has_true_annotation(&aborted, &abort_key));
+
+    // Keys from the default domain don't match the overridden domain
+    assert!(!has_true_annotation(
+        &promoted,
+        &promote_annotation_key_for(DEFAULT_ANNOTATION_DOMAIN)
+    ));
+}
+
+#[test]
+fn test_abort_annotation_rolls_back_progressing_canary() {
+    let rollout = with_annotation(
+        create_progressing_canary_rollout(),
+        &abort_annotation_key_for(DEFAULT_ANNOTATION_DOMAIN),
+        "true",
+    );
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.phase, Some(Phase::RollingBack));
+    assert_eq!(status.current_weight, Some(0));
+    assert_eq!(
+        status.message.as_deref(),
+        Some("Rolling back: aborted via annotation")
+    );
+}
+
+// ============================================================================
+// Pause timer safeguard (spec edits during a pause)
+// ============================================================================
+
+fn create_paused_canary_rollout(pause_started_secs_ago: i64) -> Rollout {
+    let mut rollout = create_test_rollout_with_canary();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.steps = vec![
+            CanaryStep {
+                set_weight: Some(20),
+                pause: Some(PauseDuration {
+                    duration: Some("60s".to_string()),
+                }),
+                analysis: None,
+            },
+            CanaryStep {
+                set_weight: Some(50),
+                pause: Some(PauseDuration {
+                    duration: Some("60s".to_string()),
+                }),
+                analysis: None,
+            },
+            CanaryStep {
+                set_weight: Some(100),
+                pause: None,
+                analysis: None,
+            },
+        ];
+    }
+    rollout.metadata.generation = Some(1);
+    rollout.status = Some(RolloutStatus {
+        current_step_index: Some(0),
+        current_weight: Some(20),
+        phase: Some(Phase::Progressing),
+        pause_start_time: Some(
+            (Utc::now() - chrono::Duration::seconds(pause_started_secs_ago)).to_rfc3339(),
+        ),
+        pause_start_generation: Some(1),
+        ..Default::default()
+    });
+    rollout
+}
+
+#[test]
+fn test_spec_edit_during_pause_does_not_reset_timer() {
+    // ARRANGE: Paused 30s into a 60s pause, then the user edits the spec
+    let mut rollout = create_paused_canary_rollout(30);
+    let original_start = rollout
+        .status
+        .as_ref()
+        .and_then(|s| s.pause_start_time.clone());
+    rollout.spec.replicas = 5;
+    rollout.metadata.generation = Some(2);
+
+    // ACT
+    let status = compute_desired_status(&rollout);
+
+    // ASSERT: Same step, same timer, generation of the original pause kept
+    assert_eq!(status.current_step_index, Some(0));
+    assert_eq!(status.pause_start_time, original_start);
+    assert_eq!(status.pause_start_generation, Some(1));
+}
+
+#[test]
+fn test_step_change_resets_pause_timer() {
+    // ARRANGE: 60s pause has elapsed, spec was edited mid-pause (generation 2)
+    let mut rollout = create_paused_canary_rollout(90);
+    let original_start = rollout
+        .status
+        .as_ref()
+        .and_then(|s| s.pause_start_time.clone());
+    rollout.metadata.generation = Some(2);
+
+    // ACT
+    let status = compute_desired_status(&rollout);
+
+    // ASSERT: Next step starts a fresh timer at the current generation
+    assert_eq!(status.current_step_index, Some(1));
+    assert!(status.pause_start_time.is_some());
+    assert_ne!(status.pause_start_time, original_start);
+    assert_eq!(status.pause_start_generation, Some(2));
+}
+
+#[test]
+fn test_pause_added_by_spec_edit_starts_timer_once() {
+    // ARRANGE: Step had no timer (pause added by a spec edit)
+    let mut rollout = create_paused_canary_rollout(0);
+    rollout.metadata.generation = Some(3);
+    if let Some(status) = rollout.status.as_mut() {
+        status.pause_start_time = None;
+        status.pause_start_generation = None;
+    }
+    let now = Utc::now();
+
+    // ACT
+    let status = compute_desired_status_at(&rollout, now);
+
+    // ASSERT: Timer starts now at the current generation...
+    assert_eq!(status.pause_start_time, Some(now.to_rfc3339()));
+    assert_eq!(status.pause_start_generation, Some(3));
+
+    // ...and a later spec edit leaves it alone
+    rollout.status = Some(status.clone());
+    rollout.metadata.generation = Some(4);
+    let later = compute_desired_status_at(&rollout, now + chrono::Duration::seconds(10));
+    assert_eq!(later.pause_start_time, status.pause_start_time);
+    assert_eq!(later.pause_start_generation, Some(3));
+}
+
+#[test]
+fn test_build_replicaset_injects_pod_template_hash_by_default() {
+    let rollout = create_test_rollout_with_canary();
+    assert!(manages_pod_template_hash(&rollout));
+
+    let rs = build_replicaset(&rollout, "canary", "canary", 1).unwrap();
+
+    let hash = pod_template_hash_label(&rs);
+    let selector_labels = rs
+        .spec
+        .as_ref()
+        .unwrap()
+        .selector
+        .match_labels
+        .clone()
+        .unwrap();
+    assert_eq!(selector_labels.get("pod-template-hash"), Some(&hash));
+    assert!(rs.metadata.annotations.is_none());
+}
+
+#[test]
+fn test_build_replicaset_skips_pod_template_hash_when_disabled() {
+    let mut rollout = create_test_rollout_with_canary();
+    rollout.spec.manage_pod_template_hash = Some(false);
+    let managed_hash = {
+        let mut managed = rollout.clone();
+        managed.spec.manage_pod_template_hash = None;
+        pod_template_hash_label(&build_replicaset(&managed, "canary", "canary", 1).unwrap())
+    };
+
+    let rs = build_replicaset(&rollout, "canary", "canary", 1).unwrap();
+
+    // No pod-template-hash on the RS, its selector, or its pods
+    let labels = rs.metadata.labels.clone().unwrap();
+    assert!(!labels.contains_key("pod-template-hash"));
+    let spec = rs.spec.as_ref().unwrap();
+    let selector_labels = spec.selector.match_labels.clone().unwrap();
+    assert!(!selector_labels.contains_key("pod-template-hash"));
+    let pod_labels = spec
+        .template
+        .as_ref()
+        .and_then(|t| t.metadata.as_ref())
+        .and_then(|m| m.labels.clone())
+        .unwrap();
+    assert!(!pod_labels.contains_key("pod-template-hash"));
+
+    // KULTA labels are kept
+    assert_eq!(
+        labels.get("rollouts.kulta.io/type"),
+        Some(&"canary".to_string())
+    );
+    assert_eq!(
+        labels.get("rollouts.kulta.io/managed"),
+        Some(&"true".to_string())
+    );
+
+    // The hash is still computed and recorded for revision detection
+    let annotations = rs.metadata.annotations.clone().unwrap();
+    assert_eq!(
+        annotations.get(POD_TEMPLATE_HASH_ANNOTATION),
+        Some(&managed_hash)
+    );
+}
+
+#[test]
+fn test_build_replicaset_for_simple_skips_pod_template_hash_when_disabled() {
+    let mut rollout = create_test_rollout_with_simple();
+    rollout.spec.manage_pod_template_hash = Some(false);
+
+    let rs = build_replicaset_for_simple(&rollout, 3).unwrap();
+
+    let labels = rs.metadata.labels.clone().unwrap();
+    assert!(!labels.contains_key("pod-template-hash"));
+    assert_eq!(
+        labels.get("rollouts.kulta.io/type"),
+        Some(&"simple".to_string())
+    );
+    assert!(rs
+        .metadata
+        .annotations
+        .as_ref()
+        .is_some_and(|a| a.contains_key(POD_TEMPLATE_HASH_ANNOTATION)));
+}
+
+/// A simple ReplicaSet as created before the -simple suffix: named after the rollout
+fn legacy_simple_replicaset(rollout: &Rollout) -> ReplicaSet {
+    let mut rs = build_replicaset_for_simple(rollout, 3).unwrap();
+    rs.metadata.name = rollout.metadata.name.clone();
+    rs
+}
+
+#[test]
+fn test_simple_replicaset_adopts_legacy_unsuffixed_replicaset() {
+    let rollout = create_test_rollout_with_simple();
+    let legacy = legacy_simple_replicaset(&rollout);
+    let desired = build_replicaset_for_simple(&rollout, 5).unwrap();
+
+    let rs = adopt_legacy_simple_replicaset(&rollout, desired, Some(&legacy));
+
+    // Managed under its old name, with the desired spec
+    assert_eq!(rs.metadata.name.as_deref(), Some("simple-rollout"));
+    assert_eq!(rs.spec.as_ref().unwrap().replicas, Some(5));
+}
+
+#[test]
+fn test_simple_replicaset_adopts_legacy_managed_by_label() {
+    let rollout = create_test_rollout_with_simple();
+    let mut legacy = legacy_simple_replicaset(&rollout);
+    let labels = legacy.metadata.labels.get_or_insert_with(BTreeMap::new);
+    labels.remove("rollouts.kulta.io/type");
+    labels.insert(LEGACY_MANAGED_BY_LABEL.to_string(), "kulta".to_string());
+
+    assert!(is_legacy_simple_replicaset(&rollout, &legacy));
+}
+
+#[test]
+fn test_simple_replicaset_without_legacy_uses_suffix() {
+    let rollout = create_test_rollout_with_simple();
+    let desired = build_replicaset_for_simple(&rollout, 3).unwrap();
+
+    let rs = adopt_legacy_simple_replicaset(&rollout, desired, None);
+
+    assert_eq!(rs.metadata.name.as_deref(), Some("simple-rollout-simple"));
+}
+
+#[test]
+fn test_simple_replicaset_does_not_adopt_foreign_replicaset() {
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+
+    let rollout = create_test_rollout_with_simple();
+
+    // Same name, but not a KULTA simple ReplicaSet
+    let mut unlabeled = legacy_simple_replicaset(&rollout);
+    unlabeled.metadata.labels = None;
+
+    // Same name and labels, but controlled by a Deployment
+    let mut owned = legacy_simple_replicaset(&rollout);
+    owned.metadata.owner_references = Some(vec![OwnerReference {
+        api_version: "apps/v1".to_string(),
+        kind: "Deployment".to_string(),
+        name: "simple-rollout".to_string(),
+        uid: "deployment-uid".to_string(),
+        controller: Some(true),
+        block_owner_deletion: None,
+    }]);
+
+    for existing in [unlabeled, owned] {
+        assert!(!is_legacy_simple_replicaset(&rollout, &existing));
+        let desired = build_replicaset_for_simple(&rollout, 3).unwrap();
+        let rs = adopt_legacy_simple_replicaset(&rollout, desired, Some(&existing));
+        assert_eq!(rs.metadata.name.as_deref(), Some("simple-rollout-simple"));
+    }
+}
+
+// =============================================================================
+// Reconcile Cache Tests
+// =============================================================================
+
+fn create_paused_rollout_with_uid() -> Rollout {
+    let mut rollout = RolloutBuilder::new()
+        .with_indefinite_pause_step(20)
+        .with_step(50, None)
+        .with_status(0, 20, Phase::Paused)
+        .build();
+    rollout.metadata.uid = Some("uid-1234".to_string());
+    rollout.metadata.generation = Some(1);
+    rollout
+}
+
+#[test]
+fn test_reconcile_cache_hit_skips_unchanged_rollout() {
+    let cache = ReconcileCache::default();
+    let rollout = create_paused_rollout_with_uid();
+    let status = rollout.status.clone().unwrap();
+    cache.record("uid-1234", Some(1), &status);
+
+    let next_status = compute_desired_status(&rollout);
+
+    assert!(
+        is_reconcile_cache_hit(&cache, &rollout, &next_status),
+        "Unchanged paused rollout should skip reconcile"
+    );
+}
+
+#[test]
+fn test_reconcile_cache_miss_without_entry() {
+    let cache = ReconcileCache::default();
+    let rollout = create_paused_rollout_with_uid();
+
+    let next_status = compute_desired_status(&rollout);
+
+    assert!(!is_reconcile_cache_hit(&cache, &rollout, &next_status));
+}
+
+#[test]
+fn test_reconcile_cache_miss_after_generation_change() {
+    let cache = ReconcileCache::default();
+    let mut rollout = create_paused_rollout_with_uid();
+    cache.record("uid-1234", Some(1), rollout.status.as_ref().unwrap());
+
+    // User edited the spec
+    rollout.metadata.generation = Some(2);
+    let next_status = compute_desired_status(&rollout);
+
+    assert!(!is_reconcile_cache_hit(&cache, &rollout, &next_status));
+}
+
+#[test]
+fn test_reconcile_cache_miss_when_promoted() {
+    let cache = ReconcileCache::default();
+    // Sitting on the pause step; promotion only advances a Progressing rollout
+    let mut rollout = RolloutBuilder::new()
+        .with_indefinite_pause_step(20)
+        .with_step(50, None)
+        .with_status(0, 20, Phase::Progressing)
+        .build();
+    rollout.metadata.uid = Some("uid-1234".to_string());
+    rollout.metadata.generation = Some(1);
+    cache.record("uid-1234", Some(1), rollout.status.as_ref().unwrap());
+
+    // Annotations don't bump generation, but promotion advances the step
+    rollout
+        .metadata
+        .annotations
+        .get_or_insert_with(BTreeMap::new)
+        .insert(promote_annotation_key(), "true".to_string());
+    let next_status = compute_desired_status(&rollout);
+
+    assert!(
+        !is_reconcile_cache_hit(&cache, &rollout, &next_status),
+        "Pending promotion should run a full reconcile"
+    );
+}
+
+#[test]
+fn test_reconcile_cache_miss_while_analysis_running() {
+    let cache = ReconcileCache::default();
+    let mut rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_analysis(AnalysisConfig {
+            prometheus: None,
+            failure_policy: None,
+            warmup_duration: None,
+            interval: None,
+            metrics: vec![],
+            requirement: None,
+            consecutive_failure_threshold: None,
+            mode: None,
+            tolerance: None,
+        })
+        .with_status(0, 20, Phase::Progressing)
+        .build();
+    rollout.metadata.uid = Some("uid-1234".to_string());
+    let status = rollout.status.clone().unwrap();
+    cache.record("uid-1234", None, &status);
+
+    // Status unchanged, but metrics must still be evaluated
+    assert!(!is_reconcile_cache_hit(&cache, &rollout, &status));
+}
+
+// ============================================================================
+// Decision history
+// ============================================================================
+
+fn assert_single_decision(
+    before: &RolloutStatus,
+    after: &RolloutStatus,
+    action: DecisionAction,
+    reason: DecisionReason,
+) {
+    assert_eq!(
+        after.decisions.len(),
+        before.decisions.len() + 1,
+        "Transition should append exactly one decision"
+    );
+    let decision = after.decisions.last().unwrap();
+    assert_eq!(decision.action, action);
+    assert_eq!(decision.reason, reason);
+    assert!(
+        chrono::DateTime::parse_from_rfc3339(&decision.timestamp).is_ok(),
+        "Decision timestamp should be RFC3339, got: {}",
+        decision.timestamp
+    );
+}
+
+#[test]
+fn test_decision_recorded_on_initialize() {
+    let rollout = RolloutBuilder::new().with_step(20, None).build();
+
+    let status = compute_desired_status(&rollout);
+
+    assert_single_decision(
+        &RolloutStatus::default(),
+        &status,
+        DecisionAction::Initialize,
+        DecisionReason::Initialization,
+    );
+    assert_eq!(status.decisions[0].to_step, Some(0));
+}
+
+#[test]
+fn test_decision_recorded_on_step_advance() {
+    let rollout = create_progressing_canary_rollout();
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.current_step_index, Some(2));
+    assert_single_decision(
+        rollout.status.as_ref().unwrap(),
+        &status,
+        DecisionAction::StepAdvance,
+        DecisionReason::StepReady,
+    );
+    let decision = status.decisions.last().unwrap();
+    assert_eq!(decision.from_step, Some(1));
+    assert_eq!(decision.to_step, Some(2));
+}
+
+#[test]
+fn test_decision_recorded_on_promotion() {
+    let rollout = RolloutBuilder::new()
+        .with_indefinite_pause_step(20)
+        .with_step(50, None)
+        .with_status(0, 20, Phase::Progressing)
+        .with_annotation(&promote_annotation_key(), "true")
+        .build();
+
+    let status = compute_desired_status(&rollout);
+
+    assert_single_decision(
+        rollout.status.as_ref().unwrap(),
+        &status,
+        DecisionAction::Promotion,
+        DecisionReason::ManualPromotion,
+    );
+}
+
+#[test]
+fn test_promote_annotation_removed_after_completing() {
+    let rollout = RolloutBuilder::new()
+        .with_step(50, None)
+        .with_step(100, None)
+        .with_status(1, 100, Phase::Completed)
+        .with_annotation(&promote_annotation_key(), "true")
+        .build();
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.phase, Some(Phase::Completed));
+    assert!(should_remove_promote_annotation(true, &status));
+}
+
+#[test]
+fn test_promote_annotation_removed_when_step_auto_advances() {
+    let rollout = RolloutBuilder::new()
+        .with_step(10, None)
+        .with_step(50, None)
+        .with_step(100, None)
+        .with_status(0, 10, Phase::Progressing)
+        .with_annotation(&promote_annotation_key(), "true")
+        .build();
+
+    let status = compute_desired_status(&rollout);
+
+    // No pause: the step advanced by itself, the annotation did nothing
+    assert_eq!(status.current_step_index, Some(1));
+    assert!(should_remove_promote_annotation(true, &status));
+    assert!(!should_remove_promote_annotation(false, &status));
+}
+
+#[test]
+fn test_promote_annotation_kept_while_still_paused() {
+    let rollout = RolloutBuilder::new()
+        .with_step(10, None)
+        .with_indefinite_pause_step(50)
+        .with_step(100, None)
+        .with_status(0, 10, Phase::Progressing)
+        .with_annotation(&promote_annotation_key(), "true")
+        .build();
+
+    // Advancing onto a paused step: the annotation is still needed there
+    let status = compute_desired_status(&rollout);
+    assert_eq!(status.current_step_index, Some(1));
+    assert!(status.pause_start_time.is_some());
+    assert!(!should_remove_promote_annotation(true, &status));
+
+    let mut paused = status;
+    paused.phase = Some(Phase::Paused);
+    assert!(!should_remove_promote_annotation(true, &paused));
+}
+
+/// Mark the rollout as paused at its current step since `pause_start_time`
+fn with_pause_timer(mut rollout: Rollout) -> Rollout {
+    rollout.metadata.generation = Some(3);
+    if let Some(status) = rollout.status.as_mut() {
+        status.pause_start_time = Some("2024-01-01T00:00:00Z".to_string());
+        status.pause_start_generation = Some(3);
+    }
+    rollout
+}
+
+#[test]
+fn test_promotion_from_indefinite_pause_to_final_step_clears_pause_timer() {
+    let rollout = with_pause_timer(
+        RolloutBuilder::new()
+            .with_indefinite_pause_step(50)
+            .with_indefinite_pause_step(100)
+            .with_status(0, 50, Phase::Progressing)
+            .with_annotation(&promote_annotation_key(), "true")
+            .build(),
+    );
+
+    let status = compute_desired_status(&rollout);
+
+    // The final step completes the rollout: its own pause never starts
+    assert_eq!(status.phase, Some(Phase::Completed));
+    assert_eq!(status.pause_start_time, None);
+    assert_eq!(status.pause_start_generation, None);
+}
+
+#[test]
+fn test_promotion_past_last_step_clears_pause_timer() {
+    let rollout = with_pause_timer(
+        RolloutBuilder::new()
+            .with_step(20, None)
+            .with_indefinite_pause_step(50)
+            .with_status(1, 50, Phase::Progressing)
+            .with_annotation(&promote_annotation_key(), "true")
+            .build(),
+    );
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.phase, Some(Phase::Completed));
+    assert_eq!(status.pause_start_time, None);
+    assert_eq!(status.pause_start_generation, None);
+}
+
+#[test]
+fn test_promotion_to_unpaused_step_clears_pause_timer() {
+    let rollout = with_pause_timer(
+        RolloutBuilder::new()
+            .with_indefinite_pause_step(20)
+            .with_step(50, None)
+            .with_step(100, None)
+            .with_status(0, 20, Phase::Progressing)
+            .with_annotation(&promote_annotation_key(), "true")
+            .build(),
+    );
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.current_step_index, Some(1));
+    assert_eq!(status.pause_start_time, None);
+    assert_eq!(status.pause_start_generation, None);
+}
+
+#[test]
+fn test_abort_during_indefinite_pause_clears_pause_timer() {
+    let rollout = with_pause_timer(
+        RolloutBuilder::new()
+            .with_indefinite_pause_step(20)
+            .with_step(100, None)
+            .with_status(0, 20, Phase::Paused)
+            .with_annotation(&abort_annotation_key(), "true")
+            .build(),
+    );
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.phase, Some(Phase::RollingBack));
+    assert_eq!(status.pause_start_time, None);
+    assert_eq!(status.pause_start_generation, None);
+}
+
+#[test]
+fn test_stale_pause_timer_cleared() {
+    // Completed (e.g. written by an older controller) with a lingering timer
+    let completed = with_pause_timer(
+        RolloutBuilder::new()
+            .with_step(50, None)
+            .with_step(100, None)
+            .with_status(2, 100, Phase::Completed)
+            .build(),
+    );
+    // Held at pauseAtStep on a step whose pause a spec edit removed
+    let mut unpaused_step = with_pause_timer(
+        RolloutBuilder::new()
+            .with_step(20, None)
+            .with_step(100, None)
+            .with_status(0, 20, Phase::Progressing)
+            .build(),
+    );
+    if let Some(canary) = unpaused_step.spec.strategy.canary.as_mut() {
+        canary.pause_at_step = Some(0);
+    }
+
+    for rollout in [completed, unpaused_step] {
+        assert!(has_stale_pause_timer(
+            &rollout,
+            rollout.status.as_ref().unwrap()
+        ));
+        let status = compute_desired_status(&rollout);
+        assert_eq!(status.pause_start_time, None);
+        assert_eq!(status.pause_start_generation, None);
+    }
+}
+
+#[test]
+fn test_running_pause_timer_not_stale() {
+    let rollout = with_pause_timer(
+        RolloutBuilder::new()
+            .with_indefinite_pause_step(20)
+            .with_step(100, None)
+            .with_status(0, 20, Phase::Progressing)
+            .build(),
+    );
+
+    assert!(!has_stale_pause_timer(
+        &rollout,
+        rollout.status.as_ref().unwrap()
+    ));
+    assert_eq!(
+        compute_desired_status(&rollout).pause_start_time,
+        Some("2024-01-01T00:00:00Z".to_string())
+    );
+}
+
+/// Reconcile once: compute the next status and store it on the rollout
+fn reconcile_status(rollout: &mut Rollout) {
+    rollout.status = Some(compute_desired_status(rollout));
+}
+
+fn set_promote_annotation(rollout: &mut Rollout, promote: bool) {
+    let annotations = rollout
+        .metadata
+        .annotations
+        .get_or_insert_with(BTreeMap::new);
+    if promote {
+        annotations.insert(promote_annotation_key(), "true".to_string());
+    } else {
+        annotations.remove(&promote_annotation_key());
+    }
+}
+
+#[test]
+fn test_transition_log_records_mixed_auto_and_manual_progression() {
+    let mut rollout = RolloutBuilder::new()
+        .with_step(10, None)
+        .with_indefinite_pause_step(25)
+        .with_step(50, None)
+        .with_indefinite_pause_step(75)
+        .with_step(100, None)
+        .build();
+
+    reconcile_status(&mut rollout); // initialize at step 0
+    reconcile_status(&mut rollout); // step 0 -> 1 (auto)
+    reconcile_status(&mut rollout); // held at step 1's indefinite pause
+    set_promote_annotation(&mut rollout, true);
+    reconcile_status(&mut rollout); // step 1 -> 2 (promoted)
+    set_promote_annotation(&mut rollout, false);
+    reconcile_status(&mut rollout); // step 2 -> 3 (auto)
+    reconcile_status(&mut rollout); // held at step 3's indefinite pause
+    set_promote_annotation(&mut rollout, true);
+    reconcile_status(&mut rollout); // step 3 -> completed (promoted)
+
+    let status = rollout.status.unwrap();
+    assert_eq!(status.phase, Some(Phase::Completed));
+    let log: Vec<_> = status
+        .transition_log
+        .iter()
+        .map(|t| (t.from_step, t.to_step, t.trigger.clone()))
+        .collect();
+    assert_eq!(
+        log,
+        vec![
+            (None, Some(0), TransitionTrigger::Initialization),
+            (Some(0), Some(1), TransitionTrigger::AutoProgression),
+            (Some(1), Some(2), TransitionTrigger::ManualPromotion),
+            (Some(2), Some(3), TransitionTrigger::AutoProgression),
+            (
+                Some(3),
+                status.current_step_index,
+                TransitionTrigger::ManualPromotion
+            ),
+        ]
+    );
+    assert_eq!(status.transition_log[0].from_phase, None);
+    assert_eq!(
+        status.transition_log[4].from_phase,
+        Some(Phase::Progressing)
+    );
+    assert_eq!(status.transition_log[4].to_phase, Phase::Completed);
+}
+
+#[test]
+fn test_transition_log_unchanged_without_phase_or_step_change() {
+    let rollout = RolloutBuilder::new()
+        .with_indefinite_pause_step(20)
+        .with_step(50, None)
+        .with_status(0, 20, Phase::Progressing)
+        .build();
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.current_step_index, Some(0));
+    assert!(status.transition_log.is_empty());
+}
+
+#[test]
+fn test_transition_log_capped() {
+    let mut rollout = create_progressing_canary_rollout();
+    let filler = StatusTransition {
+        timestamp: "2026-01-01T00:00:00+00:00".to_string(),
+        from_phase: Some(Phase::Progressing),
+        to_phase: Phase::Progressing,
+        from_step: Some(0),
+        to_step: Some(1),
+        trigger: TransitionTrigger::AutoProgression,
+    };
+    if let Some(status) = rollout.status.as_mut() {
+        status.transition_log = vec![filler; MAX_TRANSITION_LOG];
+    }
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.transition_log.len(), MAX_TRANSITION_LOG);
+    let newest = status.transition_log.last().unwrap();
+    assert_eq!((newest.from_step, newest.to_step), (Some(1), Some(2)));
+}
+
+#[test]
+fn test_decision_recorded_on_completion() {
+    let mut rollout = create_progressing_canary_rollout();
+    if let Some(status) = rollout.status.as_mut() {
+        status.current_step_index = Some(2);
+        status.current_weight = Some(80);
+    }
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.phase, Some(Phase::Completed));
+    assert_single_decision(
+        rollout.status.as_ref().unwrap(),
+        &status,
+        DecisionAction::Complete,
+        DecisionReason::StepReady,
+    );
+}
+
+#[test]
+fn test_decision_recorded_on_rollback() {
+    let rollout = create_progressing_canary_rollout();
+
+    let status = start_rollback(
+        &rollout,
+        "metric analysis exceeded thresholds",
+        DecisionReason::AnalysisFailed,
+    );
+
+    assert_single_decision(
+        rollout.status.as_ref().unwrap(),
+        &status,
+        DecisionAction::Rollback,
+        DecisionReason::AnalysisFailed,
+    );
+    assert_eq!(status.decisions.last().unwrap().from_step, Some(1));
+}
+
+#[test]
+fn test_decision_recorded_on_abort() {
+    let mut rollout = create_progressing_canary_rollout();
+    rollout
+        .metadata
+        .annotations
+        .get_or_insert_with(BTreeMap::new)
+        .insert(abort_annotation_key(), "true".to_string());
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.phase, Some(Phase::RollingBack));
+    assert_single_decision(
+        rollout.status.as_ref().unwrap(),
+        &status,
+        DecisionAction::Abort,
+        DecisionReason::ManualRollback,
+    );
+}
+
+#[test]
+fn test_decision_recorded_once_when_analysis_warmup_starts() {
+    let mut rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_step(50, None)
+        .with_status(0, 20, Phase::Progressing)
+        .with_step_start_time(&Utc::now().to_rfc3339())
+        .build();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.steps[0].analysis = Some(AnalysisConfig {
+            prometheus: None,
+            failure_policy: None,
+            warmup_duration: Some("10m".to_string()),
+            interval: None,
+            metrics: vec![],
+            requirement: None,
+            consecutive_failure_threshold: None,
+            mode: None,
+            tolerance: None,
+        });
+    }
+
+    let waiting = compute_desired_status(&rollout);
+    assert_eq!(waiting.phase, Some(Phase::WaitingForAnalysis));
+    assert_single_decision(
+        rollout.status.as_ref().unwrap(),
+        &waiting,
+        DecisionAction::AnalysisStart,
+        DecisionReason::AnalysisWarmup,
+    );
+
+    // Still warming up: no new decision
+    rollout.status = Some(waiting.clone());
+    let still_waiting = compute_desired_status(&rollout);
+    assert_eq!(still_waiting.decisions.len(), waiting.decisions.len());
+}
+
+#[test]
+fn test_no_decision_recorded_without_transition() {
+    let rollout = RolloutBuilder::new()
+        .with_indefinite_pause_step(20)
+        .with_step(50, None)
+        .with_status(0, 20, Phase::Progressing)
+        .build();
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.current_step_index, Some(0));
+    assert!(status.decisions.is_empty());
+}
+
+#[test]
+fn test_decision_history_capped() {
+    let mut status = RolloutStatus::default();
+
+    for step in 0..(MAX_DECISIONS as i32 + 5) {
+        record_decision(
+            &mut status,
+            DecisionAction::StepAdvance,
+            DecisionReason::StepReady,
+            Some(step),
+            Some(step + 1),
+        );
+    }
+
+    assert_eq!(status.decisions.len(), MAX_DECISIONS);
+    // Oldest entries are dropped first
+    assert_eq!(status.decisions[0].from_step, Some(5));
+}
+
+// ============================================================================
+// spec.selector immutability
+// ============================================================================
+
+fn with_selector(mut rollout: Rollout, app: &str) -> Rollout {
+    rollout.spec.selector.match_labels =
+        Some(BTreeMap::from([("app".to_string(), app.to_string())]));
+    rollout
+}
+
+#[test]
+fn test_immutability_allows_create() {
+    let rollout = create_progressing_canary_rollout();
+
+    assert!(ImmutabilityChecker::check(None, &rollout).is_ok());
+}
+
+#[test]
+fn test_immutability_allows_unchanged_selector() {
+    let old = create_progressing_canary_rollout();
+    let mut new = old.clone();
+    new.spec.replicas = 10;
+
+    assert!(ImmutabilityChecker::check(Some(&old), &new).is_ok());
+}
+
+#[test]
+fn test_immutability_rejects_selector_change() {
+    let old = create_progressing_canary_rollout();
+    let new = with_selector(old.clone(), "other-app");
+
+    let error = ImmutabilityChecker::check(Some(&old), &new).unwrap_err();
+
+    assert!(
+        error.contains("spec.selector is immutable"),
+        "got: {}",
+        error
+    );
+}
+
+#[test]
+fn test_immutability_accepts_managed_replicaset() {
+    let rollout = create_progressing_canary_rollout();
+    let stable_rs = build_replicaset(&rollout, "stable", "stable", 2).unwrap();
+
+    assert!(ImmutabilityChecker::check_replicaset(&rollout, &stable_rs).is_ok());
+}
+
+#[test]
+fn test_immutability_rejects_selector_change_during_canary() {
+    // Stable ReplicaSet created before the selector change
+    let rollout = create_progressing_canary_rollout();
+    let stable_rs = build_replicaset(&rollout, "stable", "stable", 2).unwrap();
+
+    let changed = with_selector(rollout, "other-app");
+    assert_eq!(
+        changed.status.as_ref().unwrap().phase,
+        Some(Phase::Progressing)
+    );
+
+    let error = ImmutabilityChecker::check_replicaset(&changed, &stable_rs).unwrap_err();
+
+    assert!(
+        error.contains("spec.selector is immutable"),
+        "Expected immutability error, got: {}",
+        error
+    );
+    assert!(error.contains("test-rollout-stable"), "got: {}", error);
+}
+
+// ============================================================================
+// Post-completion stable adoption
+// ============================================================================
+
+fn create_completed_canary_rollout() -> Rollout {
+    let mut rollout = create_progressing_canary_rollout();
+    rollout.spec.replicas = 4;
+    rollout.status = Some(RolloutStatus {
+        phase: Some(Phase::Completed),
+        current_step_index: Some(3),
+        current_weight: Some(100),
+        step_start_time: Some(Utc::now().to_rfc3339()),
+        started_at: Some(Utc::now().to_rfc3339()),
+        ..Default::default()
+    });
+    rollout
+}
+
+#[test]
+fn test_stable_adoption_pending_after_completion() {
+    let rollout = create_completed_canary_rollout();
+    assert!(is_stable_adoption_pending(&rollout));
+
+    // In progress: nothing to adopt yet
+    assert!(!is_stable_adoption_pending(
+        &create_progressing_canary_rollout()
+    ));
+}
+
+#[test]
+fn test_stable_adoption_resets_to_clean_completed_baseline() {
+    let rollout = create_completed_canary_rollout();
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.phase, Some(Phase::Completed));
+    assert_eq!(status.current_step_index, None);
+    assert_eq!(status.current_weight, Some(0));
+    assert_eq!(status.step_start_time, None);
+    assert_eq!(
+        status.started_at,
+        rollout.status.as_ref().unwrap().started_at
+    );
+
+    // Adopted: stays put
+    let mut adopted = rollout.clone();
+    adopted.status = Some(status.clone());
+    assert!(!is_stable_adoption_pending(&adopted));
+    assert_eq!(compute_desired_status(&adopted), status);
+}
+
+#[test]
+fn test_stable_adoption_produces_full_stable_and_zeroed_canary() {
+    let mut rollout = create_completed_canary_rollout();
+    let canary_rs = build_replicaset(&rollout, "canary", "canary", 4).unwrap();
+
+    rollout.status = Some(stable_adopted_status(&rollout));
+    let weight = rollout.status.as_ref().unwrap().current_weight.unwrap();
+    let (stable_replicas, canary_replicas) =
+        calculate_replica_split_with_surge(rollout.spec.replicas, weight, false);
+    let stable_rs = build_replicaset(&rollout, "stable", "stable", stable_replicas).unwrap();
+
+    // Stable runs the canary's template at full scale, canary is zeroed
+    assert_eq!(stable_rs.spec.as_ref().unwrap().replicas, Some(4));
+    assert_eq!(canary_replicas, 0);
+    assert_eq!(
+        replicaset_template_hash(&stable_rs),
+        replicaset_template_hash(&canary_rs)
+    );
+    assert_eq!(calculate_traffic_weights(&rollout), (100, 0));
+}
+
+#[test]
+fn test_stable_adoption_replaces_stale_stable_replicaset() {
+    // Stable ReplicaSet still on the previous template
+    let existing = stale_replicaset("stable");
+    let desired = desired_replicaset("stable");
+
+    // Adoption classifies stable like a canary, so it is replaced, not just scaled
+    assert_eq!(
+        classify_existing_replicaset(&existing, &desired, "canary"),
+        ExistingReplicaSetAction::Replace
+    );
+}
+
+// =============================================================================
+// Revision history and rollback-to
+// =============================================================================
+
+fn container_name(rollout: &Rollout) -> String {
+    rollout.spec.template.spec.as_ref().unwrap().containers[0]
+        .name
+        .clone()
+}
+
+fn revision(hash: &str, container: &str, image: &str) -> RevisionRef {
+    RevisionRef {
+        hash: hash.to_string(),
+        images: BTreeMap::from([(container.to_string(), image.to_string())]),
+        recorded_at: None,
+    }
+}
+
+/// Completed rollout whose stable ran rev-a (nginx:0.9), then rev-b (current)
+fn create_rollout_with_revision_history(rollback_to: Option<&str>) -> Rollout {
+    let mut rollout = create_completed_canary_rollout();
+    let container = container_name(&rollout);
+    let status = rollout.status.as_mut().unwrap();
+    status.current_weight = Some(0);
+    status.current_step_index = None;
+    status.revision_history = vec![
+        revision("rev-a", &container, "nginx:0.9"),
+        revision("rev-b", &container, "nginx:1.0"),
+    ];
+    if let Some(hash) = rollback_to {
+        rollout.metadata.annotations = Some(BTreeMap::from([(
+            rollback_to_annotation_key(),
+            hash.to_string(),
+        )]));
+    }
+    rollout
+}
+
+#[test]
+fn test_initialize_records_stable_revision() {
+    let rollout = create_test_rollout_with_canary();
+
+    let status = initialize_rollout_status(&rollout);
+
+    assert_eq!(status.revision_history.len(), 1);
+    let recorded = &status.revision_history[0];
+    assert_eq!(
+        recorded.hash,
+        compute_pod_template_hash(&build_pod_template(&rollout)).unwrap()
+    );
+    assert_eq!(
+        recorded.images.get(&container_name(&rollout)),
+        Some(&"nginx:1.0".to_string())
+    );
+}
+
+#[test]
+fn test_stable_adoption_records_new_revision() {
+    let mut rollout = create_completed_canary_rollout();
+    let container = container_name(&rollout);
+    rollout.status.as_mut().unwrap().revision_history =
+        vec![revision("old-hash", &container, "nginx:0.9")];
+
+    let status = stable_adopted_status(&rollout);
+
+    let hashes: Vec<&str> = status
+        .revision_history
+        .iter()
+        .map(|r| r.hash.as_str())
+        .collect();
+    let adopted_hash = compute_pod_template_hash(&build_pod_template(&rollout)).unwrap();
+    assert_eq!(hashes, vec!["old-hash", adopted_hash.as_str()]);
+}
+
+#[test]
+fn test_record_revision_moves_existing_and_caps_history() {
+    let mut status = RolloutStatus::default();
+    for i in 0..MAX_REVISION_HISTORY + 2 {
+        record_revision(&mut status, revision(&format!("rev-{}", i), "app", "img"));
+    }
+    assert_eq!(status.revision_history.len(), MAX_REVISION_HISTORY);
+    assert_eq!(status.revision_history[0].hash, "rev-2");
+
+    // Re-recording an existing hash moves it to the end without duplicating it
+    record_revision(&mut status, revision("rev-5", "app", "img"));
+    assert_eq!(status.revision_history.len(), MAX_REVISION_HISTORY);
+    assert_eq!(status.revision_history.last().unwrap().hash, "rev-5");
+    assert_eq!(
+        status
+            .revision_history
+            .iter()
+            .filter(|r| r.hash == "rev-5")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_find_revision_by_hash() {
+    let rollout = create_rollout_with_revision_history(None);
+    let status = rollout.status.as_ref().unwrap();
+
+    let found = find_revision(status, "rev-a").unwrap();
+    assert_eq!(
+        found.images.get(&container_name(&rollout)),
+        Some(&"nginx:0.9".to_string())
+    );
+    assert!(find_revision(status, "rev-z").is_none());
+}
+
+#[test]
+fn test_rollback_to_annotation_rolls_back_to_revision() {
+    let rollout = create_rollout_with_revision_history(Some("rev-a"));
+
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.phase, Some(Phase::RollingBack));
+    assert_eq!(status.current_weight, Some(0));
+    assert_eq!(status.rolled_back_to.as_deref(), Some("rev-a"));
+    assert_eq!(status.revision_history.last().unwrap().hash, "rev-a");
+    assert_eq!(
+        status.decisions.last().map(|d| &d.reason),
+        Some(&DecisionReason::ManualRollback)
+    );
+
+    // Stable is rebuilt from the target revision's images
+    let mut rolled_back = rollout.clone();
+    rolled_back.status = Some(status);
+    let stable_rs = build_replicaset(&rolled_back, "stable", "stable", 4).unwrap();
+    let image = stable_rs
+        .spec
+        .as_ref()
+        .unwrap()
+        .template
+        .as_ref()
+        .unwrap()
+        .spec
+        .as_ref()
+        .unwrap()
+        .containers[0]
+        .image
+        .clone();
+    assert_eq!(image.as_deref(), Some("nginx:0.9"));
+
+    // The request is processed once: the canary drains and the rollout settles
+    let drained = compute_desired_status(&rolled_back);
+    assert_eq!(drained.phase, Some(Phase::Degraded));
+    assert_eq!(drained.rolled_back_to.as_deref(), Some("rev-a"));
+}
+
+#[test]
+fn test_no_active_rollback_revision_without_processed_request() {
+    let rollout = create_rollout_with_revision_history(Some("rev-a"));
+
+    // Not processed yet: stable keeps spec.template
+    assert!(active_rollback_revision(&rollout).is_none());
+}
+
+#[test]
+fn test_validate_rollback_to_rejects_unknown_revision() {
+    let rollout = create_rollout_with_revision_history(Some("rev-z"));
+
+    let error = validate_rollout(&rollout).unwrap_err();
+    assert!(error.contains("unknown revision \"rev-z\""), "{}", error);
+    assert!(error.contains("rev-a, rev-b"), "{}", error);
+
+    assert_eq!(
+        validate_rollout(&create_rollout_with_revision_history(Some("rev-a"))),
+        Ok(())
+    );
+}
+
+// ============================================================================
+// Per-rollout requeue annotations
+// ============================================================================
+
+fn rollout_with_requeue_annotation(key: &str, value: &str) -> Rollout {
+    RolloutBuilder::new()
+        .with_step(20, None)
+        .with_status(0, 20, Phase::Progressing)
+        .with_annotation(key, value)
+        .build()
+}
+
+#[test]
+fn test_parse_requeue_override() {
+    assert_eq!(parse_requeue_override("30"), Some(Duration::from_secs(30)));
+    assert_eq!(parse_requeue_override("45s"), Some(Duration::from_secs(45)));
+    assert_eq!(parse_requeue_override("5m"), Some(Duration::from_secs(300)));
+    assert_eq!(
+        parse_requeue_override("1h"),
+        Some(Duration::from_secs(3600))
+    );
+    assert_eq!(parse_requeue_override("1"), Some(Duration::from_secs(1)));
+
+    assert_eq!(parse_requeue_override("0"), None);
+    assert_eq!(parse_requeue_override("3601"), None);
+    assert_eq!(parse_requeue_override("2h"), None);
+    assert_eq!(parse_requeue_override("soon"), None);
+}
+
+#[test]
+fn test_error_requeue_interval_uses_annotation() {
+    let error = ReconcileError::MissingName;
+
+    let fast = rollout_with_requeue_annotation(&requeue_error_annotation_key(), "2");
+    let slow = rollout_with_requeue_annotation(&requeue_error_annotation_key(), "10m");
+
+    assert_eq!(
+        error_requeue_interval(&fast, &error),
+        Duration::from_secs(2)
+    );
+    assert_eq!(
+        error_requeue_interval(&slow, &error),
+        Duration::from_secs(600)
+    );
+}
+
+#[test]
+fn test_error_requeue_interval_defaults_to_error_category() {
+    let error = ReconcileError::ValidationError("bad".to_string());
+    let plain = RolloutBuilder::new().with_step(20, None).build();
+    let invalid = rollout_with_requeue_annotation(&requeue_error_annotation_key(), "0");
+
+    assert_eq!(
+        error_requeue_interval(&plain, &error),
+        calculate_error_requeue_interval(&error)
+    );
+    assert_eq!(
+        error_requeue_interval(&invalid, &error),
+        calculate_error_requeue_interval(&error)
+    );
+}
+
+#[test]
+fn test_backed_off_error_requeue_interval_progression() {
+    let error = ReconcileError::MissingName;
+    let rollout = RolloutBuilder::new().with_step(20, None).build();
+
+    let delays: Vec<u64> = (1..=7)
+        .map(|failures| backed_off_error_requeue_interval(&rollout, &error, failures).as_secs())
+        .collect();
+
+    assert_eq!(delays, vec![10, 20, 40, 80, 160, 300, 300]);
+}
+
+#[test]
+fn test_backed_off_error_requeue_interval_keeps_annotation() {
+    let error = ReconcileError::MissingName;
+    let rollout = rollout_with_requeue_annotation(&requeue_error_annotation_key(), "2");
+
+    assert_eq!(
+        backed_off_error_requeue_interval(&rollout, &error, 4),
+        Duration::from_secs(2)
+    );
+}
+
+#[test]
+fn test_success_requeue_interval_uses_annotation() {
+    let rollout = rollout_with_requeue_annotation(&requeue_success_annotation_key(), "90");
+    let status = rollout.status.clone().unwrap();
+
+    assert_eq!(
+        calculate_requeue_interval_from_rollout(&rollout, &status, DEFAULT_TERMINAL_REQUEUE),
+        Duration::from_secs(90)
+    );
+
+    // The error override doesn't affect successful reconciles
+    let rollout = rollout_with_requeue_annotation(&requeue_error_annotation_key(), "90");
+    assert_eq!(
+        calculate_requeue_interval_from_rollout(&rollout, &status, DEFAULT_TERMINAL_REQUEUE),
+        calculate_requeue_interval(None, None)
+    );
+}
+
+#[test]
+fn test_terminal_rollouts_use_terminal_requeue() {
+    let terminal_requeue = Duration::from_secs(900);
+
+    for phase in [Phase::Completed, Phase::Degraded] {
+        let rollout = RolloutBuilder::new()
+            .with_step(100, None)
+            .with_status(0, 100, phase.clone())
+            .build();
+        let status = rollout.status.clone().unwrap();
+
+        assert_eq!(
+            calculate_requeue_interval_from_rollout(&rollout, &status, terminal_requeue),
+            terminal_requeue,
+            "{:?} should requeue after the terminal interval",
+            phase
+        );
+    }
+}
+
+#[test]
+fn test_in_progress_rollouts_ignore_terminal_requeue() {
+    let rollout = RolloutBuilder::new()
+        .with_step(20, None)
+        .with_status(0, 20, Phase::Progressing)
+        .build();
+    let status = rollout.status.clone().unwrap();
+
+    assert_eq!(
+        calculate_requeue_interval_from_rollout(&rollout, &status, Duration::from_secs(900)),
+        calculate_requeue_interval(None, None)
+    );
+}
+
+#[test]
+fn test_requeue_annotation_overrides_terminal_requeue() {
+    let mut rollout = rollout_with_requeue_annotation(&requeue_success_annotation_key(), "90");
+    let status = RolloutStatus {
+        phase: Some(Phase::Completed),
+        ..Default::default()
+    };
+    rollout.status = Some(status.clone());
+
+    assert_eq!(
+        calculate_requeue_interval_from_rollout(&rollout, &status, DEFAULT_TERMINAL_REQUEUE),
+        Duration::from_secs(90)
+    );
+}
+
+#[test]
+fn test_parse_terminal_requeue() {
+    assert_eq!(
+        parse_terminal_requeue("600"),
+        Some(Duration::from_secs(600))
+    );
+    assert_eq!(
+        parse_terminal_requeue("10m"),
+        Some(Duration::from_secs(600))
+    );
+    assert_eq!(parse_terminal_requeue("24h"), Some(MAX_TERMINAL_REQUEUE));
+    assert_eq!(parse_terminal_requeue("0"), None);
+    assert_eq!(parse_terminal_requeue("25h"), None);
+    assert_eq!(parse_terminal_requeue("soon"), None);
+}
+
+#[test]
+fn test_validate_rollout_rejects_out_of_range_requeue_annotation() {
+    let rollout = rollout_with_requeue_annotation(&requeue_success_annotation_key(), "7200");
+
+    let error = validate_rollout(&rollout).unwrap_err();
+
+    assert!(
+        error.contains("requeue-success-seconds must be between 1s and 3600s"),
+        "got: {}",
+        error
+    );
+}
+
+// ============================================================================
+// Rollout progress gauges (applied canary weight + current step)
+// ============================================================================
+
+#[test]
+fn test_observe_rollout_progress_reflects_mid_canary_weight() {
+    let metrics = crate::server::ControllerMetrics::new().unwrap();
+    let rollout = create_progressing_canary_rollout();
+    assert_eq!(calculate_traffic_weights(&rollout), (50, 50));
+
+    observe_rollout_progress(&metrics, &rollout);
+
+    let output = metrics.encode().unwrap();
+    assert!(output.contains(
+        "kulta_rollout_canary_weight{namespace=\"default\",rollout=\"test-rollout\"} 50"
+    ));
+    assert!(output.contains("kulta_rollout_step{namespace=\"default\",rollout=\"test-rollout\"} 1"));
+}
+
+#[test]
+fn test_observe_rollout_progress_before_first_step() {
+    let metrics = crate::server::ControllerMetrics::new().unwrap();
+    let rollout = create_test_rollout_with_canary();
+
+    observe_rollout_progress(&metrics, &rollout);
+
+    let output = metrics.encode().unwrap();
+    assert!(output
+        .contains("kulta_rollout_canary_weight{namespace=\"default\",rollout=\"test-rollout\"} 0"));
+    assert!(
+        output.contains("kulta_rollout_step{namespace=\"default\",rollout=\"test-rollout\"} -1")
+    );
+}
+
+// ============================================================================
+// Owned ReplicaSets (Controller::owns mapping)
+// ============================================================================
+
+#[test]
+fn test_build_replicaset_sets_rollout_controller_owner() {
+    let mut rollout = create_test_rollout_with_canary();
+    rollout.metadata.uid = Some("rollout-uid".to_string());
+
+    let rs = build_replicaset(&rollout, "canary", "canary", 1).unwrap();
+
+    let owners = rs.metadata.owner_references.unwrap();
+    assert_eq!(owners.len(), 1);
+    assert_eq!(owners[0].kind, "Rollout");
+    assert_eq!(owners[0].api_version, "kulta.io/v1alpha1");
+    assert_eq!(owners[0].name, "test-rollout");
+    assert_eq!(owners[0].uid, "rollout-uid");
+    assert_eq!(owners[0].controller, Some(true));
+}
+
+#[test]
+fn test_build_replicaset_without_uid_has_no_owner() {
+    let rollout = create_test_rollout_with_canary();
+
+    let rs = build_replicaset(&rollout, "stable", "stable", 1).unwrap();
+
+    assert!(rs.metadata.owner_references.is_none());
+}
+
+#[test]
+fn test_owning_rollout_ref_maps_replicaset_to_rollout_request() {
+    let mut rollout = create_test_rollout_with_canary();
+    rollout.metadata.uid = Some("rollout-uid".to_string());
+
+    for rs in [
+        build_replicaset(&rollout, "stable", "stable", 3).unwrap(),
+        build_replicaset(&rollout, "canary", "canary", 1).unwrap(),
+    ] {
+        assert_eq!(
+            owning_rollout_ref(&rs),
+            Some(ObjectRef::<Rollout>::new("test-rollout").within("default"))
+        );
+    }
+}
+
+#[test]
+fn test_owning_rollout_ref_maps_blue_green_replicasets_to_rollout_request() {
+    let mut rollout = create_test_rollout_with_blue_green();
+    rollout.metadata.uid = Some("rollout-uid".to_string());
+
+    let (active_rs, preview_rs) = build_replicasets_for_blue_green(&rollout, 3).unwrap();
+
+    for rs in [active_rs, preview_rs] {
+        assert_eq!(
+            owning_rollout_ref(&rs),
+            Some(ObjectRef::<Rollout>::new("blue-green-rollout").within("default"))
+        );
+    }
+}
+
+#[test]
+fn test_owning_rollout_ref_ignores_other_owners() {
+    let mut rs = desired_replicaset("canary");
+    assert_eq!(owning_rollout_ref(&rs), None);
+
+    rs.metadata.owner_references = Some(vec![OwnerReference {
+        api_version: "apps/v1".to_string(),
+        kind: "Deployment".to_string(),
+        name: "test-rollout".to_string(),
+        uid: "deployment-uid".to_string(),
+        controller: Some(true),
+        ..Default::default()
+    }]);
+    assert_eq!(owning_rollout_ref(&rs), None);
+}
+
+// ============================================================================
+// Safe apply ordering (ReplicaSets vs traffic)
+// ============================================================================
+
+/// Strategy that records the order of its apply calls and can fail traffic
+struct RecordingStrategy {
+    calls: std::sync::Mutex<Vec<&'static str>>,
+    fail_traffic: bool,
+}
+
+impl RecordingStrategy {
+    fn new(fail_traffic: bool) -> Self {
+        Self {
+            calls: std::sync::Mutex::new(vec![]),
+            fail_traffic,
+        }
+    }
+
+    fn calls(&self) -> Vec<&'static str> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::controller::strategies::RolloutStrategy for RecordingStrategy {
+    fn name(&self) -> &'static str {
+        "recording"
+    }
+
+    async fn reconcile_replicasets(
+        &self,
+        _rollout: &Rollout,
+        _ctx: &Context,
+    ) -> Result<(), crate::controller::strategies::StrategyError> {
+        self.calls.lock().unwrap().push("replicasets");
+        Ok(())
+    }
+
+    async fn reconcile_traffic(
+        &self,
+        _rollout: &Rollout,
+        _ctx: &Context,
+    ) -> Result<(), crate::controller::strategies::StrategyError> {
+        self.calls.lock().unwrap().push("traffic");
+        if self.fail_traffic {
+            return Err(
+                crate::controller::strategies::StrategyError::TrafficReconciliationFailed(
+                    "HTTPRoute patch failed".to_string(),
+                ),
+            );
+        }
+        Ok(())
+    }
+
+    fn compute_next_status(&self, rollout: &Rollout) -> RolloutStatus {
+        compute_desired_status(rollout)
+    }
+
+    fn supports_metrics_analysis(&self) -> bool {
+        true
+    }
+
+    fn supports_manual_promotion(&self) -> bool {
+        true
+    }
+}
+
+/// Progressing canary at 50% (2 of 3 replicas) routed through an HTTPRoute
+fn create_routed_canary_rollout() -> Rollout {
+    let mut rollout = create_progressing_canary_rollout();
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.traffic_routing = Some(TrafficRouting {
+            gateway_api: Some(GatewayAPIRouting {
+                http_route: "test-app-route".to_string(),
+                gateway_api_version: None,
+                verify_accepted: None,
+                canary_match: None,
+            }),
+            linkerd: None,
+        });
+    }
+    rollout
+}
+
+#[test]
+fn test_plan_apply_order_scales_up_before_shifting_traffic() {
+    let rollout = create_routed_canary_rollout();
+
+    // Canary at 1 replica growing to 2, or not created yet
+    let canary_rs = create_replicaset_with_readiness(1, 1);
+    assert_eq!(
+        plan_apply_order(&rollout, Some(&canary_rs)),
+        ApplyOrder::ReplicaSetsFirst
+    );
+    assert_eq!(
+        plan_apply_order(&rollout, None),
+        ApplyOrder::ReplicaSetsFirst
+    );
+}
+
+#[test]
+fn test_plan_apply_order_shifts_traffic_before_scaling_down() {
+    // Rollback: weight 0, canary still has 2 replicas
+    let mut rollout = create_routed_canary_rollout();
+    rollout.status = Some(start_rollback(
+        &rollout,
+        "metric analysis exceeded thresholds",
+        DecisionReason::AnalysisFailed,
+    ));
+    let canary_rs = create_replicaset_with_readiness(2, 2);
+
+    assert_eq!(
+        plan_apply_order(&rollout, Some(&canary_rs)),
+        ApplyOrder::TrafficFirst
+    );
+}
+
+#[test]
+fn test_holds_traffic_until_canary_has_target_ready_replicas() {
+    let rollout = create_routed_canary_rollout();
+    assert_eq!(calculate_canary_replicas(&rollout, 50), (1, 2));
+
+    assert!(holds_traffic_for_readiness(&rollout, None));
+    assert!(holds_traffic_for_readiness(
+        &rollout,
+        Some(&create_replicaset_with_readiness(2, 1))
+    ));
+    assert!(!holds_traffic_for_readiness(
+        &rollout,
+        Some(&create_replicaset_with_readiness(2, 2))
+    ));
+
+    // Without traffic routing there is nothing to hold
+    let unrouted = create_progressing_canary_rollout();
+    assert!(!holds_traffic_for_readiness(&unrouted, None));
+}
+
+#[tokio::test]
+async fn test_apply_holds_traffic_while_canary_scales_up() {
+    let rollout = create_routed_canary_rollout();
+    let strategy = RecordingStrategy::new(false);
+    let canary_rs = create_replicaset_with_readiness(1, 1);
+
+    let shift =
+        apply_replicasets_and_traffic(&strategy, &rollout, &Context::new_mock(), Some(&canary_rs))
+            .await
+            .unwrap();
+
+    assert_eq!(shift, TrafficShift::HeldForReadiness);
+    assert_eq!(strategy.calls(), vec!["replicasets"]);
+}
+
+#[tokio::test]
+async fn test_apply_shifts_traffic_once_canary_is_ready() {
+    let rollout = create_routed_canary_rollout();
+    let strategy = RecordingStrategy::new(false);
+    let canary_rs = create_replicaset_with_readiness(2, 2);
+
+    let shift =
+        apply_replicasets_and_traffic(&strategy, &rollout, &Context::new_mock(), Some(&canary_rs))
+            .await
+            .unwrap();
+
+    assert_eq!(shift, TrafficShift::Applied);
+    assert_eq!(strategy.calls(), vec!["replicasets", "traffic"]);
+}
+
+#[tokio::test]
+async fn test_apply_traffic_failure_after_scaling_is_an_error() {
+    // Canary scaled, HTTPRoute patch fails: the step must not count as progressed
+    let rollout = create_routed_canary_rollout();
+    let strategy = RecordingStrategy::new(true);
+    let canary_rs = create_replicaset_with_readiness(2, 2);
+
+    let result =
+        apply_replicasets_and_traffic(&strategy, &rollout, &Context::new_mock(), Some(&canary_rs))
+            .await;
+
+    assert!(matches!(
+        result,
+        Err(ReconcileError::StrategyError(
+            crate::controller::strategies::StrategyError::TrafficReconciliationFailed(_)
+        ))
+    ));
+    assert_eq!(strategy.calls(), vec!["replicasets", "traffic"]);
+}
+
+#[tokio::test]
+async fn test_apply_rollback_traffic_failure_leaves_canary_scaled() {
+    // Traffic first on rollback: a failed shift never scales the canary down
+    let mut rollout = create_routed_canary_rollout();
+    rollout.status = Some(start_rollback(
+        &rollout,
+        "metric analysis exceeded thresholds",
+        DecisionReason::AnalysisFailed,
+    ));
+    let strategy = RecordingStrategy::new(true);
+    let canary_rs = create_replicaset_with_readiness(2, 2);
+
+    let result =
+        apply_replicasets_and_traffic(&strategy, &rollout, &Context::new_mock(), Some(&canary_rs))
+            .await;
+
+    assert!(result.is_err());
+    assert_eq!(strategy.calls(), vec!["traffic"]);
+}
+
+// ============================================================================
+// spec.paused (deferred rollout)
+// ============================================================================
+
+fn set_spec_paused(rollout: &mut Rollout, paused: bool) {
+    rollout.spec.paused = Some(paused);
+}
+
+/// Routed canary (steps 20/50/80/100) created with spec.paused, not started yet
+fn create_paused_new_rollout() -> Rollout {
+    let mut rollout = create_routed_canary_rollout();
+    rollout.status = Some(initializing_status(&rollout));
+    set_spec_paused(&mut rollout, true);
+    rollout
+}
+
+#[test]
+fn test_spec_paused_holds_new_rollout_before_first_step() {
+    let rollout = create_paused_new_rollout();
+
+    let status = spec_paused_status(&rollout, Utc::now()).unwrap();
+
+    assert_eq!(status.phase, Some(Phase::Paused));
+    assert_eq!(status.message.as_deref(), Some(SPEC_PAUSED_MESSAGE));
+    assert_eq!(status.current_step_index, None);
+    assert_eq!(status.current_weight, Some(0));
+    assert!(is_paused_by_spec(&status));
+
+    // Idempotent once paused
+    let mut paused = rollout.clone();
+    paused.status = Some(status.clone());
+    assert_eq!(spec_paused_status(&paused, Utc::now()), Some(status));
+}
+
+#[tokio::test]
+async fn test_spec_paused_creates_replicasets_without_touching_traffic() {
+    let mut rollout = create_paused_new_rollout();
+    rollout.status = spec_paused_status(&rollout, Utc::now());
+    let strategy = RecordingStrategy::new(false);
+
+    let shift = apply_replicasets_and_traffic(&strategy, &rollout, &Context::new_mock(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(shift, TrafficShift::HeldForPause);
+    assert_eq!(strategy.calls(), vec!["replicasets"]);
+    // No canary pods while nothing routes to them
+    assert_eq!(calculate_canary_replicas(&rollout, 0).1, 0);
+}
+
+#[test]
+fn test_spec_paused_rollout_starts_when_unpaused() {
+    let mut rollout = create_paused_new_rollout();
+    rollout.status = spec_paused_status(&rollout, Utc::now());
+
+    // Still paused: nothing moves
+    let held = compute_desired_status(&rollout);
+    assert_eq!(held.phase, Some(Phase::Paused));
+    assert_eq!(held.current_step_index, None);
+
+    set_spec_paused(&mut rollout, false);
+    let status = compute_desired_status(&rollout);
+
+    assert_eq!(status.phase, Some(Phase::Progressing));
+    assert_eq!(status.current_step_index, Some(0));
+    assert_eq!(status.current_weight, Some(20));
+}
+
+#[test]
+fn test_spec_paused_holds_in_flight_step_until_unpaused() {
+    let mut rollout = create_routed_canary_rollout();
+    set_spec_paused(&mut rollout, true);
+
+    let status = spec_paused_status(&rollout, Utc::now()).unwrap();
+    assert_eq!(status.phase, Some(Phase::Paused));
+    assert_eq!(status.current_step_index, Some(1));
+    assert_eq!(status.current_weight, Some(50));
+
+    rollout.status = Some(status);
+    set_spec_paused(&mut rollout, false);
+    let resumed = compute_desired_status(&rollout);
+
+    // Step 1 has no pause: progression picks up right away
+    assert_eq!(resumed.phase, Some(Phase::Progressing));
+    assert_eq!(resumed.current_step_index, Some(2));
+    assert_eq!(resumed.current_weight, Some(80));
+    assert!(!is_paused_by_spec(&resumed));
+}
+
+#[test]
+fn test_spec_paused_leaves_finished_rollout_alone() {
+    for phase in [Phase::Completed, Phase::Degraded, Phase::RollingBack] {
+        let mut rollout = create_routed_canary_rollout();
+        set_spec_paused(&mut rollout, true);
+        if let Some(status) = rollout.status.as_mut() {
+            status.phase = Some(phase.clone());
+        }
+
+        assert_eq!(
+            spec_paused_status(&rollout, Utc::now()),
+            None,
+            "{:?} is not held by spec.paused",
+            phase
+        );
+    }
+}
+
+// ============================================================================
+// Cancellation on shutdown
+// ============================================================================
+
+#[tokio::test]
+async fn test_until_cancelled_stops_pending_operation() {
+    let cancellation = CancellationToken::new();
+    let trigger = cancellation.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        trigger.cancel();
+    });
+
+    let result = tokio::time::timeout(
+        Duration::from_secs(1),
+        until_cancelled(&cancellation, futures::future::pending::<()>()),
+    )
+    .await
+    .expect("cancellation must end the operation promptly");
+
+    assert!(matches!(result, Err(ReconcileError::Cancelled)));
+}
+
+#[tokio::test]
+async fn test_until_cancelled_passes_through_result() {
+    let result = until_cancelled(&CancellationToken::new(), async { 42 }).await;
+
+    assert_eq!(result.unwrap(), 42);
+}
+
+#[tokio::test]
+async fn test_reconcile_exits_promptly_when_cancelled() {
+    let cancellation = CancellationToken::new();
+    let ctx = Arc::new(Context::new_mock().with_cancellation(cancellation.clone()));
+    cancellation.cancel();
+
+    let result = tokio::time::timeout(
+        Duration::from_secs(1),
+        reconcile(Arc::new(create_progressing_canary_rollout()), ctx),
+    )
+    .await
+    .expect("cancelled reconcile must not wait on the API server");
+
+    let error = result.unwrap_err();
+    assert!(matches!(error, ReconcileError::Cancelled));
+    assert_eq!(error.category(), "cancelled");
+}
+
+// ============================================================================
+// Dry run (spec.dryRun)
+// ============================================================================
+
+/// Progressing canary (step 1 of 20/50/80/100, no pauses) in dry-run mode
+fn create_dry_run_rollout() -> Rollout {
+    let mut rollout = create_progressing_canary_rollout();
+    rollout.spec.dry_run = Some(true);
+    if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+        canary.traffic_routing = Some(TrafficRouting {
+            gateway_api: Some(GatewayAPIRouting {
+                http_route: "test-route".to_string(),
+                gateway_api_version: None,
+                verify_accepted: None,
+                canary_match: None,
+            }),
+            linkerd: None,
+        });
+    }
+    rollout
+}
+
+#[test]
+fn test_dry_run_plan_lists_replicasets_traffic_and_status() {
+    use crate::controller::dry_run::compute_dry_run_plan;
+
+    let rollout = create_dry_run_rollout();
+    let desired = compute_desired_status(&rollout);
+
+    let plan = compute_dry_run_plan(&rollout, &desired).unwrap();
+
+    assert_eq!(plan.phase, Some(Phase::Progressing));
+    assert_eq!(plan.current_step_index, Some(2));
+    assert_eq!(plan.current_weight, Some(80));
+    assert_eq!(plan.operations.len(), 4, "{:?}", plan.operations);
+    assert!(plan.operations[0].starts_with("ReplicaSet test-rollout-stable: "));
+    assert!(plan.operations[1].starts_with("ReplicaSet test-rollout-canary: "));
+    assert_eq!(
+        plan.operations[2],
+        "HTTPRoute test-route: stable 20%, canary 80%"
+    );
+    assert!(
+        plan.operations[3].contains("step 1 -> 2, weight 50 -> 80"),
+        "{}",
+        plan.operations[3]
+    );
+}
+
+#[test]
+fn test_dry_run_status_keeps_progress() {
+    use crate::controller::dry_run::{compute_dry_run_plan, dry_run_status};
+
+    let rollout = create_dry_run_rollout();
+    let plan = compute_dry_run_plan(&rollout, &compute_desired_status(&rollout)).unwrap();
+
+    let status = dry_run_status(&rollout, plan.clone());
+
+    // Only the plan is recorded; the rollout stays at step 1
+    assert_eq!(status.current_step_index, Some(1));
+    assert_eq!(status.current_weight, Some(50));
+    assert_eq!(status.dry_run_plan, Some(plan));
+}
+
+#[tokio::test]
+async fn test_dry_run_reconcile_makes_no_api_calls() {
+    use crate::controller::dry_run::{compute_dry_run_plan, dry_run_status, DRY_RUN_REQUEUE};
+
+    // Plan already recorded: nothing left to write
+    let mut rollout = create_dry_run_rollout();
+    let desired = compute_desired_status(&rollout);
+    assert_ne!(rollout.status.as_ref(), Some(&desired));
+    let plan = compute_dry_run_plan(&rollout, &desired).unwrap();
+    rollout.status = Some(dry_run_status(&rollout, plan));
+
+    // The mock client has no API server: any create/patch (or read) would fail
+    let ctx = Arc::new(Context::new_mock());
+    let action = tokio::time::timeout(Duration::from_secs(5), reconcile(Arc::new(rollout), ctx))
+        .await
+        .expect("dry-run reconcile must not wait on the API server")
+        .expect("dry-run reconcile must not call the API server");
+
+    assert_eq!(action, Action::requeue(DRY_RUN_REQUEUE));
+}
+
+// ============================================================================
+// Multi-revision tracking (stableRevisionHash / canaryRevisionHash)
+// ============================================================================
+
+fn set_image(rollout: &mut Rollout, image: &str) {
+    if let Some(pod_spec) = rollout.spec.template.spec.as_mut() {
+        pod_spec.containers[0].image = Some(image.to_string());
+    }
+}
+
+/// Progressing canary (step 1, 50%) whose status records its current canary hash
+fn create_tracked_canary_rollout() -> Rollout {
+    let mut rollout = create_progressing_canary_rollout();
+    let hash = desired_canary_revision_hash(&rollout);
+    if let Some(status) = rollout.status.as_mut() {
+        status.canary_revision_hash = hash;
+    }
+    rollout
+}
+
+#[test]
+fn test_superseded_canary_status_none_when_template_unchanged() {
+    let rollout = create_tracked_canary_rollout();
+
+    assert_eq!(superseded_canary_status(&rollout, Utc::now()), None);
+}
+
+#[test]
+fn test_superseded_canary_status_restarts_at_step_zero() {
+    let mut rollout = create_tracked_canary_rollout();
+    let old_hash = rollout
+        .status
+        .as_ref()
+        .and_then(|s| s.canary_revision_hash.clone());
+    set_image(&mut rollout, "nginx:2.0");
+
+    let status = superseded_canary_status(&rollout, Utc::now()).unwrap();
+
+    assert_eq!(status.phase, Some(Phase::Progressing));
+    assert_eq!(status.current_step_index, Some(0));
+    assert_eq!(status.current_weight, Some(20));
+    assert_eq!(
+        status.canary_revision_hash,
+        desired_canary_revision_hash(&rollout)
+    );
+    assert_ne!(status.canary_revision_hash, old_hash);
+
+    let decision = status.decisions.last().unwrap();
+    assert_eq!(decision.action, DecisionAction::Initialize);
+    assert_eq!(decision.reason, DecisionReason::RevisionSuperseded);
+    assert_eq!(decision.from_step, Some(1));
+    assert_eq!(decision.to_step, Some(0));
+
+    let transition = status.transition_log.last().unwrap();
+    assert_eq!(transition.from_step, Some(1));
+    assert_eq!(transition.to_step, Some(0));
+    assert_eq!(transition.trigger, TransitionTrigger::Initialization);
+}
+
+#[test]
+fn test_superseded_canary_status_none_without_recorded_hash() {
+    // Status from before revision tracking: the first apply records the hash
+    let mut rollout = create_progressing_canary_rollout();
+    set_image(&mut rollout, "nginx:2.0");
+
+    assert_eq!(superseded_canary_status(&rollout, Utc::now()), None);
+}
+
+#[test]
+fn test_superseded_canary_status_ignores_finished_rollout() {
+    for phase in [Phase::Completed, Phase::Degraded, Phase::RollingBack] {
+        let mut rollout = create_tracked_canary_rollout();
+        if let Some(status) = rollout.status.as_mut() {
+            status.phase = Some(phase.clone());
+        }
+        set_image(&mut rollout, "nginx:2.0");
+
+        assert_eq!(
+            superseded_canary_status(&rollout, Utc::now()),
+            None,
+            "{:?} is not an in-flight canary",
+            phase
+        );
+    }
+}
+
+#[test]
+fn test_rapid_redeploy_restarts_for_each_new_revision() {
+    // Update 1 lands mid-rollout
+    let mut rollout = create_tracked_canary_rollout();
+    set_image(&mut rollout, "nginx:2.0");
+    let mut status = superseded_canary_status(&rollout, Utc::now()).unwrap();
+
+    // It progresses to step 2 before update 2 lands
+    status.current_step_index = Some(2);
+    status.current_weight = Some(80);
+    rollout.status = Some(status);
+    assert_eq!(superseded_canary_status(&rollout, Utc::now()), None);
+
+    set_image(&mut rollout, "nginx:3.0");
+    let status = superseded_canary_status(&rollout, Utc::now()).unwrap();
+
+    assert_eq!(status.current_step_index, Some(0));
+    assert_eq!(status.current_weight, Some(20));
+    assert_eq!(
+        status.canary_revision_hash,
+        desired_canary_revision_hash(&rollout)
+    );
+    assert_eq!(
+        status
+            .decisions
+            .iter()
+            .filter(|d| d.reason == DecisionReason::RevisionSuperseded)
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn test_apply_revision_hashes_records_replicaset_hashes() {
+    let rollout = create_progressing_canary_rollout();
+    let stable_rs = build_replicaset(&rollout, "stable", "stable", 3).unwrap();
+    let mut updated = rollout.clone();
+    set_image(&mut updated, "nginx:2.0");
+    let canary_rs = build_replicaset(&updated, "canary", "canary", 1).unwrap();
+    let mut status = RolloutStatus::default();
+
+    apply_revision_hashes(&mut status, Some(&stable_rs), Some(&canary_rs));
+
+    assert_eq!(
+        status.stable_revision_hash.as_deref(),
+        replicaset_template_hash(&stable_rs)
+    );
+    assert_eq!(
+        status.canary_revision_hash.as_deref(),
+        replicaset_template_hash(&canary_rs)
+    );
+    assert_ne!(status.stable_revision_hash, status.canary_revision_hash);
+}
+
+#[test]
+fn test_apply_revision_hashes_keeps_hash_without_replicaset() {
+    let mut status = RolloutStatus {
+        stable_revision_hash: Some("abc123".to_string()),
+        canary_revision_hash: Some("def456".to_string()),
+        ..Default::default()
+    };
+
+    apply_revision_hashes(&mut status, None, None);
+
+    assert_eq!(status.stable_revision_hash.as_deref(), Some("abc123"));
+    assert_eq!(status.canary_revision_hash.as_deref(), Some("def456"));
+}
+
+// ============================================================================
+// Init container revision tracking
+// ============================================================================
+
+fn set_init_container(rollout: &mut Rollout, image: &str) {
+    if let Some(pod_spec) = rollout.spec.template.spec.as_mut() {
+        pod_spec.init_containers = Some(vec![k8s_openapi::api::core::v1::Container {
+            name: "migrate".to_string(),
+            image: Some(image.to_string()),
+            ..Default::default()
+        }]);
+    }
+}
+
+#[test]
+fn test_init_container_image_changes_pod_template_hash() {
+    let mut original = create_test_rollout_with_canary();
+    set_init_container(&mut original, "migrate:1.0");
+    let mut updated = original.clone();
+    set_init_container(&mut updated, "migrate:2.0");
+
+    assert_ne!(
+        compute_pod_template_hash(&original.spec.template).unwrap(),
+        compute_pod_template_hash(&updated.spec.template).unwrap()
+    );
+
+    // The new canary ReplicaSet is told apart from the running stable one,
+    // in its label and in its selector
+    let stable_rs = build_replicaset(&original, "stable", "stable", 3).unwrap();
+    let canary_rs = build_replicaset(&updated, "canary", "canary", 1).unwrap();
+    assert_ne!(
+        pod_template_hash_label(&stable_rs),
+        pod_template_hash_label(&canary_rs)
+    );
+    let selector_hash = |rs: &ReplicaSet| {
+        rs.spec
+            .as_ref()
+            .and_then(|spec| spec.selector.match_labels.as_ref())
+            .and_then(|labels| labels.get("pod-template-hash"))
+            .cloned()
+    };
+    assert_ne!(selector_hash(&stable_rs), selector_hash(&canary_rs));
+}
+
+#[test]
+fn test_adding_init_container_triggers_new_canary_revision() {
+    let mut rollout = create_tracked_canary_rollout();
+    let running_canary = build_replicaset(&rollout, "canary", "canary", 2).unwrap();
+
+    set_init_container(&mut rollout, "migrate:1.0");
+
+    let desired_canary = build_replicaset(&rollout, "canary", "canary", 2).unwrap();
+    assert_eq!(
+        classify_existing_replicaset(&running_canary, &desired_canary, "canary"),
+        ExistingReplicaSetAction::Replace
+    );
+    let status = superseded_canary_status(&rollout, Utc::now()).unwrap();
+    assert_eq!(status.current_step_index, Some(0));
+    assert_eq!(
+        status.canary_revision_hash,
+        desired_canary_revision_hash(&rollout)
+    );
+}
+
+#[test]
+fn test_pod_template_hash_independent_of_construction_order() {
+    let mut rollout = create_test_rollout_with_canary();
+    set_init_container(&mut rollout, "migrate:1.0");
+    let template = rollout.spec.template.clone();
+
+    // Same labels inserted in the opposite order
+    let mut reordered = template.clone();
+    if let Some(metadata) = reordered.metadata.as_mut() {
+        let mut labels = BTreeMap::new();
+        labels.insert("tier".to_string(), "web".to_string());
+        labels.insert("app".to_string(), "test-app".to_string());
+        metadata.labels = Some(labels);
+    }
+    let mut ordered = template.clone();
+    if let Some(metadata) = ordered.metadata.as_mut() {
+        let mut labels = BTreeMap::new();
+        labels.insert("app".to_string(), "test-app".to_string());
+        labels.insert("tier".to_string(), "web".to_string());
+        metadata.labels = Some(labels);
+    }
+    assert_eq!(
+        compute_pod_template_hash(&ordered).unwrap(),
+        compute_pod_template_hash(&reordered).unwrap()
+    );
+
+    // A template read back from the API (keys in whatever order) hashes the same
+    let mut value = serde_json::to_value(&template).unwrap();
+    if let Some(spec) = value.get_mut("spec").and_then(|spec| spec.as_object_mut()) {
+        let init_containers = spec.remove("initContainers").unwrap();
+        spec.insert("initContainers".to_string(), init_containers);
+    }
+    let round_tripped: k8s_openapi::api::core::v1::PodTemplateSpec =
+        serde_json::from_value(value).unwrap();
+    assert_eq!(
+        compute_pod_template_hash(&template).unwrap(),
+        compute_pod_template_hash(&round_tripped).unwrap()
+    );
+}
+
+/// Completed canary (weight back to 0) whose status records its canary hash
+fn create_completed_tracked_canary_rollout() -> Rollout {
+    let mut rollout = create_tracked_canary_rollout();
+    if let Some(status) = rollout.status.as_mut() {
+        status.phase = Some(Phase::Completed);
+        status.current_step_index = None;
+        status.current_weight = Some(0);
+    }
+    rollout
+}
+
+fn set_env(rollout: &mut Rollout, name: &str, value: &str) {
+    if let Some(pod_spec) = rollout.spec.template.spec.as_mut() {
+        pod_spec.containers[0].env = Some(vec![k8s_openapi::api::core::v1::EnvVar {
+            name: name.to_string(),
+            value: Some(value.to_string()),
+            ..Default::default()
+        }]);
+    }
+}
+
+#[test]
+fn test_env_var_change_produces_new_hash() {
+    let mut original = create_test_rollout_with_canary();
+    set_env(&mut original, "LOG_LEVEL", "info");
+    let mut updated = original.clone();
+    set_env(&mut updated, "LOG_LEVEL", "debug");
+
+    assert_ne!(
+        desired_canary_revision_hash(&original),
+        desired_canary_revision_hash(&updated)
+    );
+}
+
+#[test]
+fn test_new_revision_status_none_when_template_unchanged() {
+    let rollout = create_completed_tracked_canary_rollout();
+
+    assert_eq!(new_revision_status(&rollout, Utc::now()), None);
+}
+
+#[test]
+fn test_init_container_change_starts_fresh_rollout_after_completion() {
+    let mut rollout = create_completed_tracked_canary_rollout();
+    set_init_container(&mut rollout, "migrate:2.0");
+
+    let status = new_revision_status(&rollout, Utc::now()).unwrap();
+
+    assert_eq!(status.phase, Some(Phase::Progressing));
+    assert_eq!(status.current_step_index, Some(0));
+    assert_eq!(status.current_weight, Some(20));
+    assert_eq!(
+        status.canary_revision_hash,
+        desired_canary_revision_hash(&rollout)
+    );
+    let decision = status.decisions.last().unwrap();
+    assert_eq!(decision.action, DecisionAction::Initialize);
+    assert_eq!(decision.reason, DecisionReason::Initialization);
+}
+
+#[test]
+fn test_env_var_change_starts_fresh_rollout_after_rollback() {
+    let mut rollout = create_completed_tracked_canary_rollout();
+    if let Some(status) = rollout.status.as_mut() {
+        status.phase = Some(Phase::Degraded);
+    }
+    set_env(&mut rollout, "FEATURE_FLAG", "off");
+
+    let status = new_revision_status(&rollout, Utc::now()).unwrap();
+
+    assert_eq!(status.phase, Some(Phase::Progressing));
+    assert_eq!(status.current_step_index, Some(0));
+}
+
+#[test]
+fn test_new_revision_status_leaves_in_flight_rollout_to_superseded() {
+    let mut rollout = create_tracked_canary_rollout();
+    set_env(&mut rollout, "LOG_LEVEL", "debug");
+
+    assert_eq!(new_revision_status(&rollout, Utc::now()), None);
+    assert!(superseded_canary_status(&rollout, Utc::now()).is_some());
+}
+
+// ============================================================================
+// Absolute / percentage replica counts (maxSurge rounds up, maxUnavailable down)
+// ============================================================================
+
+fn percent(value: &str) -> IntOrString {
+    IntOrString::String(value.to_string())
+}
+
+#[test]
+fn test_resolve_int_or_percent_percentage_of_four() {
+    assert_eq!(resolve_int_or_percent(&percent("25%"), 4, true), Ok(1));
+    assert_eq!(resolve_int_or_percent(&percent("25%"), 4, false), Ok(1));
+}
+
+#[test]
+fn test_resolve_int_or_percent_rounding_direction() {
+    // 25% of 10 = 2.5: surge ceils, unavailable floors
+    assert_eq!(resolve_int_or_percent(&percent("25%"), 10, true), Ok(3));
+    assert_eq!(resolve_int_or_percent(&percent("25%"), 10, false), Ok(2));
+
+    // A small percentage of a small fleet: surge still allows one pod
+    assert_eq!(resolve_int_or_percent(&percent("10%"), 3, true), Ok(1));
+    assert_eq!(resolve_int_or_percent(&percent("10%"), 3, false), Ok(0));
+}
+
+#[test]
+fn test_resolve_int_or_percent_integer_is_absolute() {
+    for round_up in [true, false] {
+        assert_eq!(
+            resolve_int_or_percent(&IntOrString::Int(1), 4, round_up),
+            Ok(1)
+        );
+        assert_eq!(
+            resolve_int_or_percent(&IntOrString::Int(0), 4, round_up),
+            Ok(0)
+        );
+    }
+}
+
+#[test]
+fn test_resolve_int_or_percent_full_and_zero_percent() {
+    for round_up in [true, false] {
+        assert_eq!(resolve_int_or_percent(&percent("100%"), 4, round_up), Ok(4));
+        assert_eq!(resolve_int_or_percent(&percent("0%"), 4, round_up), Ok(0));
+    }
+}
+
+#[test]
+fn test_resolve_int_or_percent_rejects_invalid_values() {
+    assert!(resolve_int_or_percent(&percent("1"), 4, true).is_err());
+    assert!(resolve_int_or_percent(&percent("abc%"), 4, true).is_err());
+    assert!(resolve_int_or_percent(&percent("-25%"), 4, false).is_err());
+    assert!(resolve_int_or_percent(&IntOrString::Int(-1), 4, false).is_err());
 }
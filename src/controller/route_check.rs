@@ -0,0 +1,496 @@
+//! HTTPRoute acceptance pre-flight for canary steps
+//!
+//! With `trafficRouting.gatewayAPI.verifyAccepted: true`, a step that shifts
+//! traffic is only taken once a parent Gateway reports the HTTPRoute as
+//! Accepted (or Programmed). A route whose Gateway doesn't exist still takes
+//! the weight patch but never carries real traffic, so the canary would be
+//! analysed and promoted without having served a request.
+//!
+//! The check is non-fatal: the step is held (with a Warning decision) and
+//! re-checked until the route is accepted, then progression resumes.
+
+use crate::controller::rollout::{is_condition_true, record_decision, set_condition};
+use crate::crd::rollout::{
+    DecisionAction, DecisionReason, GatewayAPIRouting, Rollout, RolloutStatus,
+};
+use async_trait::async_trait;
+use kube::api::{Api, ApiResource};
+use kube::core::DynamicObject;
+use kube::ResourceExt;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Status condition type set while a step is held for an unaccepted HTTPRoute
+pub const ROUTE_NOT_ACCEPTED_CONDITION: &str = "RouteNotAccepted";
+
+/// Condition reason while a step is held for the HTTPRoute
+pub const NOT_ACCEPTED_REASON: &str = "NotAccepted";
+
+/// Condition reason once the HTTPRoute is accepted again
+pub const ROUTE_ACCEPTED_REASON: &str = "Accepted";
+
+/// Requeue interval while a step is held for the HTTPRoute
+pub const ROUTE_RECHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// HTTPRoute source (mockable in tests)
+#[async_trait]
+pub trait RouteLookup: Send + Sync {
+    /// Get an HTTPRoute by name, None if it doesn't exist
+    async fn get_route(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Option<DynamicObject>, kube::Error>;
+}
+
+/// RouteLookup backed by the Kubernetes API
+pub struct KubeRouteLookup {
+    client: kube::Client,
+    resource: ApiResource,
+}
+
+impl KubeRouteLookup {
+    pub fn new(client: kube::Client, resource: ApiResource) -> Self {
+        Self { client, resource }
+    }
+}
+
+#[async_trait]
+impl RouteLookup for KubeRouteLookup {
+    async fn get_route(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Option<DynamicObject>, kube::Error> {
+        let api: Api<DynamicObject> =
+            Api::namespaced_with(self.client.clone(), namespace, &self.resource);
+        api.get_opt(name).await
+    }
+}
+
+/// The Gateway API routing to verify, if the rollout opted in (canary only)
+pub fn route_verification(rollout: &Rollout) -> Option<&GatewayAPIRouting> {
+    rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()?
+        .traffic_routing
+        .as_ref()?
+        .gateway_api
+        .as_ref()
+        .filter(|routing| routing.verify_accepted.unwrap_or(false))
+}
+
+/// Check that a parent Gateway has accepted the HTTPRoute
+///
+/// The route must have at least one parentRef, and one of the parents in
+/// its status must report an Accepted or Programmed condition that is True.
+///
+/// # Errors
+/// Describes why the route isn't live
+pub fn check_route_accepted(route: &DynamicObject) -> Result<(), String> {
+    let name = route.name_any();
+    let has_parent_refs = route.data["spec"]["parentRefs"]
+        .as_array()
+        .is_some_and(|refs| !refs.is_empty());
+    if !has_parent_refs {
+        return Err(format!("HTTPRoute {} has no parentRefs", name));
+    }
+
+    let accepted = route.data["status"]["parents"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|parent| parent["conditions"].as_array())
+        .flatten()
+        .any(|condition| {
+            matches!(condition["type"].as_str(), Some("Accepted" | "Programmed"))
+                && condition["status"].as_str() == Some("True")
+        });
+    if accepted {
+        Ok(())
+    } else {
+        Err(format!(
+            "HTTPRoute {} has not been accepted by a parent Gateway",
+            name
+        ))
+    }
+}
+
+/// Hold the step computed for this reconcile until the HTTPRoute is accepted
+///
+/// Only a step advance (or a step already held) is checked, so a route that
+/// goes away mid-step doesn't interrupt a pause or analysis. A failed lookup
+/// doesn't block the rollout; see `apply_route_check` for the hold itself.
+pub async fn hold_step_for_route(
+    lookup: &dyn RouteLookup,
+    rollout: &Rollout,
+    routing: &GatewayAPIRouting,
+    desired: RolloutStatus,
+) -> RolloutStatus {
+    let current_step = rollout
+        .status
+        .as_ref()
+        .and_then(|status| status.current_step_index);
+    let advancing = desired.current_step_index > current_step
+        || is_condition_true(&desired, ROUTE_NOT_ACCEPTED_CONDITION);
+
+    let check = match rollout.namespace() {
+        Some(namespace) if advancing => {
+            match lookup.get_route(&namespace, &routing.http_route).await {
+                Ok(Some(route)) => check_route_accepted(&route),
+                Ok(None) => Err(format!("HTTPRoute {} not found", routing.http_route)),
+                Err(e) => {
+                    warn!(error = ?e, rollout = ?rollout.name_any(), httproute = ?routing.http_route, "Failed to get HTTPRoute, not holding step");
+                    Ok(())
+                }
+            }
+        }
+        _ => Ok(()),
+    };
+
+    apply_route_check(rollout, desired, check)
+}
+
+/// Apply the result of a route check to the desired status
+///
+/// - Not accepted: keep the current status (the step is held) with
+///   RouteNotAccepted=True and the reason in the message; a Warning decision
+///   is recorded when the hold starts
+/// - Accepted: the desired status, with RouteNotAccepted set back to False
+///   if it was True
+pub fn apply_route_check(
+    rollout: &Rollout,
+    mut desired: RolloutStatus,
+    check: Result<(), String>,
+) -> RolloutStatus {
+    let message = match check {
+        Ok(()) => {
+            if is_condition_true(&desired, ROUTE_NOT_ACCEPTED_CONDITION) {
+                info!(rollout = ?rollout.name_any(), "HTTPRoute accepted, resuming");
+                set_condition(
+                    &mut desired,
+                    ROUTE_NOT_ACCEPTED_CONDITION,
+                    false,
+                    ROUTE_ACCEPTED_REASON,
+                    None,
+                );
+            }
+            return desired;
+        }
+        Err(message) => message,
+    };
+
+    // Nothing to hold before the first status
+    let Some(current) = rollout.status.as_ref() else {
+        return desired;
+    };
+
+    let mut held = current.clone();
+    held.conditions = desired.conditions;
+    held.observed_generation = desired.observed_generation;
+    let entering = !is_condition_true(&held, ROUTE_NOT_ACCEPTED_CONDITION);
+
+    held.message = Some(format!("Step held, route not live: {}", message));
+    set_condition(
+        &mut held,
+        ROUTE_NOT_ACCEPTED_CONDITION,
+        true,
+        NOT_ACCEPTED_REASON,
+        Some(message),
+    );
+    if entering {
+        warn!(rollout = ?rollout.name_any(), message = ?held.message, "Holding step for HTTPRoute");
+        record_decision(
+            &mut held,
+            DecisionAction::Warning,
+            DecisionReason::RouteNotAccepted,
+            current.current_step_index,
+            desired.current_step_index,
+        );
+    }
+    held
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Tests can use unwrap for brevity
+mod tests {
+    use super::*;
+    use crate::crd::rollout::{
+        CanaryStep, CanaryStrategy, Phase, RolloutSpec, RolloutStrategy, TrafficRouting,
+    };
+    use k8s_openapi::api::core::v1::PodTemplateSpec;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+    use kube::api::ObjectMeta;
+    use serde_json::json;
+
+    enum MockRouteLookup {
+        Found(Box<DynamicObject>),
+        NotFound,
+        Failing,
+    }
+
+    #[async_trait]
+    impl RouteLookup for MockRouteLookup {
+        async fn get_route(
+            &self,
+            _namespace: &str,
+            _name: &str,
+        ) -> Result<Option<DynamicObject>, kube::Error> {
+            match self {
+                MockRouteLookup::Found(route) => Ok(Some(route.as_ref().clone())),
+                MockRouteLookup::NotFound => Ok(None),
+                MockRouteLookup::Failing => Err(kube::Error::LinesCodecMaxLineLengthExceeded),
+            }
+        }
+    }
+
+    /// HTTPRoute with one parentRef and the given Accepted condition status
+    fn route(accepted: &str) -> DynamicObject {
+        serde_json::from_value(json!({
+            "apiVersion": "gateway.networking.k8s.io/v1",
+            "kind": "HTTPRoute",
+            "metadata": {"name": "my-app-route", "namespace": "default"},
+            "spec": {"parentRefs": [{"name": "my-gateway"}]},
+            "status": {
+                "parents": [{
+                    "parentRef": {"name": "my-gateway"},
+                    "controllerName": "example.com/gateway-controller",
+                    "conditions": [{
+                        "type": "Accepted",
+                        "status": accepted,
+                        "reason": if accepted == "True" { "Accepted" } else { "NoMatchingParent" }
+                    }]
+                }]
+            }
+        }))
+        .unwrap()
+    }
+
+    fn status_at(step: i32, weight: i32) -> RolloutStatus {
+        RolloutStatus {
+            phase: Some(Phase::Progressing),
+            current_step_index: Some(step),
+            current_weight: Some(weight),
+            ..Default::default()
+        }
+    }
+
+    /// Steps 20% -> 50% through my-app-route with verifyAccepted, currently at step 0
+    fn verifying_rollout() -> Rollout {
+        Rollout {
+            metadata: ObjectMeta {
+                name: Some("my-app".to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            },
+            spec: RolloutSpec {
+                replicas: 10,
+                selector: LabelSelector::default(),
+                template: PodTemplateSpec::default(),
+                strategy: RolloutStrategy {
+                    simple: None,
+                    canary: Some(CanaryStrategy {
+                        canary_service: "my-app-canary".to_string(),
+                        stable_service: "my-app-stable".to_string(),
+                        mirror_service: None,
+                        steps: vec![
+                            CanaryStep {
+                                set_weight: Some(20),
+                                pause: None,
+                                analysis: None,
+                            },
+                            CanaryStep {
+                                set_weight: Some(50),
+                                pause: None,
+                                analysis: None,
+                            },
+                        ],
+                        traffic_routing: Some(TrafficRouting {
+                            gateway_api: Some(GatewayAPIRouting {
+                                http_route: "my-app-route".to_string(),
+                                gateway_api_version: None,
+                                verify_accepted: Some(true),
+                                canary_match: None,
+                            }),
+                            linkerd: None,
+                        }),
+                        analysis: None,
+                        max_duration_seconds: None,
+                        canary_overrides: None,
+                        canary_suffix: None,
+                        stable_suffix: None,
+                        analysis_only: None,
+                        port_name: None,
+                        pause_at_step: None,
+                        create_services: None,
+                        min_canary_replicas: None,
+                        min_stable_replicas: None,
+                        keep_stable_at_full_scale: None,
+                        extra_canaries: None,
+                        max_weight_step: None,
+                        min_initial_weight: None,
+                        on_complete: None,
+                        replica_surge: None,
+                        max_surge: None,
+                        sticky_cookie: None,
+                    }),
+                    blue_green: None,
+                },
+                manage_pod_template_hash: None,
+                quota_aware: None,
+                dry_run: None,
+                paused: None,
+            },
+            status: Some(status_at(0, 20)),
+        }
+    }
+
+    async fn hold(
+        lookup: &MockRouteLookup,
+        rollout: &Rollout,
+        desired: RolloutStatus,
+    ) -> RolloutStatus {
+        let routing = route_verification(rollout).unwrap().clone();
+        hold_step_for_route(lookup, rollout, &routing, desired).await
+    }
+
+    #[test]
+    fn test_route_verification_is_opt_in() {
+        let mut rollout = verifying_rollout();
+        assert!(route_verification(&rollout).is_some());
+
+        if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+            if let Some(routing) = canary.traffic_routing.as_mut() {
+                routing.gateway_api.as_mut().unwrap().verify_accepted = None;
+            }
+        }
+        assert!(route_verification(&rollout).is_none());
+    }
+
+    #[test]
+    fn test_check_route_accepted() {
+        assert!(check_route_accepted(&route("True")).is_ok());
+        assert!(check_route_accepted(&route("False")).is_err());
+
+        // Programmed counts as live too
+        let mut programmed = route("False");
+        programmed.data["status"]["parents"][0]["conditions"][0]["type"] = json!("Programmed");
+        programmed.data["status"]["parents"][0]["conditions"][0]["status"] = json!("True");
+        assert!(check_route_accepted(&programmed).is_ok());
+
+        // No status yet (no Gateway controller has seen the route)
+        let mut unreconciled = route("True");
+        unreconciled.data["status"] = json!(null);
+        assert!(check_route_accepted(&unreconciled).is_err());
+
+        // No parentRefs: nothing can accept it
+        let mut orphan = route("True");
+        orphan.data["spec"]["parentRefs"] = json!([]);
+        let err = check_route_accepted(&orphan).unwrap_err();
+        assert!(err.contains("no parentRefs"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_accepted_route_proceeds() {
+        let lookup = MockRouteLookup::Found(Box::new(route("True")));
+        let rollout = verifying_rollout();
+
+        let status = hold(&lookup, &rollout, status_at(1, 50)).await;
+
+        assert_eq!(status.current_step_index, Some(1));
+        assert_eq!(status.current_weight, Some(50));
+        assert!(!is_condition_true(&status, ROUTE_NOT_ACCEPTED_CONDITION));
+        assert!(status.decisions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unaccepted_route_holds_step() {
+        let lookup = MockRouteLookup::Found(Box::new(route("False")));
+        let rollout = verifying_rollout();
+
+        let status = hold(&lookup, &rollout, status_at(1, 50)).await;
+
+        assert_eq!(status.current_step_index, Some(0));
+        assert_eq!(status.current_weight, Some(20));
+        assert!(is_condition_true(&status, ROUTE_NOT_ACCEPTED_CONDITION));
+        assert_eq!(
+            status.conditions[0].reason.as_deref(),
+            Some(NOT_ACCEPTED_REASON)
+        );
+        assert_eq!(status.decisions.len(), 1);
+        assert_eq!(status.decisions[0].action, DecisionAction::Warning);
+        assert_eq!(status.decisions[0].reason, DecisionReason::RouteNotAccepted);
+        assert_eq!(status.decisions[0].to_step, Some(1));
+
+        // Still held on requeue: no duplicate decision
+        let mut rollout = rollout;
+        rollout.status = Some(status.clone());
+        let mut desired = status_at(1, 50);
+        desired.conditions = status.conditions.clone();
+        let status = hold(&lookup, &rollout, desired).await;
+        assert_eq!(status.current_step_index, Some(0));
+        assert_eq!(status.decisions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_held_step_resumes_once_route_accepted() {
+        let mut rollout = verifying_rollout();
+        let mut held = status_at(0, 20);
+        set_condition(
+            &mut held,
+            ROUTE_NOT_ACCEPTED_CONDITION,
+            true,
+            NOT_ACCEPTED_REASON,
+            None,
+        );
+        rollout.status = Some(held.clone());
+        let lookup = MockRouteLookup::Found(Box::new(route("True")));
+
+        let mut desired = status_at(1, 50);
+        desired.conditions = held.conditions;
+        let status = hold(&lookup, &rollout, desired).await;
+
+        assert_eq!(status.current_step_index, Some(1));
+        assert_eq!(status.current_weight, Some(50));
+        assert!(!is_condition_true(&status, ROUTE_NOT_ACCEPTED_CONDITION));
+        assert_eq!(
+            status.conditions[0].reason.as_deref(),
+            Some(ROUTE_ACCEPTED_REASON)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_route_holds_step() {
+        let rollout = verifying_rollout();
+
+        let status = hold(&MockRouteLookup::NotFound, &rollout, status_at(1, 50)).await;
+
+        assert_eq!(status.current_step_index, Some(0));
+        assert!(is_condition_true(&status, ROUTE_NOT_ACCEPTED_CONDITION));
+        assert!(status.message.unwrap().contains("not found"));
+    }
+
+    #[tokio::test]
+    async fn test_lookup_error_does_not_hold_step() {
+        let rollout = verifying_rollout();
+
+        let status = hold(&MockRouteLookup::Failing, &rollout, status_at(1, 50)).await;
+
+        assert_eq!(status.current_step_index, Some(1));
+        assert!(!is_condition_true(&status, ROUTE_NOT_ACCEPTED_CONDITION));
+    }
+
+    #[tokio::test]
+    async fn test_same_step_is_not_checked() {
+        // Mid-step (pause or analysis): the route isn't re-checked
+        let rollout = verifying_rollout();
+
+        let status = hold(&MockRouteLookup::NotFound, &rollout, status_at(0, 20)).await;
+
+        assert_eq!(status.current_step_index, Some(0));
+        assert!(!is_condition_true(&status, ROUTE_NOT_ACCEPTED_CONDITION));
+        assert!(status.decisions.is_empty());
+    }
+}
@@ -0,0 +1,357 @@
+//! Create the stable/canary Services for a canary rollout
+//!
+//! With `canary.createServices: true`, the controller creates the Services
+//! named by `stableService`/`canaryService` when they don't exist. Each one
+//! selects the rollout's pods of one ReplicaSet type via the
+//! `rollouts.kulta.io/type` label. Existing Services are left untouched, so
+//! users can still manage them themselves.
+
+use crate::controller::port_resolver::{KubeServiceLookup, ServiceLookup, DEFAULT_BACKEND_PORT};
+use crate::controller::rollout::ReconcileError;
+use crate::crd::rollout::Rollout;
+use async_trait::async_trait;
+use k8s_openapi::api::core::v1::{Service, ServicePort, ServiceSpec};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::api::{Api, ObjectMeta, PostParams};
+use kube::ResourceExt;
+use std::collections::BTreeMap;
+use tracing::info;
+
+/// Service port name used when canary.portName is not set
+const DEFAULT_SERVICE_PORT_NAME: &str = "http";
+
+/// Service source that can also create Services (mockable in tests)
+#[async_trait]
+pub trait ServiceStore: ServiceLookup {
+    /// Create a Service in the given namespace
+    async fn create_service(&self, namespace: &str, service: &Service) -> Result<(), kube::Error>;
+}
+
+#[async_trait]
+impl ServiceStore for KubeServiceLookup {
+    async fn create_service(&self, namespace: &str, service: &Service) -> Result<(), kube::Error> {
+        let api: Api<Service> = Api::namespaced(self.client().clone(), namespace);
+        api.create(&PostParams::default(), service).await?;
+        Ok(())
+    }
+}
+
+/// Whether the rollout asks the controller to create its Services
+pub fn creates_services(rollout: &Rollout) -> bool {
+    rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .and_then(|canary| canary.create_services)
+        .unwrap_or(false)
+}
+
+/// Build the Service selecting one ReplicaSet type ("stable" or "canary")
+///
+/// - Selector: spec.selector.matchLabels + `rollouts.kulta.io/type: <rs_type>`
+/// - Port: DEFAULT_BACKEND_PORT, named canary.portName (default "http"),
+///   targeting the first container port of the pod template (or the same port)
+pub fn build_canary_service(
+    rollout: &Rollout,
+    rs_type: &str,
+    service_name: &str,
+) -> Result<Service, ReconcileError> {
+    let namespace = rollout
+        .metadata
+        .namespace
+        .clone()
+        .ok_or(ReconcileError::MissingNamespace)?;
+
+    let mut selector = rollout
+        .spec
+        .selector
+        .match_labels
+        .clone()
+        .unwrap_or_default();
+    selector.insert("rollouts.kulta.io/type".to_string(), rs_type.to_string());
+
+    let port_name = rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .and_then(|canary| canary.port_name.clone())
+        .unwrap_or_else(|| DEFAULT_SERVICE_PORT_NAME.to_string());
+
+    let target_port = rollout
+        .spec
+        .template
+        .spec
+        .as_ref()
+        .and_then(|pod_spec| pod_spec.containers.first())
+        .and_then(|container| container.ports.as_ref())
+        .and_then(|ports| ports.first())
+        .map(|port| port.container_port)
+        .unwrap_or(DEFAULT_BACKEND_PORT);
+
+    Ok(Service {
+        metadata: ObjectMeta {
+            name: Some(service_name.to_string()),
+            namespace: Some(namespace),
+            labels: Some(BTreeMap::from([
+                ("rollouts.kulta.io/managed".to_string(), "true".to_string()),
+                ("rollouts.kulta.io/type".to_string(), rs_type.to_string()),
+            ])),
+            ..Default::default()
+        },
+        spec: Some(ServiceSpec {
+            selector: Some(selector),
+            ports: Some(vec![ServicePort {
+                name: Some(port_name),
+                port: DEFAULT_BACKEND_PORT,
+                target_port: Some(IntOrString::Int(target_port)),
+                protocol: Some("TCP".to_string()),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        status: None,
+    })
+}
+
+/// Create the stable and canary Services that don't exist yet
+///
+/// Idempotent: Services that already exist are skipped (never updated).
+///
+/// # Returns
+/// Names of the Services that were created
+pub async fn ensure_canary_services(
+    store: &dyn ServiceStore,
+    rollout: &Rollout,
+) -> Result<Vec<String>, ReconcileError> {
+    let Some(canary) = rollout.spec.strategy.canary.as_ref() else {
+        return Ok(vec![]);
+    };
+    let namespace = rollout
+        .namespace()
+        .ok_or(ReconcileError::MissingNamespace)?;
+
+    let mut created = Vec::new();
+    for (rs_type, service_name) in [
+        ("stable", &canary.stable_service),
+        ("canary", &canary.canary_service),
+    ] {
+        if store.get_service(&namespace, service_name).await?.is_some() {
+            continue;
+        }
+
+        let service = build_canary_service(rollout, rs_type, service_name)?;
+        store.create_service(&namespace, &service).await?;
+        info!(
+            rollout = ?rollout.name_any(),
+            service = service_name,
+            rs_type = rs_type,
+            "Created Service"
+        );
+        created.push(service_name.clone());
+    }
+
+    Ok(created)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Tests can use unwrap for brevity
+mod tests {
+    use super::*;
+    use crate::crd::rollout::{CanaryStrategy, RolloutSpec, RolloutStrategy};
+    use k8s_openapi::api::core::v1::{Container, ContainerPort, PodSpec, PodTemplateSpec};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+    use std::sync::Mutex;
+
+    struct MockServiceStore {
+        existing: Vec<String>,
+        created: Mutex<Vec<Service>>,
+    }
+
+    impl MockServiceStore {
+        fn new(existing: &[&str]) -> Self {
+            Self {
+                existing: existing.iter().map(|name| name.to_string()).collect(),
+                created: Mutex::new(vec![]),
+            }
+        }
+
+        fn created_names(&self) -> Vec<String> {
+            self.created
+                .lock()
+                .unwrap()
+                .iter()
+                .filter_map(|svc| svc.metadata.name.clone())
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl ServiceLookup for MockServiceStore {
+        async fn get_service(
+            &self,
+            _namespace: &str,
+            name: &str,
+        ) -> Result<Option<Service>, kube::Error> {
+            let exists = self.existing.iter().any(|existing| existing == name)
+                || self.created_names().iter().any(|created| created == name);
+            Ok(exists.then(|| Service {
+                metadata: ObjectMeta {
+                    name: Some(name.to_string()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }))
+        }
+    }
+
+    #[async_trait]
+    impl ServiceStore for MockServiceStore {
+        async fn create_service(
+            &self,
+            _namespace: &str,
+            service: &Service,
+        ) -> Result<(), kube::Error> {
+            self.created.lock().unwrap().push(service.clone());
+            Ok(())
+        }
+    }
+
+    fn rollout(container_port: Option<i32>, port_name: Option<&str>) -> Rollout {
+        Rollout {
+            metadata: ObjectMeta {
+                name: Some("my-app".to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            },
+            spec: RolloutSpec {
+                replicas: 3,
+                selector: LabelSelector {
+                    match_labels: Some(BTreeMap::from([("app".to_string(), "my-app".to_string())])),
+                    ..Default::default()
+                },
+                template: PodTemplateSpec {
+                    metadata: None,
+                    spec: Some(PodSpec {
+                        containers: vec![Container {
+                            name: "app".to_string(),
+                            ports: container_port.map(|port| {
+                                vec![ContainerPort {
+                                    container_port: port,
+                                    ..Default::default()
+                                }]
+                            }),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }),
+                },
+                strategy: RolloutStrategy {
+                    simple: None,
+                    canary: Some(CanaryStrategy {
+                        canary_service: "my-app-canary".to_string(),
+                        stable_service: "my-app-stable".to_string(),
+                        mirror_service: None,
+                        steps: vec![],
+                        traffic_routing: None,
+                        analysis: None,
+                        max_duration_seconds: None,
+                        canary_overrides: None,
+                        canary_suffix: None,
+                        stable_suffix: None,
+                        analysis_only: None,
+                        port_name: port_name.map(String::from),
+                        pause_at_step: None,
+                        create_services: Some(true),
+                        min_canary_replicas: None,
+                        min_stable_replicas: None,
+                        keep_stable_at_full_scale: None,
+                        extra_canaries: None,
+                        max_weight_step: None,
+                        min_initial_weight: None,
+                        on_complete: None,
+                        replica_surge: None,
+                        max_surge: None,
+                        sticky_cookie: None,
+                    }),
+                    blue_green: None,
+                },
+                manage_pod_template_hash: None,
+                quota_aware: None,
+                dry_run: None,
+                paused: None,
+            },
+            status: None,
+        }
+    }
+
+    #[test]
+    fn test_build_service_selects_rollout_pods_of_type() {
+        let svc = build_canary_service(&rollout(None, None), "canary", "my-app-canary").unwrap();
+
+        let selector = svc.spec.as_ref().unwrap().selector.as_ref().unwrap();
+        assert_eq!(selector.get("app"), Some(&"my-app".to_string()));
+        assert_eq!(
+            selector.get("rollouts.kulta.io/type"),
+            Some(&"canary".to_string())
+        );
+        assert_eq!(svc.metadata.name.as_deref(), Some("my-app-canary"));
+        assert_eq!(svc.metadata.namespace.as_deref(), Some("default"));
+    }
+
+    #[test]
+    fn test_build_service_port_defaults() {
+        let svc = build_canary_service(&rollout(None, None), "stable", "my-app-stable").unwrap();
+
+        let port = &svc.spec.as_ref().unwrap().ports.as_ref().unwrap()[0];
+        assert_eq!(port.port, 80);
+        assert_eq!(port.name.as_deref(), Some("http"));
+        assert_eq!(port.target_port, Some(IntOrString::Int(80)));
+    }
+
+    #[test]
+    fn test_build_service_port_targets_container_port() {
+        let svc =
+            build_canary_service(&rollout(Some(8080), Some("web")), "stable", "my-app-stable")
+                .unwrap();
+
+        let port = &svc.spec.as_ref().unwrap().ports.as_ref().unwrap()[0];
+        assert_eq!(port.port, 80);
+        assert_eq!(port.name.as_deref(), Some("web"));
+        assert_eq!(port.target_port, Some(IntOrString::Int(8080)));
+    }
+
+    #[tokio::test]
+    async fn test_ensure_services_creates_missing() {
+        let store = MockServiceStore::new(&[]);
+
+        let created = ensure_canary_services(&store, &rollout(None, None))
+            .await
+            .unwrap();
+
+        assert_eq!(created, vec!["my-app-stable", "my-app-canary"]);
+        assert_eq!(
+            store.created_names(),
+            vec!["my-app-stable", "my-app-canary"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ensure_services_skips_existing() {
+        let store = MockServiceStore::new(&["my-app-stable"]);
+
+        let created = ensure_canary_services(&store, &rollout(None, None))
+            .await
+            .unwrap();
+
+        assert_eq!(created, vec!["my-app-canary"]);
+
+        // Second run is a no-op
+        let created = ensure_canary_services(&store, &rollout(None, None))
+            .await
+            .unwrap();
+        assert!(created.is_empty());
+        assert_eq!(store.created_names(), vec!["my-app-canary"]);
+    }
+}
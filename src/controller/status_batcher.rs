@@ -0,0 +1,502 @@
+//! Batched Rollout status updates
+//!
+//! When many rollouts advance in the same reconcile cycle, patching each
+//! status inline serializes one API call per rollout behind its reconcile.
+//! The batcher buffers status updates instead and a background task flushes
+//! them every interval (KULTA_STATUS_BATCH_INTERVAL_MS, default 500ms):
+//! - Updates for the same rollout are coalesced, only the latest is patched
+//! - Patches run in parallel, at most MAX_CONCURRENT_STATUS_PATCHES at a time
+//! - Failed patches are retried up to MAX_STATUS_PATCH_RETRIES times
+//!
+//! A 409 Conflict is not retried: the update was computed from a stale
+//! Rollout, and the watch event for the newer version reconciles it again.
+
+use crate::controller::reconcile_cache::ReconcileCache;
+use crate::controller::rollout::{
+    build_status_patch, build_status_patch_params, is_conflict_error, observe_phase_transition,
+};
+use crate::crd::rollout::{Rollout, RolloutStatus};
+use async_trait::async_trait;
+use futures::StreamExt;
+use kube::api::{Api, Patch};
+use kube::ResourceExt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// Default interval between flushes
+pub const DEFAULT_STATUS_BATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Maximum number of status patches in flight during a flush
+pub const MAX_CONCURRENT_STATUS_PATCHES: usize = 10;
+
+/// Retries after a failed status patch (so at most 1 + 3 attempts)
+pub const MAX_STATUS_PATCH_RETRIES: u32 = 3;
+
+/// A status update waiting to be patched
+#[derive(Debug, Clone)]
+pub struct StatusUpdate {
+    /// Rollout the status was computed from (name, namespace, resourceVersion)
+    pub rollout: Rollout,
+    /// Status before this update (for phase transition metrics)
+    pub previous: Option<RolloutStatus>,
+    /// Status to write
+    pub status: RolloutStatus,
+}
+
+impl StatusUpdate {
+    /// Create an update for a rollout's new status
+    pub fn new(rollout: &Rollout, status: RolloutStatus) -> Self {
+        Self {
+            rollout: rollout.clone(),
+            previous: rollout.status.clone(),
+            status,
+        }
+    }
+
+    fn key(&self) -> (String, String) {
+        (
+            self.rollout.namespace().unwrap_or_default(),
+            self.rollout.name_any(),
+        )
+    }
+}
+
+/// Writes Rollout status patches (mockable in tests)
+#[async_trait]
+pub trait StatusPatcher: Send + Sync {
+    /// Patch the status subresource of a rollout
+    async fn patch_status(&self, update: &StatusUpdate) -> Result<(), kube::Error>;
+}
+
+/// StatusPatcher backed by the Kubernetes API (server-side apply)
+pub struct KubeStatusPatcher {
+    client: kube::Client,
+    field_manager: String,
+}
+
+impl KubeStatusPatcher {
+    pub fn new(client: kube::Client, field_manager: impl Into<String>) -> Self {
+        Self {
+            client,
+            field_manager: field_manager.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl StatusPatcher for KubeStatusPatcher {
+    async fn patch_status(&self, update: &StatusUpdate) -> Result<(), kube::Error> {
+        let namespace = update.rollout.namespace().unwrap_or_default();
+        let api: Api<Rollout> = Api::namespaced(self.client.clone(), &namespace);
+        api.patch_status(
+            &update.rollout.name_any(),
+            &build_status_patch_params(&self.field_manager),
+            &Patch::Apply(&build_status_patch(&update.rollout, &update.status)),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Outcome of one flush
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FlushStats {
+    /// Rollouts whose status was written
+    pub patched: usize,
+    /// Rollouts whose update was dropped (conflict or retries exhausted)
+    pub failed: usize,
+}
+
+/// Buffer of pending status updates, flushed periodically
+pub struct StatusBatcher {
+    interval: Duration,
+    buffer: Arc<Mutex<Vec<StatusUpdate>>>,
+}
+
+impl StatusBatcher {
+    /// Create a batcher that flushes every `interval`
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            buffer: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Create a batcher from KULTA_STATUS_BATCH_INTERVAL_MS (default: 500ms)
+    ///
+    /// Invalid values fall back to the default with a warning.
+    pub fn from_env() -> Self {
+        let interval = match std::env::var("KULTA_STATUS_BATCH_INTERVAL_MS") {
+            Ok(value) => parse_batch_interval(&value).unwrap_or_else(|| {
+                warn!(
+                    value = %value,
+                    default_ms = DEFAULT_STATUS_BATCH_INTERVAL.as_millis() as u64,
+                    "Invalid KULTA_STATUS_BATCH_INTERVAL_MS, using default"
+                );
+                DEFAULT_STATUS_BATCH_INTERVAL
+            }),
+            Err(_) => DEFAULT_STATUS_BATCH_INTERVAL,
+        };
+        Self::new(interval)
+    }
+
+    /// Interval between flushes
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<StatusUpdate>> {
+        match self.buffer.lock() {
+            Ok(guard) => guard,
+            // A poisoned lock only means another reconcile panicked mid-push;
+            // the buffer is still usable
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// Queue a status update for the next flush
+    pub fn push(&self, update: StatusUpdate) {
+        self.lock().push(update);
+    }
+
+    /// Number of updates waiting to be flushed
+    pub fn pending(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Take the buffered updates, keeping only the latest one per rollout
+    ///
+    /// The coalesced update keeps the oldest `previous` status, so phase
+    /// transition metrics span every update it replaced.
+    fn drain_coalesced(&self) -> Vec<StatusUpdate> {
+        let updates = std::mem::take(&mut *self.lock());
+
+        let mut coalesced: Vec<StatusUpdate> = Vec::with_capacity(updates.len());
+        for update in updates {
+            match coalesced
+                .iter_mut()
+                .find(|pending| pending.key() == update.key())
+            {
+                Some(pending) => {
+                    let previous = pending.previous.take();
+                    *pending = StatusUpdate { previous, ..update };
+                }
+                None => coalesced.push(update),
+            }
+        }
+        coalesced
+    }
+
+    /// Patch all buffered updates
+    ///
+    /// Successful patches record phase transition metrics. Dropped updates
+    /// invalidate the rollout's reconcile cache entry, so the next reconcile
+    /// recomputes the status instead of assuming it was written.
+    pub async fn flush(
+        &self,
+        patcher: &dyn StatusPatcher,
+        metrics: Option<&crate::server::ControllerMetrics>,
+        reconcile_cache: Option<&ReconcileCache>,
+    ) -> FlushStats {
+        let updates = self.drain_coalesced();
+        if updates.is_empty() {
+            return FlushStats::default();
+        }
+
+        let results = futures::stream::iter(updates)
+            .map(|update| async move {
+                let result = patch_with_retry(patcher, &update).await;
+                (update, result)
+            })
+            .buffer_unordered(MAX_CONCURRENT_STATUS_PATCHES)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut stats = FlushStats::default();
+        for (update, result) in results {
+            match result {
+                Ok(()) => {
+                    stats.patched += 1;
+                    if let Some(metrics) = metrics {
+                        observe_phase_transition(metrics, update.previous.as_ref(), &update.status);
+                    }
+                }
+                Err(e) => {
+                    stats.failed += 1;
+                    if is_conflict_error(&e) {
+                        debug!(rollout = ?update.rollout.name_any(), "Batched status conflict (stale resourceVersion), dropping");
+                    } else {
+                        warn!(error = ?e, rollout = ?update.rollout.name_any(), "Failed to update status after retries");
+                    }
+                    if let (Some(cache), Some(uid)) =
+                        (reconcile_cache, update.rollout.metadata.uid.as_deref())
+                    {
+                        cache.invalidate(uid);
+                    }
+                }
+            }
+        }
+        stats
+    }
+
+    /// Flush the buffer every interval until the task is aborted
+    pub fn spawn(
+        self: Arc<Self>,
+        patcher: Arc<dyn StatusPatcher>,
+        metrics: Option<crate::server::SharedMetrics>,
+        reconcile_cache: Arc<ReconcileCache>,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                self.flush(
+                    patcher.as_ref(),
+                    metrics.as_deref(),
+                    Some(reconcile_cache.as_ref()),
+                )
+                .await;
+            }
+        })
+    }
+}
+
+impl Default for StatusBatcher {
+    fn default() -> Self {
+        Self::new(DEFAULT_STATUS_BATCH_INTERVAL)
+    }
+}
+
+/// Patch one update, retrying failures other than 409 Conflict
+async fn patch_with_retry(
+    patcher: &dyn StatusPatcher,
+    update: &StatusUpdate,
+) -> Result<(), kube::Error> {
+    let mut retries = 0;
+    loop {
+        match patcher.patch_status(update).await {
+            Ok(()) => return Ok(()),
+            Err(e) if is_conflict_error(&e) || retries >= MAX_STATUS_PATCH_RETRIES => {
+                return Err(e)
+            }
+            Err(e) => {
+                retries += 1;
+                debug!(error = ?e, rollout = ?update.rollout.name_any(), retry = retries, "Retrying status patch");
+            }
+        }
+    }
+}
+
+/// Parse a batch interval in milliseconds (must be at least 1)
+pub fn parse_batch_interval(value: &str) -> Option<Duration> {
+    match value.trim().parse::<u64>() {
+        Ok(0) | Err(_) => None,
+        Ok(ms) => Some(Duration::from_millis(ms)),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Tests can use unwrap for brevity
+mod tests {
+    use super::*;
+    use crate::crd::rollout::{Phase, RolloutSpec, RolloutStrategy};
+    use kube::api::ObjectMeta;
+    use std::collections::HashMap;
+
+    /// Counts patch calls per rollout; fails the first `failures` calls of each
+    #[derive(Default)]
+    struct MockStatusPatcher {
+        failures: u32,
+        conflict: bool,
+        calls: Mutex<HashMap<String, u32>>,
+        written: Mutex<HashMap<String, RolloutStatus>>,
+    }
+
+    impl MockStatusPatcher {
+        fn total_calls(&self) -> u32 {
+            self.calls.lock().unwrap().values().sum()
+        }
+    }
+
+    fn api_error(code: u16) -> kube::Error {
+        kube::Error::Api(kube::core::ErrorResponse {
+            status: "Failure".to_string(),
+            message: "injected".to_string(),
+            reason: "Injected".to_string(),
+            code,
+        })
+    }
+
+    #[async_trait]
+    impl StatusPatcher for MockStatusPatcher {
+        async fn patch_status(&self, update: &StatusUpdate) -> Result<(), kube::Error> {
+            let name = update.rollout.name_any();
+            let attempt = {
+                let mut calls = self.calls.lock().unwrap();
+                let count = calls.entry(name.clone()).or_default();
+                *count += 1;
+                *count
+            };
+            if self.conflict {
+                return Err(api_error(409));
+            }
+            if attempt <= self.failures {
+                return Err(api_error(500));
+            }
+            self.written
+                .lock()
+                .unwrap()
+                .insert(name, update.status.clone());
+            Ok(())
+        }
+    }
+
+    fn rollout(name: &str) -> Rollout {
+        Rollout {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some("default".to_string()),
+                uid: Some(format!("uid-{}", name)),
+                ..Default::default()
+            },
+            spec: RolloutSpec {
+                replicas: 3,
+                selector: Default::default(),
+                template: Default::default(),
+                strategy: RolloutStrategy {
+                    simple: None,
+                    canary: None,
+                    blue_green: None,
+                },
+                manage_pod_template_hash: None,
+                quota_aware: None,
+                dry_run: None,
+                paused: None,
+            },
+            status: None,
+        }
+    }
+
+    fn status(step: i32) -> RolloutStatus {
+        RolloutStatus {
+            phase: Some(Phase::Progressing),
+            current_step_index: Some(step),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flush_coalesces_updates_into_fewer_calls() {
+        let batcher = StatusBatcher::default();
+        let patcher = MockStatusPatcher::default();
+
+        // 20 updates across 5 rollouts (4 step advances each)
+        for step in 0..4 {
+            for i in 0..5 {
+                batcher.push(StatusUpdate::new(
+                    &rollout(&format!("app-{}", i)),
+                    status(step),
+                ));
+            }
+        }
+        assert_eq!(batcher.pending(), 20);
+
+        let stats = batcher.flush(&patcher, None, None).await;
+
+        assert_eq!(
+            stats,
+            FlushStats {
+                patched: 5,
+                failed: 0
+            }
+        );
+        assert!(patcher.total_calls() < 20);
+        assert_eq!(patcher.total_calls(), 5);
+        assert_eq!(batcher.pending(), 0);
+        // Only the latest status per rollout is written
+        assert_eq!(
+            patcher.written.lock().unwrap()["app-3"].current_step_index,
+            Some(3)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_flush_retries_failed_patches() {
+        let batcher = StatusBatcher::default();
+        let patcher = MockStatusPatcher {
+            failures: 2,
+            ..Default::default()
+        };
+        batcher.push(StatusUpdate::new(&rollout("app"), status(1)));
+
+        let stats = batcher.flush(&patcher, None, None).await;
+
+        assert_eq!(stats.patched, 1);
+        assert_eq!(patcher.total_calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_flush_gives_up_after_max_retries() {
+        let batcher = StatusBatcher::default();
+        let cache = ReconcileCache::default();
+        let patcher = MockStatusPatcher {
+            failures: u32::MAX,
+            ..Default::default()
+        };
+        let rollout = rollout("app");
+        cache.record("uid-app", None, &status(1));
+        batcher.push(StatusUpdate::new(&rollout, status(1)));
+
+        let stats = batcher.flush(&patcher, None, Some(&cache)).await;
+
+        assert_eq!(stats.failed, 1);
+        assert_eq!(patcher.total_calls(), 1 + MAX_STATUS_PATCH_RETRIES);
+        // Dropped update forces a full reconcile next time
+        assert!(!cache.is_fresh("uid-app", None, &status(1)));
+    }
+
+    #[tokio::test]
+    async fn test_flush_does_not_retry_conflicts() {
+        let batcher = StatusBatcher::default();
+        let patcher = MockStatusPatcher {
+            conflict: true,
+            ..Default::default()
+        };
+        batcher.push(StatusUpdate::new(&rollout("app"), status(1)));
+
+        let stats = batcher.flush(&patcher, None, None).await;
+
+        assert_eq!(stats.failed, 1);
+        assert_eq!(patcher.total_calls(), 1);
+    }
+
+    #[test]
+    fn test_coalesced_update_keeps_oldest_previous_status() {
+        let batcher = StatusBatcher::default();
+        let mut first = rollout("app");
+        first.status = Some(status(0));
+        let mut second = rollout("app");
+        second.status = Some(status(1));
+
+        batcher.push(StatusUpdate::new(&first, status(1)));
+        batcher.push(StatusUpdate::new(&second, status(2)));
+
+        let updates = batcher.drain_coalesced();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].previous, Some(status(0)));
+        assert_eq!(updates[0].status, status(2));
+    }
+
+    #[test]
+    fn test_parse_batch_interval() {
+        assert_eq!(
+            parse_batch_interval("250"),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(parse_batch_interval("0"), None);
+        assert_eq!(parse_batch_interval("-5"), None);
+        assert_eq!(parse_batch_interval("fast"), None);
+    }
+}
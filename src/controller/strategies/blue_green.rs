@@ -3,12 +3,15 @@
 //! Maintains two full environments (active and preview).
 //! Traffic is 100% to active until promotion, then instant switch to preview.
 
-use super::{reconcile_gateway_api_traffic, RolloutStrategy, StrategyError};
+use super::{patch_httproute_weights, HttpRouteRuleOptions, RolloutStrategy, StrategyError};
 use crate::controller::rollout::{
-    build_replicasets_for_blue_green, ensure_replicaset_exists, has_promote_annotation, Context,
+    build_blue_green_backend_refs, build_replicasets_for_blue_green, check_rollout_timeout,
+    ensure_replicaset_exists, has_promote_annotation, is_declarative_promotion, record_decision,
+    Context,
 };
-use crate::crd::rollout::{Phase, Rollout, RolloutStatus};
+use crate::crd::rollout::{DecisionAction, DecisionReason, Phase, Rollout, RolloutStatus};
 use async_trait::async_trait;
+use chrono::Utc;
 use k8s_openapi::api::apps::v1::ReplicaSet;
 use kube::api::Api;
 use kube::ResourceExt;
@@ -79,13 +82,53 @@ impl RolloutStrategy for BlueGreenStrategyHandler {
         rollout: &Rollout,
         ctx: &Context,
     ) -> Result<(), StrategyError> {
-        // Use shared helper for Gateway API traffic routing
-        reconcile_gateway_api_traffic(rollout, ctx, "blue-green").await
+        let namespace = rollout
+            .namespace()
+            .ok_or_else(|| StrategyError::MissingField("namespace".to_string()))?;
+        let name = rollout.name_any();
+
+        // Only the blue-green routing config applies (never the canary one)
+        let gateway_api_routing = match rollout
+            .spec
+            .strategy
+            .blue_green
+            .as_ref()
+            .and_then(|bg| bg.traffic_routing.as_ref())
+            .and_then(|tr| tr.gateway_api.as_ref())
+        {
+            Some(routing) => routing,
+            None => return Ok(()), // Traffic routing is optional
+        };
+
+        // Instant cutover: (100, 0) before promotion, (0, 100) after - never in between
+        let backend_refs = build_blue_green_backend_refs(rollout);
+
+        patch_httproute_weights(
+            ctx,
+            &namespace,
+            &name,
+            gateway_api_routing,
+            &backend_refs,
+            &HttpRouteRuleOptions::default(),
+            self.name(),
+        )
+        .await
     }
 
     fn compute_next_status(&self, rollout: &Rollout) -> RolloutStatus {
+        // Fail the rollout if it exceeded maxDurationSeconds
+        if let Some(timed_out_status) = check_rollout_timeout(rollout, Utc::now()) {
+            return timed_out_status;
+        }
+
         // Check current status
         let current_phase = rollout.status.as_ref().and_then(|s| s.phase.clone());
+        let started_at = rollout.status.as_ref().and_then(|s| s.started_at.clone());
+        let decisions = rollout
+            .status
+            .as_ref()
+            .map(|s| s.decisions.clone())
+            .unwrap_or_default();
 
         match current_phase {
             // Already completed - stay completed
@@ -95,25 +138,40 @@ impl RolloutStrategy for BlueGreenStrategyHandler {
                     "Blue-green rollout completed: preview promoted to active".to_string(),
                 ),
                 replicas: rollout.spec.replicas,
+                started_at,
+                decisions,
                 ..Default::default()
             },
 
             // In preview phase - check for promotion
             Some(Phase::Preview) => {
-                if has_promote_annotation(rollout) {
+                // Promote via annotation, or declaratively via activeService
+                let via_annotation = has_promote_annotation(rollout);
+                if via_annotation || is_declarative_promotion(rollout) {
                     // Promote: transition to Completed
                     info!(
                         rollout = ?rollout.name_any(),
-                        "Blue-green promotion triggered via annotation"
+                        trigger = if via_annotation { "annotation" } else { "activeService" },
+                        "Blue-green promotion triggered"
                     );
-                    RolloutStatus {
+                    let mut status = RolloutStatus {
                         phase: Some(Phase::Completed),
                         message: Some(
                             "Blue-green rollout completed: preview promoted to active".to_string(),
                         ),
                         replicas: rollout.spec.replicas,
+                        started_at,
+                        decisions,
                         ..Default::default()
-                    }
+                    };
+                    record_decision(
+                        &mut status,
+                        DecisionAction::Promotion,
+                        DecisionReason::ManualPromotion,
+                        None,
+                        None,
+                    );
+                    status
                 } else {
                     // Stay in preview, waiting for promotion
                     RolloutStatus {
@@ -123,18 +181,32 @@ impl RolloutStrategy for BlueGreenStrategyHandler {
                                 .to_string(),
                         ),
                         replicas: rollout.spec.replicas,
+                        started_at,
+                        decisions,
                         ..Default::default()
                     }
                 }
             }
 
             // No status or other phase - initialize to Preview
-            _ => RolloutStatus {
-                phase: Some(Phase::Preview),
-                message: Some("Blue-green rollout: preview environment ready".to_string()),
-                replicas: rollout.spec.replicas,
-                ..Default::default()
-            },
+            _ => {
+                let mut status = RolloutStatus {
+                    phase: Some(Phase::Preview),
+                    message: Some("Blue-green rollout: preview environment ready".to_string()),
+                    replicas: rollout.spec.replicas,
+                    started_at: started_at.or_else(|| Some(Utc::now().to_rfc3339())),
+                    decisions,
+                    ..Default::default()
+                };
+                record_decision(
+                    &mut status,
+                    DecisionAction::Initialize,
+                    DecisionReason::Initialization,
+                    None,
+                    None,
+                );
+                status
+            }
         }
     }
 
@@ -151,6 +223,7 @@ impl RolloutStrategy for BlueGreenStrategyHandler {
 
 #[cfg(test)]
 mod tests {
+    use super::super::build_httproute_weights_patch;
     use super::*;
     use crate::crd::rollout::{
         BlueGreenStrategy, GatewayAPIRouting, Phase, RolloutSpec,
@@ -181,11 +254,20 @@ mod tests {
                         traffic_routing: Some(TrafficRouting {
                             gateway_api: Some(GatewayAPIRouting {
                                 http_route: "app-route".to_string(),
+                                gateway_api_version: None,
+                                verify_accepted: None,
+                                canary_match: None,
                             }),
+                            linkerd: None,
                         }),
                         analysis: None,
+                        max_duration_seconds: None,
                     }),
                 },
+                manage_pod_template_hash: None,
+                quota_aware: None,
+                dry_run: None,
+                paused: None,
             },
             status: None,
         }
@@ -278,6 +360,76 @@ mod tests {
         }
     }
 
+    fn promoted_status(rollout: &Rollout) -> RolloutStatus {
+        let mut status = BlueGreenStrategyHandler.compute_next_status(rollout);
+        // Decision timestamps differ between runs
+        for decision in status.decisions.iter_mut() {
+            decision.timestamp.clear();
+        }
+        status
+    }
+
+    #[test]
+    fn test_blue_green_strategy_promotes_when_active_service_set_to_preview() {
+        let mut rollout = create_blue_green_rollout(5);
+        rollout.status = Some(RolloutStatus {
+            phase: Some(Phase::Preview),
+            replicas: 5,
+            ..Default::default()
+        });
+        if let Some(blue_green) = rollout.spec.strategy.blue_green.as_mut() {
+            blue_green.active_service = "app-preview".to_string();
+        }
+
+        let status = BlueGreenStrategyHandler.compute_next_status(&rollout);
+
+        assert_eq!(status.phase, Some(Phase::Completed));
+    }
+
+    #[test]
+    fn test_blue_green_promotion_paths_produce_identical_status() {
+        use std::collections::BTreeMap;
+
+        let mut preview = create_blue_green_rollout(5);
+        preview.status = Some(RolloutStatus {
+            phase: Some(Phase::Preview),
+            replicas: 5,
+            started_at: Some("2024-01-01T00:00:00Z".to_string()),
+            ..Default::default()
+        });
+
+        let mut by_annotation = preview.clone();
+        by_annotation.metadata.annotations = Some(BTreeMap::from([(
+            "kulta.io/promote".to_string(),
+            "true".to_string(),
+        )]));
+
+        let mut by_spec = preview.clone();
+        if let Some(blue_green) = by_spec.spec.strategy.blue_green.as_mut() {
+            blue_green.active_service = "app-preview".to_string();
+        }
+
+        assert_eq!(promoted_status(&by_annotation), promoted_status(&by_spec));
+    }
+
+    #[test]
+    fn test_blue_green_httproute_patch_after_declarative_promotion() {
+        let mut rollout = create_blue_green_rollout(3);
+        if let Some(blue_green) = rollout.spec.strategy.blue_green.as_mut() {
+            blue_green.active_service = "app-preview".to_string();
+        }
+        rollout.status = Some(RolloutStatus {
+            phase: Some(Phase::Completed),
+            ..Default::default()
+        });
+
+        // All traffic to the preview Service, no duplicate backend
+        assert_eq!(
+            httproute_patch_weights(&rollout),
+            vec![("app-preview".to_string(), 100)]
+        );
+    }
+
     #[test]
     fn test_blue_green_strategy_stays_completed() {
         let mut rollout = create_blue_green_rollout(5);
@@ -296,6 +448,102 @@ mod tests {
         assert_eq!(status.phase, Some(Phase::Completed));
     }
 
+    #[test]
+    fn test_blue_green_strategy_times_out_in_preview() {
+        let mut rollout = create_blue_green_rollout(5);
+        if let Some(blue_green) = rollout.spec.strategy.blue_green.as_mut() {
+            blue_green.max_duration_seconds = Some(1);
+        }
+        // Preview started well before the 1-second limit
+        let started_at = Utc::now() - chrono::Duration::seconds(10);
+        rollout.status = Some(RolloutStatus {
+            phase: Some(Phase::Preview),
+            replicas: 5,
+            started_at: Some(started_at.to_rfc3339()),
+            ..Default::default()
+        });
+
+        let strategy = BlueGreenStrategyHandler;
+        let status = strategy.compute_next_status(&rollout);
+
+        assert_eq!(status.phase, Some(Phase::Failed));
+        assert_eq!(
+            status.message,
+            Some("Rollout timed out after 1s".to_string())
+        );
+    }
+
+    #[test]
+    fn test_blue_green_strategy_preserves_started_at() {
+        let mut rollout = create_blue_green_rollout(5);
+        rollout.status = Some(RolloutStatus {
+            phase: Some(Phase::Preview),
+            replicas: 5,
+            started_at: Some("2024-01-01T00:00:00Z".to_string()),
+            ..Default::default()
+        });
+
+        let strategy = BlueGreenStrategyHandler;
+        let status = strategy.compute_next_status(&rollout);
+
+        assert_eq!(status.started_at, Some("2024-01-01T00:00:00Z".to_string()));
+    }
+
+    fn httproute_patch_weights(rollout: &Rollout) -> Vec<(String, i64)> {
+        let patch = build_httproute_weights_patch(
+            &build_blue_green_backend_refs(rollout),
+            None,
+            &HttpRouteRuleOptions::default(),
+        );
+        patch["spec"]["rules"][0]["backendRefs"]
+            .as_array()
+            .map(|refs| {
+                refs.iter()
+                    .map(|r| {
+                        (
+                            r["name"].as_str().unwrap_or_default().to_string(),
+                            r["weight"].as_i64().unwrap_or(-1),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_blue_green_httproute_patch_during_preview() {
+        let mut rollout = create_blue_green_rollout(3);
+        rollout.status = Some(RolloutStatus {
+            phase: Some(Phase::Preview),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            httproute_patch_weights(&rollout),
+            vec![
+                ("app-active".to_string(), 100),
+                ("app-preview".to_string(), 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blue_green_httproute_patch_after_promotion() {
+        let mut rollout = create_blue_green_rollout(3);
+        rollout.status = Some(RolloutStatus {
+            phase: Some(Phase::Completed),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            httproute_patch_weights(&rollout),
+            vec![
+                ("app-active".to_string(), 0),
+                ("app-preview".to_string(), 100)
+            ]
+        );
+    }
+
     // Note: reconcile_replicasets() and reconcile_traffic() require K8s API
     // These are tested in integration tests
 }
@@ -2,17 +2,22 @@
 //!
 //! Progressive traffic shifting with gradual rollout through defined steps.
 
+use super::linkerd::reconcile_linkerd_traffic;
 use super::{reconcile_gateway_api_traffic, RolloutStrategy, StrategyError};
+use crate::controller::completion::{
+    delete_completed_canary, should_delete_completed_canary, KubeReplicaSetDeleter,
+};
 use crate::controller::rollout::{
-    build_replicaset, calculate_replica_split, compute_desired_status, ensure_replicaset_exists,
-    Context,
+    active_rollback_revision, adopt_stable_replicaset, build_replicaset, calculate_canary_replicas,
+    compute_desired_status, ensure_replicaset_exists, is_analysis_only, is_stable_adoption_pending,
+    replicaset_name_suffix, Context,
 };
 use crate::crd::rollout::{Rollout, RolloutStatus};
 use async_trait::async_trait;
 use k8s_openapi::api::apps::v1::ReplicaSet;
 use kube::api::Api;
 use kube::ResourceExt;
-use tracing::info;
+use tracing::{debug, info};
 
 /// Canary strategy handler
 ///
@@ -21,6 +26,7 @@ use tracing::info;
 /// - Gradual traffic weight increase (e.g., 10% → 50% → 100%)
 /// - Pause steps (time-based or manual promotion)
 /// - Metrics-based rollback support
+/// - Analysis-only mode: fixed canary scale, no traffic split
 pub struct CanaryStrategyHandler;
 
 #[async_trait]
@@ -46,9 +52,17 @@ impl RolloutStrategy for CanaryStrategyHandler {
             .and_then(|s| s.current_weight)
             .unwrap_or(0);
 
-        // Calculate replica split based on weight
-        let (stable_replicas, canary_replicas) =
-            calculate_replica_split(rollout.spec.replicas, current_weight);
+        // Stable adoption after completion: both at full scale until stable is ready
+        let adopting_stable = is_stable_adoption_pending(rollout);
+
+        // Calculate replica split based on weight (fixed canary scale in analysis-only mode,
+        // canary on top of a full stable fleet with keepStableAtFullScale),
+        // keeping minCanaryReplicas/minStableReplicas while in progress
+        let (stable_replicas, canary_replicas) = if adopting_stable {
+            (rollout.spec.replicas, rollout.spec.replicas)
+        } else {
+            calculate_canary_replicas(rollout, current_weight)
+        };
 
         info!(
             rollout = ?name,
@@ -64,20 +78,43 @@ impl RolloutStrategy for CanaryStrategyHandler {
         let rs_api: Api<ReplicaSet> = Api::namespaced(ctx.client.clone(), &namespace);
 
         // Build and ensure stable ReplicaSet exists
-        let stable_rs = build_replicaset(rollout, "stable", stable_replicas)
-            .map_err(|e| StrategyError::ReplicaSetReconciliationFailed(e.to_string()))?;
+        let stable_rs = build_replicaset(
+            rollout,
+            "stable",
+            replicaset_name_suffix(rollout, "stable"),
+            stable_replicas,
+        )
+        .map_err(|e| StrategyError::ReplicaSetReconciliationFailed(e.to_string()))?;
 
-        ensure_replicaset_exists(&rs_api, &stable_rs, "stable", stable_replicas)
-            .await
-            .map_err(|e| StrategyError::ReplicaSetReconciliationFailed(e.to_string()))?;
+        // Rolled back to a recorded revision: replace the stable ReplicaSet with it
+        if adopting_stable || active_rollback_revision(rollout).is_some() {
+            adopt_stable_replicaset(&rs_api, &stable_rs, stable_replicas).await
+        } else {
+            ensure_replicaset_exists(&rs_api, &stable_rs, "stable", stable_replicas).await
+        }
+        .map_err(|e| StrategyError::ReplicaSetReconciliationFailed(e.to_string()))?;
 
         // Build and ensure canary ReplicaSet exists
-        let canary_rs = build_replicaset(rollout, "canary", canary_replicas)
-            .map_err(|e| StrategyError::ReplicaSetReconciliationFailed(e.to_string()))?;
+        let canary_rs = build_replicaset(
+            rollout,
+            "canary",
+            replicaset_name_suffix(rollout, "canary"),
+            canary_replicas,
+        )
+        .map_err(|e| StrategyError::ReplicaSetReconciliationFailed(e.to_string()))?;
 
-        ensure_replicaset_exists(&rs_api, &canary_rs, "canary", canary_replicas)
-            .await
-            .map_err(|e| StrategyError::ReplicaSetReconciliationFailed(e.to_string()))?;
+        // Completed with onComplete=delete: the emptied canary ReplicaSet is removed
+        if should_delete_completed_canary(rollout) {
+            let deleter = KubeReplicaSetDeleter::new(ctx.client.clone());
+            let canary_name = canary_rs.name_any();
+            delete_completed_canary(&deleter, &namespace, &canary_name)
+                .await
+                .map_err(|e| StrategyError::ReplicaSetReconciliationFailed(e.to_string()))?;
+        } else {
+            ensure_replicaset_exists(&rs_api, &canary_rs, "canary", canary_replicas)
+                .await
+                .map_err(|e| StrategyError::ReplicaSetReconciliationFailed(e.to_string()))?;
+        }
 
         info!(
             rollout = ?name,
@@ -94,8 +131,20 @@ impl RolloutStrategy for CanaryStrategyHandler {
         rollout: &Rollout,
         ctx: &Context,
     ) -> Result<(), StrategyError> {
+        // Analysis-only canaries never split live traffic
+        if is_analysis_only(rollout) {
+            debug!(
+                rollout = ?rollout.name_any(),
+                "Analysis-only canary, skipping traffic routing"
+            );
+            return Ok(());
+        }
+
         // Use shared helper for Gateway API traffic routing
-        reconcile_gateway_api_traffic(rollout, ctx, "canary").await
+        reconcile_gateway_api_traffic(rollout, ctx, "canary").await?;
+
+        // Linkerd SMI TrafficSplit (if configured)
+        reconcile_linkerd_traffic(rollout, ctx).await
     }
 
     fn compute_next_status(&self, rollout: &Rollout) -> RolloutStatus {
@@ -148,16 +197,43 @@ mod tests {
                     canary: Some(CanaryStrategy {
                         canary_service: "app-canary".to_string(),
                         stable_service: "app-stable".to_string(),
+                        mirror_service: None,
                         steps,
                         traffic_routing: Some(TrafficRouting {
                             gateway_api: Some(GatewayAPIRouting {
                                 http_route: "app-route".to_string(),
+                                gateway_api_version: None,
+                                verify_accepted: None,
+                                canary_match: None,
                             }),
+                            linkerd: None,
                         }),
                         analysis: None,
+                        max_duration_seconds: None,
+                        canary_overrides: None,
+                        canary_suffix: None,
+                        stable_suffix: None,
+                        analysis_only: None,
+                        port_name: None,
+                        pause_at_step: None,
+                        create_services: None,
+                        min_canary_replicas: None,
+                        min_stable_replicas: None,
+                        keep_stable_at_full_scale: None,
+                        extra_canaries: None,
+                        max_weight_step: None,
+                        min_initial_weight: None,
+                        on_complete: None,
+                        replica_surge: None,
+                        max_surge: None,
+                        sticky_cookie: None,
                     }),
                     blue_green: None,
                 },
+                manage_pod_template_hash: None,
+                quota_aware: None,
+                dry_run: None,
+                paused: None,
             },
             status: current_weight.map(|weight| crate::crd::rollout::RolloutStatus {
                 phase: Some(Phase::Progressing),
@@ -166,10 +242,26 @@ mod tests {
                 replicas,
                 ready_replicas: 0,
                 updated_replicas: 0,
+                stable_replicas: 0,
+                canary_replicas: 0,
                 message: None,
                 pause_start_time: None,
+                pause_start_generation: None,
+                started_at: None,
                 step_start_time: None,
                 decisions: vec![],
+                restarted_at: None,
+                conditions: vec![],
+                observed_generation: None,
+                revision_history: vec![],
+                stable_revision_hash: None,
+                canary_revision_hash: None,
+                rolled_back_to: None,
+                consecutive_analysis_failures: 0,
+                last_analysis_time: None,
+                last_analysis_result: None,
+                dry_run_plan: None,
+                transition_log: vec![],
             }),
         }
     }
@@ -198,12 +290,14 @@ mod tests {
             CanaryStep {
                 set_weight: Some(10),
                 pause: None,
+                analysis: None,
             },
             CanaryStep {
                 set_weight: Some(50),
                 pause: Some(PauseDuration {
                     duration: Some("30s".to_string()),
                 }),
+                analysis: None,
             },
         ];
         let rollout = create_canary_rollout(3, None, steps);
@@ -223,10 +317,12 @@ mod tests {
             CanaryStep {
                 set_weight: Some(10),
                 pause: None,
+                analysis: None,
             },
             CanaryStep {
                 set_weight: Some(100),
                 pause: None,
+                analysis: None,
             },
         ];
         let rollout = create_canary_rollout(3, Some(10), steps);
@@ -0,0 +1,402 @@
+//! Linkerd traffic routing via SMI TrafficSplit
+//!
+//! Linkerd shifts traffic using `split.smi-spec.io/v1alpha3` TrafficSplit
+//! resources. Unlike Gateway API, backends are listed under `spec.backends`
+//! as `{service, weight}` pairs with integer weights, which we always scale
+//! to a total of 100.
+
+use super::StrategyError;
+use crate::controller::rollout::{calculate_traffic_weights, Context};
+use crate::crd::rollout::{LinkerdRouting, Rollout};
+use kube::api::{Api, Patch, PatchParams};
+use kube::core::DynamicObject;
+use kube::discovery::ApiResource;
+use kube::{Client, ResourceExt};
+use serde::Serialize;
+use thiserror::Error;
+use tracing::{error, info, warn};
+
+/// Total all TrafficSplit backend weights are scaled to
+pub const TRAFFIC_SPLIT_TOTAL_WEIGHT: i32 = 100;
+
+/// Errors specific to Linkerd TrafficSplit routing
+#[derive(Debug, Error)]
+pub enum LinkerdError {
+    #[error("Rollout has no canary strategy")]
+    MissingCanaryStrategy,
+
+    #[error("Invalid TrafficSplit weights: {0}")]
+    InvalidWeights(String),
+
+    #[error("Failed to patch TrafficSplit {name}: {source}")]
+    PatchFailed {
+        name: String,
+        #[source]
+        source: Box<kube::Error>,
+    },
+}
+
+/// A single SMI TrafficSplit backend
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct TrafficSplitBackend {
+    pub service: String,
+    pub weight: i32,
+}
+
+/// ApiResource for split.smi-spec.io/v1alpha3 TrafficSplit
+pub fn trafficsplit_api_resource() -> ApiResource {
+    ApiResource {
+        group: "split.smi-spec.io".to_string(),
+        version: "v1alpha3".to_string(),
+        api_version: "split.smi-spec.io/v1alpha3".to_string(),
+        kind: "TrafficSplit".to_string(),
+        plural: "trafficsplits".to_string(),
+    }
+}
+
+/// Scale integer weights so they sum to exactly 100
+///
+/// Uses the largest remainder method so rounding never drops or adds traffic.
+///
+/// # Errors
+/// Returns error if any weight is negative or all weights are zero
+pub fn scale_weights_to_total(weights: &[i32]) -> Result<Vec<i32>, LinkerdError> {
+    if let Some(negative) = weights.iter().find(|w| **w < 0) {
+        return Err(LinkerdError::InvalidWeights(format!(
+            "weights must be >= 0, got {}",
+            negative
+        )));
+    }
+
+    let sum: i64 = weights.iter().map(|w| *w as i64).sum();
+    if sum == 0 {
+        return Err(LinkerdError::InvalidWeights(
+            "at least one weight must be > 0".to_string(),
+        ));
+    }
+
+    let total = TRAFFIC_SPLIT_TOTAL_WEIGHT as i64;
+    let mut scaled: Vec<i32> = weights
+        .iter()
+        .map(|w| ((*w as i64 * total) / sum) as i32)
+        .collect();
+
+    // Hand out the rounding remainder to the largest fractional parts
+    let mut remainders: Vec<(usize, i64)> = weights
+        .iter()
+        .enumerate()
+        .map(|(i, w)| (i, (*w as i64 * total) % sum))
+        .collect();
+    remainders.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let missing = TRAFFIC_SPLIT_TOTAL_WEIGHT - scaled.iter().sum::<i32>();
+    for (i, _) in remainders.iter().take(missing.max(0) as usize) {
+        scaled[*i] += 1;
+    }
+
+    Ok(scaled)
+}
+
+/// Build TrafficSplit backends (stable + canary) for a Rollout
+///
+/// # Errors
+/// Returns error if the rollout has no canary strategy or weights are invalid
+pub fn build_trafficsplit_backends(
+    rollout: &Rollout,
+    stable_weight: i32,
+    canary_weight: i32,
+) -> Result<Vec<TrafficSplitBackend>, LinkerdError> {
+    let canary = rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .ok_or(LinkerdError::MissingCanaryStrategy)?;
+
+    let weights = scale_weights_to_total(&[stable_weight, canary_weight])?;
+
+    Ok(vec![
+        TrafficSplitBackend {
+            service: canary.stable_service.clone(),
+            weight: weights[0],
+        },
+        TrafficSplitBackend {
+            service: canary.canary_service.clone(),
+            weight: weights[1],
+        },
+    ])
+}
+
+/// Build the merge patch that replaces the TrafficSplit's spec.backends
+pub fn build_trafficsplit_patch(backends: &[TrafficSplitBackend]) -> serde_json::Value {
+    serde_json::json!({
+        "apiVersion": "split.smi-spec.io/v1alpha3",
+        "kind": "TrafficSplit",
+        "spec": {
+            "backends": backends
+        }
+    })
+}
+
+/// Patch a Linkerd TrafficSplit with weighted stable/canary backends
+///
+/// # Returns
+/// * `Ok(())` - TrafficSplit patched or not found (non-fatal)
+/// * `Err(LinkerdError)` - Invalid weights or API error other than 404
+pub async fn patch_trafficsplit_weights(
+    client: &Client,
+    namespace: &str,
+    rollout: &Rollout,
+    linkerd_routing: &LinkerdRouting,
+    stable_weight: i32,
+    canary_weight: i32,
+) -> Result<(), LinkerdError> {
+    let rollout_name = rollout.name_any();
+    let trafficsplit_name = &linkerd_routing.traffic_split_name;
+
+    let backends = build_trafficsplit_backends(rollout, stable_weight, canary_weight)?;
+    let patch_json = build_trafficsplit_patch(&backends);
+
+    info!(
+        rollout = ?rollout_name,
+        trafficsplit = ?trafficsplit_name,
+        stable_weight = backends[0].weight,
+        canary_weight = backends[1].weight,
+        "Updating Linkerd TrafficSplit with weighted backends"
+    );
+
+    let ar = trafficsplit_api_resource();
+    let trafficsplit_api: Api<DynamicObject> = Api::namespaced_with(client.clone(), namespace, &ar);
+
+    match trafficsplit_api
+        .patch(
+            trafficsplit_name,
+            &PatchParams::default(),
+            &Patch::Merge(&patch_json),
+        )
+        .await
+    {
+        Ok(_) => {
+            info!(
+                rollout = ?rollout_name,
+                trafficsplit = ?trafficsplit_name,
+                "TrafficSplit updated successfully"
+            );
+            Ok(())
+        }
+        Err(kube::Error::Api(err)) if err.code == 404 => {
+            // TrafficSplit not found - non-fatal, traffic routing is optional
+            warn!(
+                rollout = ?rollout_name,
+                trafficsplit = ?trafficsplit_name,
+                "TrafficSplit not found - skipping traffic routing update"
+            );
+            Ok(())
+        }
+        Err(e) => {
+            error!(
+                error = ?e,
+                rollout = ?rollout_name,
+                trafficsplit = ?trafficsplit_name,
+                "Failed to patch TrafficSplit"
+            );
+            Err(LinkerdError::PatchFailed {
+                name: trafficsplit_name.clone(),
+                source: Box::new(e),
+            })
+        }
+    }
+}
+
+/// Extract Linkerd routing config from a canary rollout
+///
+/// Returns None if Linkerd routing is not configured (which is valid).
+pub fn get_linkerd_routing(rollout: &Rollout) -> Option<&LinkerdRouting> {
+    rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .and_then(|canary| canary.traffic_routing.as_ref())
+        .and_then(|traffic_routing| traffic_routing.linkerd.as_ref())
+}
+
+/// Reconcile Linkerd traffic routing for a canary rollout
+///
+/// No-op when Linkerd routing is not configured.
+pub async fn reconcile_linkerd_traffic(
+    rollout: &Rollout,
+    ctx: &Context,
+) -> Result<(), StrategyError> {
+    let linkerd_routing = match get_linkerd_routing(rollout) {
+        Some(routing) => routing,
+        None => return Ok(()),
+    };
+
+    let namespace = rollout
+        .namespace()
+        .ok_or_else(|| StrategyError::MissingField("namespace".to_string()))?;
+    let (stable_weight, canary_weight) = calculate_traffic_weights(rollout);
+
+    patch_trafficsplit_weights(
+        &ctx.client,
+        &namespace,
+        rollout,
+        linkerd_routing,
+        stable_weight,
+        canary_weight,
+    )
+    .await
+    .map_err(|e| StrategyError::TrafficReconciliationFailed(e.to_string()))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::crd::rollout::{
+        CanaryStrategy, RolloutSpec, RolloutStrategy as RolloutStrategySpec,
+    };
+    use k8s_openapi::api::core::v1::PodTemplateSpec;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+
+    fn create_canary_rollout() -> Rollout {
+        Rollout {
+            metadata: kube::api::ObjectMeta {
+                name: Some("test-rollout".to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            },
+            spec: RolloutSpec {
+                replicas: 3,
+                selector: LabelSelector::default(),
+                template: PodTemplateSpec::default(),
+                strategy: RolloutStrategySpec {
+                    simple: None,
+                    canary: Some(CanaryStrategy {
+                        canary_service: "app-canary".to_string(),
+                        stable_service: "app-stable".to_string(),
+                        mirror_service: None,
+                        steps: vec![],
+                        traffic_routing: None,
+                        analysis: None,
+                        max_duration_seconds: None,
+                        canary_overrides: None,
+                        canary_suffix: None,
+                        stable_suffix: None,
+                        analysis_only: None,
+                        port_name: None,
+                        pause_at_step: None,
+                        create_services: None,
+                        min_canary_replicas: None,
+                        min_stable_replicas: None,
+                        keep_stable_at_full_scale: None,
+                        extra_canaries: None,
+                        max_weight_step: None,
+                        min_initial_weight: None,
+                        on_complete: None,
+                        replica_surge: None,
+                        max_surge: None,
+                        sticky_cookie: None,
+                    }),
+                    blue_green: None,
+                },
+                manage_pod_template_hash: None,
+                quota_aware: None,
+                dry_run: None,
+                paused: None,
+            },
+            status: None,
+        }
+    }
+
+    #[test]
+    fn test_trafficsplit_patch_smi_structure() {
+        let rollout = create_canary_rollout();
+        let backends = build_trafficsplit_backends(&rollout, 80, 20).unwrap();
+
+        let patch = build_trafficsplit_patch(&backends);
+
+        assert_eq!(
+            patch,
+            serde_json::json!({
+                "apiVersion": "split.smi-spec.io/v1alpha3",
+                "kind": "TrafficSplit",
+                "spec": {
+                    "backends": [
+                        {"service": "app-stable", "weight": 80},
+                        {"service": "app-canary", "weight": 20}
+                    ]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_trafficsplit_weights_sum_to_100() {
+        let rollout = create_canary_rollout();
+
+        for canary_weight in [0, 1, 33, 50, 99, 100] {
+            let backends =
+                build_trafficsplit_backends(&rollout, 100 - canary_weight, canary_weight).unwrap();
+            let total: i32 = backends.iter().map(|b| b.weight).sum();
+            assert_eq!(total, 100, "canary weight {}", canary_weight);
+        }
+    }
+
+    #[test]
+    fn test_scale_weights_to_total() {
+        assert_eq!(scale_weights_to_total(&[1, 1]).unwrap(), vec![50, 50]);
+        assert_eq!(
+            scale_weights_to_total(&[1, 1, 1]).unwrap(),
+            vec![34, 33, 33]
+        );
+        assert_eq!(scale_weights_to_total(&[3, 1]).unwrap(), vec![75, 25]);
+        assert_eq!(scale_weights_to_total(&[0, 5]).unwrap(), vec![0, 100]);
+    }
+
+    #[test]
+    fn test_scale_weights_rejects_invalid() {
+        assert!(matches!(
+            scale_weights_to_total(&[0, 0]),
+            Err(LinkerdError::InvalidWeights(_))
+        ));
+        assert!(matches!(
+            scale_weights_to_total(&[-10, 110]),
+            Err(LinkerdError::InvalidWeights(_))
+        ));
+    }
+
+    #[test]
+    fn test_get_linkerd_routing() {
+        use crate::crd::rollout::TrafficRouting;
+
+        let mut rollout = create_canary_rollout();
+        assert!(get_linkerd_routing(&rollout).is_none());
+
+        if let Some(canary) = rollout.spec.strategy.canary.as_mut() {
+            canary.traffic_routing = Some(TrafficRouting {
+                gateway_api: None,
+                linkerd: Some(LinkerdRouting {
+                    traffic_split_name: "app-split".to_string(),
+                }),
+            });
+        }
+
+        assert_eq!(
+            get_linkerd_routing(&rollout).map(|l| l.traffic_split_name.as_str()),
+            Some("app-split")
+        );
+    }
+
+    #[test]
+    fn test_trafficsplit_backends_require_canary_strategy() {
+        let mut rollout = create_canary_rollout();
+        rollout.spec.strategy.canary = None;
+
+        assert!(matches!(
+            build_trafficsplit_backends(&rollout, 100, 0),
+            Err(LinkerdError::MissingCanaryStrategy)
+        ));
+    }
+}
@@ -7,16 +7,24 @@
 
 pub mod blue_green;
 pub mod canary;
+pub mod linkerd;
 pub mod simple;
 
-use crate::controller::rollout::{build_gateway_api_backend_refs, Context};
-use crate::crd::rollout::{GatewayAPIRouting, Rollout, RolloutStatus};
+use crate::controller::port_resolver::{KubeServiceLookup, PortResolver, DEFAULT_BACKEND_PORT};
+use crate::controller::rollout::{
+    build_gateway_api_backend_refs_with_ports, build_httproute_rule_options,
+    sticky_cookie_match_pattern, Context,
+};
+use crate::crd::rollout::{
+    GatewayAPIRouting, GatewayApiVersion, HttpRouteMatch, Rollout, RolloutStatus, StickyCookie,
+};
 use async_trait::async_trait;
-use gateway_api::apis::standard::httproutes::HTTPRouteRulesBackendRefs;
+use gateway_api::apis::standard::httproutes::{HTTPRouteRulesBackendRefs, HTTPRouteRulesFilters};
 use kube::api::{Api, Patch, PatchParams};
 use kube::core::DynamicObject;
 use kube::discovery::ApiResource;
 use kube::{Client, ResourceExt};
+use std::collections::HashSet;
 use thiserror::Error;
 use tracing::{error, info, warn};
 
@@ -34,6 +42,255 @@ pub enum StrategyError {
 
     #[error("Missing required field: {0}")]
     MissingField(String),
+
+    #[error("Strategy {0} is disabled on this controller (KULTA_ENABLED_STRATEGIES)")]
+    StrategyDisabled(String),
+}
+
+/// Names of all strategies, as returned by RolloutStrategy::name()
+pub const STRATEGY_NAMES: [&str; 3] = ["simple", "canary", "blue-green"];
+
+/// Parse KULTA_ENABLED_STRATEGIES (comma-separated strategy names)
+///
+/// Unset or empty enables every strategy. Names are matched
+/// case-insensitively against STRATEGY_NAMES.
+///
+/// # Errors
+/// Returns an error naming the first unknown strategy
+pub fn parse_enabled_strategies(value: Option<&str>) -> Result<HashSet<&'static str>, String> {
+    let Some(value) = value.filter(|v| !v.trim().is_empty()) else {
+        return Ok(STRATEGY_NAMES.into_iter().collect());
+    };
+
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            STRATEGY_NAMES
+                .into_iter()
+                .find(|known| known.eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    format!(
+                        "unknown strategy '{}' (expected one of: {})",
+                        name,
+                        STRATEGY_NAMES.join(", ")
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Read KULTA_ENABLED_STRATEGIES, enabling every strategy if it is invalid
+///
+/// validate_env_config rejects invalid values at startup, so the fallback
+/// only applies to contexts built without validation.
+pub fn enabled_strategies_from_env() -> HashSet<&'static str> {
+    let value = std::env::var("KULTA_ENABLED_STRATEGIES").ok();
+    parse_enabled_strategies(value.as_deref()).unwrap_or_else(|e| {
+        warn!(error = %e, "Invalid KULTA_ENABLED_STRATEGIES, enabling all strategies");
+        STRATEGY_NAMES.into_iter().collect()
+    })
+}
+
+/// API group of the Gateway API resources
+pub const GATEWAY_API_GROUP: &str = "gateway.networking.k8s.io";
+
+/// Build the ApiResource for HTTPRoute at the given Gateway API version
+pub fn httproute_api_resource(version: GatewayApiVersion) -> ApiResource {
+    ApiResource {
+        group: GATEWAY_API_GROUP.to_string(),
+        version: version.as_str().to_string(),
+        api_version: format!("{}/{}", GATEWAY_API_GROUP, version.as_str()),
+        kind: "HTTPRoute".to_string(),
+        plural: "httproutes".to_string(),
+    }
+}
+
+/// Pick the HTTPRoute version to use from the versions the cluster serves it in
+///
+/// v1 is preferred over v1beta1; None if neither is served.
+pub fn select_httproute_version(served: &[String]) -> Option<GatewayApiVersion> {
+    [GatewayApiVersion::V1, GatewayApiVersion::V1beta1]
+        .into_iter()
+        .find(|version| served.iter().any(|served| served == version.as_str()))
+}
+
+/// Discover the HTTPRoute version served by the cluster
+///
+/// # Returns
+/// * `Ok(version)` - v1 if served, else v1beta1
+/// * `Err(StrategyError)` - Gateway API (HTTPRoute) is not installed, or discovery failed
+pub async fn discover_httproute_version(
+    client: &Client,
+) -> Result<GatewayApiVersion, StrategyError> {
+    let not_installed = || {
+        StrategyError::TrafficReconciliationFailed(format!(
+            "HTTPRoute ({}/v1 or v1beta1) is not installed in the cluster; install the Gateway API CRDs or remove trafficRouting.gatewayAPI",
+            GATEWAY_API_GROUP
+        ))
+    };
+
+    let group = match kube::discovery::group(client, GATEWAY_API_GROUP).await {
+        Ok(group) => group,
+        Err(kube::Error::Api(err)) if err.code == 404 => return Err(not_installed()),
+        Err(kube::Error::Discovery(_)) => return Err(not_installed()),
+        Err(e) => return Err(StrategyError::KubeError(e)),
+    };
+
+    let served = group
+        .versions()
+        .filter(|version| {
+            group
+                .versioned_resources(version)
+                .iter()
+                .any(|(resource, _)| resource.kind == "HTTPRoute")
+        })
+        .map(String::from)
+        .collect::<Vec<_>>();
+
+    select_httproute_version(&served).ok_or_else(not_installed)
+}
+
+/// Resolve the HTTPRoute version: gatewayApiVersion if set, else discovered once and cached
+pub async fn resolve_httproute_version(
+    ctx: &Context,
+    gateway_api_routing: &GatewayAPIRouting,
+) -> Result<GatewayApiVersion, StrategyError> {
+    if let Some(version) = gateway_api_routing.gateway_api_version {
+        return Ok(version);
+    }
+
+    ctx.httproute_version
+        .get_or_try_init(|| async {
+            let version = discover_httproute_version(&ctx.client).await?;
+            info!(
+                version = version.as_str(),
+                "Detected Gateway API HTTPRoute version"
+            );
+            Ok::<_, StrategyError>(version)
+        })
+        .await
+        .copied()
+}
+
+/// Rule-level HTTPRoute settings applied alongside the weighted backendRefs
+#[derive(Debug, Clone, Default)]
+pub struct HttpRouteRuleOptions {
+    /// RequestMirror filter (canary.mirrorService), set on every rule so each
+    /// request is mirrored once whichever backend serves it
+    pub mirror_filter: Option<HTTPRouteRulesFilters>,
+    /// Cookie routing returning users to the canary (canary.stickyCookie)
+    pub sticky_cookie: Option<StickyCookie>,
+}
+
+/// Build the merge patch that replaces the HTTPRoute's first rule's backendRefs
+///
+/// With `canary_match`, a second rule routes the matching requests to the
+/// canary (backend_refs[1]) while it receives traffic; a sticky cookie in
+/// `options` adds a rule doing the same for requests carrying the cookie.
+/// A merge patch replaces the whole rules list, so these rules are dropped
+/// again once the canary weight returns to 0 (completion, abort).
+pub fn build_httproute_weights_patch(
+    backend_refs: &[HTTPRouteRulesBackendRefs],
+    canary_match: Option<&[HttpRouteMatch]>,
+    options: &HttpRouteRuleOptions,
+) -> serde_json::Value {
+    let mut rules = vec![serde_json::json!({ "backendRefs": backend_refs })];
+
+    let live_canary = backend_refs
+        .get(1)
+        .filter(|canary| canary.weight.unwrap_or(0) > 0);
+    if let Some(canary) = live_canary {
+        if let Some(matches) = canary_match {
+            rules.push(build_canary_match_rule(matches, canary));
+        }
+        if let Some(cookie) = &options.sticky_cookie {
+            rules.push(build_sticky_cookie_rule(cookie, canary));
+        }
+    }
+
+    if let Some(mirror_filter) = &options.mirror_filter {
+        for rule in &mut rules {
+            rule["filters"] = serde_json::json!([mirror_filter]);
+        }
+    }
+
+    serde_json::json!({
+        "spec": {
+            "rules": rules
+        }
+    })
+}
+
+/// Build the HTTPRoute rule sending requests that match `matches` to the canary
+///
+/// Gateway API matches take a single method, so each method of an
+/// HttpRouteMatch becomes its own match (ORed), sharing the path prefix.
+pub fn build_canary_match_rule(
+    matches: &[HttpRouteMatch],
+    canary_ref: &HTTPRouteRulesBackendRefs,
+) -> serde_json::Value {
+    let mut route_matches = Vec::new();
+    for route_match in matches {
+        let path = route_match.path_prefix.as_ref().map(|prefix| {
+            serde_json::json!({
+                "type": "PathPrefix",
+                "value": prefix
+            })
+        });
+        let methods: Vec<Option<&String>> = match route_match.methods.as_deref() {
+            Some(methods) if !methods.is_empty() => methods.iter().map(Some).collect(),
+            _ => vec![None],
+        };
+        for method in methods {
+            let mut route_match = serde_json::Map::new();
+            if let Some(path) = &path {
+                route_match.insert("path".to_string(), path.clone());
+            }
+            if let Some(method) = method {
+                route_match.insert("method".to_string(), serde_json::json!(method));
+            }
+            route_matches.push(serde_json::Value::Object(route_match));
+        }
+    }
+
+    // Sole backend of the rule: it gets all matching traffic
+    let canary_ref = HTTPRouteRulesBackendRefs {
+        weight: None,
+        ..canary_ref.clone()
+    };
+
+    serde_json::json!({
+        "matches": route_matches,
+        "backendRefs": [canary_ref]
+    })
+}
+
+/// Build the HTTPRoute rule sending requests carrying the sticky cookie to the canary
+///
+/// Matches `<name>=canary` anywhere in the Cookie header, so users who were
+/// served by the canary stay on it whatever the current weight.
+pub fn build_sticky_cookie_rule(
+    cookie: &StickyCookie,
+    canary_ref: &HTTPRouteRulesBackendRefs,
+) -> serde_json::Value {
+    // Sole backend of the rule: it gets all matching traffic
+    let canary_ref = HTTPRouteRulesBackendRefs {
+        weight: None,
+        ..canary_ref.clone()
+    };
+
+    serde_json::json!({
+        "matches": [{
+            "headers": [{
+                "type": "RegularExpression",
+                "name": "Cookie",
+                "value": sticky_cookie_match_pattern(cookie)
+            }]
+        }],
+        "backendRefs": [canary_ref]
+    })
 }
 
 /// Patch HTTPRoute with weighted backend refs
@@ -41,23 +298,28 @@ pub enum StrategyError {
 /// Shared helper used by both canary and blue-green strategies to update
 /// Gateway API HTTPRoute resources with traffic weights.
 ///
+/// The HTTPRoute API version comes from `gatewayApiVersion`, or is detected
+/// from the cluster (see `resolve_httproute_version`).
+///
 /// # Arguments
-/// * `client` - Kubernetes client
+/// * `ctx` - Controller context with k8s client
 /// * `namespace` - Namespace of the HTTPRoute
 /// * `rollout_name` - Name of the rollout (for logging)
 /// * `gateway_api_routing` - Gateway API routing config containing HTTPRoute name
 /// * `backend_refs` - Weighted backend refs to apply
+/// * `options` - Rule-level settings (mirror filter, sticky cookie)
 /// * `strategy_name` - Strategy name for logging ("canary" or "blue-green")
 ///
 /// # Returns
 /// * `Ok(())` - HTTPRoute patched or not found (non-fatal)
-/// * `Err(StrategyError)` - API error other than 404
+/// * `Err(StrategyError)` - API error other than 404, or HTTPRoute not installed
 pub async fn patch_httproute_weights(
-    client: &Client,
+    ctx: &Context,
     namespace: &str,
     rollout_name: &str,
     gateway_api_routing: &GatewayAPIRouting,
     backend_refs: &[HTTPRouteRulesBackendRefs],
+    options: &HttpRouteRuleOptions,
     strategy_name: &str,
 ) -> Result<(), StrategyError> {
     let httproute_name = &gateway_api_routing.http_route;
@@ -70,24 +332,19 @@ pub async fn patch_httproute_weights(
     );
 
     // Create JSON patch to update HTTPRoute's first rule's backendRefs
-    let patch_json = serde_json::json!({
-        "spec": {
-            "rules": [{
-                "backendRefs": backend_refs
-            }]
-        }
-    });
+    // (plus the canaryMatch and sticky cookie rules, if configured)
+    let patch_json = build_httproute_weights_patch(
+        backend_refs,
+        gateway_api_routing.canary_match.as_deref(),
+        options,
+    );
 
     // Create HTTPRoute API client using DynamicObject
-    let ar = ApiResource {
-        group: "gateway.networking.k8s.io".to_string(),
-        version: "v1".to_string(),
-        api_version: "gateway.networking.k8s.io/v1".to_string(),
-        kind: "HTTPRoute".to_string(),
-        plural: "httproutes".to_string(),
-    };
+    let version = resolve_httproute_version(ctx, gateway_api_routing).await?;
+    let ar = httproute_api_resource(version);
 
-    let httproute_api: Api<DynamicObject> = Api::namespaced_with(client.clone(), namespace, &ar);
+    let httproute_api: Api<DynamicObject> =
+        Api::namespaced_with(ctx.client.clone(), namespace, &ar);
 
     // Apply the patch
     match httproute_api
@@ -178,16 +435,47 @@ pub async fn reconcile_gateway_api_traffic(
         }
     };
 
-    // Build the weighted backend refs
-    let backend_refs = build_gateway_api_backend_refs(rollout);
+    // Resolve backend ports (canary.portName), looking up each Service once
+    let port_name = rollout
+        .spec
+        .strategy
+        .canary
+        .as_ref()
+        .and_then(|canary| canary.port_name.as_deref());
+    let lookup = KubeServiceLookup::new(ctx.client.clone());
+    let mut port_resolver = PortResolver::new(&lookup, &namespace);
+    let (stable_port, canary_port, mirror_port) = match rollout.spec.strategy.canary.as_ref() {
+        Some(canary) => (
+            port_resolver
+                .resolve(&canary.stable_service, port_name)
+                .await,
+            port_resolver
+                .resolve(&canary.canary_service, port_name)
+                .await,
+            match canary.mirror_service.as_deref() {
+                Some(mirror_service) => port_resolver.resolve(mirror_service, port_name).await,
+                None => DEFAULT_BACKEND_PORT,
+            },
+        ),
+        None => (
+            DEFAULT_BACKEND_PORT,
+            DEFAULT_BACKEND_PORT,
+            DEFAULT_BACKEND_PORT,
+        ),
+    };
+
+    // Build the weighted backend refs and rule-level settings
+    let backend_refs = build_gateway_api_backend_refs_with_ports(rollout, stable_port, canary_port);
+    let rule_options = build_httproute_rule_options(rollout, mirror_port);
 
     // Patch HTTPRoute with weights
     patch_httproute_weights(
-        &ctx.client,
+        ctx,
         &namespace,
         &name,
         gateway_api_routing,
         &backend_refs,
+        &rule_options,
         strategy_name,
     )
     .await
@@ -299,6 +587,22 @@ pub trait RolloutStrategy: Send + Sync {
     fn supports_manual_promotion(&self) -> bool;
 }
 
+/// Select the strategy handler for a Rollout, if that strategy is enabled
+///
+/// # Errors
+/// * `StrategyError::StrategyDisabled` - The selected strategy is not in
+///   `enabled` (KULTA_ENABLED_STRATEGIES)
+pub fn select_enabled_strategy(
+    rollout: &Rollout,
+    enabled: &HashSet<&'static str>,
+) -> Result<Box<dyn RolloutStrategy>, StrategyError> {
+    let strategy = select_strategy(rollout);
+    if !enabled.contains(strategy.name()) {
+        return Err(StrategyError::StrategyDisabled(strategy.name().to_string()));
+    }
+    Ok(strategy)
+}
+
 /// Select the appropriate strategy handler based on Rollout spec
 ///
 /// # Arguments
@@ -328,12 +632,14 @@ pub fn select_strategy(rollout: &Rollout) -> Box<dyn RolloutStrategy> {
     } else if rollout.spec.strategy.blue_green.is_some() {
         Box::new(BlueGreenStrategyHandler)
     } else {
-        // Default to canary (most common)
+        // Default to canary (most common). Validation rejects an empty strategy
+        // (EMPTY_STRATEGY_ERROR), so only unvalidated rollouts get here empty.
         Box::new(CanaryStrategyHandler)
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)] // Tests can use unwrap for brevity
 mod tests {
     use super::*;
     use crate::crd::rollout::{
@@ -355,11 +661,35 @@ mod tests {
                 selector: LabelSelector::default(),
                 template: PodTemplateSpec::default(),
                 strategy: strategy_spec,
+                manage_pod_template_hash: None,
+                quota_aware: None,
+                dry_run: None,
+                paused: None,
             },
             status: None,
         }
     }
 
+    #[test]
+    fn test_parse_enabled_strategies_defaults_to_all() {
+        for value in [None, Some(""), Some("  ")] {
+            let enabled = parse_enabled_strategies(value).unwrap();
+            assert_eq!(enabled, STRATEGY_NAMES.into_iter().collect());
+        }
+    }
+
+    #[test]
+    fn test_parse_enabled_strategies_subset() {
+        let enabled = parse_enabled_strategies(Some("Canary, simple,")).unwrap();
+        assert_eq!(enabled, HashSet::from(["canary", "simple"]));
+    }
+
+    #[test]
+    fn test_parse_enabled_strategies_rejects_unknown() {
+        let result = parse_enabled_strategies(Some("canary,bluegreen"));
+        assert!(result.unwrap_err().contains("unknown strategy 'bluegreen'"));
+    }
+
     #[test]
     fn test_select_strategy_simple() {
         let rollout = create_test_rollout(RolloutStrategySpec {
@@ -384,6 +714,7 @@ mod tests {
                 auto_promotion_seconds: None,
                 traffic_routing: None,
                 analysis: None,
+                max_duration_seconds: None,
             }),
         });
 
@@ -398,9 +729,28 @@ mod tests {
             canary: Some(CanaryStrategy {
                 canary_service: "app-canary".to_string(),
                 stable_service: "app-stable".to_string(),
+                mirror_service: None,
                 steps: vec![],
                 traffic_routing: None,
                 analysis: None,
+                max_duration_seconds: None,
+                canary_overrides: None,
+                canary_suffix: None,
+                stable_suffix: None,
+                analysis_only: None,
+                port_name: None,
+                pause_at_step: None,
+                create_services: None,
+                min_canary_replicas: None,
+                min_stable_replicas: None,
+                keep_stable_at_full_scale: None,
+                extra_canaries: None,
+                max_weight_step: None,
+                min_initial_weight: None,
+                on_complete: None,
+                replica_surge: None,
+                max_surge: None,
+                sticky_cookie: None,
             }),
             blue_green: None,
         });
@@ -420,4 +770,235 @@ mod tests {
         let strategy = select_strategy(&rollout);
         assert_eq!(strategy.name(), "canary");
     }
+
+    #[test]
+    fn test_httproute_api_resource_v1() {
+        let ar = httproute_api_resource(GatewayApiVersion::V1);
+
+        assert_eq!(ar.group, "gateway.networking.k8s.io");
+        assert_eq!(ar.version, "v1");
+        assert_eq!(ar.api_version, "gateway.networking.k8s.io/v1");
+        assert_eq!(ar.kind, "HTTPRoute");
+        assert_eq!(ar.plural, "httproutes");
+    }
+
+    #[test]
+    fn test_httproute_api_resource_v1beta1() {
+        let ar = httproute_api_resource(GatewayApiVersion::V1beta1);
+
+        assert_eq!(ar.version, "v1beta1");
+        assert_eq!(ar.api_version, "gateway.networking.k8s.io/v1beta1");
+        assert_eq!(ar.kind, "HTTPRoute");
+    }
+
+    #[test]
+    fn test_gateway_api_version_from_config() {
+        let routing: GatewayAPIRouting = serde_json::from_value(serde_json::json!({
+            "httpRoute": "my-route",
+            "gatewayApiVersion": "v1beta1"
+        }))
+        .unwrap();
+
+        let version = routing.gateway_api_version.unwrap();
+        assert_eq!(
+            httproute_api_resource(version).api_version,
+            "gateway.networking.k8s.io/v1beta1"
+        );
+    }
+
+    #[test]
+    fn test_select_httproute_version_prefers_v1() {
+        let served = |versions: &[&str]| versions.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            select_httproute_version(&served(&["v1beta1", "v1"])),
+            Some(GatewayApiVersion::V1)
+        );
+        assert_eq!(
+            select_httproute_version(&served(&["v1beta1", "v1alpha2"])),
+            Some(GatewayApiVersion::V1beta1)
+        );
+        assert_eq!(select_httproute_version(&served(&["v1alpha2"])), None);
+        assert_eq!(select_httproute_version(&[]), None);
+    }
+
+    fn weighted_backend_refs(canary_weight: i32) -> Vec<HTTPRouteRulesBackendRefs> {
+        [
+            ("app-stable", 100 - canary_weight),
+            ("app-canary", canary_weight),
+        ]
+        .into_iter()
+        .map(|(name, weight)| HTTPRouteRulesBackendRefs {
+            name: name.to_string(),
+            port: Some(80),
+            weight: Some(weight),
+            kind: Some("Service".to_string()),
+            group: Some("".to_string()),
+            namespace: None,
+            filters: None,
+        })
+        .collect()
+    }
+
+    fn api_match() -> Vec<HttpRouteMatch> {
+        vec![HttpRouteMatch {
+            path_prefix: Some("/api/v2".to_string()),
+            methods: Some(vec!["GET".to_string(), "POST".to_string()]),
+        }]
+    }
+
+    #[test]
+    fn test_httproute_patch_with_canary_match_has_two_rules() {
+        let matches = api_match();
+
+        let patch = build_httproute_weights_patch(
+            &weighted_backend_refs(10),
+            Some(&matches),
+            &HttpRouteRuleOptions::default(),
+        );
+
+        let rules = patch["spec"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 2);
+
+        // First rule keeps the weighted split, without matches
+        assert!(rules[0].get("matches").is_none());
+        assert_eq!(rules[0]["backendRefs"][0]["weight"], 90);
+        assert_eq!(rules[0]["backendRefs"][1]["weight"], 10);
+
+        // Second rule sends every matching request to the canary
+        let backend_refs = rules[1]["backendRefs"].as_array().unwrap();
+        assert_eq!(backend_refs.len(), 1);
+        assert_eq!(backend_refs[0]["name"], "app-canary");
+        assert!(backend_refs[0].get("weight").is_none());
+        assert_eq!(
+            rules[1]["matches"],
+            serde_json::json!([
+                {"path": {"type": "PathPrefix", "value": "/api/v2"}, "method": "GET"},
+                {"path": {"type": "PathPrefix", "value": "/api/v2"}, "method": "POST"}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_httproute_patch_without_canary_match_has_one_rule() {
+        let patch = build_httproute_weights_patch(
+            &weighted_backend_refs(10),
+            None,
+            &HttpRouteRuleOptions::default(),
+        );
+
+        assert_eq!(patch["spec"]["rules"].as_array().unwrap().len(), 1);
+    }
+
+    fn sticky_options() -> HttpRouteRuleOptions {
+        HttpRouteRuleOptions {
+            mirror_filter: None,
+            sticky_cookie: Some(StickyCookie {
+                name: "kulta-canary".to_string(),
+                max_age_seconds: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_httproute_patch_routes_sticky_cookie_to_canary() {
+        let patch =
+            build_httproute_weights_patch(&weighted_backend_refs(10), None, &sticky_options());
+
+        let rules = patch["spec"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 2);
+
+        // Second rule sends requests carrying the cookie to the canary
+        let backend_refs = rules[1]["backendRefs"].as_array().unwrap();
+        assert_eq!(backend_refs.len(), 1);
+        assert_eq!(backend_refs[0]["name"], "app-canary");
+        assert!(backend_refs[0].get("weight").is_none());
+        assert_eq!(
+            rules[1]["matches"],
+            serde_json::json!([{
+                "headers": [{
+                    "type": "RegularExpression",
+                    "name": "Cookie",
+                    "value": "(^|;\\s*)kulta\\-canary=canary(;|$)"
+                }]
+            }])
+        );
+    }
+
+    #[test]
+    fn test_httproute_patch_drops_sticky_cookie_rule_at_zero_weight() {
+        let patch =
+            build_httproute_weights_patch(&weighted_backend_refs(0), None, &sticky_options());
+
+        assert_eq!(patch["spec"]["rules"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_httproute_patch_mirrors_once_per_rule() {
+        let mirror_filter = serde_json::from_value(serde_json::json!({
+            "type": "RequestMirror",
+            "requestMirror": {"backendRef": {"name": "app-shadow", "port": 8080}}
+        }))
+        .unwrap();
+        let options = HttpRouteRuleOptions {
+            mirror_filter: Some(mirror_filter),
+            ..sticky_options()
+        };
+        let matches = api_match();
+
+        let patch =
+            build_httproute_weights_patch(&weighted_backend_refs(10), Some(&matches), &options);
+
+        // Weighted, canaryMatch and sticky cookie rules each mirror once;
+        // no backendRef carries the filter
+        let rules = patch["spec"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 3);
+        for rule in rules {
+            let filters = rule["filters"].as_array().unwrap();
+            assert_eq!(filters.len(), 1);
+            assert_eq!(filters[0]["type"], "RequestMirror");
+            assert_eq!(filters[0]["requestMirror"]["backendRef"]["port"], 8080);
+            for backend_ref in rule["backendRefs"].as_array().unwrap() {
+                assert!(backend_ref.get("filters").is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn test_httproute_patch_drops_canary_match_at_zero_weight() {
+        // Completed or aborted: the canary no longer serves, matches go back to stable
+        let matches = api_match();
+
+        let patch = build_httproute_weights_patch(
+            &weighted_backend_refs(0),
+            Some(&matches),
+            &HttpRouteRuleOptions::default(),
+        );
+
+        assert_eq!(patch["spec"]["rules"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_canary_match_rule_path_only_and_method_only() {
+        let matches = vec![
+            HttpRouteMatch {
+                path_prefix: Some("/beta".to_string()),
+                methods: None,
+            },
+            HttpRouteMatch {
+                path_prefix: None,
+                methods: Some(vec!["DELETE".to_string()]),
+            },
+        ];
+
+        let rule = build_canary_match_rule(&matches, &weighted_backend_refs(20)[1]);
+
+        assert_eq!(
+            rule["matches"],
+            serde_json::json!([
+                {"path": {"type": "PathPrefix", "value": "/beta"}},
+                {"method": "DELETE"}
+            ])
+        );
+    }
 }
@@ -4,8 +4,11 @@
 //! No traffic splitting - just deploy, monitor metrics, and emit events.
 
 use super::{RolloutStrategy, StrategyError};
-use crate::controller::rollout::{build_replicaset_for_simple, ensure_replicaset_exists, Context};
-use crate::crd::rollout::{Phase, Rollout, RolloutStatus};
+use crate::controller::rollout::{
+    adopt_legacy_simple_replicaset, build_replicaset_for_simple, ensure_replicaset_exists,
+    record_decision, Context,
+};
+use crate::crd::rollout::{DecisionAction, DecisionReason, Phase, Rollout, RolloutStatus};
 use async_trait::async_trait;
 use k8s_openapi::api::apps::v1::ReplicaSet;
 use kube::api::Api;
@@ -51,6 +54,10 @@ impl RolloutStrategy for SimpleStrategyHandler {
         // Create ReplicaSet API client
         let rs_api: Api<ReplicaSet> = Api::namespaced(ctx.client.clone(), &namespace);
 
+        // Keep managing a ReplicaSet created before the -simple suffix
+        let legacy = rs_api.get_opt(&name).await?;
+        let rs = adopt_legacy_simple_replicaset(rollout, rs, legacy.as_ref());
+
         // Ensure ReplicaSet exists (idempotent)
         ensure_replicaset_exists(&rs_api, &rs, "simple", rollout.spec.replicas)
             .await
@@ -77,7 +84,8 @@ impl RolloutStrategy for SimpleStrategyHandler {
 
     fn compute_next_status(&self, rollout: &Rollout) -> RolloutStatus {
         // Simple strategy always completes immediately (no steps)
-        RolloutStatus {
+        let previous = rollout.status.as_ref();
+        let mut status = RolloutStatus {
             phase: Some(Phase::Completed),
             current_step_index: None,
             current_weight: None,
@@ -88,10 +96,41 @@ impl RolloutStrategy for SimpleStrategyHandler {
             replicas: rollout.spec.replicas,
             ready_replicas: 0,
             updated_replicas: 0,
+            stable_replicas: 0,
+            canary_replicas: 0,
             pause_start_time: None,
+            pause_start_generation: None,
+            started_at: None,
             step_start_time: None,
-            decisions: vec![],
+            decisions: previous.map(|s| s.decisions.clone()).unwrap_or_default(),
+            restarted_at: None,
+            conditions: vec![],
+            observed_generation: None,
+            revision_history: previous
+                .map(|s| s.revision_history.clone())
+                .unwrap_or_default(),
+            stable_revision_hash: None,
+            canary_revision_hash: None,
+            rolled_back_to: None,
+            consecutive_analysis_failures: 0,
+            last_analysis_time: None,
+            last_analysis_result: None,
+            dry_run_plan: None,
+            transition_log: previous
+                .map(|s| s.transition_log.clone())
+                .unwrap_or_default(),
+        };
+
+        if previous.and_then(|s| s.phase.as_ref()) != Some(&Phase::Completed) {
+            record_decision(
+                &mut status,
+                DecisionAction::Complete,
+                DecisionReason::StepReady,
+                None,
+                None,
+            );
         }
+        status
     }
 
     fn supports_metrics_analysis(&self) -> bool {
@@ -117,6 +156,7 @@ mod tests {
     };
     use k8s_openapi::api::core::v1::PodTemplateSpec;
     use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+    use std::collections::BTreeMap;
 
     fn create_simple_rollout(replicas: i32, with_analysis: bool) -> Rollout {
         let analysis = if with_analysis {
@@ -126,13 +166,20 @@ mod tests {
                 }),
                 failure_policy: None,
                 warmup_duration: None,
+                interval: None,
                 metrics: vec![MetricConfig {
                     name: "error-rate".to_string(),
                     threshold: 5.0,
                     interval: None,
                     failure_threshold: None,
                     min_sample_size: None,
+                    comparison: None,
+                    labels: BTreeMap::new(),
                 }],
+                requirement: None,
+                consecutive_failure_threshold: None,
+                mode: None,
+                tolerance: None,
             })
         } else {
             None
@@ -153,6 +200,10 @@ mod tests {
                     canary: None,
                     blue_green: None,
                 },
+                manage_pod_template_hash: None,
+                quota_aware: None,
+                dry_run: None,
+                paused: None,
             },
             status: None,
         }
@@ -1,5 +1,6 @@
-use k8s_openapi::api::core::v1::PodTemplateSpec;
+use k8s_openapi::api::core::v1::{PodTemplateSpec, ResourceRequirements};
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use kube::CustomResource;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -21,6 +22,7 @@ use serde::{Deserialize, Serialize};
     printcolumn = r#"{"name":"Weight", "type":"integer", "jsonPath":".status.currentWeight"}"#,
     printcolumn = r#"{"name":"Age", "type":"date", "jsonPath":".metadata.creationTimestamp"}"#
 )]
+#[schemars(extend("x-kubernetes-preserve-unknown-fields" = false))]
 pub struct RolloutSpec {
     /// Number of desired pods
     #[serde(default = "default_replicas")]
@@ -34,13 +36,55 @@ pub struct RolloutSpec {
 
     /// Deployment strategy (currently only canary)
     pub strategy: RolloutStrategy,
+
+    /// Whether to inject the `pod-template-hash` label (default: true)
+    ///
+    /// Set to false when a GitOps tool manages that label itself. The hash is
+    /// then recorded in the `rollouts.kulta.io/pod-template-hash` annotation.
+    #[serde(
+        rename = "managePodTemplateHash",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub manage_pod_template_hash: Option<bool>,
+
+    /// Hold canary steps that would exceed the namespace's ResourceQuotas (default: false)
+    ///
+    /// Before a step scales the canary up, the extra CPU/memory/pods are
+    /// checked against every ResourceQuota in the namespace. If they don't
+    /// fit, the rollout stays at its current step with QuotaExceeded=True and
+    /// resumes once headroom is available.
+    #[serde(rename = "quotaAware", skip_serializing_if = "Option::is_none")]
+    pub quota_aware: Option<bool>,
+
+    /// Preview what reconcile would do without applying it (default: false)
+    ///
+    /// The ReplicaSets, traffic weights and status the controller would apply
+    /// are logged and recorded in status.dryRunPlan; nothing else is written.
+    #[serde(rename = "dryRun", skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+
+    /// Hold the rollout where it is (default: false)
+    ///
+    /// Unlike a step pause, this defers the whole rollout: the ReplicaSets are
+    /// still created, but no step is taken and traffic weights are left alone
+    /// until it is set back to false. Canary only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paused: Option<bool>,
 }
 
 fn default_replicas() -> i32 {
     1
 }
 
+/// Deployment strategy
+///
+/// At most one strategy may be set. The CEL rule rejects violations at
+/// admission time so they are never persisted.
 #[derive(Serialize, Deserialize, Clone, Debug, Default, JsonSchema)]
+#[schemars(extend("x-kubernetes-validations" = [{
+    "rule": "[has(self.simple), has(self.canary), has(self.blueGreen)].filter(x, x).size() <= 1",
+    "message": "only one strategy may be configured (simple, canary, blueGreen)"
+}]))]
 pub struct RolloutStrategy {
     /// Simple deployment strategy (rolling update with observability)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -102,6 +146,10 @@ pub struct BlueGreenStrategy {
     /// Analysis configuration for automated metrics-based rollback
     #[serde(skip_serializing_if = "Option::is_none")]
     pub analysis: Option<AnalysisConfig>,
+
+    /// Maximum rollout duration in seconds before it is marked Failed
+    #[serde(rename = "maxDurationSeconds", skip_serializing_if = "Option::is_none")]
+    pub max_duration_seconds: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
@@ -114,6 +162,11 @@ pub struct CanaryStrategy {
     #[serde(rename = "stableService")]
     pub stable_service: String,
 
+    /// Name of a service that receives a mirrored copy of all traffic
+    /// (via Gateway API RequestMirror filter, does not consume traffic weight)
+    #[serde(rename = "mirrorService", skip_serializing_if = "Option::is_none")]
+    pub mirror_service: Option<String>,
+
     /// Steps define the canary rollout progression
     #[serde(default)]
     pub steps: Vec<CanaryStep>,
@@ -125,6 +178,175 @@ pub struct CanaryStrategy {
     /// Analysis configuration for automated metrics-based rollback
     #[serde(skip_serializing_if = "Option::is_none")]
     pub analysis: Option<AnalysisConfig>,
+
+    /// Maximum rollout duration in seconds before it is marked Failed
+    #[serde(rename = "maxDurationSeconds", skip_serializing_if = "Option::is_none")]
+    pub max_duration_seconds: Option<u64>,
+
+    /// Overrides applied to the canary pod template only (stable is unaffected)
+    #[serde(rename = "canaryOverrides", skip_serializing_if = "Option::is_none")]
+    pub canary_overrides: Option<CanaryOverrides>,
+
+    /// Suffix for the canary ReplicaSet name: {rollout}-{suffix} (default: "canary")
+    #[serde(rename = "canarySuffix", skip_serializing_if = "Option::is_none")]
+    pub canary_suffix: Option<String>,
+
+    /// Suffix for the stable ReplicaSet name: {rollout}-{suffix} (default: "stable")
+    #[serde(rename = "stableSuffix", skip_serializing_if = "Option::is_none")]
+    pub stable_suffix: Option<String>,
+
+    /// Run the canary at a fixed scale without splitting live traffic
+    ///
+    /// Traffic routing is never patched; metric analysis (e.g. from synthetic
+    /// probes) alone gates promotion or rollback.
+    #[serde(rename = "analysisOnly", skip_serializing_if = "Option::is_none")]
+    pub analysis_only: Option<bool>,
+
+    /// Named Service port used for Gateway API backendRefs (e.g. "http")
+    ///
+    /// Resolved to its number from the stable and canary Services on each
+    /// reconcile. Defaults to port 80 when unset or not found.
+    #[serde(rename = "portName", skip_serializing_if = "Option::is_none")]
+    pub port_name: Option<String>,
+
+    /// Hold the rollout at this step index until promoted
+    ///
+    /// Applies regardless of the step's own pause config; the promote
+    /// annotation releases the hold.
+    #[serde(rename = "pauseAtStep", skip_serializing_if = "Option::is_none")]
+    pub pause_at_step: Option<i32>,
+
+    /// Create the stable and canary Services if they don't exist (default: false)
+    ///
+    /// Each Service selects spec.selector plus the `rollouts.kulta.io/type`
+    /// label of its ReplicaSet. Existing Services are never modified.
+    #[serde(rename = "createServices", skip_serializing_if = "Option::is_none")]
+    pub create_services: Option<bool>,
+
+    /// Minimum canary replicas while the rollout is in progress
+    ///
+    /// Applies at weights between 0 and 100 (exclusive); released at 0% and
+    /// 100%. If both floors exceed spec.replicas, the total surges above it.
+    #[serde(rename = "minCanaryReplicas", skip_serializing_if = "Option::is_none")]
+    pub min_canary_replicas: Option<i32>,
+
+    /// Minimum stable replicas while the rollout is in progress
+    ///
+    /// Applies at weights between 0 and 100 (exclusive); released at 0% and
+    /// 100%.
+    #[serde(rename = "minStableReplicas", skip_serializing_if = "Option::is_none")]
+    pub min_stable_replicas: Option<i32>,
+
+    /// Keep the stable ReplicaSet at spec.replicas until the rollout completes
+    ///
+    /// The canary scales up on top of the stable fleet instead of replacing
+    /// it, so rollback is instant at the cost of running more pods in total.
+    /// Stable is only scaled down once the canary reaches 100%.
+    #[serde(
+        rename = "keepStableAtFullScale",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub keep_stable_at_full_scale: Option<bool>,
+
+    /// Additional canary tracks receiving traffic alongside the canary (A/B/C testing)
+    ///
+    /// Each track routes a fixed weight to its own Service from its stepIndex
+    /// on, taken from the stable weight. Gateway API routing only; the
+    /// Services and their pods are managed outside the Rollout.
+    #[serde(rename = "extraCanaries", skip_serializing_if = "Option::is_none")]
+    pub extra_canaries: Option<Vec<ExtraCanary>>,
+
+    /// Largest allowed weight increase between consecutive steps
+    ///
+    /// Guards against risky jumps such as steps [5, 100]. Validated when the
+    /// rollout is reconciled, not at admission; decreases are always allowed.
+    #[serde(rename = "maxWeightStep", skip_serializing_if = "Option::is_none")]
+    pub max_weight_step: Option<i32>,
+
+    /// Smallest allowed weight for the first step
+    ///
+    /// Catches low-traffic starts (e.g. steps [1, ...]) that never give the
+    /// analysis a meaningful signal. Validated when the rollout is reconciled,
+    /// not at admission.
+    #[serde(rename = "minInitialWeight", skip_serializing_if = "Option::is_none")]
+    pub min_initial_weight: Option<i32>,
+
+    /// What happens to the canary ReplicaSet once the rollout completed
+    ///
+    /// "retain" (default) keeps it at 0 replicas for a fast rollback,
+    /// "delete" removes it to free quota.
+    #[serde(rename = "onComplete", skip_serializing_if = "Option::is_none")]
+    pub on_complete: Option<OnComplete>,
+
+    /// Surge canary pods above spec.replicas instead of taking them from stable
+    ///
+    /// Stable stays at spec.replicas while the canary scales up on top of it
+    /// (bounded by maxSurge), like a Deployment's surge. Stable is scaled
+    /// down once the canary reaches 100%.
+    #[serde(rename = "replicaSurge", skip_serializing_if = "Option::is_none")]
+    pub replica_surge: Option<bool>,
+
+    /// With replicaSurge: the most canary pods run above spec.replicas
+    ///
+    /// An integer or a percentage of spec.replicas (rounded up), default "100%".
+    #[serde(rename = "maxSurge", skip_serializing_if = "Option::is_none")]
+    pub max_surge: Option<IntOrString>,
+
+    /// Cookie set by the canary backend so returning users can stay on the canary
+    ///
+    /// Added as a Gateway API ResponseHeaderModifier filter on the canary
+    /// backendRef only; an HTTPRoute rule routes requests carrying the cookie
+    /// to the canary (requires trafficRouting.gatewayAPI).
+    #[serde(rename = "stickyCookie", skip_serializing_if = "Option::is_none")]
+    pub sticky_cookie: Option<StickyCookie>,
+}
+
+/// Session cookie marking responses served by the canary
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct StickyCookie {
+    /// Cookie name; the canary sets `<name>=canary`
+    pub name: String,
+
+    /// Cookie lifetime in seconds (Max-Age); a session cookie when unset
+    #[serde(rename = "maxAgeSeconds", skip_serializing_if = "Option::is_none")]
+    pub max_age_seconds: Option<i64>,
+}
+
+/// What a completed canary rollout does with its emptied ReplicaSet
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub enum OnComplete {
+    /// Keep the ReplicaSet, scaled to 0
+    #[default]
+    #[serde(rename = "retain")]
+    Retain,
+    /// Delete the ReplicaSet
+    #[serde(rename = "delete")]
+    Delete,
+}
+
+/// Additional canary track in a multi-track canary
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
+pub struct ExtraCanary {
+    /// Service receiving this track's traffic
+    pub service: String,
+
+    /// Percentage of traffic (0-100) routed to the service once active
+    pub weight: i32,
+
+    /// Step index from which the track receives traffic
+    #[serde(rename = "stepIndex")]
+    pub step_index: i32,
+}
+
+/// Canary pod template overrides
+///
+/// Lets teams run canary pods with different settings than stable,
+/// e.g. tighter resource limits to surface regressions early.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
+pub struct CanaryOverrides {
+    /// Resource requests/limits applied to every container in the canary pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ResourceRequirements>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
@@ -136,6 +358,10 @@ pub struct CanaryStep {
     /// Pause the rollout
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pause: Option<PauseDuration>,
+
+    /// Analysis configuration for this step (overrides strategy-level analysis)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis: Option<AnalysisConfig>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
@@ -151,6 +377,17 @@ pub struct TrafficRouting {
     /// Gateway API configuration (KULTA-specific)
     #[serde(rename = "gatewayAPI", skip_serializing_if = "Option::is_none")]
     pub gateway_api: Option<GatewayAPIRouting>,
+
+    /// Linkerd (SMI TrafficSplit) configuration (canary strategy)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linkerd: Option<LinkerdRouting>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
+pub struct LinkerdRouting {
+    /// Name of the SMI TrafficSplit to manipulate
+    #[serde(rename = "trafficSplitName")]
+    pub traffic_split_name: String,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
@@ -158,6 +395,57 @@ pub struct GatewayAPIRouting {
     /// Name of the HTTPRoute to manipulate
     #[serde(rename = "httpRoute")]
     pub http_route: String,
+
+    /// HTTPRoute API version (v1 or v1beta1); detected from the cluster when unset
+    #[serde(rename = "gatewayApiVersion", skip_serializing_if = "Option::is_none")]
+    pub gateway_api_version: Option<GatewayApiVersion>,
+
+    /// Hold canary steps until a parent Gateway has accepted the HTTPRoute (default: false)
+    #[serde(rename = "verifyAccepted", skip_serializing_if = "Option::is_none")]
+    pub verify_accepted: Option<bool>,
+
+    /// Requests routed to the canary regardless of the current weight
+    ///
+    /// Adds a second HTTPRoute rule matching any of these conditions with the
+    /// canary as its only backend, while the canary receives traffic. The
+    /// first rule keeps the weighted stable/canary split. Canary strategy only.
+    #[serde(rename = "canaryMatch", skip_serializing_if = "Option::is_none")]
+    pub canary_match: Option<Vec<HttpRouteMatch>>,
+}
+
+/// Request condition routing matching requests to the canary
+///
+/// Both fields must match when set; at least one is required.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct HttpRouteMatch {
+    /// Path prefix to match (e.g. "/api/v2")
+    #[serde(rename = "pathPrefix", skip_serializing_if = "Option::is_none")]
+    pub path_prefix: Option<String>,
+
+    /// HTTP methods to match (e.g. ["GET", "POST"]); any method when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub methods: Option<Vec<String>>,
+}
+
+/// Gateway API version serving HTTPRoute
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+pub enum GatewayApiVersion {
+    /// gateway.networking.k8s.io/v1 (Gateway API v1.0+)
+    #[serde(rename = "v1")]
+    V1,
+    /// gateway.networking.k8s.io/v1beta1 (older Gateway API releases)
+    #[serde(rename = "v1beta1")]
+    V1beta1,
+}
+
+impl GatewayApiVersion {
+    /// Version string as used in apiVersion (e.g. "v1beta1")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GatewayApiVersion::V1 => "v1",
+            GatewayApiVersion::V1beta1 => "v1beta1",
+        }
+    }
 }
 
 /// What to do when Prometheus is unreachable during analysis
@@ -187,9 +475,51 @@ pub struct AnalysisConfig {
     #[serde(rename = "warmupDuration", skip_serializing_if = "Option::is_none")]
     pub warmup_duration: Option<String>,
 
+    /// How often metrics are queried while a run passes (e.g., "30s", "1m")
+    ///
+    /// Within the interval the last passing run is reused instead of querying
+    /// Prometheus again. Unset queries on every reconcile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+
     /// List of metrics to monitor
     #[serde(default)]
     pub metrics: Vec<MetricConfig>,
+
+    /// How many metrics must pass: "all" (default), "any", or a count (e.g., 2)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requirement: Option<IntOrString>,
+
+    /// Consecutive failed analysis runs before rolling back (default: 1)
+    ///
+    /// Unlike a metric's failureThreshold, this counts whole analysis runs.
+    #[serde(
+        rename = "consecutiveFailureThreshold",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub consecutive_failure_threshold: Option<i32>,
+
+    /// How metrics are judged: against their thresholds (default) or canary vs stable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<AnalysisMode>,
+
+    /// canary-vs-stable: how much worse than stable the canary may be, as a
+    /// fraction of the stable value (default: 0, e.g. 0.1 = 10% worse)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolerance: Option<f64>,
+}
+
+/// How an analysis judges its metrics
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub enum AnalysisMode {
+    /// Each canary metric is compared with its threshold
+    #[default]
+    #[serde(rename = "threshold")]
+    Threshold,
+    /// Each metric is queried for canary and stable; the canary must be no
+    /// worse than stable within the tolerance (thresholds are not used)
+    #[serde(rename = "canary-vs-stable")]
+    CanaryVsStable,
 }
 
 /// Prometheus configuration
@@ -220,6 +550,26 @@ pub struct MetricConfig {
     /// Minimum sample size required for metric evaluation
     #[serde(rename = "minSampleSize", skip_serializing_if = "Option::is_none")]
     pub min_sample_size: Option<i32>,
+
+    /// Which side of the threshold is healthy (default: LessThan, GreaterThan for request-rate)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comparison: Option<MetricComparison>,
+
+    /// Extra label matchers added to the query (e.g. service, cluster, version)
+    ///
+    /// Each selector of the template gets `key="value"` appended; `rollout`
+    /// and `revision` are set by the template and can't be overridden.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub labels: std::collections::BTreeMap<String, String>,
+}
+
+/// Which side of a metric's threshold is healthy
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+pub enum MetricComparison {
+    /// Healthy below the threshold (error rates, latencies)
+    LessThan,
+    /// Healthy above the threshold (request rates, throughput)
+    GreaterThan,
 }
 
 /// Phase of a Rollout
@@ -227,19 +577,32 @@ pub struct MetricConfig {
 /// Represents the current lifecycle stage of the rollout
 #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
 pub enum Phase {
-    /// Initial phase when rollout is being set up
+    /// First reconcile: status recorded before any ReplicaSet is created
     #[default]
     Initializing,
     /// Rollout is actively progressing through canary steps
     Progressing,
     /// Rollout is paused waiting for manual promotion or duration
     Paused,
+    /// Canary ReplicaSet is scaling to its desired replica count for the current weight
+    Scaling,
+    /// Step advancement is held until the canary ReplicaSet's pods are ready
+    WaitingForReadiness,
+    /// Analysis is warming up and holding step advancement
+    WaitingForAnalysis,
     /// Blue-green: Preview environment ready, awaiting promotion
     Preview,
     /// Rollout successfully completed (100% canary or promoted blue-green)
     Completed,
     /// Rollout failed and requires manual intervention
     Failed,
+    /// Metric analysis failed: canary traffic and replicas are being drained to stable
+    RollingBack,
+    /// Rollback finished: canary fully drained, stable serving all traffic
+    ///
+    /// Also reached when an external error forced the rollback (Prometheus
+    /// unreachable with failurePolicy: Rollback).
+    Degraded,
 }
 
 /// Action taken by the controller
@@ -261,6 +624,16 @@ pub enum DecisionAction {
     Resume,
     /// Rollout completed successfully
     Complete,
+    /// Rollout stopped before completion (abort annotation or timeout)
+    Abort,
+    /// Step held while its analysis warms up
+    AnalysisStart,
+    /// Step analysis no longer blocking, progression resumed
+    AnalysisComplete,
+    /// Failed step analysis bypassed by the skip-analysis annotation
+    AnalysisSkipped,
+    /// Progression held by a non-fatal problem that clears on its own
+    Warning,
 }
 
 /// Reason for the decision
@@ -282,6 +655,20 @@ pub enum DecisionReason {
     Timeout,
     /// Initial rollout setup
     Initialization,
+    /// Current step is ready and has no pause or analysis to wait for
+    StepReady,
+    /// Analysis has a warmupDuration that has not elapsed yet
+    AnalysisWarmup,
+    /// Metrics could not be evaluated (e.g., Prometheus unreachable)
+    MetricsUnavailable,
+    /// The next step's canary scale-up would exceed a ResourceQuota
+    QuotaExceeded,
+    /// The skip-analysis annotation advanced the step without evaluating metrics
+    SkipAnalysisAnnotation,
+    /// The pod template changed mid-rollout, superseding the in-flight canary
+    RevisionSuperseded,
+    /// The HTTPRoute has not been accepted by a parent Gateway (verifyAccepted)
+    RouteNotAccepted,
 }
 
 /// Metric snapshot at decision time
@@ -310,7 +697,12 @@ pub struct Decision {
 
 /// Status of the Rollout
 #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[schemars(extend("x-kubernetes-preserve-unknown-fields" = false))]
 pub struct RolloutStatus {
+    /// Most recent metadata.generation processed by the controller
+    #[serde(rename = "observedGeneration", skip_serializing_if = "Option::is_none")]
+    pub observed_generation: Option<i64>,
+
     /// Total number of non-terminated pods
     #[serde(default)]
     pub replicas: i32,
@@ -323,6 +715,14 @@ pub struct RolloutStatus {
     #[serde(rename = "updatedReplicas", default)]
     pub updated_replicas: i32,
 
+    /// Number of non-terminated pods in the stable ReplicaSet
+    #[serde(rename = "stableReplicas", default)]
+    pub stable_replicas: i32,
+
+    /// Number of non-terminated pods in the canary ReplicaSet
+    #[serde(rename = "canaryReplicas", default)]
+    pub canary_replicas: i32,
+
     /// Current canary step index (0-indexed)
     #[serde(rename = "currentStepIndex", skip_serializing_if = "Option::is_none")]
     pub current_step_index: Option<i32>,
@@ -343,14 +743,227 @@ pub struct RolloutStatus {
     #[serde(rename = "pauseStartTime", skip_serializing_if = "Option::is_none")]
     pub pause_start_time: Option<String>,
 
+    /// Rollout metadata.generation when the current pause started
+    /// Spec edits during a pause do not restart its timer; only a step change does
+    #[serde(
+        rename = "pauseStartGeneration",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pause_start_generation: Option<i64>,
+
+    /// Timestamp when the rollout started (RFC3339 format)
+    /// Used to enforce maxDurationSeconds
+    #[serde(rename = "startedAt", skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+
     /// Timestamp when current step started (RFC3339 format)
     /// Used for warmup duration tracking before metrics analysis begins
     #[serde(rename = "stepStartTime", skip_serializing_if = "Option::is_none")]
     pub step_start_time: Option<String>,
 
-    /// Decision history for observability
+    /// Decision history for observability (most recent last, capped at 100)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub decisions: Vec<Decision>,
+
+    /// Last processed kulta.io/restart annotation value
+    #[serde(rename = "restartedAt", skip_serializing_if = "Option::is_none")]
+    pub restarted_at: Option<String>,
+    /// Current conditions of the Rollout (e.g. QuotaExceeded)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<RolloutCondition>,
+
+    /// Pod templates the stable ReplicaSet has run (most recent last, capped at 10)
+    ///
+    /// Targets for the `kulta.io/rollback-to=<hash>` annotation.
+    #[serde(
+        rename = "revisionHistory",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub revision_history: Vec<RevisionRef>,
+
+    /// pod-template-hash of the stable ReplicaSet, as last observed
+    #[serde(rename = "stableRevisionHash", skip_serializing_if = "Option::is_none")]
+    pub stable_revision_hash: Option<String>,
+
+    /// pod-template-hash of the canary ReplicaSet the current rollout runs
+    ///
+    /// A different hash for spec.template means a newer revision superseded
+    /// the in-flight canary, which restarts from step 0.
+    #[serde(rename = "canaryRevisionHash", skip_serializing_if = "Option::is_none")]
+    pub canary_revision_hash: Option<String>,
+
+    /// Last processed kulta.io/rollback-to annotation value
+    #[serde(rename = "rolledBackTo", skip_serializing_if = "Option::is_none")]
+    pub rolled_back_to: Option<String>,
+
+    /// Consecutive failed analysis runs at the current step (reset when one passes)
+    #[serde(rename = "consecutiveAnalysisFailures", default)]
+    pub consecutive_analysis_failures: i32,
+
+    /// When metrics were last queried (RFC3339), set when analysis.interval is configured
+    #[serde(rename = "lastAnalysisTime", skip_serializing_if = "Option::is_none")]
+    pub last_analysis_time: Option<String>,
+
+    /// Metrics evaluated by the most recent analysis run
+    #[serde(rename = "lastAnalysisResult", skip_serializing_if = "Option::is_none")]
+    pub last_analysis_result: Option<AnalysisResult>,
+
+    /// What reconcile would apply, while spec.dryRun is true
+    #[serde(rename = "dryRunPlan", skip_serializing_if = "Option::is_none")]
+    pub dry_run_plan: Option<DryRunPlan>,
+
+    /// Phase/step transitions for replaying the rollout (most recent last, capped at 200)
+    #[serde(
+        rename = "transitionLog",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub transition_log: Vec<StatusTransition>,
+}
+
+/// Operations a dry-run reconcile would have performed
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DryRunPlan {
+    /// Phase the rollout would move to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<Phase>,
+
+    /// Step the rollout would move to
+    #[serde(rename = "currentStepIndex", skip_serializing_if = "Option::is_none")]
+    pub current_step_index: Option<i32>,
+
+    /// Canary weight the rollout would move to
+    #[serde(rename = "currentWeight", skip_serializing_if = "Option::is_none")]
+    pub current_weight: Option<i32>,
+
+    /// Writes that would be made, in order (e.g. "ReplicaSet my-app-canary: 2 replicas")
+    #[serde(default)]
+    pub operations: Vec<String>,
+}
+
+/// Outcome of one analysis run
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AnalysisResult {
+    /// When the analysis ran (RFC3339)
+    pub timestamp: String,
+
+    /// Whether the analysis passed (false if it failed or could not run)
+    pub healthy: bool,
+
+    /// Per-metric results, in spec order
+    #[serde(rename = "metricResults", default)]
+    pub metric_results: Vec<MetricResult>,
+
+    /// Consecutive failed analysis runs after this one
+    #[serde(rename = "consecutiveFailures", default)]
+    pub consecutive_failures: i32,
+}
+
+/// Result of evaluating one metric
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MetricResult {
+    /// Metric template name
+    pub name: String,
+
+    pub status: MetricResultStatus,
+
+    /// Value returned by Prometheus
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+
+    pub threshold: f64,
+
+    /// Why the metric could not be evaluated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Outcome of evaluating one metric
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+pub enum MetricResultStatus {
+    /// Value within the threshold
+    Passed,
+    /// Value outside the threshold
+    Failed,
+    /// Not evaluated (query failed)
+    Skipped,
+}
+
+/// One phase or step change of a Rollout
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StatusTransition {
+    /// When the transition was computed (RFC3339)
+    pub timestamp: String,
+
+    #[serde(rename = "fromPhase", skip_serializing_if = "Option::is_none")]
+    pub from_phase: Option<Phase>,
+
+    #[serde(rename = "toPhase")]
+    pub to_phase: Phase,
+
+    #[serde(rename = "fromStep", skip_serializing_if = "Option::is_none")]
+    pub from_step: Option<i32>,
+
+    #[serde(rename = "toStep", skip_serializing_if = "Option::is_none")]
+    pub to_step: Option<i32>,
+
+    /// What caused the transition
+    pub trigger: TransitionTrigger,
+}
+
+/// What caused a status transition
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub enum TransitionTrigger {
+    /// First status of the rollout
+    Initialization,
+    /// The controller moved on by itself (step ready, readiness gate cleared, ...)
+    AutoProgression,
+    /// User promoted the rollout (promote annotation or declarative promotion)
+    ManualPromotion,
+    /// Metrics analysis passed, failed or could not run
+    AnalysisResult,
+    /// A timed pause elapsed
+    PauseExpired,
+    /// User aborted or rolled back the rollout
+    Abort,
+}
+
+/// A pod template the stable ReplicaSet has run
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RevisionRef {
+    /// pod-template-hash of the stable pod template
+    pub hash: String,
+
+    /// Container images by container name
+    pub images: std::collections::BTreeMap<String, String>,
+
+    /// When this template became stable (RFC3339)
+    #[serde(rename = "recordedAt", skip_serializing_if = "Option::is_none")]
+    pub recorded_at: Option<String>,
+}
+
+/// Condition of a Rollout, in the style of Kubernetes object conditions
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RolloutCondition {
+    /// Condition type (e.g. "QuotaExceeded")
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// "True", "False" or "Unknown"
+    pub status: String,
+
+    /// Machine-readable reason for the condition's last transition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// Human-readable details
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// When the condition last changed status (RFC3339)
+    #[serde(rename = "lastTransitionTime", skip_serializing_if = "Option::is_none")]
+    pub last_transition_time: Option<String>,
 }
 
 #[cfg(test)]
@@ -1,22 +1,30 @@
 use futures::StreamExt;
+use k8s_openapi::api::apps::v1::ReplicaSet;
+use kube::api::ListParams;
 use kube::runtime::controller::Action;
 use kube::runtime::{watcher, Controller};
-use kube::{Api, Client};
-use kulta::controller::cdevents::CDEventsSink;
+use kube::{Api, Client, ResourceExt};
 use kulta::controller::prometheus::PrometheusClient;
+use kulta::controller::rollout::{backed_off_error_requeue_interval, is_terminal_status};
+use kulta::controller::status_batcher::{KubeStatusPatcher, StatusPatcher};
 use kulta::controller::{reconcile, Context, ReconcileError};
 use kulta::crd::rollout::Rollout;
 use kulta::server::{
-    create_metrics, run_health_server, run_leader_election, shutdown_channel, wait_for_signal,
-    LeaderConfig, LeaderState, ReadinessState,
+    create_metrics, drain_timeout_from_env, run_health_server, run_leader_election,
+    shutdown_channel, wait_for_signal, Heartbeat, LeaderConfig, LeaderState, ReadinessState,
+    RolloutDebugView, ShutdownReason,
 };
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Duration;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 /// Default port for health endpoints
 const HEALTH_PORT: u16 = 8080;
 
+/// Label selector for the ReplicaSets KULTA manages (watched via `owns`)
+const MANAGED_REPLICASET_SELECTOR: &str = "rollouts.kulta.io/managed=true";
+
 /// Check if leader election is enabled via env var
 fn is_leader_election_enabled() -> bool {
     std::env::var("KULTA_LEADER_ELECTION")
@@ -24,14 +32,177 @@ fn is_leader_election_enabled() -> bool {
         .unwrap_or(false)
 }
 
+/// Check if the startup summary is enabled via env var (default: on)
+fn is_startup_summary_enabled() -> bool {
+    std::env::var("KULTA_STARTUP_SUMMARY")
+        .map(|v| !(v == "false" || v == "0"))
+        .unwrap_or(true)
+}
+
+/// Phase label for Rollouts that have no status yet
+const UNKNOWN_PHASE: &str = "Unknown";
+
+/// Counts of existing Rollouts by phase, logged once at startup
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StartupSummary {
+    /// Total number of Rollouts found
+    pub total: usize,
+    /// Rollout count per phase (sorted by phase name for stable log output)
+    pub by_phase: BTreeMap<String, usize>,
+}
+
+/// Summarize Rollouts by phase
+///
+/// Rollouts without a status (or without a phase) are counted as "Unknown",
+/// which usually means the controller has not reconciled them yet.
+pub fn summarize_rollouts(rollouts: &[Rollout]) -> StartupSummary {
+    let mut summary = StartupSummary {
+        total: rollouts.len(),
+        ..Default::default()
+    };
+    for rollout in rollouts {
+        let phase = rollout
+            .status
+            .as_ref()
+            .and_then(|status| status.phase.as_ref())
+            .map(|phase| format!("{:?}", phase))
+            .unwrap_or_else(|| UNKNOWN_PHASE.to_string());
+        *summary.by_phase.entry(phase).or_insert(0) += 1;
+    }
+    summary
+}
+
+/// List existing Rollouts once and log a phase summary
+///
+/// Best-effort: a failed list is logged and startup continues, since the
+/// controller's own watch will surface the same resources anyway.
+async fn log_startup_summary(rollouts: &Api<Rollout>) {
+    let list = match rollouts.list(&Default::default()).await {
+        Ok(list) => list,
+        Err(e) => {
+            warn!(error = %e, "Failed to list Rollouts for startup summary");
+            return;
+        }
+    };
+
+    for rollout in &list.items {
+        debug!(
+            rollout = ?rollout.metadata.name,
+            namespace = ?rollout.metadata.namespace,
+            phase = ?rollout.status.as_ref().and_then(|s| s.phase.as_ref()),
+            "Found existing Rollout"
+        );
+    }
+
+    let summary = summarize_rollouts(&list.items);
+    info!(
+        total = summary.total,
+        phases = ?summary.by_phase,
+        "Startup summary of existing Rollouts"
+    );
+}
+
+/// How often readiness checks are re-evaluated
+const READINESS_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How often the watched Rollouts are checked for /livez (staleness only counts if any exist)
+const HEARTBEAT_WATCH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Readiness check: the Kubernetes API server answers
+const CHECK_KUBERNETES_CONNECTED: &str = "kubernetes_connected";
+/// Readiness check: the Rollout CRD is installed (Rollouts can be listed)
+const CHECK_CRD_INSTALLED: &str = "crd_installed";
+/// Readiness check: leader election state (only with leader election)
+const CHECK_LEADER_STATE: &str = "leader_state";
+/// Readiness check: Prometheus answers queries (only when configured)
+const CHECK_PROMETHEUS_REACHABLE: &str = "prometheus_reachable";
+
+/// Clients used to evaluate readiness checks
+struct ReadinessProbes {
+    client: Client,
+    rollouts: Api<Rollout>,
+    leader_state: Option<LeaderState>,
+    prometheus: Option<Arc<PrometheusClient>>,
+}
+
+impl ReadinessProbes {
+    /// Register every applicable check as failing until first evaluated
+    fn register(&self, readiness: &ReadinessState) {
+        readiness.add_check(CHECK_KUBERNETES_CONNECTED, false, "not checked yet");
+        readiness.add_check(CHECK_CRD_INSTALLED, false, "not checked yet");
+        if self.leader_state.is_some() {
+            readiness.add_check(CHECK_LEADER_STATE, false, "not checked yet");
+        }
+        if self.prometheus.is_some() {
+            readiness.add_check(CHECK_PROMETHEUS_REACHABLE, false, "not checked yet");
+        }
+    }
+
+    /// Evaluate every registered check once
+    async fn refresh(&self, readiness: &ReadinessState) {
+        match self.client.apiserver_version().await {
+            Ok(version) => readiness.update_check(
+                CHECK_KUBERNETES_CONNECTED,
+                true,
+                &format!("API server {}", version.git_version),
+            ),
+            Err(e) => readiness.update_check(CHECK_KUBERNETES_CONNECTED, false, &e.to_string()),
+        };
+
+        match self.rollouts.list(&ListParams::default().limit(1)).await {
+            Ok(_) => readiness.update_check(CHECK_CRD_INSTALLED, true, "Rollout CRD available"),
+            Err(e) => readiness.update_check(CHECK_CRD_INSTALLED, false, &e.to_string()),
+        };
+
+        if let Some(leader_state) = &self.leader_state {
+            // Followers are ready too (they may take over at any time); the
+            // check reports the current role
+            let role = if leader_state.is_leader() {
+                "leader"
+            } else {
+                "follower"
+            };
+            readiness.update_check(CHECK_LEADER_STATE, true, role);
+        }
+
+        if let Some(prometheus) = &self.prometheus {
+            match prometheus.query_instant("vector(1)").await {
+                Ok(_) => readiness.update_check(CHECK_PROMETHEUS_REACHABLE, true, "query ok"),
+                Err(e) => readiness.update_check(CHECK_PROMETHEUS_REACHABLE, false, &e.to_string()),
+            };
+        }
+    }
+}
+
 /// Error policy for the controller
 ///
 /// Determines how to handle reconciliation errors:
-/// - Requeue after delay (exponential backoff)
+/// - Record error metric labeled by category
+/// - Requeue after a category-specific delay (5s validation, 10s API and other errors),
+///   doubled for each consecutive failure of the rollout (capped at 5m), or the rollout's
+///   `<domain>/requeue-error-seconds` annotation when set
+/// - Cancelled reconciles (shutdown) are not errors: wait for the next change
 ///
 /// Uses `warn!` since reconciliation errors are expected and trigger retries.
 pub fn error_policy(rollout: Arc<Rollout>, error: &ReconcileError, ctx: Arc<Context>) -> Action {
-    warn!("Reconcile error (will retry): {:?}", error);
+    // Cancelled by shutdown: not a failure, the next leader/instance picks it up
+    if matches!(error, ReconcileError::Cancelled) {
+        info!(rollout = ?rollout.name_any(), "Reconcile cancelled by shutdown");
+        return Action::await_change();
+    }
+
+    let category = error.category();
+    let failures = ctx
+        .error_backoff
+        .record_failure(&rollout.uid().unwrap_or_else(|| rollout.name_any()));
+    let requeue_interval = backed_off_error_requeue_interval(&rollout, error, failures);
+    warn!(
+        category = category,
+        consecutive_failures = failures,
+        requeue_secs = requeue_interval.as_secs(),
+        "Reconcile error (will retry): {:?}",
+        error
+    );
 
     // Record error metric
     if let Some(ref metrics) = ctx.metrics {
@@ -45,9 +216,10 @@ pub fn error_policy(rollout: Arc<Rollout>, error: &ReconcileError, ctx: Arc<Cont
         };
         // Duration unknown for errors (didn't complete), use 0
         metrics.record_reconciliation_error(strategy, 0.0);
+        metrics.record_reconcile_error_category(category);
     }
 
-    Action::requeue(Duration::from_secs(10))
+    Action::requeue(requeue_interval)
 }
 
 #[tokio::main]
@@ -68,18 +240,35 @@ async fn main() -> anyhow::Result<()> {
     // Create readiness state (initially not ready)
     let readiness = ReadinessState::new();
 
+    // Reconcile loop heartbeat, served by /livez
+    let heartbeat = Heartbeat::from_env();
+
+    // Rollout cache view for /debug/rollouts (attached once the controller starts)
+    let debug_view = RolloutDebugView::new();
+
     // Create metrics registry
     let metrics = create_metrics().expect("Failed to create metrics registry");
     info!("Prometheus metrics registry initialized");
 
-    // Create leader state
-    let leader_state = LeaderState::new();
+    // Create leader state (reports kulta_is_leader for this instance)
+    let leader_config = LeaderConfig::from_env();
+    let leader_state = LeaderState::with_metrics(metrics.clone(), &leader_config.holder_id);
 
     // Start health server in background
     let health_readiness = readiness.clone();
+    let health_heartbeat = heartbeat.clone();
+    let health_debug = debug_view.clone();
     let health_metrics = metrics.clone();
     let health_handle = tokio::spawn(async move {
-        if let Err(e) = run_health_server(HEALTH_PORT, health_readiness, health_metrics).await {
+        if let Err(e) = run_health_server(
+            HEALTH_PORT,
+            health_readiness,
+            health_heartbeat,
+            health_debug,
+            health_metrics,
+        )
+        .await
+        {
             warn!(error = %e, "Health server failed");
         }
     });
@@ -102,7 +291,6 @@ async fn main() -> anyhow::Result<()> {
     let leader_election_enabled = is_leader_election_enabled();
     let leader_handle = if leader_election_enabled {
         let leader_client = client.clone();
-        let leader_config = LeaderConfig::from_env();
         let leader_state_clone = leader_state.clone();
         let leader_shutdown = shutdown_signal.clone();
 
@@ -130,41 +318,69 @@ async fn main() -> anyhow::Result<()> {
     // Create API for Rollout resources
     let rollouts = Api::<Rollout>::all(client.clone());
 
-    // Create CDEvents sink (configured from env vars)
-    let cdevents_sink = CDEventsSink::new();
-    info!(
-        enabled = std::env::var("KULTA_CDEVENTS_ENABLED").unwrap_or_else(|_| "false".to_string()),
-        "CDEvents sink configured"
-    );
-
-    // Create Prometheus client (configured from env var)
-    let prometheus_address =
-        std::env::var("KULTA_PROMETHEUS_ADDRESS").unwrap_or_else(|_| "".to_string());
-    let prometheus_client = if prometheus_address.is_empty() {
-        info!("Prometheus address not configured - metrics analysis disabled");
-        PrometheusClient::new("http://localhost:9090".to_string()) // Dummy address, metrics will be skipped
-    } else {
-        info!(address = %prometheus_address, "Prometheus client configured");
-        PrometheusClient::new(prometheus_address)
+    // Create controller context (validates env config, fails fast before reconciling)
+    let ctx = match Context::try_new(
+        client.clone(),
+        leader_election_enabled.then(|| leader_state.clone()),
+        Some(metrics.clone()),
+    ) {
+        Ok(ctx) => Arc::new(
+            ctx.with_cancellation(shutdown_controller.cancellation_token())
+                .with_heartbeat(heartbeat.clone()),
+        ),
+        Err(e) => {
+            error!(error = %e, "Invalid controller configuration");
+            if let Some(handle) = leader_handle {
+                handle.abort();
+            }
+            health_handle.abort();
+            return Err(e);
+        }
     };
 
-    // Create controller context (with metrics for observability)
-    let ctx = if leader_election_enabled {
-        Arc::new(Context::new_with_leader(
-            client.clone(),
-            cdevents_sink,
-            prometheus_client,
-            leader_state.clone(),
-            Some(metrics.clone()),
-        ))
-    } else {
-        Arc::new(Context::new(
-            client.clone(),
-            cdevents_sink,
-            prometheus_client,
+    // Flush batched status updates in the background
+    let status_batcher = ctx.status_batcher.clone();
+    let status_patcher: Arc<dyn StatusPatcher> = Arc::new(KubeStatusPatcher::new(
+        client.clone(),
+        ctx.field_manager.clone(),
+    ));
+    let batcher_handle = status_batcher.clone().map(|batcher| {
+        info!(
+            interval_ms = batcher.interval().as_millis() as u64,
+            "Status update batching enabled"
+        );
+        batcher.spawn(
+            status_patcher.clone(),
             Some(metrics.clone()),
-        ))
+            ctx.reconcile_cache.clone(),
+        )
+    });
+
+    if is_startup_summary_enabled() {
+        log_startup_summary(&rollouts).await;
+    }
+
+    // Readiness checks: evaluate once now, then keep refreshing in background
+    let prometheus_configured = std::env::var("KULTA_PROMETHEUS_ADDRESS")
+        .map(|v| !v.is_empty())
+        .unwrap_or(false);
+    let probes = ReadinessProbes {
+        client: client.clone(),
+        rollouts: rollouts.clone(),
+        leader_state: leader_election_enabled.then(|| leader_state.clone()),
+        prometheus: prometheus_configured.then(|| ctx.prometheus_client.clone()),
     };
+    probes.register(&readiness);
+    probes.refresh(&readiness).await;
+    let probe_readiness = readiness.clone();
+    let readiness_handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(READINESS_CHECK_INTERVAL);
+        interval.tick().await; // First tick fires immediately; already refreshed
+        loop {
+            interval.tick().await;
+            probes.refresh(&probe_readiness).await;
+        }
+    });
 
     // Mark as ready - controller is initialized and about to start
     //
@@ -177,30 +393,110 @@ async fn main() -> anyhow::Result<()> {
     info!("Controller ready, starting reconciliation loop");
 
     // Create the controller stream
+    // Each reconciliation holds a budget permit so shutdown can drain it;
+    // once shutdown is signaled no new reconciliation starts.
     // Note: error_policy already logs errors with warn!, so we only log success here
-    let controller = Controller::new(rollouts, watcher::Config::default())
-        .run(reconcile, error_policy, ctx)
-        .for_each(|res| async move {
-            if let Ok(o) = res {
-                info!("Reconciled: {:?}", o);
+    let budget = shutdown_controller.reconcile_budget();
+    //
+    // Owned ReplicaSets (KULTA-managed only) are watched too, so an external
+    // delete or scale re-reconciles the owning Rollout right away.
+    let replicasets = Api::<ReplicaSet>::all(client.clone());
+    let controller = Controller::new(rollouts, watcher::Config::default()).owns(
+        replicasets,
+        watcher::Config::default().labels(MANAGED_REPLICASET_SELECTOR),
+    );
+
+    // /livez only fails on a stale heartbeat while there are rollouts to reconcile.
+    // Terminal rollouts only requeue every KULTA_TERMINAL_REQUEUE, so they don't count.
+    let rollout_store = controller.store();
+    debug_view.attach(rollout_store.clone());
+    let watch_heartbeat = heartbeat.clone();
+    let heartbeat_handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(HEARTBEAT_WATCH_INTERVAL);
+        loop {
+            interval.tick().await;
+            let in_progress = rollout_store
+                .state()
+                .iter()
+                .any(|rollout| !rollout.status.as_ref().is_some_and(is_terminal_status));
+            watch_heartbeat.set_rollouts_present(in_progress);
+        }
+    });
+
+    let stream_heartbeat = heartbeat.clone();
+    let controller = controller
+        .run(
+            move |rollout, ctx| {
+                let guard = budget.begin();
+                async move {
+                    let Some(_guard) = guard else {
+                        return Ok(Action::await_change());
+                    };
+                    // A successful reconcile ends the rollout's error backoff
+                    let uid = rollout.uid().unwrap_or_else(|| rollout.name_any());
+                    let error_backoff = ctx.error_backoff.clone();
+                    let result = reconcile(rollout, ctx).await;
+                    if result.is_ok() {
+                        error_backoff.reset(&uid);
+                    }
+                    result
+                }
+            },
+            error_policy,
+            ctx,
+        )
+        .for_each(move |res| {
+            stream_heartbeat.beat();
+            async move {
+                if let Ok(o) = res {
+                    info!("Reconciled: {:?}", o);
+                }
+                // Errors are logged in error_policy, no duplicate logging
             }
-            // Errors are logged in error_policy, no duplicate logging
         });
 
+    // Run the controller in its own task so in-flight reconciliations keep
+    // making progress while we drain them
+    let mut controller_handle = tokio::spawn(controller);
+
     // Run controller until shutdown signal received
-    tokio::select! {
-        _ = controller => {
-            info!("Controller stream ended");
+    let reason = tokio::select! {
+        _ = &mut controller_handle => {
+            ShutdownReason::Error("controller stream ended".to_string())
         }
         signal = wait_for_signal() => {
-            info!(signal = signal, "Initiating graceful shutdown");
             // Mark not ready so K8s stops sending traffic during shutdown
             readiness.set_not_ready();
+            ShutdownReason::Signal(signal.to_string())
         }
+    };
+    info!(reason = %reason, "Initiating graceful shutdown");
+
+    // Trigger shutdown for all components, draining in-flight reconciliations
+    match reason {
+        ShutdownReason::Signal(_) => {
+            shutdown_controller
+                .shutdown_with_drain(reason, drain_timeout_from_env())
+                .await;
+        }
+        _ => shutdown_controller.shutdown_with_reason(reason),
     }
+    controller_handle.abort();
 
-    // Trigger shutdown for all components
-    shutdown_controller.shutdown();
+    // Write status updates queued by the drained reconciliations
+    if let Some(handle) = batcher_handle {
+        handle.abort();
+    }
+    if let Some(batcher) = status_batcher {
+        let stats = batcher
+            .flush(status_patcher.as_ref(), Some(metrics.as_ref()), None)
+            .await;
+        info!(
+            patched = stats.patched,
+            failed = stats.failed,
+            "Flushed pending status updates"
+        );
+    }
 
     // Graceful shutdown sequence
     info!("Stopping components...");
@@ -208,6 +504,8 @@ async fn main() -> anyhow::Result<()> {
     if let Some(handle) = leader_handle {
         handle.abort();
     }
+    readiness_handle.abort();
+    heartbeat_handle.abort();
     health_handle.abort();
 
     info!("KULTA controller shut down gracefully");
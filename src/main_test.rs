@@ -1,17 +1,109 @@
+use super::*;
+
 #[test]
 fn test_error_policy_returns_requeue() {
+    use kulta::controller::rollout::calculate_error_requeue_interval;
+    use kulta::controller::ReconcileError;
     use std::time::Duration;
-    // Test that error_policy function returns correct requeue duration
+    // Test that error_policy uses the error's category-specific requeue interval
     // The function signature is:
     //   pub fn error_policy(_rollout: Arc<Rollout>, error: &ReconcileError, _ctx: Arc<Context>) -> Action
     //
-    // It always returns: Action::requeue(Duration::from_secs(10))
+    // It returns: Action::requeue(calculate_error_requeue_interval(error)), backed off
     // This test verifies the expected behavior without calling the function
     // (to avoid needing a real Kubernetes client/context in unit tests)
 
     let expected_requeue_duration = Duration::from_secs(10);
 
-    // Verify the duration matches what error_policy returns
-    // This is a smoke test to ensure the constant hasn't changed
-    assert_eq!(expected_requeue_duration, Duration::from_secs(10));
+    // Verify the default duration (non-validation, non-API errors) hasn't changed
+    assert_eq!(
+        calculate_error_requeue_interval(&ReconcileError::MissingName),
+        expected_requeue_duration
+    );
+}
+
+fn rollout_in_phase(name: &str, phase: Option<kulta::crd::rollout::Phase>) -> Rollout {
+    use kulta::crd::rollout::{RolloutSpec, RolloutStatus};
+
+    let mut rollout = Rollout::new(
+        name,
+        RolloutSpec {
+            replicas: 1,
+            selector: Default::default(),
+            template: Default::default(),
+            strategy: Default::default(),
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
+        },
+    );
+    rollout.status = phase.map(|phase| RolloutStatus {
+        phase: Some(phase),
+        ..Default::default()
+    });
+    rollout
+}
+
+#[test]
+fn test_summarize_rollouts_empty() {
+    let summary = summarize_rollouts(&[]);
+
+    assert_eq!(summary.total, 0);
+    assert!(summary.by_phase.is_empty());
+}
+
+#[test]
+fn test_summarize_rollouts_counts_phases() {
+    use kulta::crd::rollout::Phase;
+
+    let rollouts = vec![
+        rollout_in_phase("a", Some(Phase::Progressing)),
+        rollout_in_phase("b", Some(Phase::Progressing)),
+        rollout_in_phase("c", Some(Phase::Completed)),
+        rollout_in_phase("d", Some(Phase::Paused)),
+    ];
+
+    let summary = summarize_rollouts(&rollouts);
+
+    assert_eq!(summary.total, 4);
+    assert_eq!(summary.by_phase.get("Progressing"), Some(&2));
+    assert_eq!(summary.by_phase.get("Completed"), Some(&1));
+    assert_eq!(summary.by_phase.get("Paused"), Some(&1));
+    assert_eq!(summary.by_phase.len(), 3);
+}
+
+#[test]
+fn test_summarize_rollouts_without_status_counted_as_unknown() {
+    use kulta::crd::rollout::Phase;
+
+    let mut no_phase = rollout_in_phase("no-phase", None);
+    no_phase.status = Some(Default::default());
+    let rollouts = vec![
+        rollout_in_phase("new", None),
+        no_phase,
+        rollout_in_phase("done", Some(Phase::Completed)),
+    ];
+
+    let summary = summarize_rollouts(&rollouts);
+
+    assert_eq!(summary.total, 3);
+    assert_eq!(summary.by_phase.get("Unknown"), Some(&2));
+    assert_eq!(summary.by_phase.get("Completed"), Some(&1));
+}
+
+#[test]
+fn test_startup_summary_enabled_by_default() {
+    temp_env::with_var_unset("KULTA_STARTUP_SUMMARY", || {
+        assert!(is_startup_summary_enabled());
+    });
+    temp_env::with_var("KULTA_STARTUP_SUMMARY", Some("false"), || {
+        assert!(!is_startup_summary_enabled());
+    });
+    temp_env::with_var("KULTA_STARTUP_SUMMARY", Some("0"), || {
+        assert!(!is_startup_summary_enabled());
+    });
+    temp_env::with_var("KULTA_STARTUP_SUMMARY", Some("true"), || {
+        assert!(is_startup_summary_enabled());
+    });
 }
@@ -0,0 +1,76 @@
+//! Debug view of the controller's rollout cache, served by `/debug/rollouts`
+//!
+//! Reads the controller's reflector store, so the endpoint never calls the
+//! Kubernetes API. Until the controller attaches its store (e.g. while the
+//! client is still connecting) the view is unavailable.
+
+use crate::crd::rollout::{Phase, Rollout, StatusTransition};
+use kube::runtime::reflector::Store;
+use kube::ResourceExt;
+use serde::Serialize;
+use std::sync::{Arc, RwLock};
+
+/// Shared handle to the rollout store, set once the controller starts
+///
+/// Clone is cheap; clones share the same store.
+#[derive(Clone, Default)]
+pub struct RolloutDebugView {
+    store: Arc<RwLock<Option<Store<Rollout>>>>,
+}
+
+impl RolloutDebugView {
+    /// Create a view with no store attached
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attach the controller's rollout store
+    pub fn attach(&self, store: Store<Rollout>) {
+        if let Ok(mut guard) = self.store.write() {
+            *guard = Some(store);
+        }
+    }
+
+    /// Snapshot every cached rollout, sorted by namespace and name
+    ///
+    /// Returns None while no store is attached.
+    pub fn rollouts(&self) -> Option<Vec<RolloutDebugEntry>> {
+        let guard = self.store.read().ok()?;
+        let store = guard.as_ref()?;
+
+        let mut entries: Vec<RolloutDebugEntry> = store
+            .state()
+            .iter()
+            .map(|rollout| RolloutDebugEntry::from_rollout(rollout))
+            .collect();
+        entries.sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)));
+        Some(entries)
+    }
+}
+
+/// One rollout in the /debug/rollouts response
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RolloutDebugEntry {
+    pub namespace: String,
+    pub name: String,
+    pub phase: Option<Phase>,
+    pub current_step_index: Option<i32>,
+    pub current_weight: Option<i32>,
+    /// Full status.transitionLog, oldest first
+    pub transition_log: Vec<StatusTransition>,
+}
+
+impl RolloutDebugEntry {
+    fn from_rollout(rollout: &Rollout) -> Self {
+        let status = rollout.status.as_ref();
+        Self {
+            namespace: rollout.namespace().unwrap_or_default(),
+            name: rollout.name_any(),
+            phase: status.and_then(|s| s.phase.clone()),
+            current_step_index: status.and_then(|s| s.current_step_index),
+            current_weight: status.and_then(|s| s.current_weight),
+            transition_log: status.map(|s| s.transition_log.clone()).unwrap_or_default(),
+        }
+    }
+}
@@ -1,36 +1,215 @@
 //! Health check and metrics endpoints for Kubernetes probes
 //!
 //! - `/healthz` - Liveness: Is the process alive?
-//! - `/readyz` - Readiness: Is the controller ready to handle requests?
+//! - `/livez` - Liveness: Is the reconcile loop still making progress? (JSON heartbeat)
+//! - `/readyz` - Readiness: Is the controller ready to handle requests? (JSON check results)
 //! - `/metrics` - Prometheus metrics in text format
+//! - `/debug/rollouts` - Cached rollouts with their status transition logs (JSON)
+//!
+//! Every request is bounded by a timeout and a max-in-flight limit so a
+//! misbehaving scraper cannot exhaust the server and starve liveness probes.
+//! Requests are counted and timed per endpoint (`kulta_http_requests_total`,
+//! `kulta_http_request_duration_seconds`) to spot probe spam or misconfiguration.
 
+use crate::server::debug::RolloutDebugView;
 use crate::server::metrics::SharedMetrics;
 use axum::{
-    extract::State,
+    extract::{MatchedPath, Request, State},
     http::{header::CONTENT_TYPE, StatusCode},
-    response::IntoResponse,
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
     routing::get,
-    Router,
+    Json, Router,
 };
+use serde::Serialize;
+use std::collections::HashMap;
 use std::net::SocketAddr;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 use tokio::net::TcpListener;
-use tracing::info;
+use tokio::sync::Semaphore;
+use tracing::{info, warn};
+
+/// Default time a single health/metrics request may take
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default number of requests served concurrently
+pub const DEFAULT_MAX_IN_FLIGHT: usize = 32;
+
+/// Default time without a heartbeat before /livez reports the loop as wedged
+///
+/// Longer than the 5 minute maximum requeue interval, so an idle but healthy
+/// loop still beats in time.
+pub const DEFAULT_HEARTBEAT_STALENESS: Duration = Duration::from_secs(600);
+
+/// Request limits for the health server
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthServerLimits {
+    /// Requests taking longer are terminated with 408 Request Timeout
+    pub request_timeout: Duration,
+    /// Requests beyond this many in flight are rejected with 503
+    pub max_in_flight: usize,
+}
+
+impl HealthServerLimits {
+    /// Read limits from the environment
+    ///
+    /// - KULTA_HEALTH_REQUEST_TIMEOUT_SECS (default: 5)
+    /// - KULTA_HEALTH_MAX_IN_FLIGHT (default: 32)
+    ///
+    /// Invalid values (including 0) fall back to the default with a warning.
+    pub fn from_env() -> Self {
+        let request_timeout = env_positive_u64("KULTA_HEALTH_REQUEST_TIMEOUT_SECS")
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_REQUEST_TIMEOUT);
+        let max_in_flight = env_positive_u64("KULTA_HEALTH_MAX_IN_FLIGHT")
+            .map(|v| v as usize)
+            .unwrap_or(DEFAULT_MAX_IN_FLIGHT);
+
+        Self {
+            request_timeout,
+            max_in_flight,
+        }
+    }
+}
+
+impl Default for HealthServerLimits {
+    fn default() -> Self {
+        Self {
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            max_in_flight: DEFAULT_MAX_IN_FLIGHT,
+        }
+    }
+}
+
+/// Read a positive integer env var, warning (and returning None) if invalid
+fn env_positive_u64(name: &str) -> Option<u64> {
+    let value = std::env::var(name).ok()?;
+    match value.trim().parse::<u64>() {
+        Ok(parsed) if parsed > 0 => Some(parsed),
+        _ => {
+            warn!(variable = name, value = %value, "Invalid value, using default");
+            None
+        }
+    }
+}
+
+/// Heartbeat of the reconcile loop, served by /livez
+///
+/// Reconciles beat at their start and the controller stream beats as each
+/// one finishes. While rollouts exist, a heartbeat older than the staleness
+/// window means the loop is wedged and the pod should be restarted. With no
+/// rollouts there is nothing to reconcile, so the loop is always live
+/// (the controller doesn't count terminal rollouts, which requeue rarely).
+/// Clone is cheap; clones share the same heartbeat.
+#[derive(Debug, Clone)]
+pub struct Heartbeat {
+    started: Instant,
+    /// Milliseconds since `started` at the last beat
+    last_beat_ms: Arc<AtomicU64>,
+    rollouts_present: Arc<AtomicBool>,
+    staleness: Duration,
+}
+
+impl Heartbeat {
+    /// Create a heartbeat with the given staleness window (creation counts as a beat)
+    pub fn new(staleness: Duration) -> Self {
+        Self {
+            started: Instant::now(),
+            last_beat_ms: Arc::new(AtomicU64::new(0)),
+            rollouts_present: Arc::new(AtomicBool::new(false)),
+            staleness,
+        }
+    }
+
+    /// Read the staleness window from KULTA_LIVENESS_STALENESS_SECS (default: 600)
+    ///
+    /// Invalid values (including 0) fall back to the default with a warning.
+    pub fn from_env() -> Self {
+        Self::new(
+            env_positive_u64("KULTA_LIVENESS_STALENESS_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_HEARTBEAT_STALENESS),
+        )
+    }
+
+    /// Record that the reconcile loop made progress
+    pub fn beat(&self) {
+        let elapsed_ms = self.started.elapsed().as_millis() as u64;
+        self.last_beat_ms.store(elapsed_ms, Ordering::SeqCst);
+    }
+
+    /// Record whether any rollouts exist (staleness only matters if they do)
+    pub fn set_rollouts_present(&self, present: bool) {
+        self.rollouts_present.store(present, Ordering::SeqCst);
+    }
+
+    /// Time since the last beat
+    pub fn age(&self) -> Duration {
+        let last_beat = Duration::from_millis(self.last_beat_ms.load(Ordering::SeqCst));
+        self.started.elapsed().saturating_sub(last_beat)
+    }
+
+    /// Whether the loop is live: no rollouts, or a beat within the staleness window
+    pub fn is_live(&self) -> bool {
+        !self.rollouts_present.load(Ordering::SeqCst) || self.age() <= self.staleness
+    }
+
+    /// JSON body for the /livez endpoint
+    fn report(&self) -> LivenessReport {
+        LivenessReport {
+            live: self.is_live(),
+            rollouts_present: self.rollouts_present.load(Ordering::SeqCst),
+            heartbeat_age_seconds: self.age().as_secs(),
+            staleness_seconds: self.staleness.as_secs(),
+        }
+    }
+}
+
+impl Default for Heartbeat {
+    fn default() -> Self {
+        Self::new(DEFAULT_HEARTBEAT_STALENESS)
+    }
+}
+
+/// Response body of /livez
+#[derive(Debug, Serialize)]
+struct LivenessReport {
+    live: bool,
+    rollouts_present: bool,
+    heartbeat_age_seconds: u64,
+    staleness_seconds: u64,
+}
+
+/// Result of one named readiness check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReadinessCheck {
+    /// Check name (e.g. "kubernetes_connected")
+    pub name: String,
+    /// Whether the check currently passes
+    pub passing: bool,
+    /// Human-readable detail (error message when failing)
+    pub message: String,
+}
 
 /// Shared state for readiness tracking
 ///
 /// The controller sets this to ready once it's fully initialized
-/// and connected to the Kubernetes API.
+/// and connected to the Kubernetes API. Registered checks must also all
+/// pass for the controller to be considered ready.
 #[derive(Debug, Clone)]
 pub struct ReadinessState {
     ready: Arc<std::sync::atomic::AtomicBool>,
+    checks: Arc<RwLock<HashMap<String, ReadinessCheck>>>,
 }
 
 impl ReadinessState {
-    /// Create a new readiness state (initially not ready)
+    /// Create a new readiness state (initially not ready, no checks)
     pub fn new() -> Self {
         Self {
             ready: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            checks: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -47,10 +226,88 @@ impl ReadinessState {
         self.ready.store(false, std::sync::atomic::Ordering::SeqCst);
     }
 
-    /// Check if the controller is ready
+    /// Check if the controller is ready (initialized and every check passing)
     pub fn is_ready(&self) -> bool {
         self.ready.load(std::sync::atomic::Ordering::SeqCst)
+            && self.checks().iter().all(|check| check.passing)
+    }
+
+    /// Register a check (replaces any existing check with the same name)
+    pub fn add_check(&self, name: &str, passing: bool, message: &str) {
+        if let Ok(mut checks) = self.checks.write() {
+            checks.insert(
+                name.to_string(),
+                ReadinessCheck {
+                    name: name.to_string(),
+                    passing,
+                    message: message.to_string(),
+                },
+            );
+        }
+    }
+
+    /// Update a registered check's result
+    ///
+    /// Returns false (and changes nothing) if no check has that name.
+    pub fn update_check(&self, name: &str, passing: bool, message: &str) -> bool {
+        match self.checks.write() {
+            Ok(mut checks) => match checks.get_mut(name) {
+                Some(check) => {
+                    check.passing = passing;
+                    check.message = message.to_string();
+                    true
+                }
+                None => false,
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// Replace a registered check's message without changing its result
+    ///
+    /// Returns false if no check has that name.
+    pub fn set_detail(&self, name: &str, message: &str) -> bool {
+        match self.checks.write() {
+            Ok(mut checks) => match checks.get_mut(name) {
+                Some(check) => {
+                    check.message = message.to_string();
+                    true
+                }
+                None => false,
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// Snapshot of all registered checks, sorted by name
+    pub fn checks(&self) -> Vec<ReadinessCheck> {
+        let mut checks: Vec<ReadinessCheck> = self
+            .checks
+            .read()
+            .map(|checks| checks.values().cloned().collect())
+            .unwrap_or_default();
+        checks.sort_by(|a, b| a.name.cmp(&b.name));
+        checks
     }
+
+    /// JSON body for the /readyz endpoint
+    fn report(&self) -> ReadinessReport {
+        let checks = self.checks();
+        let initialized = self.ready.load(std::sync::atomic::Ordering::SeqCst);
+        ReadinessReport {
+            ready: initialized && checks.iter().all(|check| check.passing),
+            initialized,
+            checks,
+        }
+    }
+}
+
+/// Response body of /readyz
+#[derive(Debug, Serialize)]
+struct ReadinessReport {
+    ready: bool,
+    initialized: bool,
+    checks: Vec<ReadinessCheck>,
 }
 
 impl Default for ReadinessState {
@@ -63,13 +320,25 @@ impl Default for ReadinessState {
 #[derive(Clone)]
 pub struct ServerState {
     readiness: ReadinessState,
+    heartbeat: Heartbeat,
+    debug: RolloutDebugView,
     metrics: SharedMetrics,
 }
 
 impl ServerState {
     /// Create new server state
-    pub fn new(readiness: ReadinessState, metrics: SharedMetrics) -> Self {
-        Self { readiness, metrics }
+    pub fn new(
+        readiness: ReadinessState,
+        heartbeat: Heartbeat,
+        debug: RolloutDebugView,
+        metrics: SharedMetrics,
+    ) -> Self {
+        Self {
+            readiness,
+            heartbeat,
+            debug,
+            metrics,
+        }
     }
 }
 
@@ -80,14 +349,46 @@ async fn healthz() -> StatusCode {
     StatusCode::OK
 }
 
+/// Reconcile loop liveness handler
+///
+/// Returns 200 OK while the heartbeat is fresh (or no rollouts exist), 503
+/// Service Unavailable once it is stale, with a JSON heartbeat report.
+async fn livez(State(state): State<ServerState>) -> impl IntoResponse {
+    let report = state.heartbeat.report();
+    let status = if report.live {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(report))
+}
+
 /// Readiness probe handler
 ///
-/// Returns 200 OK if ready, 503 Service Unavailable if not.
-async fn readyz(State(state): State<ServerState>) -> StatusCode {
-    if state.readiness.is_ready() {
+/// Returns 200 OK if ready, 503 Service Unavailable if not, with a JSON
+/// body listing each check's result.
+async fn readyz(State(state): State<ServerState>) -> impl IntoResponse {
+    let report = state.readiness.report();
+    let status = if report.ready {
         StatusCode::OK
     } else {
         StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(report))
+}
+
+/// Rollout debug handler
+///
+/// Returns 200 OK with every cached rollout and its transition log, or 503
+/// Service Unavailable until the controller has attached its store.
+async fn debug_rollouts(State(state): State<ServerState>) -> Response {
+    match state.debug.rollouts() {
+        Some(rollouts) => (StatusCode::OK, Json(rollouts)).into_response(),
+        None => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Rollout cache not available yet",
+        )
+            .into_response(),
     }
 }
 
@@ -110,37 +411,157 @@ async fn metrics(State(state): State<ServerState>) -> impl IntoResponse {
     }
 }
 
+/// Terminate requests that exceed the configured timeout
+async fn enforce_timeout(
+    State(timeout): State<Duration>,
+    request: Request,
+    next: Next,
+) -> Response {
+    match tokio::time::timeout(timeout, next.run(request)).await {
+        Ok(response) => response,
+        Err(_) => StatusCode::REQUEST_TIMEOUT.into_response(),
+    }
+}
+
+/// Reject requests once the max-in-flight limit is reached
+///
+/// Sheds load immediately rather than queueing, so a burst of slow
+/// requests cannot pile up behind each other.
+async fn enforce_in_flight_limit(
+    State(permits): State<Arc<Semaphore>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    match permits.try_acquire_owned() {
+        Ok(_permit) => next.run(request).await,
+        Err(_) => StatusCode::SERVICE_UNAVAILABLE.into_response(),
+    }
+}
+
+/// Path label for requests that matched no route (keeps label cardinality bounded)
+const UNMATCHED_PATH: &str = "unmatched";
+
+/// Record each request's count and duration, labeled by its route
+///
+/// Runs outside the limits, so requests rejected with 408/503 are counted too.
+async fn record_http_metrics(
+    State(metrics): State<SharedMetrics>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| UNMATCHED_PATH.to_string());
+    let method = request.method().to_string();
+    let start = Instant::now();
+
+    let response = next.run(request).await;
+
+    metrics.record_http_request(
+        &path,
+        &method,
+        response.status().as_u16(),
+        start.elapsed().as_secs_f64(),
+    );
+    response
+}
+
+/// Build the health server's router: endpoints, request limits and request metrics
+pub(crate) fn build_router(
+    readiness: ReadinessState,
+    heartbeat: Heartbeat,
+    debug: RolloutDebugView,
+    metrics: SharedMetrics,
+    limits: HealthServerLimits,
+) -> Router {
+    let state = ServerState::new(readiness, heartbeat, debug, metrics.clone());
+
+    let router = Router::new()
+        .route("/healthz", get(healthz))
+        .route("/livez", get(livez))
+        .route("/readyz", get(readyz))
+        .route("/metrics", get(self::metrics))
+        .route("/debug/rollouts", get(debug_rollouts))
+        .with_state(state);
+
+    apply_limits(router, limits).layer(middleware::from_fn_with_state(metrics, record_http_metrics))
+}
+
+/// Wrap a router with the request timeout and max-in-flight limit
+pub(crate) fn apply_limits(router: Router, limits: HealthServerLimits) -> Router {
+    let permits = Arc::new(Semaphore::new(limits.max_in_flight));
+
+    router
+        .layer(middleware::from_fn_with_state(
+            limits.request_timeout,
+            enforce_timeout,
+        ))
+        .layer(middleware::from_fn_with_state(
+            permits,
+            enforce_in_flight_limit,
+        ))
+}
+
 /// Run the health server on the specified port
 ///
 /// This function starts an HTTP server that responds to:
 /// - GET /healthz - Always returns 200 OK (liveness)
-/// - GET /readyz - Returns 200 OK if ready, 503 Service Unavailable if not
+/// - GET /livez - Returns 200 OK while the reconcile loop heartbeat is fresh, 503 if stale
+/// - GET /readyz - Returns 200 OK if ready, 503 Service Unavailable if not (JSON check list)
 /// - GET /metrics - Prometheus metrics in text format
+/// - GET /debug/rollouts - Cached rollouts with their transition logs (JSON)
 ///
 /// # Arguments
 /// * `port` - The port to listen on
 /// * `readiness` - Shared state for readiness tracking
+/// * `heartbeat` - Reconcile loop heartbeat for /livez
+/// * `debug` - Rollout store view for /debug/rollouts
 /// * `metrics` - Shared metrics registry for Prometheus
 ///
+/// Request limits are read from the environment (see `HealthServerLimits::from_env`).
+///
 /// # Returns
 /// This function runs forever until the server is shut down
 pub async fn run_health_server(
     port: u16,
     readiness: ReadinessState,
+    heartbeat: Heartbeat,
+    debug: RolloutDebugView,
     metrics: SharedMetrics,
 ) -> Result<(), std::io::Error> {
-    let state = ServerState::new(readiness, metrics);
+    run_health_server_with_limits(
+        port,
+        readiness,
+        heartbeat,
+        debug,
+        metrics,
+        HealthServerLimits::from_env(),
+    )
+    .await
+}
 
-    let app = Router::new()
-        .route("/healthz", get(healthz))
-        .route("/readyz", get(readyz))
-        .route("/metrics", get(self::metrics))
-        .with_state(state);
+/// Run the health server with explicit request limits
+pub async fn run_health_server_with_limits(
+    port: u16,
+    readiness: ReadinessState,
+    heartbeat: Heartbeat,
+    debug: RolloutDebugView,
+    metrics: SharedMetrics,
+    limits: HealthServerLimits,
+) -> Result<(), std::io::Error> {
+    let app = build_router(readiness, heartbeat, debug, metrics, limits);
 
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
     let listener = TcpListener::bind(addr).await?;
     // Log after successful bind - server is actually listening
-    info!(port = %port, "Health and metrics server listening");
+    info!(
+        port = %port,
+        request_timeout_secs = limits.request_timeout.as_secs(),
+        max_in_flight = limits.max_in_flight,
+        "Health and metrics server listening"
+    );
 
     axum::serve(listener, app)
         .await
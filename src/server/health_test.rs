@@ -3,20 +3,32 @@
 //! TDD Cycle 1: Health server responds to /healthz
 
 use super::*;
+use crate::crd::rollout::{Phase, Rollout, StatusTransition, TransitionTrigger};
 use crate::server::create_metrics;
+use kube::runtime::{reflector, watcher};
 use std::time::Duration;
 
+#[allow(clippy::duplicate_mod)] // Also included by rollout_test.rs
+#[path = "../../tests/common/builders.rs"]
+mod builders;
+use builders::RolloutBuilder;
+
 /// Wait for server to be ready with retry logic
 ///
 /// Retries connection up to max_retries times with exponential backoff.
 /// More reliable than fixed sleep for test environments.
 async fn wait_for_server(port: u16, max_retries: u32) -> reqwest::Client {
+    wait_for_server_path(port, "/healthz", max_retries).await
+}
+
+/// Like `wait_for_server`, polling `path` instead of /healthz
+async fn wait_for_server_path(port: u16, path: &str, max_retries: u32) -> reqwest::Client {
     let client = reqwest::Client::new();
     let mut delay = Duration::from_millis(10);
 
     for attempt in 1..=max_retries {
         match client
-            .get(format!("http://127.0.0.1:{}/healthz", port))
+            .get(format!("http://127.0.0.1:{}{}", port, path))
             .timeout(Duration::from_millis(100))
             .send()
             .await
@@ -43,10 +55,16 @@ async fn test_healthz_returns_200() {
     // Start server in background
     let server_readiness = readiness.clone();
     let server_metrics = metrics.clone();
-    let server_handle =
-        tokio::spawn(
-            async move { run_health_server(port, server_readiness, server_metrics).await },
-        );
+    let server_handle = tokio::spawn(async move {
+        run_health_server(
+            port,
+            server_readiness,
+            Heartbeat::default(),
+            RolloutDebugView::default(),
+            server_metrics,
+        )
+        .await
+    });
 
     // Wait for server to be ready (with retry)
     let client = wait_for_server(port, 10).await;
@@ -79,10 +97,16 @@ async fn test_readyz_returns_503_when_not_ready() {
     // Start server in background
     let server_readiness = readiness.clone();
     let server_metrics = metrics.clone();
-    let server_handle =
-        tokio::spawn(
-            async move { run_health_server(port, server_readiness, server_metrics).await },
-        );
+    let server_handle = tokio::spawn(async move {
+        run_health_server(
+            port,
+            server_readiness,
+            Heartbeat::default(),
+            RolloutDebugView::default(),
+            server_metrics,
+        )
+        .await
+    });
 
     // Wait for server to be ready (with retry)
     let client = wait_for_server(port, 10).await;
@@ -119,10 +143,16 @@ async fn test_readyz_returns_200_when_ready() {
     // Start server in background
     let server_readiness = readiness.clone();
     let server_metrics = metrics.clone();
-    let server_handle =
-        tokio::spawn(
-            async move { run_health_server(port, server_readiness, server_metrics).await },
-        );
+    let server_handle = tokio::spawn(async move {
+        run_health_server(
+            port,
+            server_readiness,
+            Heartbeat::default(),
+            RolloutDebugView::default(),
+            server_metrics,
+        )
+        .await
+    });
 
     // Wait for server to be ready (with retry)
     let client = wait_for_server(port, 10).await;
@@ -176,10 +206,16 @@ async fn test_metrics_returns_prometheus_format() {
     // Start server in background
     let server_readiness = readiness.clone();
     let server_metrics = metrics.clone();
-    let server_handle =
-        tokio::spawn(
-            async move { run_health_server(port, server_readiness, server_metrics).await },
-        );
+    let server_handle = tokio::spawn(async move {
+        run_health_server(
+            port,
+            server_readiness,
+            Heartbeat::default(),
+            RolloutDebugView::default(),
+            server_metrics,
+        )
+        .await
+    });
 
     // Wait for server to be ready (with retry)
     let client = wait_for_server(port, 10).await;
@@ -219,3 +255,478 @@ async fn test_metrics_returns_prometheus_format() {
 
     server_handle.abort();
 }
+
+/// Test that a slow request is terminated and the server stays responsive
+#[tokio::test]
+async fn test_request_exceeding_timeout_is_terminated() {
+    use super::health::{apply_limits, HealthServerLimits};
+    use axum::{routing::get, Router};
+
+    // ARRANGE: Router with a handler slower than the request timeout
+    let limits = HealthServerLimits {
+        request_timeout: Duration::from_millis(100),
+        max_in_flight: 4,
+    };
+    let router = Router::new()
+        .route("/healthz", get(|| async { "ok" }))
+        .route(
+            "/slow",
+            get(|| async {
+                tokio::time::sleep(Duration::from_secs(10)).await;
+                "too late"
+            }),
+        );
+    let app = apply_limits(router, limits);
+
+    let port = 18084;
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", port))
+        .await
+        .expect("bind test listener");
+    let server_handle = tokio::spawn(async move { axum::serve(listener, app).await });
+
+    let client = wait_for_server(port, 10).await;
+
+    // ACT: Slow request is cut off by the server, well before the client timeout
+    let slow = client
+        .get(format!("http://127.0.0.1:{}/slow", port))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("slow request should get a response");
+
+    // ASSERT: 408 Request Timeout, and liveness still answers
+    assert_eq!(slow.status(), 408, "Slow request should time out");
+
+    let healthz = client
+        .get(format!("http://127.0.0.1:{}/healthz", port))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("server should remain responsive");
+    assert_eq!(healthz.status(), 200);
+
+    server_handle.abort();
+}
+
+/// Test that health server limits are read from the environment
+#[test]
+fn test_health_server_limits_from_env() {
+    use super::health::{HealthServerLimits, DEFAULT_MAX_IN_FLIGHT, DEFAULT_REQUEST_TIMEOUT};
+
+    let configured = temp_env::with_vars(
+        [
+            ("KULTA_HEALTH_REQUEST_TIMEOUT_SECS", Some("2")),
+            ("KULTA_HEALTH_MAX_IN_FLIGHT", Some("8")),
+        ],
+        HealthServerLimits::from_env,
+    );
+    assert_eq!(configured.request_timeout, Duration::from_secs(2));
+    assert_eq!(configured.max_in_flight, 8);
+
+    // Zero and garbage fall back to the defaults
+    let invalid = temp_env::with_vars(
+        [
+            ("KULTA_HEALTH_REQUEST_TIMEOUT_SECS", Some("0")),
+            ("KULTA_HEALTH_MAX_IN_FLIGHT", Some("many")),
+        ],
+        HealthServerLimits::from_env,
+    );
+    assert_eq!(invalid.request_timeout, DEFAULT_REQUEST_TIMEOUT);
+    assert_eq!(invalid.max_in_flight, DEFAULT_MAX_IN_FLIGHT);
+}
+
+/// Start the health server on `port` and wait until it answers
+async fn start_health_server(
+    port: u16,
+    readiness: ReadinessState,
+) -> (
+    reqwest::Client,
+    tokio::task::JoinHandle<Result<(), std::io::Error>>,
+) {
+    let metrics = create_metrics().expect("create metrics");
+    let handle = tokio::spawn(async move {
+        run_health_server(
+            port,
+            readiness,
+            Heartbeat::default(),
+            RolloutDebugView::default(),
+            metrics,
+        )
+        .await
+    });
+    let client = wait_for_server(port, 10).await;
+    (client, handle)
+}
+
+/// Test that /readyz lists every registered check in its JSON body
+#[tokio::test]
+async fn test_readyz_body_lists_all_checks() {
+    // ARRANGE: Ready controller with two passing checks
+    let readiness = ReadinessState::new();
+    readiness.add_check("kubernetes_connected", true, "API server v1.30.0");
+    readiness.add_check("crd_installed", true, "Rollout CRD available");
+    readiness.set_ready();
+
+    let port = 18085;
+    let (client, server_handle) = start_health_server(port, readiness).await;
+
+    // ACT
+    let response = client
+        .get(format!("http://127.0.0.1:{}/readyz", port))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to connect to health server");
+
+    // ASSERT: 200 with both checks in the body, sorted by name
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("JSON body");
+    assert_eq!(body["ready"], true);
+    let checks = body["checks"].as_array().expect("checks array");
+    assert_eq!(checks.len(), 2);
+    assert_eq!(checks[0]["name"], "crd_installed");
+    assert_eq!(checks[0]["passing"], true);
+    assert_eq!(checks[1]["name"], "kubernetes_connected");
+    assert_eq!(checks[1]["message"], "API server v1.30.0");
+
+    server_handle.abort();
+}
+
+/// Test that a single failing check makes /readyz return 503
+#[tokio::test]
+async fn test_readyz_returns_503_when_one_check_fails() {
+    // ARRANGE: Initialized, but Prometheus is unreachable
+    let readiness = ReadinessState::new();
+    readiness.add_check("kubernetes_connected", true, "ok");
+    readiness.add_check("crd_installed", true, "ok");
+    readiness.add_check("prometheus_reachable", false, "connection refused");
+    readiness.set_ready();
+
+    let port = 18086;
+    let (client, server_handle) = start_health_server(port, readiness.clone()).await;
+
+    // ACT
+    let response = client
+        .get(format!("http://127.0.0.1:{}/readyz", port))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to connect to health server");
+
+    // ASSERT: 503, and the failing check explains why
+    assert_eq!(response.status(), 503);
+    let body: serde_json::Value = response.json().await.expect("JSON body");
+    assert_eq!(body["ready"], false);
+    assert_eq!(body["initialized"], true);
+    let failing: Vec<_> = body["checks"]
+        .as_array()
+        .expect("checks array")
+        .iter()
+        .filter(|check| check["passing"] == false)
+        .collect();
+    assert_eq!(failing.len(), 1);
+    assert_eq!(failing[0]["name"], "prometheus_reachable");
+    assert_eq!(failing[0]["message"], "connection refused");
+
+    // Recovering the check makes the probe pass again
+    readiness.update_check("prometheus_reachable", true, "query ok");
+    let response = client
+        .get(format!("http://127.0.0.1:{}/readyz", port))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to connect to health server");
+    assert_eq!(response.status(), 200);
+
+    server_handle.abort();
+}
+
+/// Test ReadinessState check management
+#[test]
+fn test_readiness_state_checks() {
+    let state = ReadinessState::new();
+    state.set_ready();
+
+    state.add_check("crd_installed", false, "not checked yet");
+    assert!(!state.is_ready(), "Failing check should block readiness");
+
+    assert!(state.update_check("crd_installed", true, "ok"));
+    assert!(state.is_ready());
+
+    assert!(state.set_detail("crd_installed", "3 Rollouts"));
+    assert_eq!(
+        state.checks(),
+        vec![ReadinessCheck {
+            name: "crd_installed".to_string(),
+            passing: true,
+            message: "3 Rollouts".to_string(),
+        }]
+    );
+
+    // Unknown checks are not created implicitly
+    assert!(!state.update_check("missing", false, "boom"));
+    assert!(!state.set_detail("missing", "boom"));
+    assert!(state.is_ready());
+
+    // Clones share checks
+    let cloned = state.clone();
+    cloned.update_check("crd_installed", false, "list failed");
+    assert!(!state.is_ready());
+}
+
+/// Test that health server requests are counted per path, method and status
+#[tokio::test]
+async fn test_http_metrics_count_healthz_requests() {
+    // ARRANGE: Wait on /readyz so /healthz starts from zero
+    let readiness = ReadinessState::new();
+    let metrics = create_metrics().expect("create metrics");
+    let port = 18087;
+
+    let server_metrics = metrics.clone();
+    let server_handle = tokio::spawn(async move {
+        run_health_server(
+            port,
+            readiness,
+            Heartbeat::default(),
+            RolloutDebugView::default(),
+            server_metrics,
+        )
+        .await
+    });
+    let client = wait_for_server_path(port, "/readyz", 10).await;
+
+    // ACT: 5 liveness probes, then scrape
+    for _ in 0..5 {
+        let response = client
+            .get(format!("http://127.0.0.1:{}/healthz", port))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await
+            .expect("Failed to connect to health server");
+        assert_eq!(response.status(), 200);
+    }
+    let body = client
+        .get(format!("http://127.0.0.1:{}/metrics", port))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to connect to health server")
+        .text()
+        .await
+        .expect("metrics body");
+
+    // ASSERT: exactly the 5 probes, plus a latency histogram for the path
+    assert!(
+        body.contains(r#"kulta_http_requests_total{method="GET",path="/healthz",status="200"} 5"#),
+        "Expected 5 /healthz requests in:\n{}",
+        body
+    );
+    assert!(
+        body.contains(r#"kulta_http_request_duration_seconds_count{path="/healthz"} 5"#),
+        "Expected 5 /healthz latency observations in:\n{}",
+        body
+    );
+    // Readiness polls are counted under their own labels (503: not ready)
+    assert!(body.contains(r#"kulta_http_requests_total{method="GET",path="/readyz",status="503"}"#));
+
+    server_handle.abort();
+}
+
+/// Test that requests to unknown paths share a single label
+#[tokio::test]
+async fn test_http_metrics_label_unmatched_paths() {
+    let metrics = create_metrics().expect("create metrics");
+    let port = 18088;
+
+    let server_metrics = metrics.clone();
+    let server_handle = tokio::spawn(async move {
+        run_health_server(
+            port,
+            ReadinessState::new(),
+            Heartbeat::default(),
+            RolloutDebugView::default(),
+            server_metrics,
+        )
+        .await
+    });
+    let client = wait_for_server(port, 10).await;
+
+    let response = client
+        .get(format!("http://127.0.0.1:{}/does-not-exist", port))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to connect to health server");
+
+    // ASSERT: scraped from the same registry the server records into
+    assert_eq!(response.status(), 404);
+    let body = metrics.encode().expect("encode metrics");
+    assert!(
+        body.contains(r#"kulta_http_requests_total{method="GET",path="unmatched",status="404"} 1"#)
+    );
+
+    server_handle.abort();
+}
+
+/// Test that /livez returns 200 while the reconcile heartbeat is fresh
+#[tokio::test]
+async fn test_livez_returns_200_with_fresh_heartbeat() {
+    let metrics = create_metrics().expect("create metrics");
+    let heartbeat = Heartbeat::new(Duration::from_secs(60));
+    heartbeat.set_rollouts_present(true);
+    heartbeat.beat();
+    let port = 18089;
+
+    let server_heartbeat = heartbeat.clone();
+    let server_handle = tokio::spawn(async move {
+        run_health_server(
+            port,
+            ReadinessState::new(),
+            server_heartbeat,
+            RolloutDebugView::default(),
+            metrics,
+        )
+        .await
+    });
+    let client = wait_for_server(port, 10).await;
+
+    let response = client
+        .get(format!("http://127.0.0.1:{}/livez", port))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to connect to health server");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("JSON body");
+    assert_eq!(body["live"], true);
+    assert_eq!(body["rollouts_present"], true);
+
+    server_handle.abort();
+}
+
+/// Test that /livez returns 503 once the heartbeat is stale while rollouts exist
+#[tokio::test]
+async fn test_livez_returns_503_with_stale_heartbeat() {
+    let metrics = create_metrics().expect("create metrics");
+    let heartbeat = Heartbeat::new(Duration::from_millis(50));
+    heartbeat.set_rollouts_present(true);
+    let port = 18090;
+
+    let server_heartbeat = heartbeat.clone();
+    let server_handle = tokio::spawn(async move {
+        run_health_server(
+            port,
+            ReadinessState::new(),
+            server_heartbeat,
+            RolloutDebugView::default(),
+            metrics,
+        )
+        .await
+    });
+    let client = wait_for_server(port, 10).await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let response = client
+        .get(format!("http://127.0.0.1:{}/livez", port))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to connect to health server");
+
+    assert_eq!(
+        response.status(),
+        503,
+        "Stale heartbeat should fail the liveness probe"
+    );
+    let body: serde_json::Value = response.json().await.expect("JSON body");
+    assert_eq!(body["live"], false);
+
+    server_handle.abort();
+}
+
+/// Test that a stale heartbeat is live when there are no rollouts, and a beat revives it
+#[tokio::test]
+async fn test_heartbeat_staleness_only_counts_with_rollouts() {
+    let heartbeat = Heartbeat::new(Duration::from_millis(20));
+    tokio::time::sleep(Duration::from_millis(40)).await;
+
+    assert!(heartbeat.is_live(), "No rollouts: nothing to reconcile");
+
+    heartbeat.set_rollouts_present(true);
+    assert!(
+        !heartbeat.is_live(),
+        "Rollouts exist and heartbeat is stale"
+    );
+
+    heartbeat.beat();
+    assert!(heartbeat.is_live(), "Fresh beat revives liveness");
+}
+
+/// Test that /debug/rollouts returns 503 until the store is attached, then the transition logs
+#[tokio::test]
+async fn test_debug_rollouts_serves_transition_log() {
+    let metrics = create_metrics().expect("create metrics");
+    let debug_view = RolloutDebugView::new();
+    let port = 18091;
+
+    let server_debug = debug_view.clone();
+    let server_handle = tokio::spawn(async move {
+        run_health_server(
+            port,
+            ReadinessState::new(),
+            Heartbeat::default(),
+            server_debug,
+            metrics,
+        )
+        .await
+    });
+    let client = wait_for_server(port, 10).await;
+    let url = format!("http://127.0.0.1:{}/debug/rollouts", port);
+
+    let response = client
+        .get(&url)
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to connect to health server");
+    assert_eq!(response.status(), 503, "No store attached yet");
+
+    let mut rollout: Rollout = RolloutBuilder::new()
+        .with_name("debug-rollout")
+        .with_namespace("apps")
+        .with_canary_strategy("stable", "canary")
+        .with_step(20, None)
+        .with_status(0, 20, Phase::Progressing)
+        .build();
+    if let Some(status) = rollout.status.as_mut() {
+        status.transition_log = vec![StatusTransition {
+            timestamp: "2026-01-01T00:00:00+00:00".to_string(),
+            from_phase: None,
+            to_phase: Phase::Progressing,
+            from_step: None,
+            to_step: Some(0),
+            trigger: TransitionTrigger::Initialization,
+        }];
+    }
+    let (store, mut writer) = reflector::store::<Rollout>();
+    writer.apply_watcher_event(&watcher::Event::Apply(rollout));
+    debug_view.attach(store);
+
+    let response = client
+        .get(&url)
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .expect("Failed to connect to health server");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("JSON body");
+    assert_eq!(body[0]["namespace"], "apps");
+    assert_eq!(body[0]["name"], "debug-rollout");
+    assert_eq!(body[0]["phase"], "Progressing");
+    assert_eq!(body[0]["transitionLog"][0]["trigger"], "Initialization");
+    assert_eq!(body[0]["transitionLog"][0]["toStep"], 0);
+
+    server_handle.abort();
+}
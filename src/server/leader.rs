@@ -5,6 +5,7 @@
 //!
 //! Implementation uses the coordination.k8s.io/v1 Lease API directly.
 
+use crate::server::SharedMetrics;
 use chrono::Utc;
 use k8s_openapi::api::coordination::v1::Lease;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::MicroTime;
@@ -12,8 +13,8 @@ use kube::api::{Api, Patch, PatchParams, PostParams};
 use kube::Client;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
-use tracing::{debug, info, warn};
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, warn};
 
 /// Default lease TTL (how long leadership is valid)
 pub const DEFAULT_LEASE_TTL: Duration = Duration::from_secs(15);
@@ -21,6 +22,12 @@ pub const DEFAULT_LEASE_TTL: Duration = Duration::from_secs(15);
 /// Default renew interval (should be ~1/3 of TTL)
 pub const DEFAULT_RENEW_INTERVAL: Duration = Duration::from_secs(5);
 
+/// Maximum delay between leader election attempts after repeated errors
+pub const MAX_ELECTION_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Number of consecutive errors after which failures are logged at error level
+pub const REPEATED_FAILURE_THRESHOLD: u32 = 3;
+
 /// Leader election configuration
 #[derive(Clone)]
 pub struct LeaderConfig {
@@ -64,6 +71,10 @@ impl LeaderConfig {
 #[derive(Clone)]
 pub struct LeaderState {
     is_leader: Arc<AtomicBool>,
+    /// Metrics updated on leadership changes (None = no metrics)
+    metrics: Option<SharedMetrics>,
+    /// Instance label for the leader gauge (usually the holder id)
+    instance: String,
 }
 
 impl LeaderState {
@@ -71,9 +82,29 @@ impl LeaderState {
     pub fn new() -> Self {
         Self {
             is_leader: Arc::new(AtomicBool::new(false)),
+            metrics: None,
+            instance: String::new(),
+        }
+    }
+
+    /// Create leader state that reports `kulta_is_leader{instance}`
+    ///
+    /// The gauge starts at 0 so followers are visible before the first
+    /// election attempt.
+    pub fn with_metrics(metrics: SharedMetrics, instance: &str) -> Self {
+        metrics.set_is_leader(instance, false);
+        Self {
+            is_leader: Arc::new(AtomicBool::new(false)),
+            metrics: Some(metrics),
+            instance: instance.to_string(),
         }
     }
 
+    /// Metrics attached to this state, if any
+    pub fn metrics(&self) -> Option<&SharedMetrics> {
+        self.metrics.as_ref()
+    }
+
     /// Check if this instance is currently the leader
     pub fn is_leader(&self) -> bool {
         self.is_leader.load(Ordering::SeqCst)
@@ -85,6 +116,9 @@ impl LeaderState {
     /// running in single-instance mode (no leader election).
     pub fn set_leader(&self, is_leader: bool) {
         self.is_leader.store(is_leader, Ordering::SeqCst);
+        if let Some(ref metrics) = self.metrics {
+            metrics.set_is_leader(&self.instance, is_leader);
+        }
     }
 }
 
@@ -112,6 +146,91 @@ pub(crate) fn is_lease_expired(
     }
 }
 
+/// Calculate the delay before the next election attempt
+///
+/// With no failures, the normal renew interval is used. Each consecutive
+/// error doubles the delay (starting from the renew interval), capped at `max`.
+pub(crate) fn election_backoff(
+    renew_interval: Duration,
+    consecutive_failures: u32,
+    max: Duration,
+) -> Duration {
+    if consecutive_failures == 0 {
+        return renew_interval.min(max);
+    }
+
+    // 2^(failures-1), saturating to avoid overflow on long outages
+    let multiplier = 1u32
+        .checked_shl(consecutive_failures.saturating_sub(1))
+        .unwrap_or(u32::MAX);
+    renew_interval.saturating_mul(multiplier).min(max)
+}
+
+/// Record a failed acquire/renew attempt
+///
+/// Drops leadership (safe fallback: a renewal we cannot confirm must not
+/// keep us reconciling) and logs repeated failures at error level.
+///
+/// # Returns
+/// The updated consecutive failure count
+pub(crate) fn record_election_failure(
+    state: &LeaderState,
+    holder_id: &str,
+    consecutive_failures: u32,
+    err: &dyn std::fmt::Display,
+) -> u32 {
+    let consecutive_failures = consecutive_failures.saturating_add(1);
+
+    if consecutive_failures >= REPEATED_FAILURE_THRESHOLD {
+        error!(
+            error = %err,
+            holder_id = %holder_id,
+            consecutive_failures,
+            "Leader election failing repeatedly"
+        );
+    } else {
+        warn!(error = %err, consecutive_failures, "Leader election error");
+    }
+
+    if state.is_leader() {
+        warn!(holder_id = %holder_id, "Lost leadership due to error");
+        state.set_leader(false);
+    }
+
+    consecutive_failures
+}
+
+/// Record election metrics for a leadership change
+///
+/// Counts acquisitions and, when leadership ends, observes how long it was
+/// held. `leader_since` is when the current tenure began (None = follower).
+///
+/// # Returns
+/// The start of the current tenure after this change
+pub(crate) fn record_leadership_change(
+    state: &LeaderState,
+    was_leader: bool,
+    leader_since: Option<Instant>,
+    now: Instant,
+) -> Option<Instant> {
+    let is_leader = state.is_leader();
+    match (was_leader, is_leader) {
+        (false, true) => {
+            if let Some(metrics) = state.metrics() {
+                metrics.record_leader_election();
+            }
+            Some(now)
+        }
+        (true, false) => {
+            if let (Some(metrics), Some(since)) = (state.metrics(), leader_since) {
+                metrics.observe_leader_lease_duration(now.duration_since(since).as_secs_f64());
+            }
+            None
+        }
+        _ => leader_since,
+    }
+}
+
 /// Try to acquire or renew leadership
 ///
 /// Returns true if we are now the leader, false otherwise.
@@ -273,16 +392,27 @@ pub async fn run_leader_election(
         "Starting leader election"
     );
 
-    // Note: tokio::time::interval fires its first tick immediately.
-    // This is intentional so we try to acquire/renew leadership right away
-    // on startup; config.renew_interval applies to subsequent renewals.
-    let mut renew_interval = tokio::time::interval(config.renew_interval);
+    // First attempt fires immediately so we try to acquire leadership right
+    // away on startup; later attempts wait for the renew interval, or for an
+    // exponential backoff after errors.
+    let mut next_delay = Duration::ZERO;
+    let mut consecutive_failures: u32 = 0;
+    let mut leader_since: Option<Instant> = None;
 
     loop {
         tokio::select! {
-            _ = renew_interval.tick() => {
+            _ = tokio::time::sleep(next_delay) => {
+                let was_leader_before_attempt = state.is_leader();
                 match try_acquire_or_renew(&api, &config).await {
                     Ok(is_leader) => {
+                        if consecutive_failures > 0 {
+                            info!(
+                                consecutive_failures,
+                                "Leader election recovered after errors"
+                            );
+                            consecutive_failures = 0;
+                        }
+
                         let was_leader = state.is_leader();
                         state.set_leader(is_leader);
 
@@ -293,17 +423,30 @@ pub async fn run_leader_election(
                         }
                     }
                     Err(e) => {
-                        warn!(error = %e, "Leader election error");
-                        // On error, assume we're not leader (safe fallback)
-                        if state.is_leader() {
-                            warn!(holder_id = %config.holder_id, "Lost leadership due to error");
-                            state.set_leader(false);
-                        }
+                        consecutive_failures = record_election_failure(
+                            &state,
+                            &config.holder_id,
+                            consecutive_failures,
+                            &e,
+                        );
                     }
                 }
+
+                leader_since = record_leadership_change(
+                    &state,
+                    was_leader_before_attempt,
+                    leader_since,
+                    Instant::now(),
+                );
+
+                next_delay = election_backoff(
+                    config.renew_interval,
+                    consecutive_failures,
+                    MAX_ELECTION_BACKOFF,
+                );
             }
-            _ = shutdown.wait() => {
-                info!("Leader election shutting down");
+            reason = shutdown.wait() => {
+                info!(reason = %reason, "Leader election shutting down");
                 // Note: We don't explicitly release the lease on shutdown.
                 // It will expire naturally after lease_duration_seconds.
                 // This is safer than trying to release, which could fail.
@@ -3,7 +3,7 @@
 use super::leader::*;
 use chrono::Utc;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::MicroTime;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Test LeaderState initial value
 #[test]
@@ -227,3 +227,148 @@ fn test_lease_expired_neither_field() {
         "Lease with neither renew time nor duration should be expired"
     );
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Election backoff tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Test no failures uses the normal renew interval
+#[test]
+fn test_election_backoff_no_failures() {
+    let delay = election_backoff(Duration::from_secs(5), 0, MAX_ELECTION_BACKOFF);
+    assert_eq!(delay, Duration::from_secs(5));
+}
+
+/// Test backoff doubles with each consecutive failure
+#[test]
+fn test_election_backoff_sequence() {
+    let base = Duration::from_secs(5);
+    let delays: Vec<u64> = (1..=5)
+        .map(|failures| election_backoff(base, failures, MAX_ELECTION_BACKOFF).as_secs())
+        .collect();
+
+    assert_eq!(delays, vec![5, 10, 20, 40, 60]);
+}
+
+/// Test backoff is capped at the max delay, even after many failures
+#[test]
+fn test_election_backoff_capped() {
+    let base = Duration::from_secs(5);
+    assert_eq!(
+        election_backoff(base, 10, MAX_ELECTION_BACKOFF),
+        MAX_ELECTION_BACKOFF
+    );
+    assert_eq!(
+        election_backoff(base, u32::MAX, MAX_ELECTION_BACKOFF),
+        MAX_ELECTION_BACKOFF
+    );
+}
+
+/// Test leadership is dropped when renewal fails
+#[test]
+fn test_record_election_failure_drops_leadership() {
+    let state = LeaderState::new();
+    state.set_leader(true);
+
+    let failures = record_election_failure(&state, "kulta-0", 0, &"connection refused");
+
+    assert_eq!(failures, 1);
+    assert!(
+        !state.is_leader(),
+        "Leadership must be dropped on renewal failure"
+    );
+}
+
+/// Test consecutive failures accumulate while not leader
+#[test]
+fn test_record_election_failure_counts_consecutive() {
+    let state = LeaderState::new();
+
+    let mut failures = 0;
+    for _ in 0..REPEATED_FAILURE_THRESHOLD {
+        failures = record_election_failure(&state, "kulta-0", failures, &"timeout");
+    }
+
+    assert_eq!(failures, REPEATED_FAILURE_THRESHOLD);
+    assert!(!state.is_leader());
+}
+
+/// Test set_leader updates the kulta_is_leader gauge
+#[test]
+fn test_set_leader_updates_is_leader_gauge() {
+    let metrics = crate::server::create_metrics().expect("should create metrics");
+    let state = LeaderState::with_metrics(metrics.clone(), "kulta-0");
+    let gauge = || metrics.is_leader.with_label_values(&["kulta-0"]).get();
+
+    assert_eq!(gauge(), 0, "Gauge should start at 0 (follower)");
+
+    state.set_leader(true);
+    assert_eq!(gauge(), 1, "Gauge should be 1 after becoming leader");
+
+    state.set_leader(false);
+    assert_eq!(
+        gauge(),
+        0,
+        "Gauge should return to 0 after losing leadership"
+    );
+}
+
+/// Test LeaderState without metrics still tracks leadership
+#[test]
+fn test_leader_state_without_metrics() {
+    let state = LeaderState::new();
+
+    state.set_leader(true);
+
+    assert!(state.is_leader());
+    assert!(state.metrics().is_none());
+}
+
+/// Test acquiring leadership counts an election and starts a tenure
+#[test]
+fn test_record_leadership_change_acquired() {
+    let metrics = crate::server::create_metrics().expect("should create metrics");
+    let state = LeaderState::with_metrics(metrics.clone(), "kulta-0");
+    let now = Instant::now();
+
+    state.set_leader(true);
+    let since = record_leadership_change(&state, false, None, now);
+
+    assert_eq!(since, Some(now));
+    assert_eq!(metrics.leader_elections_total.get(), 1);
+    assert_eq!(metrics.leader_lease_duration_seconds.get_sample_count(), 0);
+}
+
+/// Test losing leadership observes the tenure duration
+#[test]
+fn test_record_leadership_change_lost_observes_duration() {
+    let metrics = crate::server::create_metrics().expect("should create metrics");
+    let state = LeaderState::with_metrics(metrics.clone(), "kulta-0");
+    let acquired = Instant::now();
+    let lost = acquired + Duration::from_secs(30);
+
+    state.set_leader(true);
+    let since = record_leadership_change(&state, false, None, acquired);
+    state.set_leader(false);
+    let since = record_leadership_change(&state, true, since, lost);
+
+    assert_eq!(since, None);
+    assert_eq!(metrics.leader_elections_total.get(), 1);
+    assert_eq!(metrics.leader_lease_duration_seconds.get_sample_count(), 1);
+    assert_eq!(metrics.leader_lease_duration_seconds.get_sample_sum(), 30.0);
+}
+
+/// Test renewals keep the tenure start and record nothing
+#[test]
+fn test_record_leadership_change_renewal_is_noop() {
+    let metrics = crate::server::create_metrics().expect("should create metrics");
+    let state = LeaderState::with_metrics(metrics.clone(), "kulta-0");
+    let acquired = Instant::now();
+
+    state.set_leader(true);
+    let since = record_leadership_change(&state, false, None, acquired);
+    let since = record_leadership_change(&state, true, since, acquired + DEFAULT_RENEW_INTERVAL);
+
+    assert_eq!(since, Some(acquired));
+    assert_eq!(metrics.leader_elections_total.get(), 1);
+}
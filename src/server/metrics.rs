@@ -4,10 +4,12 @@
 //! - Reconciliation counts and durations
 //! - Rollout phase transitions
 //! - Traffic weight distribution
+//! - Canary progression (applied weight and current step)
+//! - Health server requests (count and latency per endpoint)
 
 use prometheus::{
-    self, Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry,
-    TextEncoder,
+    self, Encoder, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGaugeVec,
+    Opts, Registry, TextEncoder,
 };
 use std::sync::Arc;
 
@@ -26,13 +28,36 @@ pub struct ControllerMetrics {
     pub rollouts_active: IntGaugeVec,
     /// Traffic weight per rollout (0-100)
     pub traffic_weight: IntGaugeVec,
+    /// Canary traffic weight applied to the routes per rollout (0-100)
+    pub rollout_canary_weight: IntGaugeVec,
+    /// Current canary step index per rollout (-1 when no step is active)
+    pub rollout_step: IntGaugeVec,
+    /// Reconciliation errors by category (kube_api, validation, traffic, etc.)
+    pub reconcile_errors_total: IntCounterVec,
+    /// Rollout phase transitions by previous and new phase
+    pub phase_transitions_total: IntCounterVec,
+    /// Whether this instance holds the leader lease (1) or not (0)
+    pub is_leader: IntGaugeVec,
+    /// Times this instance acquired leadership
+    pub leader_elections_total: IntCounter,
+    /// How long each leadership tenure lasted, observed when it ends
+    pub leader_lease_duration_seconds: Histogram,
+    /// Health server requests by path, method and status code
+    pub http_requests_total: IntCounterVec,
+    /// Health server request duration in seconds by path
+    pub http_request_duration_seconds: HistogramVec,
 }
 
 impl ControllerMetrics {
     /// Create a new metrics registry with all KULTA metrics
     pub fn new() -> Result<Self, prometheus::Error> {
-        let registry = Registry::new();
+        Self::with_registry(Registry::new())
+    }
 
+    /// Register all KULTA metrics into the given registry
+    ///
+    /// Lets tests keep a handle on an isolated registry and scrape it directly.
+    pub fn with_registry(registry: Registry) -> Result<Self, prometheus::Error> {
         // Reconciliation counter
         let reconciliations_total = IntCounterVec::new(
             Opts::new(
@@ -74,12 +99,109 @@ impl ControllerMetrics {
         )?;
         registry.register(Box::new(traffic_weight.clone()))?;
 
+        // Applied canary weight gauge
+        let rollout_canary_weight = IntGaugeVec::new(
+            Opts::new(
+                "kulta_rollout_canary_weight",
+                "Canary traffic weight percentage currently applied to the routes",
+            ),
+            &["namespace", "rollout"],
+        )?;
+        registry.register(Box::new(rollout_canary_weight.clone()))?;
+
+        // Canary step gauge
+        let rollout_step = IntGaugeVec::new(
+            Opts::new(
+                "kulta_rollout_step",
+                "Current canary step index (-1 when no step is active)",
+            ),
+            &["namespace", "rollout"],
+        )?;
+        registry.register(Box::new(rollout_step.clone()))?;
+
+        // Reconcile errors by category
+        let reconcile_errors_total = IntCounterVec::new(
+            Opts::new(
+                "kulta_reconcile_errors_total",
+                "Total number of reconciliation errors by category",
+            ),
+            &["category"], // kube_api, validation, traffic, replicaset, metrics, ...
+        )?;
+        registry.register(Box::new(reconcile_errors_total.clone()))?;
+
+        // Phase transitions counter
+        let phase_transitions_total = IntCounterVec::new(
+            Opts::new(
+                "kulta_rollout_phase_transitions_total",
+                "Total number of rollout phase transitions",
+            ),
+            &["from", "to"],
+        )?;
+        registry.register(Box::new(phase_transitions_total.clone()))?;
+
+        // Leader election gauge
+        let is_leader = IntGaugeVec::new(
+            Opts::new(
+                "kulta_is_leader",
+                "Whether this instance is the leader (1) or a follower (0)",
+            ),
+            &["instance"],
+        )?;
+        registry.register(Box::new(is_leader.clone()))?;
+
+        // Leader acquisitions counter
+        let leader_elections_total = IntCounter::new(
+            "kulta_leader_elections_total",
+            "Total number of times this instance acquired leadership",
+        )?;
+        registry.register(Box::new(leader_elections_total.clone()))?;
+
+        // Leadership tenure histogram
+        let leader_lease_duration_seconds = Histogram::with_opts(
+            HistogramOpts::new(
+                "kulta_leader_lease_duration_seconds",
+                "How long this instance held leadership before losing it",
+            )
+            .buckets(vec![15.0, 60.0, 300.0, 900.0, 3600.0, 14400.0, 86400.0]),
+        )?;
+        registry.register(Box::new(leader_lease_duration_seconds.clone()))?;
+
+        // Health server request counter
+        let http_requests_total = IntCounterVec::new(
+            Opts::new(
+                "kulta_http_requests_total",
+                "Total number of health server requests",
+            ),
+            &["path", "method", "status"],
+        )?;
+        registry.register(Box::new(http_requests_total.clone()))?;
+
+        // Health server request duration histogram
+        let http_request_duration_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "kulta_http_request_duration_seconds",
+                "Duration of health server requests in seconds",
+            )
+            .buckets(vec![0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0]),
+            &["path"],
+        )?;
+        registry.register(Box::new(http_request_duration_seconds.clone()))?;
+
         Ok(Self {
             registry,
             reconciliations_total,
             reconciliation_duration_seconds,
             rollouts_active,
             traffic_weight,
+            rollout_canary_weight,
+            rollout_step,
+            reconcile_errors_total,
+            phase_transitions_total,
+            is_leader,
+            leader_elections_total,
+            leader_lease_duration_seconds,
+            http_requests_total,
+            http_request_duration_seconds,
         })
     }
 
@@ -103,6 +225,47 @@ impl ControllerMetrics {
             .observe(duration_secs);
     }
 
+    /// Record a reconciliation error by category
+    pub fn record_reconcile_error_category(&self, category: &str) {
+        self.reconcile_errors_total
+            .with_label_values(&[category])
+            .inc();
+    }
+
+    /// Record a rollout moving from one phase to another
+    pub fn record_phase_transition(&self, from: &str, to: &str) {
+        self.phase_transitions_total
+            .with_label_values(&[from, to])
+            .inc();
+    }
+
+    /// Update the leader gauge for an instance
+    pub fn set_is_leader(&self, instance: &str, is_leader: bool) {
+        self.is_leader
+            .with_label_values(&[instance])
+            .set(i64::from(is_leader));
+    }
+
+    /// Record this instance acquiring leadership
+    pub fn record_leader_election(&self) {
+        self.leader_elections_total.inc();
+    }
+
+    /// Record how long a leadership tenure lasted
+    pub fn observe_leader_lease_duration(&self, duration_secs: f64) {
+        self.leader_lease_duration_seconds.observe(duration_secs);
+    }
+
+    /// Record a health server request
+    pub fn record_http_request(&self, path: &str, method: &str, status: u16, duration_secs: f64) {
+        self.http_requests_total
+            .with_label_values(&[path, method, &status.to_string()])
+            .inc();
+        self.http_request_duration_seconds
+            .with_label_values(&[path])
+            .observe(duration_secs);
+    }
+
     /// Record a skipped reconciliation (not leader)
     pub fn record_reconciliation_skipped(&self) {
         self.reconciliations_total
@@ -117,6 +280,16 @@ impl ControllerMetrics {
             .set(weight);
     }
 
+    /// Update the applied canary weight and current step for a rollout
+    pub fn set_rollout_progress(&self, namespace: &str, rollout: &str, weight: i64, step: i64) {
+        self.rollout_canary_weight
+            .with_label_values(&[namespace, rollout])
+            .set(weight);
+        self.rollout_step
+            .with_label_values(&[namespace, rollout])
+            .set(step);
+    }
+
     /// Update active rollout count for a phase
     pub fn set_rollouts_active(&self, phase: &str, strategy: &str, count: i64) {
         self.rollouts_active
@@ -1,6 +1,11 @@
 //! Tests for controller metrics
 
 use super::metrics::{create_metrics, ControllerMetrics};
+use crate::controller::ReconcileError;
+
+#[path = "../../tests/common/metrics_harness.rs"]
+mod metrics_harness;
+use metrics_harness::MetricsTestHarness;
 
 #[test]
 fn test_metrics_creation() {
@@ -79,6 +84,25 @@ fn test_set_traffic_weight() {
     );
 }
 
+#[test]
+fn test_set_rollout_progress() {
+    let harness = MetricsTestHarness::new();
+
+    let values = harness.run(|metrics| {
+        metrics.set_rollout_progress("default", "my-app", 20, 0);
+        metrics.set_rollout_progress("default", "my-app", 50, 1);
+    });
+
+    assert_eq!(
+        values["kulta_rollout_canary_weight{namespace=\"default\",rollout=\"my-app\"}"],
+        50.0
+    );
+    assert_eq!(
+        values["kulta_rollout_step{namespace=\"default\",rollout=\"my-app\"}"],
+        1.0
+    );
+}
+
 #[test]
 fn test_set_rollouts_active() {
     let metrics = ControllerMetrics::new().expect("should create metrics");
@@ -142,3 +166,133 @@ fn test_metrics_new_is_infallible_in_practice() {
     let output = metrics.encode().expect("should encode metrics");
     assert!(output.contains("kulta_reconciliations_total"));
 }
+
+#[test]
+fn test_record_reconcile_error_category() {
+    let metrics = ControllerMetrics::new().expect("should create metrics");
+
+    metrics.record_reconcile_error_category("validation");
+    metrics.record_reconcile_error_category("validation");
+    metrics.record_reconcile_error_category("kube_api");
+
+    let output = metrics.encode().expect("should encode metrics");
+
+    assert!(output.contains("kulta_reconcile_errors_total{category=\"validation\"} 2"));
+    assert!(output.contains("kulta_reconcile_errors_total{category=\"kube_api\"} 1"));
+}
+
+#[test]
+fn test_record_phase_transition() {
+    let metrics = ControllerMetrics::new().expect("should create metrics");
+
+    metrics.record_phase_transition("Progressing", "Paused");
+
+    let output = metrics.encode().expect("should encode metrics");
+    assert!(output
+        .contains("kulta_rollout_phase_transitions_total{from=\"Progressing\",to=\"Paused\"} 1"));
+}
+
+// =============================================================================
+// MetricsTestHarness: assert values recorded by reconcile-like flows
+// =============================================================================
+
+#[test]
+fn test_harness_successful_reconcile_increments_success_counter() {
+    let harness = MetricsTestHarness::new();
+
+    // Mirrors the success path at the end of reconcile()
+    let values = harness.run(|metrics| {
+        metrics.record_reconciliation_success("canary", 0.2);
+        metrics.set_traffic_weight("default", "my-app", 20);
+    });
+
+    assert_eq!(
+        values["kulta_reconciliations_total{result=\"success\"}"],
+        1.0
+    );
+    assert_eq!(
+        values["kulta_reconciliation_duration_seconds_count{strategy=\"canary\"}"],
+        1.0
+    );
+    assert_eq!(
+        values["kulta_traffic_weight{namespace=\"default\",rollout=\"my-app\"}"],
+        20.0
+    );
+    assert!(!values.contains_key("kulta_reconciliations_total{result=\"error\"}"));
+}
+
+#[test]
+fn test_harness_failed_reconcile_increments_error_counters() {
+    let harness = MetricsTestHarness::new();
+
+    // Mirrors error_policy() in main.rs
+    let error = ReconcileError::MissingNamespace;
+    let values = harness.run(|metrics| {
+        metrics.record_reconciliation_error("canary", 0.0);
+        metrics.record_reconcile_error_category(error.category());
+    });
+
+    assert_eq!(values["kulta_reconciliations_total{result=\"error\"}"], 1.0);
+    assert_eq!(
+        values[&format!(
+            "kulta_reconcile_errors_total{{category=\"{}\"}}",
+            error.category()
+        )],
+        1.0
+    );
+    assert_eq!(
+        harness.value("kulta_reconciliations_total{result=\"success\"}"),
+        0.0
+    );
+}
+
+#[test]
+fn test_harness_phase_transition_increments_counter() {
+    use crate::controller::rollout::observe_phase_transition;
+    use crate::crd::rollout::{Phase, RolloutStatus};
+
+    let harness = MetricsTestHarness::new();
+    let progressing = RolloutStatus {
+        phase: Some(Phase::Progressing),
+        ..Default::default()
+    };
+    let paused = RolloutStatus {
+        phase: Some(Phase::Paused),
+        ..Default::default()
+    };
+
+    let values = harness.run(|metrics| {
+        observe_phase_transition(metrics, None, &progressing);
+        observe_phase_transition(metrics, Some(&progressing), &paused);
+        // Same phase: not a transition
+        observe_phase_transition(metrics, Some(&paused), &paused);
+    });
+
+    assert_eq!(
+        values["kulta_rollout_phase_transitions_total{from=\"None\",to=\"Progressing\"}"],
+        1.0
+    );
+    assert_eq!(
+        values["kulta_rollout_phase_transitions_total{from=\"Progressing\",to=\"Paused\"}"],
+        1.0
+    );
+    assert!(!values
+        .contains_key("kulta_rollout_phase_transitions_total{from=\"Paused\",to=\"Paused\"}"));
+}
+
+#[test]
+fn test_harness_registries_are_isolated() {
+    let first = MetricsTestHarness::new();
+    let second = MetricsTestHarness::new();
+
+    first.run(|metrics| metrics.record_reconciliation_skipped());
+
+    assert_eq!(
+        first.value("kulta_reconciliations_total{result=\"skipped\"}"),
+        1.0
+    );
+    assert_eq!(
+        second.value("kulta_reconciliations_total{result=\"skipped\"}"),
+        0.0
+    );
+}
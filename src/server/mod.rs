@@ -2,35 +2,49 @@
 //!
 //! Provides Kubernetes health probes:
 //! - `/healthz` - Liveness probe (process is running)
+//! - `/livez` - Liveness probe (reconcile loop is making progress)
 //! - `/readyz` - Readiness probe (controller is ready to serve)
 //! - `/metrics` - Prometheus metrics endpoint
+//! - `/debug/rollouts` - Cached rollouts with their status transition logs
 //!
 //! Also provides:
 //! - Graceful shutdown handling for SIGTERM/SIGINT
 //! - Leader election for multi-replica safety
 
+mod debug;
 mod health;
 pub mod leader;
 pub mod metrics;
 pub mod shutdown;
 
-pub use health::{run_health_server, ReadinessState};
+pub use debug::{RolloutDebugEntry, RolloutDebugView};
+pub use health::{
+    run_health_server, run_health_server_with_limits, HealthServerLimits, Heartbeat,
+    ReadinessCheck, ReadinessState, DEFAULT_HEARTBEAT_STALENESS,
+};
 pub use leader::{run_leader_election, LeaderConfig, LeaderState};
 pub use metrics::{create_metrics, ControllerMetrics, SharedMetrics};
-pub use shutdown::{shutdown_channel, wait_for_signal, ShutdownController, ShutdownSignal};
+pub use shutdown::{
+    drain_timeout_from_env, shutdown_channel, wait_for_signal, DrainResult, ReconcileBudget,
+    ReconcileGuard, ShutdownController, ShutdownReason, ShutdownSignal,
+};
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Tests can use unwrap/expect for brevity
 #[path = "health_test.rs"]
 mod health_tests;
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Tests can use unwrap/expect for brevity
 #[path = "shutdown_test.rs"]
 mod shutdown_tests;
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Tests can use unwrap/expect for brevity
 #[path = "leader_test.rs"]
 mod leader_tests;
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)] // Tests can use unwrap/expect for brevity
 #[path = "metrics_test.rs"]
 mod metrics_tests;
@@ -2,55 +2,261 @@
 //!
 //! This module:
 //! - Listens for SIGTERM and SIGINT (or Ctrl+C on non-Unix platforms)
-//! - Broadcasts a shutdown signal to interested components
+//! - Broadcasts a shutdown signal to interested components, along with the
+//!   [`ShutdownReason`] that triggered it
 //!
 //! Components that receive the [`ShutdownSignal`] are responsible for:
 //! - Stopping acceptance of new work
 //! - Performing any necessary resource cleanup
 //!
-//! Note: Advanced graceful draining (e.g., waiting for in-flight reconciliations)
-//! may be added as a future enhancement.
+//! In-flight reconciliations are tracked by a [`ReconcileBudget`] so that
+//! [`ShutdownController::shutdown_with_drain`] can wait for them to finish
+//! (up to `KULTA_SHUTDOWN_DRAIN_TIMEOUT_SECS`) before the process exits.
+//! Shutdown also cancels the [`ShutdownController::cancellation_token`], so
+//! reconciliations stop at their next Kubernetes API call instead of running
+//! it to completion.
 
-use tokio::sync::watch;
-use tracing::info;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{watch, OwnedSemaphorePermit, Semaphore};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+/// Default time to wait for in-flight reconciliations during shutdown
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Maximum number of reconciliations the budget tracks at once
+pub const RECONCILE_BUDGET_CAPACITY: u32 = 10_000;
+
+/// Why shutdown was triggered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShutdownReason {
+    /// OS signal received (e.g. "SIGTERM", "SIGINT")
+    Signal(String),
+    /// Shutdown caused by an error (e.g. the controller stream ended)
+    Error(String),
+    /// Shutdown triggered without a specific cause (tests)
+    TestShutdown,
+}
+
+impl fmt::Display for ShutdownReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShutdownReason::Signal(signal) => write!(f, "signal {}", signal),
+            ShutdownReason::Error(message) => write!(f, "error: {}", message),
+            ShutdownReason::TestShutdown => write!(f, "test shutdown"),
+        }
+    }
+}
 
 /// Shutdown signal sender/receiver pair
 ///
 /// The sender is used to trigger shutdown, the receiver is used to wait for it.
 #[derive(Clone)]
 pub struct ShutdownSignal {
-    receiver: watch::Receiver<bool>,
+    receiver: watch::Receiver<Option<ShutdownReason>>,
 }
 
 impl ShutdownSignal {
-    /// Wait for shutdown signal
-    pub async fn wait(&mut self) {
-        // Wait until the value becomes true
-        while !*self.receiver.borrow() {
+    /// Wait for shutdown signal, returning the reason it was triggered
+    pub async fn wait(&mut self) -> ShutdownReason {
+        // Wait until a reason is set
+        loop {
+            if let Some(reason) = self.receiver.borrow().clone() {
+                return reason;
+            }
             if self.receiver.changed().await.is_err() {
                 // Sender dropped, treat as shutdown
-                break;
+                return ShutdownReason::Error("shutdown controller dropped".to_string());
             }
         }
     }
 
     /// Check if shutdown was signaled (non-blocking)
     pub fn is_shutdown(&self) -> bool {
-        *self.receiver.borrow()
+        self.receiver.borrow().is_some()
+    }
+
+    /// Reason shutdown was triggered, if it has been (non-blocking)
+    pub fn reason(&self) -> Option<ShutdownReason> {
+        self.receiver.borrow().clone()
     }
 }
 
+/// Tracks in-flight reconciliations for shutdown draining
+///
+/// Each reconciliation holds one semaphore permit for its duration; the
+/// budget is idle when every permit is available again. Clone is cheap.
+#[derive(Clone)]
+pub struct ReconcileBudget {
+    permits: Arc<Semaphore>,
+    shutdown: watch::Receiver<Option<ShutdownReason>>,
+}
+
+/// Marks one reconciliation as in flight until dropped
+pub struct ReconcileGuard {
+    _permit: OwnedSemaphorePermit,
+}
+
+impl ReconcileBudget {
+    fn new(shutdown: watch::Receiver<Option<ShutdownReason>>) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(RECONCILE_BUDGET_CAPACITY as usize)),
+            shutdown,
+        }
+    }
+
+    /// Start tracking a reconciliation
+    ///
+    /// Returns None once shutdown has been signaled (or while a drain holds
+    /// the budget), meaning the caller should not start new work.
+    pub fn begin(&self) -> Option<ReconcileGuard> {
+        if self.shutdown.borrow().is_some() {
+            return None;
+        }
+        let permit = Arc::clone(&self.permits).try_acquire_owned().ok()?;
+        Some(ReconcileGuard { _permit: permit })
+    }
+
+    /// Number of reconciliations currently in flight
+    pub fn in_flight(&self) -> usize {
+        (RECONCILE_BUDGET_CAPACITY as usize).saturating_sub(self.permits.available_permits())
+    }
+
+    /// Wait until no reconciliations are in flight
+    async fn wait_idle(&self) {
+        // Holding every permit proves nothing else does; release them right away
+        let _all = self.permits.acquire_many(RECONCILE_BUDGET_CAPACITY).await;
+    }
+}
+
+/// Result of draining in-flight reconciliations during shutdown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DrainResult {
+    /// Reconciliations that finished within the drain timeout
+    pub drained: usize,
+    /// Reconciliations still running when the timeout expired
+    pub timed_out: usize,
+}
+
 /// Controller for triggering shutdown
 pub struct ShutdownController {
-    sender: watch::Sender<bool>,
+    sender: watch::Sender<Option<ShutdownReason>>,
+    budget: ReconcileBudget,
+    cancellation: CancellationToken,
 }
 
 impl ShutdownController {
-    /// Trigger shutdown
+    /// Trigger shutdown without a specific cause (reported as TestShutdown)
     pub fn shutdown(&self) {
-        let _ = self.sender.send(true);
-        info!("Shutdown signal sent");
+        self.shutdown_with_reason(ShutdownReason::TestShutdown);
+    }
+
+    /// Trigger shutdown (and cancel in-flight reconciliations) for `reason`
+    ///
+    /// Only the first reason is kept; later calls don't overwrite it.
+    pub fn shutdown_with_reason(&self, reason: ShutdownReason) {
+        self.sender.send_if_modified(|current| {
+            if current.is_some() {
+                return false;
+            }
+            info!(reason = %reason, "Shutdown signal sent");
+            *current = Some(reason);
+            true
+        });
+        self.cancellation.cancel();
     }
+
+    /// Token cancelled on shutdown, for reconciliations to stop early
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation.clone()
+    }
+
+    /// Budget that reconciliations must hold while running
+    pub fn reconcile_budget(&self) -> ReconcileBudget {
+        self.budget.clone()
+    }
+
+    /// Trigger shutdown, then wait up to `timeout` for in-flight reconciliations
+    ///
+    /// No new reconciliations can begin once shutdown is signaled, so the
+    /// in-flight count only goes down while draining.
+    pub async fn shutdown_with_drain(
+        &self,
+        reason: ShutdownReason,
+        timeout: Duration,
+    ) -> DrainResult {
+        self.shutdown_with_reason(reason);
+
+        let in_flight = self.budget.in_flight();
+        if in_flight == 0 {
+            info!("No in-flight reconciliations to drain");
+            return DrainResult {
+                drained: 0,
+                timed_out: 0,
+            };
+        }
+
+        info!(
+            in_flight,
+            timeout_secs = timeout.as_secs(),
+            "Draining in-flight reconciliations"
+        );
+        let completed = tokio::time::timeout(timeout, self.budget.wait_idle())
+            .await
+            .is_ok();
+
+        let timed_out = if completed {
+            0
+        } else {
+            self.budget.in_flight()
+        };
+        let result = DrainResult {
+            drained: in_flight.saturating_sub(timed_out),
+            timed_out,
+        };
+
+        if timed_out == 0 {
+            info!(
+                drained = result.drained,
+                "All in-flight reconciliations drained"
+            );
+        } else {
+            warn!(
+                drained = result.drained,
+                timed_out, "Drain timeout expired with reconciliations still in flight"
+            );
+        }
+        result
+    }
+}
+
+/// Parse KULTA_SHUTDOWN_DRAIN_TIMEOUT_SECS (whole seconds, default 30)
+pub fn parse_drain_timeout(value: Option<&str>) -> Duration {
+    match value {
+        None => DEFAULT_DRAIN_TIMEOUT,
+        Some(raw) => match raw.trim().parse::<u64>() {
+            Ok(secs) => Duration::from_secs(secs),
+            Err(_) => {
+                warn!(
+                    value = raw,
+                    default_secs = DEFAULT_DRAIN_TIMEOUT.as_secs(),
+                    "Invalid KULTA_SHUTDOWN_DRAIN_TIMEOUT_SECS, using default"
+                );
+                DEFAULT_DRAIN_TIMEOUT
+            }
+        },
+    }
+}
+
+/// Read the shutdown drain timeout from KULTA_SHUTDOWN_DRAIN_TIMEOUT_SECS
+pub fn drain_timeout_from_env() -> Duration {
+    parse_drain_timeout(
+        std::env::var("KULTA_SHUTDOWN_DRAIN_TIMEOUT_SECS")
+            .ok()
+            .as_deref(),
+    )
 }
 
 /// Create a new shutdown signal pair
@@ -59,8 +265,16 @@ impl ShutdownController {
 /// - controller: Used to trigger shutdown
 /// - signal: Cloned and passed to components that need to listen
 pub fn shutdown_channel() -> (ShutdownController, ShutdownSignal) {
-    let (sender, receiver) = watch::channel(false);
-    (ShutdownController { sender }, ShutdownSignal { receiver })
+    let (sender, receiver) = watch::channel(None);
+    let budget = ReconcileBudget::new(receiver.clone());
+    (
+        ShutdownController {
+            sender,
+            budget,
+            cancellation: CancellationToken::new(),
+        },
+        ShutdownSignal { receiver },
+    )
 }
 
 /// Wait for SIGTERM or SIGINT signal
@@ -12,6 +12,17 @@ async fn test_shutdown_channel_initially_not_shutdown() {
     assert!(!signal.is_shutdown());
 }
 
+/// Test that shutdown cancels the reconcile cancellation token
+#[tokio::test]
+async fn test_shutdown_cancels_token() {
+    let (controller, _signal) = shutdown_channel();
+    let token = controller.cancellation_token();
+
+    assert!(!token.is_cancelled());
+    controller.shutdown();
+    assert!(token.is_cancelled());
+}
+
 /// Test that shutdown can be triggered
 #[tokio::test]
 async fn test_shutdown_channel_triggers_shutdown() {
@@ -45,6 +56,53 @@ async fn test_shutdown_wait_completes_on_signal() {
     assert!(signal.is_shutdown());
 }
 
+/// Test that wait resolves with the reason passed to shutdown_with_reason
+#[tokio::test]
+async fn test_shutdown_wait_returns_reason() {
+    let (controller, mut signal) = shutdown_channel();
+
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        controller.shutdown_with_reason(ShutdownReason::Signal("SIGTERM".into()));
+    });
+
+    let result = tokio::time::timeout(Duration::from_secs(1), signal.wait()).await;
+
+    assert_eq!(
+        result.ok(),
+        Some(ShutdownReason::Signal("SIGTERM".to_string()))
+    );
+    assert_eq!(
+        signal.reason(),
+        Some(ShutdownReason::Signal("SIGTERM".to_string()))
+    );
+}
+
+/// Test that the first shutdown reason is kept
+#[tokio::test]
+async fn test_shutdown_keeps_first_reason() {
+    let (controller, mut signal) = shutdown_channel();
+
+    controller.shutdown_with_reason(ShutdownReason::Signal("SIGINT".into()));
+    controller.shutdown_with_reason(ShutdownReason::Error("late".into()));
+    controller.shutdown();
+
+    assert_eq!(
+        signal.wait().await,
+        ShutdownReason::Signal("SIGINT".to_string())
+    );
+}
+
+/// Test that plain shutdown() reports TestShutdown
+#[tokio::test]
+async fn test_shutdown_without_reason_reports_test_shutdown() {
+    let (controller, mut signal) = shutdown_channel();
+
+    controller.shutdown();
+
+    assert_eq!(signal.wait().await, ShutdownReason::TestShutdown);
+}
+
 /// Test that cloned signals all receive shutdown
 #[tokio::test]
 async fn test_shutdown_signal_clones_share_state() {
@@ -62,3 +120,110 @@ async fn test_shutdown_signal_clones_share_state() {
     assert!(signal2.is_shutdown());
     assert!(signal3.is_shutdown());
 }
+
+/// Test a reconcile started before shutdown completes during the drain window
+#[tokio::test]
+async fn test_shutdown_with_drain_waits_for_in_flight_reconcile() {
+    let (controller, _signal) = shutdown_channel();
+    let budget = controller.reconcile_budget();
+    let completed = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+    // Start a "reconcile" that takes a while
+    let guard = budget
+        .begin()
+        .expect("budget should accept work before shutdown");
+    let task_completed = completed.clone();
+    let task = tokio::spawn(async move {
+        let _guard = guard;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        task_completed.store(true, std::sync::atomic::Ordering::SeqCst);
+    });
+    assert_eq!(budget.in_flight(), 1);
+
+    let result = controller
+        .shutdown_with_drain(ShutdownReason::TestShutdown, Duration::from_secs(5))
+        .await;
+
+    assert!(
+        completed.load(std::sync::atomic::Ordering::SeqCst),
+        "in-flight reconcile should finish before drain returns"
+    );
+    assert_eq!(
+        result,
+        DrainResult {
+            drained: 1,
+            timed_out: 0
+        }
+    );
+    assert_eq!(budget.in_flight(), 0);
+    task.await.expect("reconcile task should not panic");
+}
+
+/// Test the drain gives up after the timeout
+#[tokio::test]
+async fn test_shutdown_with_drain_times_out() {
+    let (controller, signal) = shutdown_channel();
+    let budget = controller.reconcile_budget();
+    let _stuck = budget
+        .begin()
+        .expect("budget should accept work before shutdown");
+
+    let result = controller
+        .shutdown_with_drain(ShutdownReason::TestShutdown, Duration::from_millis(50))
+        .await;
+
+    assert_eq!(
+        result,
+        DrainResult {
+            drained: 0,
+            timed_out: 1
+        }
+    );
+    assert!(signal.is_shutdown(), "drain should still signal shutdown");
+}
+
+/// Test drain returns immediately when nothing is in flight
+#[tokio::test]
+async fn test_shutdown_with_drain_nothing_in_flight() {
+    let (controller, signal) = shutdown_channel();
+
+    let result = tokio::time::timeout(
+        Duration::from_secs(1),
+        controller.shutdown_with_drain(ShutdownReason::TestShutdown, Duration::from_secs(30)),
+    )
+    .await
+    .expect("drain with nothing in flight should not wait");
+
+    assert_eq!(
+        result,
+        DrainResult {
+            drained: 0,
+            timed_out: 0
+        }
+    );
+    assert!(signal.is_shutdown());
+}
+
+/// Test no new reconciles begin once shutdown is signaled
+#[tokio::test]
+async fn test_reconcile_budget_rejects_work_after_shutdown() {
+    let (controller, _signal) = shutdown_channel();
+    let budget = controller.reconcile_budget();
+
+    assert!(budget.begin().is_some());
+
+    controller.shutdown();
+
+    assert!(budget.begin().is_none());
+    assert_eq!(budget.in_flight(), 0);
+}
+
+/// Test KULTA_SHUTDOWN_DRAIN_TIMEOUT_SECS parsing
+#[test]
+fn test_parse_drain_timeout() {
+    assert_eq!(parse_drain_timeout(None), DEFAULT_DRAIN_TIMEOUT);
+    assert_eq!(parse_drain_timeout(Some("10")), Duration::from_secs(10));
+    assert_eq!(parse_drain_timeout(Some("0")), Duration::ZERO);
+    assert_eq!(parse_drain_timeout(Some("soon")), DEFAULT_DRAIN_TIMEOUT);
+    assert_eq!(parse_drain_timeout(Some("-1")), DEFAULT_DRAIN_TIMEOUT);
+}
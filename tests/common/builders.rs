@@ -0,0 +1,231 @@
+//! Test data builders for Rollout resources
+//!
+//! Included into unit tests via `#[path]`, so paths are relative to the
+//! `kulta` crate root.
+//!
+//! ```ignore
+//! let rollout = RolloutBuilder::new()
+//!     .with_name("test")
+//!     .with_canary_strategy("s", "c")
+//!     .with_step(20, None)
+//!     .build();
+//! ```
+
+#![allow(dead_code)] // Not every test file uses every builder method
+
+use crate::crd::rollout::{
+    AnalysisConfig, CanaryStep, CanaryStrategy, GatewayAPIRouting, PauseDuration, Phase, Rollout,
+    RolloutSpec, RolloutStatus, RolloutStrategy, TrafficRouting,
+};
+use k8s_openapi::api::core::v1::PodTemplateSpec;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use kube::api::ObjectMeta;
+
+/// Builder for Rollout test fixtures
+///
+/// Defaults to "test-rollout" in the "default" namespace with 3 replicas and
+/// a canary strategy (stable "test-app-stable", canary "test-app-canary",
+/// no steps, no status).
+#[derive(Debug, Clone)]
+pub struct RolloutBuilder {
+    rollout: Rollout,
+}
+
+impl Default for RolloutBuilder {
+    fn default() -> Self {
+        Self {
+            rollout: Rollout {
+                metadata: ObjectMeta {
+                    name: Some("test-rollout".to_string()),
+                    namespace: Some("default".to_string()),
+                    ..Default::default()
+                },
+                spec: RolloutSpec {
+                    replicas: 3,
+                    selector: LabelSelector::default(),
+                    template: PodTemplateSpec::default(),
+                    strategy: RolloutStrategy {
+                        simple: None,
+                        canary: Some(canary_strategy("test-app-stable", "test-app-canary")),
+                        blue_green: None,
+                    },
+                    manage_pod_template_hash: None,
+                    quota_aware: None,
+                    dry_run: None,
+                    paused: None,
+                },
+                status: None,
+            },
+        }
+    }
+}
+
+/// Canary strategy with the given services and every optional field unset
+fn canary_strategy(stable_svc: &str, canary_svc: &str) -> CanaryStrategy {
+    CanaryStrategy {
+        canary_service: canary_svc.to_string(),
+        stable_service: stable_svc.to_string(),
+        mirror_service: None,
+        steps: vec![],
+        traffic_routing: None,
+        analysis: None,
+        max_duration_seconds: None,
+        canary_overrides: None,
+        canary_suffix: None,
+        stable_suffix: None,
+        analysis_only: None,
+        port_name: None,
+        pause_at_step: None,
+        create_services: None,
+        min_canary_replicas: None,
+        min_stable_replicas: None,
+        keep_stable_at_full_scale: None,
+        extra_canaries: None,
+        max_weight_step: None,
+        min_initial_weight: None,
+        on_complete: None,
+        replica_surge: None,
+        max_surge: None,
+        sticky_cookie: None,
+    }
+}
+
+impl RolloutBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.rollout.metadata.name = Some(name.to_string());
+        self
+    }
+
+    pub fn with_namespace(mut self, namespace: &str) -> Self {
+        self.rollout.metadata.namespace = Some(namespace.to_string());
+        self
+    }
+
+    pub fn with_replicas(mut self, replicas: i32) -> Self {
+        self.rollout.spec.replicas = replicas;
+        self
+    }
+
+    /// Replace the pod template (the default has no spec, so no containers)
+    pub fn with_template(mut self, template: PodTemplateSpec) -> Self {
+        self.rollout.spec.template = template;
+        self
+    }
+
+    /// Replace the strategy with a canary strategy (keeps no previous steps)
+    pub fn with_canary_strategy(mut self, stable_svc: &str, canary_svc: &str) -> Self {
+        self.rollout.spec.strategy = RolloutStrategy {
+            simple: None,
+            canary: Some(canary_strategy(stable_svc, canary_svc)),
+            blue_green: None,
+        };
+        self
+    }
+
+    /// Append a canary step; `pause` is a duration like "30s" (None = no pause)
+    pub fn with_step(self, weight: i32, pause: Option<&str>) -> Self {
+        self.push_step(
+            weight,
+            pause.map(|duration| PauseDuration {
+                duration: Some(duration.to_string()),
+            }),
+        )
+    }
+
+    /// Append a canary step with an indefinite pause (manual promotion)
+    pub fn with_indefinite_pause_step(self, weight: i32) -> Self {
+        self.push_step(weight, Some(PauseDuration { duration: None }))
+    }
+
+    /// Route traffic through a Gateway API HTTPRoute
+    pub fn with_traffic_routing(mut self, http_route: &str) -> Self {
+        if let Some(canary) = self.rollout.spec.strategy.canary.as_mut() {
+            canary.traffic_routing = Some(TrafficRouting {
+                gateway_api: Some(GatewayAPIRouting {
+                    http_route: http_route.to_string(),
+                    gateway_api_version: None,
+                    verify_accepted: None,
+                    canary_match: None,
+                }),
+                linkerd: None,
+            });
+        }
+        self
+    }
+
+    /// Attach rollout-level metrics analysis
+    pub fn with_analysis(mut self, analysis: AnalysisConfig) -> Self {
+        if let Some(canary) = self.rollout.spec.strategy.canary.as_mut() {
+            canary.analysis = Some(analysis);
+        }
+        self
+    }
+
+    /// Set step index, weight and phase (other status fields default)
+    pub fn with_status(mut self, step: i32, weight: i32, phase: Phase) -> Self {
+        let status = self
+            .rollout
+            .status
+            .get_or_insert_with(RolloutStatus::default);
+        status.current_step_index = Some(step);
+        status.current_weight = Some(weight);
+        status.phase = Some(phase);
+        self
+    }
+
+    /// Set only the current step index (other status fields default)
+    pub fn with_step_index(mut self, step: i32) -> Self {
+        let status = self
+            .rollout
+            .status
+            .get_or_insert_with(RolloutStatus::default);
+        status.current_step_index = Some(step);
+        self
+    }
+
+    /// Set only the phase (other status fields untouched)
+    pub fn with_phase(mut self, phase: Phase) -> Self {
+        self.rollout
+            .status
+            .get_or_insert_with(RolloutStatus::default)
+            .phase = Some(phase);
+        self
+    }
+
+    /// Set when the current step started (RFC3339)
+    pub fn with_step_start_time(mut self, time: &str) -> Self {
+        self.rollout
+            .status
+            .get_or_insert_with(RolloutStatus::default)
+            .step_start_time = Some(time.to_string());
+        self
+    }
+
+    pub fn with_annotation(mut self, key: &str, value: &str) -> Self {
+        self.rollout
+            .metadata
+            .annotations
+            .get_or_insert_with(Default::default)
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn build(self) -> Rollout {
+        self.rollout
+    }
+
+    fn push_step(mut self, weight: i32, pause: Option<PauseDuration>) -> Self {
+        if let Some(canary) = self.rollout.spec.strategy.canary.as_mut() {
+            canary.steps.push(CanaryStep {
+                set_weight: Some(weight),
+                pause,
+                analysis: None,
+            });
+        }
+        self
+    }
+}
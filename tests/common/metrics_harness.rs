@@ -0,0 +1,99 @@
+//! Test harness for asserting Prometheus metric values
+//!
+//! Included into unit tests via `#[path]`, so paths are relative to the
+//! `kulta` crate root.
+//!
+//! ```ignore
+//! let harness = MetricsTestHarness::new();
+//! let values = harness.run(|metrics| metrics.record_reconciliation_skipped());
+//! assert_eq!(values["kulta_reconciliations_total{result=\"skipped\"}"], 1.0);
+//! ```
+
+#![allow(dead_code)] // Not every test file uses every harness method
+
+use crate::server::ControllerMetrics;
+use prometheus::proto::MetricType;
+use prometheus::Registry;
+use std::collections::HashMap;
+
+/// Runs code against a `ControllerMetrics` backed by an isolated registry
+///
+/// Each harness owns a fresh `Registry`, so parallel tests never observe
+/// each other's counters.
+pub struct MetricsTestHarness {
+    registry: Registry,
+    metrics: ControllerMetrics,
+}
+
+impl MetricsTestHarness {
+    #[allow(clippy::expect_used)] // Test helper - panicking is acceptable
+    pub fn new() -> Self {
+        let registry = Registry::new();
+        let metrics = ControllerMetrics::with_registry(registry.clone())
+            .expect("metrics should register into a fresh registry");
+        Self { registry, metrics }
+    }
+
+    pub fn metrics(&self) -> &ControllerMetrics {
+        &self.metrics
+    }
+
+    /// Run `f` against the metrics, then scrape the registry
+    pub fn run<F: FnOnce(&ControllerMetrics)>(&self, f: F) -> HashMap<String, f64> {
+        f(&self.metrics);
+        self.scrape()
+    }
+
+    /// Current value of every sample, keyed like the text exposition format
+    ///
+    /// Counters and gauges are keyed `name{label="value",...}` (just `name`
+    /// without labels). Histograms contribute `name_count{...}` and
+    /// `name_sum{...}`; buckets are left out.
+    pub fn scrape(&self) -> HashMap<String, f64> {
+        let mut samples = HashMap::new();
+        for family in self.registry.gather() {
+            let name = family.get_name();
+            for metric in family.get_metric() {
+                let labels = metric
+                    .get_label()
+                    .iter()
+                    .map(|pair| format!("{}=\"{}\"", pair.get_name(), pair.get_value()))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let key = |suffix: &str| {
+                    if labels.is_empty() {
+                        format!("{}{}", name, suffix)
+                    } else {
+                        format!("{}{}{{{}}}", name, suffix, labels)
+                    }
+                };
+                match family.get_field_type() {
+                    MetricType::COUNTER => {
+                        samples.insert(key(""), metric.get_counter().get_value());
+                    }
+                    MetricType::GAUGE => {
+                        samples.insert(key(""), metric.get_gauge().get_value());
+                    }
+                    MetricType::HISTOGRAM => {
+                        let histogram = metric.get_histogram();
+                        samples.insert(key("_count"), histogram.get_sample_count() as f64);
+                        samples.insert(key("_sum"), histogram.get_sample_sum());
+                    }
+                    _ => {}
+                }
+            }
+        }
+        samples
+    }
+
+    /// Value of one sample, 0.0 if it was never recorded
+    pub fn value(&self, key: &str) -> f64 {
+        self.scrape().get(key).copied().unwrap_or(0.0)
+    }
+}
+
+impl Default for MetricsTestHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,6 +1,6 @@
 //! Assertion helpers for progressive deployment validation
 
-use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
 use kube::api::Api;
 use std::error::Error;
 
@@ -28,6 +28,30 @@ pub async fn assert_replicas(
     Ok(())
 }
 
+/// Assert ReplicaSet has expected spec.replicas
+pub async fn assert_replicaset_replicas(
+    client: &kube::Client,
+    namespace: &str,
+    replicaset_name: &str,
+    expected_replicas: i32,
+) -> Result<(), Box<dyn Error>> {
+    let replicasets: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+    let replicaset = replicasets.get(replicaset_name).await?;
+
+    let actual = replicaset.spec.and_then(|s| s.replicas).unwrap_or(0);
+
+    if actual != expected_replicas {
+        return Err(format!(
+            "ReplicaSet {}: expected {} replicas, got {}",
+            replicaset_name, expected_replicas, actual
+        )
+        .into());
+    }
+
+    println!("✅ ReplicaSet {} has {} replicas", replicaset_name, actual);
+    Ok(())
+}
+
 /// Assert HTTPRoute has correct traffic weights
 pub async fn assert_traffic_split(
     client: &kube::Client,
@@ -1,8 +1,11 @@
 //! Kubernetes resource helpers
 
 use k8s_openapi::api::core::v1::Namespace;
-use kube::api::{Api, DeleteParams, ObjectMeta, PostParams};
+use k8s_openapi::NamespaceResourceScope;
+use kube::api::{Api, DeleteParams, ObjectMeta, Patch, PatchParams, PostParams};
+use serde::de::DeserializeOwned;
 use std::error::Error;
+use std::fmt::Debug;
 
 /// Create a namespace
 pub async fn create_namespace(client: &kube::Client, name: &str) -> Result<(), Box<dyn Error>> {
@@ -39,6 +42,181 @@ pub async fn delete_namespace(client: &kube::Client, name: &str) -> Result<(), B
     }
 }
 
+/// Retry policy for namespace teardown
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total attempts before giving up
+    pub max_attempts: u32,
+    /// Delay before the second attempt, doubled after each further failure
+    pub initial_delay: std::time::Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_delay: std::time::Duration::from_secs(2),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay after a failed attempt (1-indexed): initial_delay * 2^(attempt-1)
+    pub fn delay_after(&self, attempt: u32) -> std::time::Duration {
+        self.initial_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+    }
+}
+
+/// Run an operation until it succeeds or the policy's attempts are exhausted
+///
+/// The operation receives the 1-indexed attempt number. Returns the last
+/// error if every attempt fails.
+pub async fn retry_with_backoff<T, F, Fut>(
+    policy: &RetryPolicy,
+    mut operation: F,
+) -> Result<T, Box<dyn Error>>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: std::future::Future<Output = Result<T, Box<dyn Error>>>,
+{
+    let mut attempt = 1;
+    loop {
+        match operation(attempt).await {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt >= policy.max_attempts => return Err(e),
+            Err(e) => {
+                let delay = policy.delay_after(attempt);
+                println!(
+                    "⏳ Attempt {}/{} failed: {} (retrying in {:?})",
+                    attempt, policy.max_attempts, e, delay
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Delete a namespace and wait for it to disappear, retrying with backoff
+///
+/// Namespaces with stuck terminating resources can linger after the delete
+/// request succeeds. After the last failed attempt the remaining Pods,
+/// ReplicaSets and Rollouts are logged for debugging.
+pub async fn delete_namespace_with_retry(
+    client: &kube::Client,
+    name: &str,
+    policy: &RetryPolicy,
+) -> Result<(), Box<dyn Error>> {
+    let ns: Api<Namespace> = Api::all(client.clone());
+    let ns = &ns;
+
+    let result = retry_with_backoff(policy, move |_| async move {
+        delete_namespace(client, name).await?;
+
+        match ns.get_opt(name).await? {
+            None => Ok(()),
+            Some(_) => Err(format!("namespace {} is still terminating", name).into()),
+        }
+    })
+    .await;
+
+    if let Err(e) = &result {
+        println!(
+            "❌ Namespace {} not deleted after {} attempts: {}",
+            name, policy.max_attempts, e
+        );
+        log_namespace_resources(client, name).await;
+    }
+
+    result
+}
+
+/// Log Pods, ReplicaSets and Rollouts remaining in a namespace
+pub async fn log_namespace_resources(client: &kube::Client, namespace: &str) {
+    use k8s_openapi::api::apps::v1::ReplicaSet;
+    use k8s_openapi::api::core::v1::Pod;
+    use kube::api::ListParams;
+    use kube::ResourceExt;
+    use kulta::crd::rollout::Rollout;
+
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    match pods.list(&ListParams::default()).await {
+        Ok(list) => {
+            for pod in list.items {
+                let terminating = pod.metadata.deletion_timestamp.is_some();
+                println!("   Pod {} (terminating: {})", pod.name_any(), terminating);
+            }
+        }
+        Err(e) => println!("   Failed to list Pods: {}", e),
+    }
+
+    let replicasets: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+    match replicasets.list(&ListParams::default()).await {
+        Ok(list) => {
+            for rs in list.items {
+                println!("   ReplicaSet {}", rs.name_any());
+            }
+        }
+        Err(e) => println!("   Failed to list ReplicaSets: {}", e),
+    }
+
+    let rollouts: Api<Rollout> = Api::namespaced(client.clone(), namespace);
+    match rollouts.list(&ListParams::default()).await {
+        Ok(list) => {
+            for rollout in list.items {
+                println!("   Rollout {}", rollout.name_any());
+            }
+        }
+        Err(e) => println!("   Failed to list Rollouts: {}", e),
+    }
+}
+
+/// Force delete Pods stuck in Terminating
+///
+/// Runs `kubectl delete pod --force --grace-period=0` for every Pod that
+/// already has a deletion timestamp.
+pub async fn force_delete_stuck_pods(
+    client: &kube::Client,
+    namespace: &str,
+) -> Result<(), Box<dyn Error>> {
+    use k8s_openapi::api::core::v1::Pod;
+    use kube::api::ListParams;
+    use kube::ResourceExt;
+    use std::process::Command;
+
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let stuck = pods
+        .list(&ListParams::default())
+        .await?
+        .items
+        .into_iter()
+        .filter(|pod| pod.metadata.deletion_timestamp.is_some());
+
+    for pod in stuck {
+        let name = pod.name_any();
+        println!("🔨 Force deleting stuck pod: {}/{}", namespace, name);
+
+        let status = Command::new("kubectl")
+            .args([
+                "delete",
+                "pod",
+                &name,
+                "-n",
+                namespace,
+                "--force",
+                "--grace-period=0",
+            ])
+            .status()?;
+
+        if !status.success() {
+            return Err(format!("Failed to force delete pod {}/{}", namespace, name).into());
+        }
+    }
+
+    Ok(())
+}
+
 /// Wait for deployment to be ready
 pub async fn wait_for_deployment(
     client: &kube::Client,
@@ -117,3 +295,162 @@ pub async fn wait_for_httproute(
         sleep(Duration::from_secs(1)).await;
     }
 }
+
+/// Apply a JSON merge patch to a namespaced resource
+pub async fn patch<K>(
+    client: &kube::Client,
+    namespace: &str,
+    name: &str,
+    patch: &serde_json::Value,
+) -> Result<(), Box<dyn Error>>
+where
+    K: kube::Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
+    K::DynamicType: Default,
+{
+    let api: Api<K> = Api::namespaced(client.clone(), namespace);
+    api.patch(name, &PatchParams::default(), &Patch::Merge(patch))
+        .await?;
+
+    Ok(())
+}
+
+/// Check if a Rollout status matches a condition
+///
+/// Matches an entry in `status.conditions` with the given type and status.
+/// Rollouts without conditions fall back to a top-level status field of the
+/// same name (e.g. `("phase", "Completed")`).
+pub fn rollout_condition_matches(
+    rollout: &kulta::crd::rollout::Rollout,
+    condition_type: &str,
+    status: &str,
+) -> bool {
+    let rollout_status = match rollout
+        .status
+        .as_ref()
+        .and_then(|s| serde_json::to_value(s).ok())
+    {
+        Some(value) => value,
+        None => return false,
+    };
+
+    let condition = rollout_status
+        .get("conditions")
+        .and_then(|c| c.as_array())
+        .and_then(|conditions| {
+            conditions
+                .iter()
+                .find(|c| c.get("type").and_then(|t| t.as_str()) == Some(condition_type))
+        });
+
+    match condition {
+        Some(condition) => condition.get("status").and_then(|s| s.as_str()) == Some(status),
+        None => rollout_status.get(condition_type).and_then(|v| v.as_str()) == Some(status),
+    }
+}
+
+/// Wait until a Rollout condition matches, returning the matching Rollout
+pub async fn wait_for_rollout_condition(
+    client: &kube::Client,
+    namespace: &str,
+    name: &str,
+    condition_type: &str,
+    status: &str,
+    timeout_secs: u64,
+) -> Result<kulta::crd::rollout::Rollout, Box<dyn Error>> {
+    use kulta::crd::rollout::Rollout;
+    use std::time::Duration;
+    use tokio::time::sleep;
+
+    let rollouts: Api<Rollout> = Api::namespaced(client.clone(), namespace);
+    let start = std::time::Instant::now();
+
+    loop {
+        match rollouts.get(name).await {
+            Ok(rollout) => {
+                if rollout_condition_matches(&rollout, condition_type, status) {
+                    println!("✅ Rollout {}: {}={}", name, condition_type, status);
+                    return Ok(rollout);
+                }
+
+                if start.elapsed().as_secs() > timeout_secs {
+                    return Err(format!(
+                        "timeout waiting for Rollout {} {}={} (status: {:?})",
+                        name, condition_type, status, rollout.status
+                    )
+                    .into());
+                }
+            }
+            Err(kube::Error::Api(err)) if err.code == 404 => {
+                // Not found yet, keep waiting
+                if start.elapsed().as_secs() > timeout_secs {
+                    return Err(format!("timeout waiting for Rollout: {}", name).into());
+                }
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        sleep(Duration::from_millis(500)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    fn instant_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            initial_delay: Duration::ZERO,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_after_max_attempts() {
+        let attempts = Cell::new(0);
+
+        let result: Result<(), _> = retry_with_backoff(&instant_policy(5), |_| {
+            attempts.set(attempts.get() + 1);
+            async { Err("namespace still terminating".into()) }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            attempts.get(),
+            5,
+            "should attempt exactly max_attempts times"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_stops_after_success() {
+        let attempts = Cell::new(0);
+
+        let result = retry_with_backoff(&instant_policy(5), |attempt| {
+            attempts.set(attempts.get() + 1);
+            async move {
+                if attempt < 3 {
+                    Err("not yet".into())
+                } else {
+                    Ok(attempt)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.ok(), Some(3));
+        assert_eq!(attempts.get(), 3);
+    }
+
+    #[test]
+    fn test_retry_policy_backoff_doubles() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(policy.max_attempts, 5);
+        assert_eq!(policy.delay_after(1), Duration::from_secs(2));
+        assert_eq!(policy.delay_after(2), Duration::from_secs(4));
+        assert_eq!(policy.delay_after(4), Duration::from_secs(16));
+    }
+}
@@ -133,6 +133,63 @@ impl TestContext {
         })
     }
 
+    /// Apply a JSON merge patch to a resource in the test namespace
+    pub async fn patch<K>(&self, name: &str, patch: &serde_json::Value) -> TestResult
+    where
+        K: kube::Resource<Scope = k8s_openapi::NamespaceResourceScope>
+            + Clone
+            + serde::de::DeserializeOwned
+            + std::fmt::Debug,
+        K::DynamicType: Default,
+    {
+        k8s::patch::<K>(&self.client, &self.namespace, name, patch).await
+    }
+
+    /// Set an annotation on a Rollout (e.g. `kulta.io/promote`)
+    pub async fn patch_annotation(&self, name: &str, key: &str, value: &str) -> TestResult {
+        let patch = serde_json::json!({
+            "metadata": {
+                "annotations": {
+                    key: value
+                }
+            }
+        });
+        self.patch::<kulta::crd::rollout::Rollout>(name, &patch)
+            .await
+    }
+
+    /// Remove an annotation from a Rollout
+    pub async fn remove_annotation(&self, name: &str, key: &str) -> TestResult {
+        let patch = serde_json::json!({
+            "metadata": {
+                "annotations": {
+                    key: null
+                }
+            }
+        });
+        self.patch::<kulta::crd::rollout::Rollout>(name, &patch)
+            .await
+    }
+
+    /// Poll until a Rollout condition matches (see `k8s::rollout_condition_matches`)
+    pub async fn wait_for_condition(
+        &self,
+        name: &str,
+        condition_type: &str,
+        status: &str,
+        timeout_secs: u64,
+    ) -> Result<kulta::crd::rollout::Rollout, Box<dyn Error>> {
+        k8s::wait_for_rollout_condition(
+            &self.client,
+            &self.namespace,
+            name,
+            condition_type,
+            status,
+            timeout_secs,
+        )
+        .await
+    }
+
     /// Cleanup test resources
     pub async fn cleanup(&self, config: &TestConfig) -> Result<(), Box<dyn Error>> {
         // Delete test namespace
@@ -145,6 +202,32 @@ impl TestContext {
 
         Ok(())
     }
+
+    /// Cleanup test resources, forcing out pods stuck in Terminating
+    ///
+    /// Retries namespace deletion with backoff; if it still lingers,
+    /// force deletes stuck pods and retries once more.
+    pub async fn cleanup_with_force(&self, config: &TestConfig) -> Result<(), Box<dyn Error>> {
+        let policy = k8s::RetryPolicy::default();
+
+        if let Err(e) =
+            k8s::delete_namespace_with_retry(&self.client, &self.namespace, &policy).await
+        {
+            println!(
+                "⚠️  Namespace {} stuck ({}), force deleting terminating pods",
+                self.namespace, e
+            );
+            k8s::force_delete_stuck_pods(&self.client, &self.namespace).await?;
+            k8s::delete_namespace_with_retry(&self.client, &self.namespace, &policy).await?;
+        }
+
+        // Optionally cleanup cluster
+        if config.cluster.cleanup {
+            cluster::delete_cluster(&config.cluster).await?;
+        }
+
+        Ok(())
+    }
 }
 
 /// Trait for test scenarios
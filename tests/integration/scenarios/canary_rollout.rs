@@ -322,9 +322,6 @@ async fn create_httproute(ctx: &TestContext) -> TestResult {
 /// Update HTTPRoute traffic split
 async fn update_traffic_split(ctx: &TestContext, canary_weight: i32) -> TestResult {
     use gateway_api::apis::standard::httproutes::HTTPRoute;
-    use kube::api::{Patch, PatchParams};
-
-    let routes: Api<HTTPRoute> = Api::namespaced(ctx.client.clone(), &ctx.namespace);
 
     let stable_weight = 100 - canary_weight;
 
@@ -351,9 +348,5 @@ async fn update_traffic_split(ctx: &TestContext, canary_weight: i32) -> TestResu
         }
     });
 
-    routes
-        .patch("app-route", &PatchParams::default(), &Patch::Merge(&patch))
-        .await?;
-
-    Ok(())
+    ctx.patch::<HTTPRoute>("app-route", &patch).await
 }
@@ -1,5 +1,7 @@
 //! Test scenarios for KULTA progressive deployment
 
 pub mod canary_rollout;
+pub mod rollback;
 
 pub use canary_rollout::CanaryRolloutScenario;
+pub use rollback::RollbackScenario;
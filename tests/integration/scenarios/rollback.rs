@@ -0,0 +1,294 @@
+//! Rollback scenario - failed analysis drains the canary back to stable
+
+use crate::integration::framework::{assertions, k8s, TestContext, TestResult, TestScenario};
+use crate::integration::TestConfig;
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::core::v1::{ConfigMap, Service};
+use kube::api::{Api, PostParams};
+use kulta::crd::rollout::{DecisionAction, Rollout};
+
+const ROLLOUT_NAME: &str = "rollback-app";
+const STABLE_SERVICE: &str = "rollback-app-stable";
+const CANARY_SERVICE: &str = "rollback-app-canary";
+const ROUTE_NAME: &str = "rollback-app-route";
+const MOCK_PROMETHEUS: &str = "mock-prometheus";
+
+/// Error rate threshold configured on the rollout's analysis
+const ERROR_RATE_THRESHOLD: f64 = 0.05;
+
+/// Error rate the mock Prometheus reports (well above the threshold)
+const MOCK_ERROR_RATE: f64 = 0.5;
+
+pub struct RollbackScenario;
+
+#[async_trait::async_trait]
+impl TestScenario for RollbackScenario {
+    fn name(&self) -> &str {
+        "rollback_on_error"
+    }
+
+    async fn run(&self, ctx: &mut TestContext) -> TestResult {
+        println!("\n⏪ Testing Rollback on Failed Analysis");
+        println!("======================================\n");
+
+        // Step 1: Mock Prometheus that always reports a high error rate
+        println!(
+            "📈 Step 1: Deploying mock Prometheus (error rate {})...",
+            MOCK_ERROR_RATE
+        );
+        deploy_mock_prometheus(ctx, MOCK_ERROR_RATE).await?;
+        k8s::wait_for_deployment(
+            &ctx.client,
+            &ctx.namespace,
+            MOCK_PROMETHEUS,
+            ctx.config.timeouts.deployment_ready,
+        )
+        .await?;
+
+        // Step 2: Services and HTTPRoute managed by the rollout
+        println!("\n📡 Step 2: Creating services and HTTPRoute...");
+        create_services(ctx).await?;
+        create_httproute(ctx).await?;
+        k8s::wait_for_httproute(
+            &ctx.client,
+            &ctx.namespace,
+            ROUTE_NAME,
+            ctx.config.timeouts.route_ready,
+        )
+        .await?;
+
+        // Step 3: Rollout with analysis against the mock
+        println!("\n🐤 Step 3: Creating canary Rollout with analysis...");
+        create_rollout(ctx).await?;
+
+        // Step 4: Analysis fails → RollingBack → Degraded (rollback finished)
+        println!("\n⏳ Step 4: Waiting for analysis to roll the canary back...");
+        let rollout = ctx
+            .wait_for_condition(
+                ROLLOUT_NAME,
+                "phase",
+                "Degraded",
+                ctx.config.timeouts.deployment_ready,
+            )
+            .await?;
+
+        // Step 5: Verify the canary is fully drained
+        println!("\n🔍 Step 5: Verifying rollback...");
+        assertions::assert_replicaset_replicas(
+            &ctx.client,
+            &ctx.namespace,
+            &format!("{}-stable", ROLLOUT_NAME),
+            ctx.config.deployment.replicas,
+        )
+        .await?;
+        assertions::assert_replicaset_replicas(
+            &ctx.client,
+            &ctx.namespace,
+            &format!("{}-canary", ROLLOUT_NAME),
+            0,
+        )
+        .await?;
+        assertions::assert_traffic_split(&ctx.client, &ctx.namespace, ROUTE_NAME, 100, 0).await?;
+        assert_rollback_decision(&rollout)?;
+
+        println!("\n✅ Canary rolled back successfully!\n");
+        Ok(())
+    }
+
+    fn should_skip(&self, config: &TestConfig) -> bool {
+        !config.scenarios.rollback_on_error
+    }
+}
+
+/// Assert status.decisions records the rollback
+fn assert_rollback_decision(rollout: &Rollout) -> TestResult {
+    let decisions = rollout
+        .status
+        .as_ref()
+        .map(|s| s.decisions.as_slice())
+        .unwrap_or_default();
+
+    if !decisions
+        .iter()
+        .any(|d| d.action == DecisionAction::Rollback)
+    {
+        return Err(format!(
+            "Rollout {}: no Rollback decision in status.decisions ({:?})",
+            ROLLOUT_NAME, decisions
+        )
+        .into());
+    }
+
+    println!("✅ Rollback decision recorded");
+    Ok(())
+}
+
+/// Deploy an nginx that answers every instant query with a fixed value
+async fn deploy_mock_prometheus(ctx: &TestContext, value: f64) -> TestResult {
+    let response = serde_json::json!({
+        "status": "success",
+        "data": {
+            "resultType": "vector",
+            "result": [{ "metric": {}, "value": [0, value.to_string()] }]
+        }
+    });
+    let nginx_conf = format!(
+        "server {{\n  listen 9090;\n  location /api/v1/query {{\n    default_type application/json;\n    return 200 '{}';\n  }}\n}}\n",
+        response
+    );
+
+    let config_map: ConfigMap = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": MOCK_PROMETHEUS, "namespace": ctx.namespace },
+        "data": { "default.conf": nginx_conf }
+    }))?;
+
+    let labels = serde_json::json!({ "app": MOCK_PROMETHEUS });
+    let deployment: Deployment = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": MOCK_PROMETHEUS, "namespace": ctx.namespace },
+        "spec": {
+            "replicas": 1,
+            "selector": { "matchLabels": labels },
+            "template": {
+                "metadata": { "labels": labels },
+                "spec": {
+                    "containers": [{
+                        "name": "nginx",
+                        "image": ctx.config.deployment.stable_image,
+                        "ports": [{ "containerPort": 9090 }],
+                        "volumeMounts": [{
+                            "name": "config",
+                            "mountPath": "/etc/nginx/conf.d"
+                        }]
+                    }],
+                    "volumes": [{
+                        "name": "config",
+                        "configMap": { "name": MOCK_PROMETHEUS }
+                    }]
+                }
+            }
+        }
+    }))?;
+
+    let service: Service = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": MOCK_PROMETHEUS, "namespace": ctx.namespace },
+        "spec": {
+            "selector": labels,
+            "ports": [{ "port": 9090 }]
+        }
+    }))?;
+
+    let config_maps: Api<ConfigMap> = Api::namespaced(ctx.client.clone(), &ctx.namespace);
+    config_maps
+        .create(&PostParams::default(), &config_map)
+        .await?;
+
+    let deployments: Api<Deployment> = Api::namespaced(ctx.client.clone(), &ctx.namespace);
+    deployments
+        .create(&PostParams::default(), &deployment)
+        .await?;
+
+    let services: Api<Service> = Api::namespaced(ctx.client.clone(), &ctx.namespace);
+    services.create(&PostParams::default(), &service).await?;
+
+    Ok(())
+}
+
+/// Create the stable and canary services referenced by the rollout
+async fn create_services(ctx: &TestContext) -> TestResult {
+    let services: Api<Service> = Api::namespaced(ctx.client.clone(), &ctx.namespace);
+
+    for name in [STABLE_SERVICE, CANARY_SERVICE] {
+        let service: Service = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": name, "namespace": ctx.namespace },
+            "spec": {
+                "selector": { "app": ROLLOUT_NAME },
+                "ports": [{ "port": 80 }]
+            }
+        }))?;
+        services.create(&PostParams::default(), &service).await?;
+    }
+
+    Ok(())
+}
+
+/// Create the HTTPRoute the rollout shifts traffic on
+async fn create_httproute(ctx: &TestContext) -> TestResult {
+    use gateway_api::apis::standard::httproutes::HTTPRoute;
+
+    let backend = |name: &str, weight: i32| {
+        serde_json::json!({
+            "name": name,
+            "port": 80,
+            "weight": weight,
+            "kind": "Service",
+            "group": ""
+        })
+    };
+
+    let httproute: HTTPRoute = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": ROUTE_NAME, "namespace": ctx.namespace },
+        "spec": {
+            "rules": [{
+                "name": "default",
+                "backendRefs": [backend(STABLE_SERVICE, 100), backend(CANARY_SERVICE, 0)]
+            }]
+        }
+    }))?;
+
+    let routes: Api<HTTPRoute> = Api::namespaced(ctx.client.clone(), &ctx.namespace);
+    routes.create(&PostParams::default(), &httproute).await?;
+
+    Ok(())
+}
+
+/// Create a canary Rollout whose analysis queries the mock Prometheus
+async fn create_rollout(ctx: &TestContext) -> TestResult {
+    let labels = serde_json::json!({ "app": ROLLOUT_NAME });
+    let prometheus_address = format!("http://{}.{}.svc:9090", MOCK_PROMETHEUS, ctx.namespace);
+
+    let rollout: Rollout = serde_json::from_value(serde_json::json!({
+        "apiVersion": "kulta.io/v1alpha1",
+        "kind": "Rollout",
+        "metadata": { "name": ROLLOUT_NAME, "namespace": ctx.namespace },
+        "spec": {
+            "replicas": ctx.config.deployment.replicas,
+            "selector": { "matchLabels": labels },
+            "template": {
+                "metadata": { "labels": labels },
+                "spec": {
+                    "containers": [{
+                        "name": "app",
+                        "image": ctx.config.deployment.canary_image,
+                        "ports": [{ "containerPort": 80 }]
+                    }]
+                }
+            },
+            "strategy": {
+                "canary": {
+                    "stableService": STABLE_SERVICE,
+                    "canaryService": CANARY_SERVICE,
+                    "steps": [
+                        { "setWeight": 20 },
+                        { "pause": { "duration": "5m" } },
+                        { "setWeight": 100 }
+                    ],
+                    "trafficRouting": {
+                        "gatewayAPI": { "httpRoute": ROUTE_NAME }
+                    },
+                    "analysis": {
+                        "prometheus": { "address": prometheus_address },
+                        "metrics": [{
+                            "name": "error-rate",
+                            "threshold": ERROR_RATE_THRESHOLD
+                        }]
+                    }
+                }
+            }
+        }
+    }))?;
+
+    let rollouts: Api<Rollout> = Api::namespaced(ctx.client.clone(), &ctx.namespace);
+    rollouts.create(&PostParams::default(), &rollout).await?;
+
+    Ok(())
+}
@@ -6,7 +6,7 @@
 
 mod integration;
 
-use integration::scenarios::CanaryRolloutScenario;
+use integration::scenarios::{CanaryRolloutScenario, RollbackScenario};
 use integration::{TestConfig, TestContext, TestScenario};
 
 #[tokio::test]
@@ -30,6 +30,7 @@ async fn run_integration_tests() {
     // Register scenarios
     let scenarios: Vec<Box<dyn TestScenario>> = vec![
         Box::new(CanaryRolloutScenario),
+        Box::new(RollbackScenario),
         // Add more scenarios here as they're implemented
     ];
 
@@ -64,7 +65,9 @@ async fn run_integration_tests() {
     }
 
     // Cleanup
-    ctx.cleanup(&config).await.expect("Cleanup failed");
+    ctx.cleanup_with_force(&config)
+        .await
+        .expect("Cleanup failed");
 
     // Print summary
     println!("\n📊 Summary");
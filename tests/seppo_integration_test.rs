@@ -5,7 +5,7 @@
 //!
 //! Requirements:
 //! - Kind cluster running (or any K8s cluster)
-//! - KULTA CRD installed: kubectl apply -f <(cargo run --bin gen-crd)
+//! - KULTA CRD installed: kubectl apply -f config/crds/rollout.yaml
 //! - Gateway API CRDs installed
 //! - KULTA controller running
 
@@ -19,6 +19,7 @@ use k8s_openapi::api::core::v1::Service;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
 use kube::api::{ObjectMeta, Patch, PatchParams};
 use kube::Api;
+use kulta::controller::rollout::promote_annotation_key;
 use kulta::crd::rollout::{
     BlueGreenStrategy, CanaryStep, CanaryStrategy, PauseDuration, Phase, Rollout, RolloutSpec,
     RolloutStrategy, SimpleStrategy, TrafficRouting,
@@ -229,29 +230,59 @@ async fn test_canary_full_lifecycle(ctx: TestContext) {
                 blue_green: None,
                 canary: Some(CanaryStrategy {
                     stable_service: format!("{}-stable", name),
+                    mirror_service: None,
                     canary_service: format!("{}-canary", name),
                     steps: vec![
                         CanaryStep {
                             set_weight: Some(25),
                             pause: None,
+                            analysis: None,
                         },
                         CanaryStep {
                             set_weight: Some(50),
                             pause: None,
+                            analysis: None,
                         },
                         CanaryStep {
                             set_weight: Some(75),
                             pause: None,
+                            analysis: None,
                         },
                     ],
                     traffic_routing: Some(TrafficRouting {
                         gateway_api: Some(kulta::crd::rollout::GatewayAPIRouting {
                             http_route: name.to_string(),
+                            gateway_api_version: None,
+                            verify_accepted: None,
+                            canary_match: None,
                         }),
+                        linkerd: None,
                     }),
                     analysis: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -367,21 +398,46 @@ async fn test_canary_pause_and_promote(ctx: TestContext) {
                 blue_green: None,
                 canary: Some(CanaryStrategy {
                     stable_service: format!("{}-stable", name),
+                    mirror_service: None,
                     canary_service: format!("{}-canary", name),
                     steps: vec![
                         CanaryStep {
                             set_weight: Some(30),
                             pause: Some(PauseDuration { duration: None }), // Manual pause
+                            analysis: None,
                         },
                         CanaryStep {
                             set_weight: Some(100),
                             pause: None,
+                            analysis: None,
                         },
                     ],
                     traffic_routing: None,
                     analysis: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -409,10 +465,11 @@ async fn test_canary_pause_and_promote(ctx: TestContext) {
 
     // ACT: Add promote annotation
     let rollout_api: Api<Rollout> = Api::namespaced(ctx.client.clone(), &ctx.namespace);
+    let promote_key = promote_annotation_key();
     let patch = serde_json::json!({
         "metadata": {
             "annotations": {
-                "rollouts.kulta.io/promote": "true"
+                promote_key: "true"
             }
         }
     });
@@ -468,15 +525,39 @@ async fn test_status_decisions_tracking(ctx: TestContext) {
                 blue_green: None,
                 canary: Some(CanaryStrategy {
                     stable_service: format!("{}-stable", name),
+                    mirror_service: None,
                     canary_service: format!("{}-canary", name),
                     steps: vec![CanaryStep {
                         set_weight: Some(50),
                         pause: None,
+                        analysis: None,
                     }],
                     traffic_routing: None,
                     analysis: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -556,8 +637,13 @@ async fn test_blue_green_promotion(ctx: TestContext) {
                     auto_promotion_seconds: None,
                     traffic_routing: None,
                     analysis: None,
+                    max_duration_seconds: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -597,10 +683,11 @@ async fn test_blue_green_promotion(ctx: TestContext) {
 
     // ACT: Promote
     let rollout_api: Api<Rollout> = Api::namespaced(ctx.client.clone(), &ctx.namespace);
+    let promote_key = promote_annotation_key();
     let patch = serde_json::json!({
         "metadata": {
             "annotations": {
-                "rollouts.kulta.io/promote": "true"
+                promote_key: "true"
             }
         }
     });
@@ -685,8 +772,13 @@ async fn test_blue_green_auto_promotion(ctx: TestContext) {
                     auto_promotion_seconds: Some(5),
                     traffic_routing: None,
                     analysis: None,
+                    max_duration_seconds: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -756,25 +848,54 @@ async fn test_httproute_weight_updates(ctx: TestContext) {
                 blue_green: None,
                 canary: Some(CanaryStrategy {
                     stable_service: format!("{}-stable", name),
+                    mirror_service: None,
                     canary_service: format!("{}-canary", name),
                     steps: vec![
                         CanaryStep {
                             set_weight: Some(30),
                             pause: None,
+                            analysis: None,
                         },
                         CanaryStep {
                             set_weight: Some(70),
                             pause: None,
+                            analysis: None,
                         },
                     ],
                     traffic_routing: Some(TrafficRouting {
                         gateway_api: Some(kulta::crd::rollout::GatewayAPIRouting {
                             http_route: name.to_string(),
+                            gateway_api_version: None,
+                            verify_accepted: None,
+                            canary_match: None,
                         }),
+                        linkerd: None,
                     }),
                     analysis: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -877,6 +998,10 @@ async fn test_simple_strategy_lifecycle(ctx: TestContext) {
                 blue_green: None,
                 simple: Some(SimpleStrategy { analysis: None }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -952,15 +1077,39 @@ async fn test_image_update_triggers_rollout(ctx: TestContext) {
                 blue_green: None,
                 canary: Some(CanaryStrategy {
                     stable_service: format!("{}-stable", name),
+                    mirror_service: None,
                     canary_service: format!("{}-canary", name),
                     steps: vec![CanaryStep {
                         set_weight: Some(50),
                         pause: None,
+                        analysis: None,
                     }],
                     traffic_routing: None,
                     analysis: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
@@ -998,15 +1147,39 @@ async fn test_image_update_triggers_rollout(ctx: TestContext) {
                 blue_green: None,
                 canary: Some(CanaryStrategy {
                     stable_service: format!("{}-stable", name),
+                    mirror_service: None,
                     canary_service: format!("{}-canary", name),
                     steps: vec![CanaryStep {
                         set_weight: Some(50),
                         pause: None,
+                        analysis: None,
                     }],
                     traffic_routing: None,
                     analysis: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };
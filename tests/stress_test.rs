@@ -93,25 +93,51 @@ fn create_rollout(name: &str, namespace: &str, replicas: i32, image: &str) -> Ro
                 blue_green: None,
                 canary: Some(CanaryStrategy {
                     stable_service: format!("{}-stable", name),
+                    mirror_service: None,
                     canary_service: format!("{}-canary", name),
                     steps: vec![
                         CanaryStep {
                             set_weight: Some(25),
                             pause: None,
+                            analysis: None,
                         },
                         CanaryStep {
                             set_weight: Some(50),
                             pause: None,
+                            analysis: None,
                         },
                         CanaryStep {
                             set_weight: Some(75),
                             pause: None,
+                            analysis: None,
                         },
                     ],
                     traffic_routing: None,
                     analysis: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     }
@@ -143,6 +169,7 @@ fn create_rollout_with_pauses(
                 blue_green: None,
                 canary: Some(CanaryStrategy {
                     stable_service: format!("{}-stable", name),
+                    mirror_service: None,
                     canary_service: format!("{}-canary", name),
                     steps: vec![
                         CanaryStep {
@@ -150,24 +177,49 @@ fn create_rollout_with_pauses(
                             pause: Some(PauseDuration {
                                 duration: Some(pause_duration.to_string()),
                             }),
+                            analysis: None,
                         },
                         CanaryStep {
                             set_weight: Some(50),
                             pause: Some(PauseDuration {
                                 duration: Some(pause_duration.to_string()),
                             }),
+                            analysis: None,
                         },
                         CanaryStep {
                             set_weight: Some(75),
                             pause: Some(PauseDuration {
                                 duration: Some(pause_duration.to_string()),
                             }),
+                            analysis: None,
                         },
                     ],
                     traffic_routing: None,
                     analysis: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     }
@@ -797,17 +849,41 @@ async fn test_edge_minimal_steps(ctx: Context) {
                 blue_green: None,
                 canary: Some(CanaryStrategy {
                     stable_service: format!("{}-stable", name),
+                    mirror_service: None,
                     canary_service: format!("{}-canary", name),
                     steps: vec![
                         CanaryStep {
                             set_weight: Some(100),
                             pause: None,
+                            analysis: None,
                         }, // Direct to 100%
                     ],
                     traffic_routing: None,
                     analysis: None,
+                    max_duration_seconds: None,
+                    canary_overrides: None,
+                    canary_suffix: None,
+                    stable_suffix: None,
+                    analysis_only: None,
+                    port_name: None,
+                    pause_at_step: None,
+                    create_services: None,
+                    min_canary_replicas: None,
+                    min_stable_replicas: None,
+                    keep_stable_at_full_scale: None,
+                    extra_canaries: None,
+                    max_weight_step: None,
+                    min_initial_weight: None,
+                    on_complete: None,
+                    replica_surge: None,
+                    max_surge: None,
+                    sticky_cookie: None,
                 }),
             },
+            manage_pod_template_hash: None,
+            quota_aware: None,
+            dry_run: None,
+            paused: None,
         },
         status: None,
     };